@@ -0,0 +1,224 @@
+//! Conversion of command output to the flavour of markdown understood by chat services, which
+//! lack headings, tables and clickable commands.
+
+use initiative_core::app::{Block, Inline, Output};
+
+/// Render the output for a chat message. Commands are shown prefixed with the bot's `trigger` so
+/// that they can be copied and sent as-is.
+pub fn render(output: &Output, trigger: &str) -> String {
+    render_blocks(&output.blocks, trigger)
+}
+
+/// Split a message into parts of at most `max_len` characters, preferring to break between
+/// paragraphs, then between lines, then anywhere at all.
+pub fn split(message: &str, max_len: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut part = String::new();
+
+    for paragraph in message.split("\n\n") {
+        for (i, line) in paragraph.lines().enumerate() {
+            let separator = match (part.is_empty(), i) {
+                (true, _) => "",
+                (false, 0) => "\n\n",
+                (false, _) => "\n",
+            };
+
+            if len(&part) + len(separator) + len(line) <= max_len {
+                part.push_str(separator);
+                part.push_str(line);
+                continue;
+            }
+
+            if !part.is_empty() {
+                parts.push(std::mem::take(&mut part));
+            }
+
+            let mut chars = line.chars().peekable();
+
+            while chars.peek().is_some() {
+                part = chars.by_ref().take(max_len).collect();
+
+                if chars.peek().is_some() {
+                    parts.push(std::mem::take(&mut part));
+                }
+            }
+        }
+    }
+
+    if !part.is_empty() {
+        parts.push(part);
+    }
+
+    parts
+}
+
+fn len(s: &str) -> usize {
+    s.chars().count()
+}
+
+fn render_blocks(blocks: &[Block], trigger: &str) -> String {
+    blocks
+        .iter()
+        .map(|block| render_block(block, trigger))
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn render_block(block: &Block, trigger: &str) -> String {
+    match block {
+        Block::Heading { content, .. } => format!("**{}**", render_inlines(content, trigger)),
+        Block::Paragraph { content } | Block::Error { content } => render_inlines(content, trigger),
+        Block::Warning { content } => format!("⚠️ {}", render_inlines(content, trigger)),
+        Block::List { items } => items
+            .iter()
+            .map(|item| format!("• {}", render_inlines(item, trigger)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Table { header, rows } => {
+            // Chat services don't support tables, so fake one in a code block.
+            let cells = |row: &[Vec<Inline>]| -> Vec<String> {
+                row.iter().map(|cell| plain_text(cell)).collect()
+            };
+
+            let rows: Vec<Vec<String>> = std::iter::once(header)
+                .filter(|header| !header.is_empty())
+                .chain(rows.iter())
+                .map(|row| cells(row))
+                .collect();
+
+            let widths: Vec<usize> = (0..rows.iter().map(Vec::len).max().unwrap_or(0))
+                .map(|i| {
+                    rows.iter()
+                        .filter_map(|row| row.get(i))
+                        .map(|cell| len(cell))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+
+            let lines: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(widths.iter())
+                        .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - len(cell))))
+                        .collect::<Vec<_>>()
+                        .join("  ")
+                        .trim_end()
+                        .to_string()
+                })
+                .collect();
+
+            format!("```\n{}\n```", lines.join("\n"))
+        }
+        Block::Thing { blocks, .. } => render_blocks(blocks, trigger),
+    }
+}
+
+fn render_inlines(inlines: &[Inline], trigger: &str) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text } => escape(text),
+            Inline::Strong { content } => format!("**{}**", render_inlines(content, trigger)),
+            Inline::Emphasis { content } => format!("*{}*", render_inlines(content, trigger)),
+            Inline::Command { command } | Inline::Suggestion { command } => {
+                format!("`{} {}`", trigger, command)
+            }
+            Inline::Link { text, url } => format!("[{}](<{}>)", escape(text), url),
+            Inline::Roll { expression, total } if expression.is_empty() => total.to_string(),
+            Inline::Roll { expression, total } => format!("{} = **{}**", escape(expression), total),
+            Inline::LineBreak => "\n".to_string(),
+        })
+        .collect()
+}
+
+fn plain_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text } => text.clone(),
+            Inline::Strong { content } | Inline::Emphasis { content } => plain_text(content),
+            Inline::Command { command } | Inline::Suggestion { command } => command.clone(),
+            Inline::Link { text, .. } => text.clone(),
+            Inline::Roll { expression, total } if expression.is_empty() => total.to_string(),
+            Inline::Roll { expression, total } => format!("{} = {}", expression, total),
+            Inline::LineBreak => " ".to_string(),
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '`' | '~' | '|' | '\\') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let output = Output::from_markdown(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Ana
+*elf, she/her*
+
+**Species:** elf\
+**Age:** 122 years
+
+</div>
+
+_Ana has not yet been saved. Use ~save~ to save her to your `journal`._
+
+! Your changes could not be saved."#,
+        );
+
+        assert_eq!(
+            "**Ana**\n\n*elf, she/her*\n\n**Species:** elf\n**Age:** 122 years\n\n*Ana has not yet been saved. Use `!i save` to save her to your `!i journal`.*\n\n⚠️ Your changes could not be saved.",
+            render(&output, "!i"),
+        );
+    }
+
+    #[test]
+    fn render_test_table() {
+        let output = Output::from_markdown(
+            "| Name | Cost |\n|---|---|\n| `Dagger` | 2 gp |\n| `Longsword` | 15 gp |",
+        );
+
+        assert_eq!(
+            "```\nName       Cost\nDagger     2 gp\nLongsword  15 gp\n```",
+            render(&output, "!i"),
+        );
+    }
+
+    #[test]
+    fn render_test_escape() {
+        assert_eq!(
+            "2 \\* 3 = **6**",
+            render(&Output::from_markdown("2 \\* 3 = **6**"), "!i"),
+        );
+    }
+
+    #[test]
+    fn split_test() {
+        assert_eq!(vec!["Short message."], split("Short message.", 20));
+        assert_eq!(
+            vec!["First paragraph.", "Second\nparagraph."],
+            split("First paragraph.\n\nSecond\nparagraph.", 20),
+        );
+        assert_eq!(vec!["One\ntwo", "three"], split("One\ntwo\nthree", 8));
+        assert_eq!(vec!["abcde", "fghij", "k"], split("abcdefghijk", 5));
+        assert!(split("", 5).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+//! An adapter for running initiative.sh as a chat bot, eg. on Discord. Messages beginning with a
+//! trigger (such as `!i inn`) are run as commands, and the output is posted back to the channel.
+//! Each channel gets its own instance of the app, so one group's journal doesn't leak into
+//! another's.
+//!
+//! The chat service itself is abstracted behind the [`Gateway`] trait, which the integration for
+//! a particular service implements.
+
+mod format;
+
+pub use format::{render, split};
+
+use async_trait::async_trait;
+use initiative_core::{self as core, App, Event, MemoryDataStore};
+use std::collections::HashMap;
+use std::io;
+
+/// Discord's limit on the length of a message.
+pub const DEFAULT_MAX_MESSAGE_LEN: usize = 2000;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    pub channel_id: String,
+    pub content: String,
+}
+
+/// The connection to a chat service.
+#[async_trait(?Send)]
+pub trait Gateway {
+    /// Wait for the next message in any channel the bot can see, returning `None` when the
+    /// connection is closed.
+    async fn next_message(&mut self) -> io::Result<Option<Message>>;
+
+    async fn send(&mut self, channel_id: &str, content: &str) -> io::Result<()>;
+}
+
+pub struct Bot {
+    trigger: String,
+    max_message_len: usize,
+    channels: HashMap<String, App>,
+}
+
+impl Bot {
+    /// Create a bot that responds to messages beginning with `trigger`, eg. `!i`.
+    pub fn new(trigger: &str) -> Bot {
+        Bot {
+            trigger: trigger.to_string(),
+            max_message_len: DEFAULT_MAX_MESSAGE_LEN,
+            channels: HashMap::new(),
+        }
+    }
+
+    pub fn with_max_message_len(mut self, max_message_len: usize) -> Bot {
+        self.max_message_len = max_message_len;
+        self
+    }
+
+    /// Relay messages between the gateway and the app until the gateway disconnects.
+    pub async fn run(&mut self, gateway: &mut impl Gateway) -> io::Result<()> {
+        while let Some(message) = gateway.next_message().await? {
+            for reply in self.handle(&message).await {
+                gateway.send(&message.channel_id, &reply).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the command contained in a message, if any, returning the replies to be posted.
+    pub async fn handle(&mut self, message: &Message) -> Vec<String> {
+        let Some(input) = self.parse_command(&message.content) else {
+            return Vec::new();
+        };
+
+        let app = match self.channels.get_mut(&message.channel_id) {
+            Some(app) => app,
+            None => {
+                let mut app = core::app(MemoryDataStore::default(), event_dispatcher);
+                app.init().await;
+
+                self.channels
+                    .entry(message.channel_id.clone())
+                    .or_insert(app)
+            }
+        };
+
+        let output = app.command_output(&input).await.unwrap_or_else(|e| e);
+
+        split(&render(&output, &self.trigger), self.max_message_len)
+    }
+
+    fn parse_command(&self, content: &str) -> Option<String> {
+        let rest = content.trim().strip_prefix(&self.trigger)?;
+
+        // Require a space after the trigger so that `!initiative` doesn't match `!i`.
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+
+        let input = rest.trim();
+
+        if input.is_empty() {
+            Some("help".to_string())
+        } else {
+            Some(input.to_string())
+        }
+    }
+}
+
+/// Chat services have no equivalent of downloading or uploading a file from a command, so import
+/// and export are unavailable.
+fn event_dispatcher(_event: Event) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// A stand-in for a chat service, which delivers a fixed list of messages and records the
+    /// bot's replies.
+    #[derive(Default)]
+    struct MockGateway {
+        incoming: VecDeque<Message>,
+        sent: Vec<Message>,
+    }
+
+    impl MockGateway {
+        fn new(messages: &[(&str, &str)]) -> MockGateway {
+            MockGateway {
+                incoming: messages
+                    .iter()
+                    .map(|(channel_id, content)| message(channel_id, content))
+                    .collect(),
+                sent: Vec::new(),
+            }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl Gateway for MockGateway {
+        async fn next_message(&mut self) -> io::Result<Option<Message>> {
+            Ok(self.incoming.pop_front())
+        }
+
+        async fn send(&mut self, channel_id: &str, content: &str) -> io::Result<()> {
+            self.sent.push(message(channel_id, content));
+            Ok(())
+        }
+    }
+
+    fn message(channel_id: &str, content: &str) -> Message {
+        Message {
+            channel_id: channel_id.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn run_test() {
+        let mut gateway = MockGateway::new(&[
+            ("general", "hello everyone"),
+            ("general", "!i roll d1"),
+            ("general", "!initiative roll d1"),
+            ("general", "!i roll banana"),
+        ]);
+
+        Bot::new("!i").run(&mut gateway).await.unwrap();
+
+        assert_eq!(
+            vec![
+                message("general", "[1] = **1**"),
+                message(
+                    "general",
+                    "\"banana\" is not a valid dice formula. See `!i help` for some examples.",
+                ),
+            ],
+            gateway.sent,
+        );
+    }
+
+    #[tokio::test]
+    async fn run_test_per_channel() {
+        let mut gateway = MockGateway::new(&[
+            ("table-1", "!i npc named Odysseus"),
+            ("table-1", "!i save Odysseus"),
+            ("table-1", "!i load Odysseus"),
+            ("table-2", "!i load Odysseus"),
+        ]);
+
+        Bot::new("!i").run(&mut gateway).await.unwrap();
+
+        let replies: Vec<_> = gateway.sent.iter().skip(2).collect();
+        assert_eq!(2, replies.len(), "{:?}", gateway.sent);
+
+        assert_eq!("table-1", replies[0].channel_id);
+        assert!(
+            replies[0].content.starts_with("**Odysseus"),
+            "{}",
+            replies[0].content,
+        );
+
+        assert_eq!("table-2", replies[1].channel_id);
+        assert!(
+            replies[1]
+                .content
+                .starts_with("No matches for \"Odysseus\""),
+            "{}",
+            replies[1].content,
+        );
+    }
+
+    #[tokio::test]
+    async fn run_test_split() {
+        let mut gateway = MockGateway::new(&[("general", "!i")]);
+
+        Bot::new("!i")
+            .with_max_message_len(200)
+            .run(&mut gateway)
+            .await
+            .unwrap();
+
+        assert!(gateway.sent.len() > 1, "{:?}", gateway.sent);
+        assert!(gateway
+            .sent
+            .iter()
+            .all(|message| message.content.chars().count() <= 200));
+    }
+}
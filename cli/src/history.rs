@@ -0,0 +1,209 @@
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// The maximum number of commands retained in the history.
+const MAX_LEN: usize = 1000;
+
+/// Commands are appended to the history file as they're entered, so that sessions running at the
+/// same time don't overwrite one another's history. The file is compacted on load once it grows
+/// past this many lines.
+const MAX_FILE_LEN: usize = MAX_LEN * 2;
+
+/// Commands entered in previous sessions, persisted to a file so that they can be recalled with
+/// Up/Down and searched with Ctrl+R across restarts.
+#[derive(Debug, Default)]
+pub struct History {
+    path: Option<PathBuf>,
+    entries: Vec<String>,
+}
+
+impl History {
+    /// Load the history from the default location, or start with an empty in-memory history if
+    /// the user's data directory can't be determined.
+    pub fn load_default() -> History {
+        if let Some(path) = default_path() {
+            History::load(path)
+        } else {
+            History::default()
+        }
+    }
+
+    /// Load the history stored at `path`. A missing or unreadable file is treated as empty.
+    pub fn load(path: PathBuf) -> History {
+        let mut history = History::default();
+
+        if let Ok(contents) = fs::read_to_string(&path) {
+            contents.lines().for_each(|line| history.push(line));
+
+            if contents.lines().count() > MAX_FILE_LEN {
+                let _ = save(&path, &history.entries);
+            }
+        }
+
+        history.path = Some(path);
+        history
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Record a command, moving it to the end if it has been used before, and append it to the
+    /// history file. Failing to save isn't worth interrupting the user over, so errors are
+    /// ignored.
+    pub fn add(&mut self, command: &str) {
+        let command = command.trim();
+
+        if command.is_empty() {
+            return;
+        }
+
+        self.push(command);
+
+        if let Some(path) = &self.path {
+            let _ = append(path, command);
+        }
+    }
+
+    fn push(&mut self, command: &str) {
+        let command = command.trim();
+
+        if command.is_empty() {
+            return;
+        }
+
+        self.entries.retain(|entry| entry != command);
+        self.entries.push(command.to_string());
+
+        if self.entries.len() > MAX_LEN {
+            self.entries.drain(..self.entries.len() - MAX_LEN);
+        }
+    }
+}
+
+/// Eg. `~/.local/share/initiative-sh/history` on Linux.
+fn default_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("initiative-sh").join("history"))
+}
+
+fn append(path: &Path, command: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", command)
+}
+
+fn save(path: &Path, entries: &[String]) -> std::io::Result<()> {
+    let mut contents = entries.join("\n");
+    contents.push('\n');
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("initiative-cli-test-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn add_test() {
+        let mut history = History::default();
+
+        history.add("npc");
+        history.add("  inn ");
+        history.add("");
+        history.add("npc");
+
+        assert_eq!(["inn", "npc"], history.entries());
+    }
+
+    #[test]
+    fn add_test_max_len() {
+        let mut history = History::default();
+
+        (0..MAX_LEN + 5).for_each(|i| history.add(&format!("roll {}d6", i)));
+
+        assert_eq!(MAX_LEN, history.entries().len());
+        assert_eq!("roll 5d6", history.entries()[0]);
+        assert_eq!(
+            format!("roll {}d6", MAX_LEN + 4),
+            history.entries()[MAX_LEN - 1],
+        );
+    }
+
+    #[test]
+    fn load_test() {
+        let path = temp_path("load_test/history");
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut history = History::load(path.clone());
+            assert!(history.entries().is_empty());
+
+            history.add("npc");
+            history.add("journal");
+            history.add("npc");
+        }
+
+        assert_eq!("npc\njournal\nnpc\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(["journal", "npc"], History::load(path.clone()).entries());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_test_concurrent_sessions() {
+        let path = temp_path("load_test_concurrent_sessions/history");
+        let _ = fs::remove_file(&path);
+
+        let mut first = History::load(path.clone());
+        let mut second = History::load(path.clone());
+
+        first.add("npc");
+        second.add("inn");
+        first.add("journal");
+
+        assert_eq!(
+            ["npc", "inn", "journal"],
+            History::load(path.clone()).entries(),
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_test_compacts() {
+        let path = temp_path("load_test_compacts");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let contents = "npc\n".repeat(MAX_FILE_LEN);
+        fs::write(&path, &contents).unwrap();
+        assert_eq!(["npc"], History::load(path.clone()).entries());
+        assert_eq!(contents, fs::read_to_string(&path).unwrap());
+
+        fs::write(&path, contents + "inn\n").unwrap();
+        assert_eq!(["npc", "inn"], History::load(path.clone()).entries());
+        assert_eq!("npc\ninn\n", fs::read_to_string(&path).unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_test_deduplicates() {
+        let path = temp_path("load_test_deduplicates");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "npc\n\ninn\nnpc\n").unwrap();
+
+        assert_eq!(["inn", "npc"], History::load(path.clone()).entries());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
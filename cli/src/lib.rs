@@ -4,21 +4,51 @@
 //! surpassed the command line in terms of features, specifically:
 //!
 //! * No autocomplete suggestions are displayed
-//! * Markdown output is displayed literally rather than being formatted
-//! * No scrolling is present in the rich interface
 //! * No data storage is available
 //! * Import/export don't work
 
-mod light;
+mod history;
 mod rich;
+mod script;
+
+pub use history::History;
+pub use script::ScriptOptions;
 
 use initiative_core::App;
 use std::io;
+use std::io::prelude::*;
+
+/// Run an interactive session if stdin is a terminal, or treat piped input as a script otherwise.
+/// Returns `false` if any scripted command failed.
+///
+/// Either way, commands are read from and appended to the history in the user's data directory,
+/// so that piped commands can be recalled in a later interactive session.
+pub async fn run(app: App, options: &ScriptOptions) -> io::Result<bool> {
+    let mut history = History::load_default();
 
-pub async fn run(app: App) -> io::Result<()> {
     if termion::is_tty(&io::stdin()) {
-        rich::run(app).await
+        rich::run(app, history).await.map(|_| true)
     } else {
-        light::run(app).await
+        run_script(app, io::stdin().lock(), &mut history, options).await
     }
 }
+
+/// Run each line of `input` as a command, writing the results to stdout (or stderr for errors in
+/// the human-readable format) and recording each command in `history`. Returns `false` if any
+/// command failed.
+pub async fn run_script(
+    app: App,
+    input: impl BufRead,
+    history: &mut History,
+    options: &ScriptOptions,
+) -> io::Result<bool> {
+    script::run(
+        app,
+        input,
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock(),
+        history,
+        options,
+    )
+    .await
+}
@@ -1,14 +1,165 @@
 use initiative_cli as cli;
 use initiative_core as core;
+use std::env;
 use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::process;
+
+const USAGE: &str =
+    "Usage: initiative-cli [--seed NUMBER] [--script FILE] [--stop-on-error] [--json]";
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Args {
+    seed: Option<u64>,
+    script: Option<String>,
+    script_options: cli::ScriptOptions,
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
     let data_store = core::NullDataStore;
-    cli::run(core::app(data_store, &event_dispatcher)).await?;
+    let mut app = core::app(data_store, event_dispatcher);
+
+    if let Some(seed) = args.seed {
+        app.set_seed(seed);
+    }
+
+    let success = if let Some(path) = &args.script {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Couldn't open script {}: {}", path, e);
+                process::exit(2);
+            }
+        };
+        // Scripts are replayed from a file, so recording them would only crowd out commands the
+        // user actually typed.
+        cli::run_script(
+            app,
+            BufReader::new(file),
+            &mut cli::History::default(),
+            &args.script_options,
+        )
+        .await?
+    } else {
+        cli::run(app, &args.script_options).await?
+    };
+
+    if !success {
+        process::exit(1);
+    }
+
     Ok(())
 }
 
-fn event_dispatcher(event: core::Event) {
-    println!("Dispatched event: {:?}", event);
+/// The terminal doesn't display anything that needs to be kept in sync with changes, and printing
+/// events would interleave them with the interface.
+fn event_dispatcher(_event: core::Event) {}
+
+/// Parse arguments of the form `--seed 1234` or `--seed=1234`, plus the script mode flags.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut result = Args::default();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+
+        match flag.as_str() {
+            "--seed" => {
+                result.seed = Some(
+                    inline_value
+                        .or_else(|| args.next())
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| "--seed requires a number, eg. --seed 1234".to_string())?,
+                );
+            }
+            "--script" => {
+                result.script = Some(
+                    inline_value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| "--script requires a file name".to_string())?,
+                );
+            }
+            "--stop-on-error" if inline_value.is_none() => {
+                result.script_options.stop_on_error = true;
+            }
+            "--json" if inline_value.is_none() => result.script_options.json = true,
+            _ => {
+                return Err(format!(
+                    "Unrecognized argument: {}",
+                    inline_value.map_or(flag.clone(), |v| format!("{}={}", flag, v)),
+                ))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(Ok(Args::default()), parse(&[]));
+
+        assert_eq!(
+            Ok(Args {
+                seed: Some(1234),
+                script: Some("session.txt".to_string()),
+                script_options: cli::ScriptOptions {
+                    stop_on_error: true,
+                    json: true,
+                },
+            }),
+            parse(&[
+                "--seed",
+                "1234",
+                "--script=session.txt",
+                "--stop-on-error",
+                "--json",
+            ]),
+        );
+
+        assert_eq!(
+            Ok(Some(1234)),
+            parse(&["--seed=1234"]).map(|args| args.seed)
+        );
+    }
+
+    #[test]
+    fn parse_args_test_invalid() {
+        assert_eq!(
+            Err("--seed requires a number, eg. --seed 1234".to_string()),
+            parse(&["--seed", "potato"]),
+        );
+        assert_eq!(
+            Err("--script requires a file name".to_string()),
+            parse(&["--script"]),
+        );
+        assert_eq!(
+            Err("Unrecognized argument: --json=yes".to_string()),
+            parse(&["--json=yes"]),
+        );
+        assert_eq!(
+            Err("Unrecognized argument: potato".to_string()),
+            parse(&["potato"]),
+        );
+    }
 }
@@ -1,6 +1,7 @@
 mod wrap;
 
-use initiative_core::app::AutocompleteSuggestion;
+use crate::history::History;
+use initiative_core::app::{AutocompleteSuggestion, Output};
 use initiative_core::App;
 use std::fmt;
 use std::io;
@@ -8,7 +9,6 @@ use std::io::prelude::*;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
-use termion::color;
 use termion::event::{Event, Key};
 use termion::input::TermRead;
 use termion::raw::IntoRawMode;
@@ -19,6 +19,8 @@ const CTRL_DOWN_ARROW: [u8; 6] = [27, 91, 49, 59, 53, 66];
 const CTRL_RIGHT_ARROW: [u8; 6] = [27, 91, 49, 59, 53, 67];
 const CTRL_LEFT_ARROW: [u8; 6] = [27, 91, 49, 59, 53, 68];
 const CTRL_DELETE: [u8; 6] = [27, 91, 51, 59, 53, 126];
+const ALT_UP_ARROW: [u8; 6] = [27, 91, 49, 59, 51, 65];
+const ALT_DOWN_ARROW: [u8; 6] = [27, 91, 49, 59, 51, 66];
 
 #[derive(Debug)]
 struct Input {
@@ -36,6 +38,53 @@ struct Autocomplete {
     query: String,
 }
 
+/// The command links in the most recent output, which can be cycled through with Alt+Up/Down.
+#[derive(Debug, Default)]
+struct Links {
+    commands: Vec<String>,
+    selected: Option<usize>,
+}
+
+impl Links {
+    fn new(output: &Output) -> Links {
+        Links {
+            commands: output.commands().into_iter().map(str::to_string).collect(),
+            selected: None,
+        }
+    }
+
+    fn up(&mut self) {
+        if !self.commands.is_empty() {
+            self.selected = match self.selected {
+                Some(0) | None => Some(self.commands.len() - 1),
+                Some(x) => Some(x - 1),
+            };
+        }
+    }
+
+    fn down(&mut self) {
+        if !self.commands.is_empty() {
+            self.selected = match self.selected {
+                Some(selected) => Some((selected + 1) % self.commands.len()),
+                None => Some(0),
+            };
+        }
+    }
+
+    fn get_selected_command(&self) -> Option<&str> {
+        self.selected
+            .and_then(|selected| self.commands.get(selected))
+            .map(String::as_str)
+    }
+
+    fn render(&self, output: &Output) -> String {
+        match self.selected {
+            Some(selected) => output.to_ansi_with_selection(selected),
+            None => output.to_ansi(),
+        }
+    }
+}
+
 impl Autocomplete {
     fn up(self) -> Autocomplete {
         let suggestions = self.suggestions;
@@ -176,7 +225,7 @@ impl Autocomplete {
     }
 }
 
-pub async fn run(mut app: App) -> io::Result<()> {
+pub async fn run(mut app: App, mut history: History) -> io::Result<()> {
     let mut screen = termion::screen::AlternateScreen::from(io::stdout())
         .into_raw_mode()
         .unwrap();
@@ -192,11 +241,13 @@ pub async fn run(mut app: App) -> io::Result<()> {
         }
     });
 
-    let mut input = Input::default();
-    let mut output = String::new();
+    let mut input = Input::with_history(history.entries());
+    let mut output = Output::default();
+    let mut links = Links::default();
+    let mut scroll = 0;
     let mut autocomplete: Option<Autocomplete> = None;
 
-    draw_output(&mut screen, &output)?;
+    draw_output(&mut screen, &links.render(&output), &mut scroll)?;
     draw_autocomplete(&mut screen, autocomplete.as_ref())?;
     draw_status(&mut screen, "")?;
     draw_input(&mut screen, &input, None)?;
@@ -210,84 +261,111 @@ pub async fn run(mut app: App) -> io::Result<()> {
                     let status = format!("{:?}", event);
 
                     match event {
-                        Ok(Event::Key(key)) => match key {
-                            Key::Up => {
-                                autocomplete = autocomplete.take().map(Autocomplete::up);
-
-                                match autocomplete
-                                    .as_ref()
-                                    .and_then(Autocomplete::get_selected_suggestion)
-                                {
-                                    Some(suggestion) => input.set_text(&suggestion.term),
-                                    None => input.key(key, false),
-                                }
+                        Ok(Event::Key(key)) => {
+                            // Editing the input deselects the link that populated it.
+                            if !matches!(key, Key::Char('\n') | Key::PageUp | Key::PageDown) {
+                                links.selected = None;
                             }
-                            Key::Down => {
-                                autocomplete = autocomplete.take().map(Autocomplete::down);
-
-                                match autocomplete
-                                    .as_ref()
-                                    .and_then(Autocomplete::get_selected_suggestion)
-                                {
-                                    Some(suggestion) => input.set_text(&suggestion.term),
-                                    None => input.key(key, false),
-                                }
-                            }
-                            Key::Backspace => {
-                                let has_suggestion = autocomplete
-                                    .as_ref()
-                                    .and_then(Autocomplete::get_only_suggestion)
-                                    .is_some();
-
-                                autocomplete = if let Some(autocomplete) = autocomplete.take() {
-                                    autocomplete.try_back(&app).await
-                                } else {
-                                    None
-                                };
 
-                                if !has_suggestion {
-                                    input.key(Key::Backspace, false);
+                            match key {
+                                Key::Up => {
+                                    autocomplete = autocomplete.take().map(Autocomplete::up);
+
+                                    match autocomplete
+                                        .as_ref()
+                                        .and_then(Autocomplete::get_selected_suggestion)
+                                    {
+                                        Some(suggestion) => input.set_text(&suggestion.term),
+                                        None => input.key(key, false),
+                                    }
                                 }
-                            }
-                            Key::Char('\n') => {
-                                let command = autocomplete
-                                    .take()
-                                    .as_ref()
-                                    .and_then(Autocomplete::get_only_suggestion)
-                                    .map(|suggestion| suggestion.term.to_string())
-                                    .unwrap_or(input.get_text().to_string());
-
-                                input.key(key, false);
-                                break command;
-                            }
-                            Key::Char('\t') => {
-                                autocomplete = autocomplete.take().map(Autocomplete::expand_match);
+                                Key::Down => {
+                                    autocomplete = autocomplete.take().map(Autocomplete::down);
+
+                                    match autocomplete
+                                        .as_ref()
+                                        .and_then(Autocomplete::get_selected_suggestion)
+                                    {
+                                        Some(suggestion) => input.set_text(&suggestion.term),
+                                        None => input.key(key, false),
+                                    }
+                                }
+                                Key::Backspace => {
+                                    let has_suggestion = autocomplete
+                                        .as_ref()
+                                        .and_then(Autocomplete::get_only_suggestion)
+                                        .is_some();
+
+                                    autocomplete = if let Some(autocomplete) = autocomplete.take() {
+                                        autocomplete.try_back(&app).await
+                                    } else {
+                                        None
+                                    };
+
+                                    if !has_suggestion {
+                                        input.key(Key::Backspace, false);
+                                    }
+                                }
+                                Key::Char('\n') => {
+                                    let command = autocomplete
+                                        .take()
+                                        .as_ref()
+                                        .and_then(Autocomplete::get_only_suggestion)
+                                        .map(|suggestion| suggestion.term.to_string())
+                                        .unwrap_or(input.get_text().to_string());
+
+                                    input.key(key, false);
+                                    history.add(&command);
+                                    break command;
+                                }
+                                Key::Char('\t') => {
+                                    autocomplete =
+                                        autocomplete.take().map(Autocomplete::expand_match);
 
-                                if let Some(query) = autocomplete.as_ref().map(|it| &it.query) {
-                                    input.set_text(query);
+                                    if let Some(query) = autocomplete.as_ref().map(|it| &it.query) {
+                                        input.set_text(query);
+                                    }
+                                }
+                                Key::PageUp => scroll = scroll.saturating_sub(page_height()),
+                                Key::PageDown => scroll += page_height(),
+                                Key::Ctrl('c') => return Ok(()),
+                                Key::Ctrl('h') => input.key(Key::Backspace, true),
+                                Key::Ctrl(c) => input.key(Key::Char(c), true),
+                                k => {
+                                    input.key(k, false);
+                                    autocomplete = Autocomplete::try_new(&app, &input).await;
                                 }
                             }
-                            Key::Ctrl('c') => return Ok(()),
-                            Key::Ctrl('h') => input.key(Key::Backspace, true),
-                            Key::Ctrl(c) => input.key(Key::Char(c), true),
-                            k => {
-                                input.key(k, false);
-                                autocomplete = Autocomplete::try_new(&app, &input).await;
-                            }
-                        },
+                        }
                         Ok(Event::Unsupported(bytes)) => match bytes.as_slice() {
                             s if s == &CTRL_LEFT_ARROW[..] => input.key(Key::Left, true),
                             s if s == &CTRL_RIGHT_ARROW[..] => input.key(Key::Right, true),
                             s if s == &CTRL_UP_ARROW[..] => input.key(Key::Up, true),
                             s if s == &CTRL_DOWN_ARROW[..] => input.key(Key::Down, true),
                             s if s == &CTRL_DELETE[..] => input.key(Key::Delete, true),
+                            s if s == &ALT_UP_ARROW[..] || s == &ALT_DOWN_ARROW[..] => {
+                                if s == &ALT_UP_ARROW[..] {
+                                    links.up();
+                                } else {
+                                    links.down();
+                                }
+
+                                if let Some(command) = links.get_selected_command() {
+                                    autocomplete = None;
+                                    input.set_text(command);
+
+                                    if let Some(line) = selected_line(&links.render(&output)) {
+                                        scroll = scroll_to_line(scroll, line, page_height());
+                                    }
+                                }
+                            }
                             _ => {}
                         },
                         Err(e) => return Err(e),
                         _ => {}
                     }
 
-                    draw_output(&mut screen, &output)?;
+                    draw_output(&mut screen, &links.render(&output), &mut scroll)?;
                     draw_autocomplete(&mut screen, autocomplete.as_ref())?;
                     draw_status(&mut screen, &status)?;
                     draw_input(
@@ -304,18 +382,11 @@ pub async fn run(mut app: App) -> io::Result<()> {
             }
         };
 
-        output = app.command(&command).await.unwrap_or_else(|e| {
-            format!(
-                "{}{}{}{}{}",
-                color::Fg(color::Black),
-                color::Bg(color::Red),
-                e,
-                color::Fg(color::Reset),
-                color::Bg(color::Reset),
-            )
-        });
+        output = app.command_output(&command).await.unwrap_or_else(|e| e);
+        links = Links::new(&output);
+        scroll = 0;
 
-        draw_output(&mut screen, &output)?;
+        draw_output(&mut screen, &links.render(&output), &mut scroll)?;
         draw_autocomplete(&mut screen, autocomplete.as_ref())?;
         draw_status(&mut screen, "")?;
         draw_input(&mut screen, &input, None)?;
@@ -324,6 +395,13 @@ pub async fn run(mut app: App) -> io::Result<()> {
 }
 
 impl Input {
+    fn with_history(history: &[String]) -> Input {
+        let mut input = Input::default();
+        input.history.splice(0..0, history.iter().cloned());
+        input.index = input.history.len() - 1;
+        input
+    }
+
     fn get_text(&self) -> &str {
         self.history.get(self.index).unwrap()
     }
@@ -525,12 +603,51 @@ impl fmt::Display for &Input {
     }
 }
 
-fn draw_output(screen: &mut dyn Write, output: &str) -> io::Result<()> {
+/// The number of rows available for output, leaving room for the input and status lines.
+fn page_height() -> usize {
+    let (_, term_height) = termion::terminal_size().unwrap();
+    term_height.saturating_sub(3) as usize
+}
+
+/// Clamp the scroll position so that the last page of output fills the screen.
+fn clamp_scroll(scroll: usize, line_count: usize, page_height: usize) -> usize {
+    scroll.min(line_count.saturating_sub(page_height))
+}
+
+/// Adjust the scroll position as little as possible to bring `line` into view.
+fn scroll_to_line(scroll: usize, line: usize, page_height: usize) -> usize {
+    if line < scroll {
+        line
+    } else if line >= scroll + page_height {
+        line + 1 - page_height
+    } else {
+        scroll
+    }
+}
+
+fn wrap_output(output: &str) -> String {
+    let (term_width, _) = termion::terminal_size().unwrap();
+    wrap(output, term_width as usize - 4)
+}
+
+/// The line of the wrapped output containing the highlighted link, if any.
+fn selected_line(output: &str) -> Option<usize> {
+    wrap_output(output)
+        .lines()
+        .position(|line| line.contains(&termion::style::Invert.to_string()))
+}
+
+fn draw_output(screen: &mut dyn Write, output: &str, scroll: &mut usize) -> io::Result<()> {
     let (term_width, _) = termion::terminal_size().unwrap();
+    let page_height = page_height();
 
     print!("{}", termion::clear::All);
 
-    for (num, line) in wrap(output, term_width as usize - 4).lines().enumerate() {
+    let wrapped = wrap_output(output);
+    let line_count = wrapped.lines().count();
+    *scroll = clamp_scroll(*scroll, line_count, page_height);
+
+    for (num, line) in wrapped.lines().skip(*scroll).take(page_height).enumerate() {
         write!(
             screen,
             "{}{}",
@@ -539,6 +656,21 @@ fn draw_output(screen: &mut dyn Write, output: &str) -> io::Result<()> {
         )?;
     }
 
+    write!(screen, "{}", termion::style::Reset)?;
+
+    // Indicate that there is more output above or below the visible page.
+    if *scroll > 0 {
+        write!(screen, "{}▲", termion::cursor::Goto(term_width, 1))?;
+    }
+
+    if *scroll + page_height < line_count {
+        write!(
+            screen,
+            "{}▼",
+            termion::cursor::Goto(term_width, page_height as u16),
+        )?;
+    }
+
     Ok(())
 }
 
@@ -705,6 +837,51 @@ fn draw_autocomplete(
 mod test {
     use super::*;
 
+    #[test]
+    fn links_test() {
+        let output = Output::from_markdown("* `one`\n* `two`\n\nUse ~save~ to save.");
+        let mut links = Links::new(&output);
+
+        assert_eq!(None, links.get_selected_command());
+
+        links.down();
+        assert_eq!(Some("one"), links.get_selected_command());
+        links.down();
+        links.down();
+        assert_eq!(Some("save"), links.get_selected_command());
+        links.down();
+        assert_eq!(Some("one"), links.get_selected_command());
+        links.up();
+        assert_eq!(Some("save"), links.get_selected_command());
+
+        assert_eq!(output.to_ansi_with_selection(2), links.render(&output));
+    }
+
+    #[test]
+    fn links_empty_test() {
+        let mut links = Links::new(&Output::from_markdown("Nothing to see here."));
+
+        links.up();
+        assert_eq!(None, links.get_selected_command());
+        links.down();
+        assert_eq!(None, links.get_selected_command());
+    }
+
+    #[test]
+    fn scroll_to_line_test() {
+        assert_eq!(5, scroll_to_line(5, 7, 10));
+        assert_eq!(3, scroll_to_line(5, 3, 10));
+        assert_eq!(11, scroll_to_line(5, 20, 10));
+    }
+
+    #[test]
+    fn clamp_scroll_test() {
+        assert_eq!(0, clamp_scroll(0, 5, 10));
+        assert_eq!(0, clamp_scroll(3, 5, 10));
+        assert_eq!(3, clamp_scroll(3, 20, 10));
+        assert_eq!(10, clamp_scroll(15, 20, 10));
+    }
+
     #[test]
     fn expand_single_match() {
         let autocomplete = Autocomplete {
@@ -937,6 +1114,22 @@ mod test {
         assert_eq!("bar baz", input.get_text());
     }
 
+    #[test]
+    fn with_history_test() {
+        let mut input = Input::with_history(&["npc".to_string(), "inn".to_string()]);
+
+        assert_eq!("", input.get_text());
+        input.key(Key::Up, false);
+        assert_eq!("inn", input.get_text());
+        input.key(Key::Up, false);
+        assert_eq!("npc", input.get_text());
+
+        let mut input = Input::with_history(&["npc".to_string(), "inn".to_string()]);
+        input.key(Key::Char('r'), true);
+        input.key(Key::Char('p'), false);
+        assert_eq!("npc", input.get_text());
+    }
+
     #[test]
     fn key_enter_test() {
         let mut input = Input {
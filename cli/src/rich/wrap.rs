@@ -1,16 +1,18 @@
+/// Wrap `input` to lines of at most `line_len` visible characters. ANSI escape sequences don't
+/// take up any space on the screen, so they aren't counted.
 pub fn wrap(input: &str, line_len: usize) -> String {
     let mut result = String::with_capacity(input.len());
     let mut cur_line_len = 0;
 
     input.split_inclusive(char::is_whitespace).for_each(|word| {
-        let word_len = word.trim_end().len();
+        let word_len = display_len(word.trim_end());
 
         if word_len + cur_line_len <= line_len {
             result.push_str(word);
             if word.ends_with('\n') {
                 cur_line_len = 0;
             } else {
-                cur_line_len += word.len();
+                cur_line_len += display_len(word);
             }
         } else {
             // Trim trailing whitespace from the previous line.
@@ -24,17 +26,28 @@ pub fn wrap(input: &str, line_len: usize) -> String {
             result.push('\n');
 
             cur_line_len = if word_len > line_len {
-                word.chars().enumerate().for_each(|(i, c)| {
+                let mut i = 0;
+                let mut in_escape = false;
+
+                word.chars().for_each(|c| {
                     result.push(c);
-                    if i % line_len == line_len - 1 && !c.is_whitespace() {
-                        result.push('\n');
+
+                    if in_escape {
+                        in_escape = !c.is_ascii_alphabetic();
+                    } else if c == '\x1b' {
+                        in_escape = true;
+                    } else {
+                        if i % line_len == line_len - 1 && !c.is_whitespace() {
+                            result.push('\n');
+                        }
+                        i += 1;
                     }
                 });
 
-                word.len() % line_len
+                display_len(word) % line_len
             } else {
                 result.push_str(word);
-                word.len()
+                display_len(word)
             };
         }
     });
@@ -42,6 +55,24 @@ pub fn wrap(input: &str, line_len: usize) -> String {
     result
 }
 
+/// The number of characters in `input` that will be visible on the screen.
+pub fn display_len(input: &str) -> usize {
+    let mut len = 0;
+    let mut in_escape = false;
+
+    for c in input.chars() {
+        if in_escape {
+            in_escape = !c.is_ascii_alphabetic();
+        } else if c == '\x1b' {
+            in_escape = true;
+        } else {
+            len += 1;
+        }
+    }
+
+    len
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -62,6 +93,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn wrap_ansi_test() {
+        assert_eq!(
+            "\x1b[1mBold\x1b[22m\n\x1b[4mwords\x1b[24m\nand ü\nö",
+            wrap("\x1b[1mBold\x1b[22m \x1b[4mwords\x1b[24m and ü ö", 5),
+        );
+    }
+
+    #[test]
+    fn display_len_test() {
+        assert_eq!(0, display_len(""));
+        assert_eq!(4, display_len("\x1b[1mBold\x1b[22m"));
+        assert_eq!(6, display_len("• Café"));
+    }
+
     #[test]
     fn wrap_long_test() {
         let input = "\
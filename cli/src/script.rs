@@ -0,0 +1,205 @@
+use crate::history::History;
+use initiative_core::App;
+use std::io;
+use std::io::prelude::*;
+
+/// Options for running a non-interactive session, eg. `initiative --script session.txt`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScriptOptions {
+    /// Stop processing the script after the first command that fails.
+    pub stop_on_error: bool,
+
+    /// Emit one JSON object per command (`{"input": ..., "ok": ..., "output": ...}`) instead of
+    /// the human-readable format.
+    pub json: bool,
+}
+
+/// Run each line of `input` as a command, recording it in `history`. Blank lines and lines
+/// starting with `#` are ignored.
+///
+/// Returns `true` if every command succeeded.
+pub async fn run(
+    mut app: App,
+    input: impl BufRead,
+    output: &mut impl Write,
+    error_output: &mut impl Write,
+    history: &mut History,
+    options: &ScriptOptions,
+) -> io::Result<bool> {
+    let mut success = true;
+
+    // The message of the day isn't useful in a non-interactive context.
+    app.init().await;
+
+    for line in input.lines() {
+        let line = line?;
+        let command = line.trim();
+
+        if command.is_empty() || command.starts_with('#') {
+            continue;
+        }
+
+        history.add(command);
+        let result = app.command(command).await;
+
+        if options.json {
+            let (ok, message) = match &result {
+                Ok(s) => (true, s),
+                Err(e) => (false, e),
+            };
+
+            writeln!(
+                output,
+                "{}",
+                serde_json::json!({
+                    "input": command,
+                    "ok": ok,
+                    "output": message,
+                }),
+            )?;
+        } else {
+            match &result {
+                Ok(s) => writeln!(output, "\n{}\n", s)?,
+                Err(e) => writeln!(error_output, "\n{}\n", e)?,
+            }
+        }
+
+        if result.is_err() {
+            success = false;
+
+            if options.stop_on_error {
+                break;
+            }
+        }
+    }
+
+    output.flush()?;
+    Ok(success)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use initiative_core::NullDataStore;
+    use std::{env, fs};
+
+    async fn run_str(input: &str, options: &ScriptOptions) -> (bool, String, String) {
+        run_str_with_history(input, &mut History::default(), options).await
+    }
+
+    async fn run_str_with_history(
+        input: &str,
+        history: &mut History,
+        options: &ScriptOptions,
+    ) -> (bool, String, String) {
+        let app = initiative_core::app(NullDataStore, |_| {});
+        let (mut output, mut error_output) = (Vec::new(), Vec::new());
+
+        let success = run(
+            app,
+            input.as_bytes(),
+            &mut output,
+            &mut error_output,
+            history,
+            options,
+        )
+        .await
+        .unwrap();
+
+        (
+            success,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(error_output).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn run_test_human() {
+        let (success, output, error_output) = run_str(
+            "# A comment\n\nseed 1\n  seed  \n",
+            &ScriptOptions::default(),
+        )
+        .await;
+
+        assert!(success);
+        assert_eq!(
+            "\nThe random seed is now 1. Anything generated from here on will be the same each time this seed is used.\n\n\nThe random seed is currently 1. Use `seed random` to return to unpredictable results.\n\n",
+            output,
+        );
+        assert_eq!("", error_output);
+    }
+
+    #[tokio::test]
+    async fn run_test_json() {
+        let (success, output, error_output) = run_str(
+            "seed 1\nload Nobody\nseed\n",
+            &ScriptOptions {
+                json: true,
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert!(!success);
+        assert_eq!("", error_output);
+
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(3, lines.len(), "{}", output);
+        assert_eq!("seed 1", lines[0]["input"]);
+        assert_eq!(true, lines[0]["ok"]);
+        assert_eq!("load Nobody", lines[1]["input"]);
+        assert_eq!(false, lines[1]["ok"]);
+        assert!(lines[1]["output"].is_string());
+        assert_eq!(true, lines[2]["ok"]);
+    }
+
+    #[tokio::test]
+    async fn run_test_stop_on_error() {
+        let (success, output, error_output) = run_str(
+            "load Nobody\nseed 1\n",
+            &ScriptOptions {
+                stop_on_error: true,
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert!(!success);
+        assert_eq!("", output);
+        assert_ne!("", error_output);
+    }
+
+    #[tokio::test]
+    async fn run_test_history() {
+        let path = env::temp_dir()
+            .join(format!("initiative-cli-test-{}", std::process::id()))
+            .join("run_test_history");
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut history = History::load(path.clone());
+            history.add("npc");
+
+            let (success, _, _) = run_str_with_history(
+                "# A comment\n\nseed 1\nload Nobody\n  npc  \n",
+                &mut history,
+                &ScriptOptions::default(),
+            )
+            .await;
+
+            assert!(!success);
+            assert_eq!(["seed 1", "load Nobody", "npc"], history.entries());
+        }
+
+        assert_eq!(
+            ["seed 1", "load Nobody", "npc"],
+            History::load(path.clone()).entries(),
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+}
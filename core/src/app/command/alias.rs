@@ -1,5 +1,6 @@
 use super::{
-    Autocomplete, AutocompleteSuggestion, Command, CommandMatches, ContextAwareParse, Runnable,
+    AppCommand, Autocomplete, AutocompleteSuggestion, Command, CommandMatches, ContextAwareParse,
+    Runnable, UserAlias,
 };
 use crate::app::AppMeta;
 use crate::utils::CaseInsensitiveStr;
@@ -40,6 +41,15 @@ impl CommandAlias {
         }
     }
 
+    /// Wrap an alias defined by the user with the `alias` or `macro` command.
+    pub fn user(alias: &UserAlias) -> Self {
+        Self::literal(
+            alias.name.clone(),
+            alias.summary(),
+            AppCommand::RunAlias(alias.clone()).into(),
+        )
+    }
+
     pub fn get_command(&self) -> &Command {
         match self {
             Self::Literal { command, .. } => command,
@@ -130,6 +140,7 @@ impl ContextAwareParse for CommandAlias {
                     })
             })
             .cloned()
+            .or_else(|| app_meta.user_aliases.get(input).map(Self::user))
             .map(CommandMatches::from)
             .unwrap_or_default()
     }
@@ -154,6 +165,13 @@ impl Autocomplete for CommandAlias {
                 }
                 Self::StrictWildcard { .. } => None,
             })
+            .chain(
+                app_meta
+                    .user_aliases
+                    .iter()
+                    .filter(|alias| alias.name.starts_with_ci(input))
+                    .map(|alias| AutocompleteSuggestion::new(alias.name.clone(), alias.summary())),
+            )
             .collect()
     }
 }
@@ -174,8 +192,7 @@ impl fmt::Display for CommandAlias {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::{AppCommand, Command};
-    use crate::command::TransitionalCommand;
+    use crate::app::{AppCommand, Command, CommandType};
     use crate::test_utils as test;
     use std::collections::HashSet;
 
@@ -314,8 +331,44 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn runnable_test_user() {
+        let info_alias = UserAlias {
+            name: "info".to_string(),
+            commands: vec!["about".to_string()],
+        };
+
+        let mut app_meta = test::app_meta();
+        app_meta.user_aliases.insert(info_alias.clone());
+
+        test::assert_autocomplete_eq!(
+            [("info", "about")],
+            CommandAlias::autocomplete("IN", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(CommandAlias::user(&info_alias)),
+            CommandAlias::parse_input("Info", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            CommandAlias::parse_input("info about", &app_meta).await,
+        );
+
+        let (about_result, info_result) = (
+            about().run("about", &mut app_meta).await,
+            CommandAlias::user(&info_alias)
+                .run("info", &mut app_meta)
+                .await,
+        );
+
+        assert!(about_result.is_ok(), "{:?}", about_result);
+        assert_eq!(about_result, info_result);
+    }
+
     fn about() -> Command {
-        Command::from(TransitionalCommand::new("about"))
+        Command::from(CommandType::App(AppCommand::About))
     }
 
     fn literal(
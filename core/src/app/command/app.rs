@@ -1,104 +1,417 @@
-use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
-};
-use crate::utils::CaseInsensitiveStr;
+use super::{Command, RolledDice, UserAlias};
+use crate::app::{AppMeta, Block, Inline, Output, Runnable};
+use crate::command::{help_index, help_topic};
+use crate::storage::{Change, KeyValue};
+use crate::utils::{distribution, expand_formula, DistributionError};
 use async_trait::async_trait;
-use caith::Roller;
+use caith::{RollResult, RollResultType, Roller, SingleRollResult};
 use initiative_macros::changelog;
 use std::fmt;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AppCommand {
+    About,
+    Aliases,
     Changelog,
     Debug,
     Help,
+    HelpTopic(String),
     Roll(String),
+    RollHistory,
+    RollStats(String),
+    RunAlias(UserAlias),
+    Seed,
+    SetAlias(UserAlias),
+    SetSeed(Option<u64>),
+    Unalias(String),
 }
 
 #[async_trait(?Send)]
 impl Runnable for AppCommand {
     async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         Ok(match self {
+            Self::About => include_str!("../../../../data/about.md")
+                .trim_end()
+                .to_string(),
+            Self::Aliases => list_aliases(app_meta),
             Self::Debug => format!(
                 "{:?}\n\n{:?}",
                 app_meta,
                 app_meta.repository.journal().await,
             ),
             Self::Changelog => changelog!().to_string(),
-            Self::Help => include_str!("../../../../data/help.md")
+            Self::Help => help_index(),
+            Self::HelpTopic(topic) => help_topic(&topic)
+                .ok_or_else(|| format!("There is no help available for \"{}\".", topic))?,
+            Self::Roll(s) => roll(&s, app_meta)?
+                .to_string()
                 .trim_end()
-                .to_string(),
-            Self::Roll(s) => Roller::new(&s)
-                .ok()
-                .and_then(|r| r.roll_with(&mut app_meta.rng).ok())
-                .map(|result| {
-                    result
-                        .to_string()
-                        .trim_end()
-                        .replace('\n', "\\\n")
-                        .replace('`', "")
-                })
-                .ok_or_else(|| {
-                    format!(
-                        "\"{}\" is not a valid dice formula. See `help` for some examples.",
-                        s
-                    )
-                })?,
+                .replace('\n', "\\\n")
+                .replace('`', ""),
+            Self::RollHistory => roll_history(app_meta),
+            Self::RollStats(s) => roll_stats(&s)?,
+            Self::RunAlias(alias) => run_alias(alias, app_meta).await?,
+            Self::Seed => match app_meta
+                .repository
+                .get_key_value(&KeyValue::Seed(None))
+                .await
+                .map_err(|_| "Storage error.".to_string())?
+                .seed()
+            {
+                Some(seed) => format!("The random seed is currently {}. Use `seed random` to return to unpredictable results.", seed),
+                None => "No random seed has been set. Use `seed [number]` to make generated results reproducible.".to_string(),
+            },
+            Self::SetAlias(alias) => set_alias(alias, app_meta).await?,
+            Self::SetSeed(seed) => {
+                app_meta.set_seed(seed);
+
+                let message = if let Some(seed) = seed {
+                    format!("The random seed is now {}. Anything generated from here on will be the same each time this seed is used.", seed)
+                } else {
+                    "The random seed has been cleared. Generated results are unpredictable once again.".to_string()
+                };
+
+                match app_meta
+                    .repository
+                    .modify_without_undo(Change::SetKeyValue {
+                        key_value: KeyValue::Seed(seed),
+                    })
+                    .await
+                {
+                    Ok(_) => message,
+                    Err(_) => format!("{}\n\n! The seed could not be saved and will only apply until you close initiative.sh.", message),
+                }
+            }
+            Self::Unalias(name) => unalias(&name, app_meta).await?,
         })
     }
+
+    async fn run_output(self, input: &str, app_meta: &mut AppMeta) -> Result<Output, Output> {
+        match self {
+            Self::Roll(s) => roll(&s, app_meta)
+                .map(|result| roll_output(&result))
+                .map_err(|message| Output::error(&message)),
+            command => Output::from_result(command.run(input, app_meta).await),
+        }
+    }
 }
 
-#[async_trait(?Send)]
-impl ContextAwareParse for AppCommand {
-    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
-        if input.eq_ci("changelog") {
-            CommandMatches::new_canonical(Self::Changelog)
-        } else if input.eq_ci("debug") {
-            CommandMatches::new_canonical(Self::Debug)
-        } else if input.eq_ci("help") {
-            CommandMatches::new_canonical(Self::Help)
-        } else if input.starts_with_ci("roll ") {
-            CommandMatches::new_canonical(Self::Roll(input[5..].to_string()))
-        } else if !input.chars().all(|c| c.is_ascii_digit())
-            && Roller::new(input).is_ok_and(|r| r.roll().is_ok())
-        {
-            CommandMatches::new_fuzzy(Self::Roll(input.to_string()))
-        } else {
-            CommandMatches::default()
+/// Roll a formula such as `2d20k1+5: attack roll` or `adv d20+5 stealth`, recording the result in
+/// the roll history.
+fn roll(formula: &str, app_meta: &mut AppMeta) -> Result<RollResult, String> {
+    let result = expand_formula(formula)
+        .and_then(|expanded| Roller::new(&expanded).ok())
+        .and_then(|roller| roller.roll_with(&mut app_meta.rng).ok())
+        .ok_or_else(|| invalid_formula(formula))?;
+
+    let totals = match result.get_result() {
+        RollResultType::Single(roll) => vec![roll.get_total()],
+        RollResultType::Repeated(rolls) => match rolls.get_total() {
+            Some(total) => vec![total],
+            None => rolls.iter().map(SingleRollResult::get_total).collect(),
+        },
+    };
+
+    app_meta.roll_history.push(RolledDice {
+        formula: formula.to_string(),
+        totals,
+    });
+
+    Ok(result)
+}
+
+/// Produce the same content as the markdown version of the `roll` command.
+fn roll_output(result: &RollResult) -> Output {
+    let single = |roll: &SingleRollResult| Inline::Roll {
+        expression: roll.to_string_history(),
+        total: roll.get_total(),
+    };
+
+    let mut content = Vec::new();
+
+    match result.get_result() {
+        RollResultType::Single(roll) => content.push(single(roll)),
+        RollResultType::Repeated(rolls) => {
+            for (i, roll) in rolls.iter().enumerate() {
+                if i > 0 {
+                    content.push(Inline::LineBreak);
+                }
+
+                if rolls.get_total().is_some() {
+                    content.push(Inline::text(roll.to_string_history()));
+                } else {
+                    content.push(single(roll));
+                }
+            }
+
+            if let Some(total) = rolls.get_total() {
+                content.push(Inline::LineBreak);
+                content.push(Inline::text("Sum: "));
+                content.push(Inline::Strong {
+                    content: vec![Inline::text(total.to_string())],
+                });
+            }
+        }
+    }
+
+    if let Some(reason) = result.get_reason() {
+        match result.get_result() {
+            RollResultType::Repeated(rolls) if rolls.get_total().is_none() => {
+                content.push(Inline::LineBreak);
+                content.push(Inline::text(format!("Reason: {}", reason)));
+            }
+            _ => content.push(Inline::text(format!(", Reason: {}", reason))),
+        }
+    }
+
+    Output::new(vec![Block::Paragraph { content }])
+}
+
+fn roll_history(app_meta: &AppMeta) -> String {
+    if app_meta.roll_history.is_empty() {
+        return "You haven't rolled any dice yet. Try `roll d20`.".to_string();
+    }
+
+    let mut output = "# Roll history\n".to_string();
+
+    for rolled_dice in app_meta.roll_history.iter() {
+        output.push_str(&format!(
+            "\n* `roll {}`: {}",
+            rolled_dice.formula,
+            rolled_dice
+                .totals
+                .iter()
+                .map(|total| format!("**{}**", total))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ));
+    }
+
+    output
+}
+
+/// Describe the range, average and distribution of a formula without rolling it.
+fn roll_stats(formula: &str) -> Result<String, String> {
+    /// A table of every possible total is only useful if it fits on the screen.
+    const MAX_TABLE_ROWS: usize = 50;
+
+    let expanded = expand_formula(formula).ok_or_else(|| invalid_formula(formula))?;
+    let distribution = distribution(&expanded).map_err(|e| match e {
+        DistributionError::Unsupported => format!(
+            "Statistics aren't available for \"{}\". Try a simpler formula, like `roll stats 3d6` or `roll stats adv d20+5`.",
+            formula,
+        ),
+        DistributionError::TooManyOutcomes => format!(
+            "\"{}\" has too many outcomes to compute statistics for. Try fewer or smaller dice.",
+            formula,
+        ),
+    })?;
+
+    // unwrap: a distribution always has at least one total
+    let (min, max) = (
+        distribution.keys().next().unwrap(),
+        distribution.keys().next_back().unwrap(),
+    );
+    let average: f64 = distribution
+        .iter()
+        .map(|(total, probability)| *total as f64 * probability)
+        .sum();
+
+    let mut output = format!(
+        "# Statistics for `{}`\n\nRange: **{}** to **{}**\\\nAverage: **{}**",
+        formula,
+        min,
+        max,
+        format_number(average),
+    );
+
+    if distribution.len() <= MAX_TABLE_ROWS {
+        output.push_str("\n\n| Total | Chance | At least |\n|---:|---:|---:|");
+
+        let mut at_least = 1.;
+        for (total, probability) in distribution.iter() {
+            output.push_str(&format!(
+                "\n| {} | {} | {} |",
+                total,
+                format_percent(*probability),
+                format_percent(at_least),
+            ));
+            at_least -= probability;
         }
     }
+
+    Ok(output)
 }
 
-#[async_trait(?Send)]
-impl Autocomplete for AppCommand {
-    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        if input.is_empty() {
-            return Vec::new();
+/// Round to at most two decimal places, omitting any trailing zeroes.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn format_percent(probability: f64) -> String {
+    if probability < 0.0005 {
+        "<0.1%".to_string()
+    } else {
+        format!("{}%", format_number((probability * 1000.).round() / 10.))
+    }
+}
+
+fn list_aliases(app_meta: &AppMeta) -> String {
+    if app_meta.user_aliases.is_empty() {
+        return "You haven't created any aliases yet. Try `alias fight = roll d20+5`, then type `fight` to run it.".to_string();
+    }
+
+    let mut output = "# Aliases\n".to_string();
+
+    for alias in app_meta.user_aliases.iter() {
+        output.push_str(&format!(
+            "\n* `{}`: {}",
+            alias.name,
+            alias
+                .commands
+                .iter()
+                .map(|command| format!("`{}`", command))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ));
+    }
+
+    output
+}
+
+/// Run each of the alias's commands in turn, stopping at the first error. The commands are parsed
+/// without expanding other aliases, so an alias can never invoke itself.
+async fn run_alias(alias: UserAlias, app_meta: &mut AppMeta) -> Result<String, String> {
+    let is_macro = alias.is_macro();
+    let mut output = String::new();
+
+    for command_str in alias.commands {
+        let command = Command::from(crate::command::parse_input(&command_str, app_meta).await);
+
+        if is_macro {
+            if !output.is_empty() {
+                output.push_str("\n\n");
+            }
+            output.push_str(&format!("\\> {}\n\n", command_str));
+        }
+
+        match command.run(&command_str, app_meta).await {
+            Ok(result) => output.push_str(&result),
+            Err(result) => {
+                output.push_str(&result);
+                return Err(output);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+async fn set_alias(alias: UserAlias, app_meta: &mut AppMeta) -> Result<String, String> {
+    let kind = if alias.is_macro() { "macro" } else { "alias" };
+
+    // Aliases take precedence over everything else, so they mustn't hide anything.
+    let matches = crate::command::parse_input(&alias.name, app_meta).await;
+    if matches.canonical_match.is_some() || !matches.fuzzy_matches.is_empty() {
+        return Err(format!(
+            r#""{}" already means something to initiative.sh, so it can't be used as the name of {} {}."#,
+            alias.name,
+            if alias.is_macro() { "a" } else { "an" },
+            kind,
+        ));
+    }
+
+    for command in alias.commands.iter() {
+        let matches = crate::command::parse_input(command, app_meta).await;
+        if matches.canonical_match.is_none() && matches.fuzzy_matches.is_empty() {
+            return Err(format!(
+                r#""{}" isn't a command that initiative.sh understands."#,
+                command,
+            ));
         }
+    }
+
+    let message = format!(
+        "{} the {} `{}`, which runs {}.",
+        if app_meta.user_aliases.get(&alias.name).is_some() {
+            "Updated"
+        } else {
+            "Created"
+        },
+        kind,
+        alias.name,
+        alias
+            .commands
+            .iter()
+            .map(|command| format!("`{}`", command))
+            .collect::<Vec<_>>()
+            .join(", then "),
+    );
+
+    app_meta.user_aliases.insert(alias);
+    Ok(save_aliases(app_meta, message).await)
+}
+
+async fn unalias(name: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+    let alias = app_meta
+        .user_aliases
+        .remove(name)
+        .ok_or_else(|| format!(r#"There is no alias named "{}"."#, name))?;
 
-        [
-            AutocompleteSuggestion::new("changelog", "show latest updates"),
-            AutocompleteSuggestion::new("help", "how to use initiative.sh"),
-        ]
-        .into_iter()
-        .filter(|suggestion| suggestion.term.starts_with_ci(input))
-        .chain(
-            ["roll"]
-                .into_iter()
-                .filter(|s| s.starts_with_ci(input))
-                .map(|_| AutocompleteSuggestion::new("roll [dice]", "roll eg. 8d6 or d20+3")),
-        )
-        .collect()
+    let message = format!(
+        r#"Removed the {} "{}"."#,
+        if alias.is_macro() { "macro" } else { "alias" },
+        alias.name,
+    );
+
+    Ok(save_aliases(app_meta, message).await)
+}
+
+async fn save_aliases(app_meta: &mut AppMeta, message: String) -> String {
+    match app_meta
+        .repository
+        .modify_without_undo(Change::SetKeyValue {
+            key_value: KeyValue::Aliases(Some(app_meta.user_aliases.clone())),
+        })
+        .await
+    {
+        Ok(_) => message,
+        Err(_) => format!(
+            "{}\n\n! Your changes could not be saved and will only apply until you close initiative.sh.",
+            message,
+        ),
     }
 }
 
+fn invalid_formula(formula: &str) -> String {
+    format!(
+        "\"{}\" is not a valid dice formula. See `help` for some examples.",
+        formula,
+    )
+}
+
 impl fmt::Display for AppCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::About => write!(f, "about"),
+            Self::Aliases => write!(f, "aliases"),
             Self::Changelog => write!(f, "changelog"),
             Self::Debug => write!(f, "debug"),
             Self::Help => write!(f, "help"),
+            Self::HelpTopic(topic) => write!(f, "help {}", topic),
             Self::Roll(s) => write!(f, "roll {}", s),
+            Self::RollHistory => write!(f, "roll history"),
+            Self::RollStats(s) => write!(f, "roll stats {}", s),
+            Self::RunAlias(alias) => write!(f, "{}", alias.name),
+            Self::Seed => write!(f, "seed"),
+            Self::SetAlias(alias) => write!(f, "{}", alias),
+            Self::SetSeed(Some(seed)) => write!(f, "seed {}", seed),
+            Self::SetSeed(None) => write!(f, "seed random"),
+            Self::Unalias(name) => write!(f, "unalias {}", name),
         }
     }
 }
@@ -109,94 +422,129 @@ mod test {
     use crate::test_utils as test;
 
     #[tokio::test]
-    async fn parse_input_test() {
-        let app_meta = test::app_meta();
+    async fn run_output_test_roll() {
+        let mut app_meta = test::app_meta();
 
-        assert_eq!(
-            CommandMatches::new_canonical(AppCommand::Debug),
-            AppCommand::parse_input("debug", &app_meta).await,
-        );
+        let output = AppCommand::Roll("(d1+1)^2: test".to_string())
+            .run_output("(d1+1)^2: test", &mut app_meta)
+            .await
+            .unwrap();
 
         assert_eq!(
-            CommandMatches::new_canonical(AppCommand::Roll("d20".to_string())),
-            AppCommand::parse_input("roll d20", &app_meta).await,
+            Output::new(vec![Block::Paragraph {
+                content: vec![
+                    Inline::Roll {
+                        expression: "[1] + 1".to_string(),
+                        total: 2,
+                    },
+                    Inline::LineBreak,
+                    Inline::Roll {
+                        expression: "[1] + 1".to_string(),
+                        total: 2,
+                    },
+                    Inline::LineBreak,
+                    Inline::text("Reason: test"),
+                ],
+            }]),
+            output,
         );
 
         assert_eq!(
-            CommandMatches::new_fuzzy(AppCommand::Roll("d20".to_string())),
-            AppCommand::parse_input("d20", &app_meta).await,
+            AppCommand::Roll("(d1+1)^2: test".to_string())
+                .run("(d1+1)^2: test", &mut app_meta)
+                .await
+                .unwrap(),
+            output.to_markdown(),
         );
 
         assert_eq!(
-            CommandMatches::default(),
-            AppCommand::parse_input("potato", &app_meta).await,
+            Err(Output::error(
+                "\"d0\" is not a valid dice formula. See `help` for some examples."
+            )),
+            AppCommand::Roll("d0".to_string())
+                .run_output("d0", &mut app_meta)
+                .await,
         );
     }
 
     #[tokio::test]
-    async fn autocomplete_test() {
-        let app_meta = test::app_meta();
-
-        for (term, summary) in [
-            ("changelog", "show latest updates"),
-            ("help", "how to use initiative.sh"),
-        ] {
-            test::assert_autocomplete_eq!(
-                [(term, summary)],
-                AppCommand::autocomplete(term, &app_meta).await,
-            );
-
-            test::assert_autocomplete_eq!(
-                [(term, summary)],
-                AppCommand::autocomplete(&term.to_uppercase(), &app_meta).await,
-            );
-        }
+    async fn run_test_roll_history() {
+        let mut app_meta = test::app_meta();
 
-        test::assert_autocomplete_eq!(
-            [("roll [dice]", "roll eg. 8d6 or d20+3")],
-            AppCommand::autocomplete("roll", &app_meta).await,
+        assert_eq!(
+            Ok("You haven't rolled any dice yet. Try `roll d20`.".to_string()),
+            AppCommand::RollHistory
+                .run("roll history", &mut app_meta)
+                .await,
         );
 
-        // Debug should be excluded from the autocomplete results.
+        for formula in ["d1+4 stealth", "d0", "(d1)^2", "adv d1"] {
+            let _ = AppCommand::Roll(formula.to_string())
+                .run(formula, &mut app_meta)
+                .await;
+        }
+
+        AppCommand::Roll("2d1 drop lowest x2".to_string())
+            .run_output("2d1 drop lowest x2", &mut app_meta)
+            .await
+            .unwrap();
+
         assert_eq!(
-            Vec::<AutocompleteSuggestion>::new(),
-            AppCommand::autocomplete("debug", &app_meta).await,
+            Ok(
+                "# Roll history\n\n* `roll 2d1 drop lowest x2`: **1**, **1**\n* `roll (d1)^2`: **1**, **1**\n* `roll d1+4 stealth`: **5**"
+                    .to_string()
+            ),
+            AppCommand::RollHistory.run("roll history", &mut app_meta).await,
         );
     }
 
     #[tokio::test]
-    async fn display_test() {
-        let app_meta = test::app_meta();
-
-        for command in [AppCommand::Changelog, AppCommand::Debug, AppCommand::Help] {
-            let command_string = command.to_string();
-            assert_ne!("", command_string);
-
-            assert_eq!(
-                CommandMatches::new_canonical(command.clone()),
-                AppCommand::parse_input(&command_string, &app_meta).await,
-                "{}",
-                command_string,
-            );
-
-            assert_eq!(
-                CommandMatches::new_canonical(command),
-                AppCommand::parse_input(&command_string.to_uppercase(), &app_meta).await,
-                "{}",
-                command_string.to_uppercase(),
-            );
-        }
+    async fn run_test_roll_stats() {
+        let mut app_meta = test::app_meta();
+
+        assert_eq!(
+            Ok("# Statistics for `2d2+1`\n\nRange: **3** to **5**\\\nAverage: **4**\n\n| Total | Chance | At least |\n|---:|---:|---:|\n| 3 | 25% | 100% |\n| 4 | 50% | 75% |\n| 5 | 25% | 25% |".to_string()),
+            AppCommand::RollStats("2d2+1".to_string())
+                .run("roll stats 2d2+1", &mut app_meta)
+                .await,
+        );
 
-        assert_eq!("roll d20", AppCommand::Roll("d20".to_string()).to_string());
+        let output = AppCommand::RollStats("adv d20+5 stealth".to_string())
+            .run("roll stats adv d20+5 stealth", &mut app_meta)
+            .await
+            .unwrap();
+        assert!(
+            output.starts_with("# Statistics for `adv d20+5 stealth`\n\nRange: **6** to **25**\\\nAverage: **18.83**\n\n"),
+            "{}",
+            output,
+        );
+        assert!(output.ends_with("\n| 25 | 9.8% | 9.8% |"), "{}", output);
+
+        let output = AppCommand::RollStats("100d6".to_string())
+            .run("roll stats 100d6", &mut app_meta)
+            .await
+            .unwrap();
+        assert_eq!(
+            "# Statistics for `100d6`\n\nRange: **100** to **600**\\\nAverage: **350**",
+            output,
+        );
 
         assert_eq!(
-            CommandMatches::new_canonical(AppCommand::Roll("d20".to_string())),
-            AppCommand::parse_input("roll d20", &app_meta).await,
+            Err("Statistics aren't available for \"(d4+1)^3\". Try a simpler formula, like `roll stats 3d6` or `roll stats adv d20+5`.".to_string()),
+            AppCommand::RollStats("(d4+1)^3".to_string())
+                .run("roll stats (d4+1)^3", &mut app_meta)
+                .await,
         );
 
         assert_eq!(
-            CommandMatches::new_canonical(AppCommand::Roll("D20".to_string())),
-            AppCommand::parse_input("ROLL D20", &app_meta).await,
+            Err(
+                "\"banana\" is not a valid dice formula. See `help` for some examples.".to_string()
+            ),
+            AppCommand::RollStats("banana".to_string())
+                .run("roll stats banana", &mut app_meta)
+                .await,
         );
+
+        assert!(app_meta.roll_history.is_empty());
     }
 }
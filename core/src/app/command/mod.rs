@@ -1,19 +1,23 @@
 pub use alias::CommandAlias;
 pub use app::AppCommand;
+pub use roll_history::{RollHistory, RolledDice};
 pub use runnable::{
     Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
 };
 pub use tutorial::TutorialCommand;
+pub use user_alias::{UserAlias, UserAliases};
 
 mod alias;
 mod app;
+mod roll_history;
 mod runnable;
 mod tutorial;
+mod user_alias;
 
-use super::AppMeta;
-use crate::command::TransitionalCommand;
+use super::{AppMeta, Block, Inline, Output};
 use crate::reference::ReferenceCommand;
 use crate::storage::StorageCommand;
+use crate::table::TableCommand;
 use crate::time::TimeCommand;
 use crate::world::WorldCommand;
 use async_trait::async_trait;
@@ -44,39 +48,21 @@ impl Command {
     }
 
     pub async fn parse_input_irrefutable(input: &str, app_meta: &AppMeta) -> Self {
-        let parse_results = join!(
+        let (alias_matches, command_matches) = join!(
             CommandAlias::parse_input(input, app_meta),
-            AppCommand::parse_input(input, app_meta),
-            ReferenceCommand::parse_input(input, app_meta),
-            StorageCommand::parse_input(input, app_meta),
-            TimeCommand::parse_input(input, app_meta),
-            TransitionalCommand::parse_input(input, app_meta),
-            TutorialCommand::parse_input(input, app_meta),
-            WorldCommand::parse_input(input, app_meta),
+            crate::command::parse_input(input, app_meta),
         );
 
-        // We deliberately skip parse_results.0 and handle it afterwards.
-        let mut result = CommandMatches::default()
-            .union(parse_results.1)
-            .union(parse_results.2)
-            .union(parse_results.3)
-            .union(parse_results.4)
-            .union(parse_results.5)
-            .union(parse_results.6)
-            .union(parse_results.7);
-
-        // While it is normally a fatal error to encounter two command subtypes claiming canonical
-        // matches on a given input, the exception is where aliases are present. In this case, we
-        // want the alias to overwrite the canonical match that would otherwise be returned.
-        result = result.union_with_overwrite(parse_results.0);
-
-        result.into()
+        // While it is normally a fatal error to encounter two commands claiming canonical matches
+        // on a given input, the exception is where aliases are present. In this case, we want the
+        // alias to overwrite the canonical match that would otherwise be returned.
+        command_matches.union_with_overwrite(alias_matches).into()
     }
 }
 
 #[async_trait(?Send)]
 impl Runnable for Command {
-    async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+    async fn run(mut self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         if let Some(command) = &self.matches.canonical_match {
             let other_interpretations_message = if !self.matches.fuzzy_matches.is_empty()
                 && !matches!(
@@ -117,11 +103,21 @@ impl Runnable for Command {
                 result
             }
         } else {
-            match &self.matches.fuzzy_matches.len() {
+            match self.matches.fuzzy_matches.len() {
                 0 => Err(format!("Unknown command: \"{}\"", input)),
                 1 => {
-                    let mut fuzzy_matches = self.matches.fuzzy_matches;
-                    fuzzy_matches.pop().unwrap().run(input, app_meta).await
+                    let command = self.matches.fuzzy_matches.pop().unwrap();
+
+                    if self.matches.corrected {
+                        let notice = Inline::to_markdown(&corrected_notice(&command));
+                        command
+                            .run(input, app_meta)
+                            .await
+                            .map(|s| format!("{}\n\n{}", notice, s))
+                            .map_err(|s| format!("{}\n\n{}", notice, s))
+                    } else {
+                        command.run(input, app_meta).await
+                    }
                 }
                 _ => {
                     let mut message =
@@ -140,6 +136,33 @@ impl Runnable for Command {
             }
         }
     }
+
+    async fn run_output(mut self, input: &str, app_meta: &mut AppMeta) -> Result<Output, Output> {
+        // Unambiguous commands can produce structured output directly. Otherwise, the notices
+        // about alternative interpretations are still assembled as markdown.
+        match (
+            self.matches.canonical_match.take(),
+            self.matches.fuzzy_matches.len(),
+        ) {
+            (Some(command), 0) => command.run_output(input, app_meta).await,
+            (None, 1) => {
+                let command = self.matches.fuzzy_matches.pop().unwrap();
+                let notice = self.matches.corrected.then(|| corrected_notice(&command));
+                let mut result = command.run_output(input, app_meta).await;
+
+                if let Some(notice) = notice {
+                    let (Ok(output) | Err(output)) = &mut result;
+                    output.blocks.insert(0, Block::paragraph(notice));
+                }
+
+                result
+            }
+            (canonical_match, _) => {
+                self.matches.canonical_match = canonical_match;
+                Output::from_result(self.run(input, app_meta).await)
+            }
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -152,26 +175,14 @@ impl ContextAwareParse for Command {
 #[async_trait(?Send)]
 impl Autocomplete for Command {
     async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        let results = join!(
+        let (alias_suggestions, command_suggestions) = join!(
             CommandAlias::autocomplete(input, app_meta),
-            AppCommand::autocomplete(input, app_meta),
-            ReferenceCommand::autocomplete(input, app_meta),
-            StorageCommand::autocomplete(input, app_meta),
-            TimeCommand::autocomplete(input, app_meta),
-            TransitionalCommand::autocomplete(input, app_meta),
-            TutorialCommand::autocomplete(input, app_meta),
-            WorldCommand::autocomplete(input, app_meta),
+            crate::command::autocomplete(input, app_meta),
         );
 
-        std::iter::empty()
-            .chain(results.0)
-            .chain(results.1)
-            .chain(results.2)
-            .chain(results.3)
-            .chain(results.4)
-            .chain(results.5)
-            .chain(results.6)
-            .chain(results.7)
+        alias_suggestions
+            .into_iter()
+            .chain(command_suggestions)
             .collect()
     }
 }
@@ -182,29 +193,50 @@ pub enum CommandType {
     App(AppCommand),
     Reference(ReferenceCommand),
     Storage(StorageCommand),
+    Table(TableCommand),
     Time(TimeCommand),
-    Transitional(TransitionalCommand),
     Tutorial(TutorialCommand),
     World(WorldCommand),
 }
 
 impl CommandType {
     async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
-        if !matches!(self, Self::Alias(_) | Self::Tutorial(_)) {
-            app_meta.command_aliases.clear();
-        }
+        self.clear_aliases(app_meta);
 
         match self {
             Self::Alias(c) => c.run(input, app_meta).await,
             Self::App(c) => c.run(input, app_meta).await,
             Self::Reference(c) => c.run(input, app_meta).await,
             Self::Storage(c) => c.run(input, app_meta).await,
+            Self::Table(c) => c.run(input, app_meta).await,
             Self::Time(c) => c.run(input, app_meta).await,
-            Self::Transitional(c) => c.run(input, app_meta).await,
             Self::Tutorial(c) => c.run(input, app_meta).await,
             Self::World(c) => c.run(input, app_meta).await,
         }
     }
+
+    async fn run_output(self, input: &str, app_meta: &mut AppMeta) -> Result<Output, Output> {
+        self.clear_aliases(app_meta);
+
+        match self {
+            Self::Alias(c) => c.run_output(input, app_meta).await,
+            Self::App(c) => c.run_output(input, app_meta).await,
+            Self::Reference(c) => c.run_output(input, app_meta).await,
+            Self::Storage(c) => c.run_output(input, app_meta).await,
+            Self::Table(c) => c.run_output(input, app_meta).await,
+            Self::Time(c) => c.run_output(input, app_meta).await,
+            Self::Tutorial(c) => c.run_output(input, app_meta).await,
+            Self::World(c) => c.run_output(input, app_meta).await,
+        }
+    }
+
+    /// Aliases only remain valid until the next command is run, unless that command is itself an
+    /// alias or part of the tutorial.
+    fn clear_aliases(&self, app_meta: &mut AppMeta) {
+        if !matches!(self, Self::Alias(_) | Self::Tutorial(_)) {
+            app_meta.command_aliases.clear();
+        }
+    }
 }
 
 impl fmt::Display for CommandType {
@@ -214,14 +246,23 @@ impl fmt::Display for CommandType {
             Self::App(c) => write!(f, "{}", c),
             Self::Reference(c) => write!(f, "{}", c),
             Self::Storage(c) => write!(f, "{}", c),
+            Self::Table(c) => write!(f, "{}", c),
             Self::Time(c) => write!(f, "{}", c),
-            Self::Transitional(c) => write!(f, "{}", c),
             Self::Tutorial(c) => write!(f, "{}", c),
             Self::World(c) => write!(f, "{}", c),
         }
     }
 }
 
+/// Tell the user what their misspelled input was taken to mean, eg. `load Elendor` for "Elandor".
+fn corrected_notice(command: &CommandType) -> Vec<Inline> {
+    vec![Inline::emphasis(vec![
+        Inline::text("Showing results for "),
+        Inline::command(command.to_string()),
+        Inline::text("."),
+    ])]
+}
+
 impl<T: Into<CommandType>> From<T> for Command {
     fn from(c: T) -> Command {
         Command {
@@ -268,8 +309,8 @@ mod test {
         );
 
         assert_eq!(
-            Command::from(CommandMatches::new_canonical(CommandType::Transitional(
-                TransitionalCommand::new("about"),
+            Command::from(CommandMatches::new_canonical(CommandType::App(
+                AppCommand::About,
             ))),
             block_on(Command::parse_input("about", &app_meta))
                 .take_best_match()
@@ -279,11 +320,11 @@ mod test {
         assert_eq!(
             Command::from(CommandMatches::new_canonical(CommandType::World(
                 WorldCommand::Create {
-                    parsed_thing_data: ParsedThing {
+                    parsed_thing_data: Box::new(ParsedThing {
                         thing_data: NpcData::default().into(),
                         unknown_words: Vec::new(),
                         word_count: 1,
-                    },
+                    }),
                 }
             ))),
             block_on(Command::parse_input("create npc", &app_meta))
@@ -317,10 +358,14 @@ mod test {
                 ("person", "create person"),
                 ("pet-store", "create pet-store"),
                 ("pier", "create pier"),
+                ("pious", "create person"),
                 ("place", "create place"),
                 ("plain", "create plain"),
                 ("plateau", "create plateau"),
+                ("poor", "create place"),
                 ("portal", "create portal"),
+                ("pricey", "create place"),
+                ("priest", "create person"),
                 ("principality", "create principality"),
                 ("prison", "create prison"),
                 ("province", "create province"),
@@ -349,18 +394,18 @@ mod test {
 
         assert_eq!(
             CommandType::World(WorldCommand::Create {
-                parsed_thing_data: ParsedThing {
+                parsed_thing_data: Box::new(ParsedThing {
                     thing_data: NpcData::default().into(),
                     unknown_words: Vec::new(),
                     word_count: 1,
-                },
+                }),
             }),
             WorldCommand::Create {
-                parsed_thing_data: ParsedThing {
+                parsed_thing_data: Box::new(ParsedThing {
                     thing_data: NpcData::default().into(),
                     unknown_words: Vec::new(),
                     word_count: 1,
-                },
+                }),
             }
             .into(),
         );
@@ -0,0 +1,70 @@
+use std::collections::VecDeque;
+
+/// The number of rolls remembered by `roll history`.
+const ROLL_HISTORY_LEN: usize = 20;
+
+/// The most recent dice rolls of the session, shown by `roll history`. This is not persisted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RollHistory(VecDeque<RolledDice>);
+
+/// A formula as the user typed it, and the total (or totals, for repeated rolls) that it rolled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RolledDice {
+    pub formula: String,
+    pub totals: Vec<i64>,
+}
+
+impl RollHistory {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the rolls, most recent first.
+    pub fn iter(&self) -> impl Iterator<Item = &RolledDice> {
+        self.0.iter()
+    }
+
+    /// Record a roll, forgetting the oldest one if the history is full.
+    pub fn push(&mut self, rolled_dice: RolledDice) {
+        if self.0.len() >= ROLL_HISTORY_LEN {
+            self.0.pop_back();
+        }
+
+        self.0.push_front(rolled_dice);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_test() {
+        let mut history = RollHistory::default();
+        assert!(history.is_empty());
+
+        for i in 0..25 {
+            history.push(RolledDice {
+                formula: format!("d20+{}", i),
+                totals: vec![i],
+            });
+        }
+
+        assert_eq!(ROLL_HISTORY_LEN, history.iter().count());
+        assert_eq!(
+            vec!["d20+24", "d20+23"],
+            history
+                .iter()
+                .take(2)
+                .map(|rolled_dice| rolled_dice.formula.as_str())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Some("d20+5"),
+            history
+                .iter()
+                .last()
+                .map(|rolled_dice| rolled_dice.formula.as_str()),
+        );
+    }
+}
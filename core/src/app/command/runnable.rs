@@ -1,4 +1,4 @@
-use crate::app::AppMeta;
+use crate::app::{AppMeta, Output};
 use async_trait::async_trait;
 use serde::Serialize;
 use std::borrow::Cow;
@@ -6,6 +6,12 @@ use std::borrow::Cow;
 #[async_trait(?Send)]
 pub trait Runnable: Sized {
     async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String>;
+
+    /// Run the command, producing structured output. Commands that don't override this produce
+    /// markdown via [`Runnable::run`], which is then parsed.
+    async fn run_output(self, input: &str, app_meta: &mut AppMeta) -> Result<Output, Output> {
+        Output::from_result(self.run(input, app_meta).await)
+    }
 }
 
 #[async_trait(?Send)]
@@ -74,6 +80,10 @@ where
 /// | 1 | 0 | The canonical match is run. |
 /// | 1 | 1+ | The canonical match is run, suffixed with the error: "There are other possible interpretations of this command. Did you mean:" |
 ///
+/// Fuzzy matches that correct a misspelling in the input, eg. `load Elendor` for "Elandor", are
+/// flagged as **corrected**. When a lone corrected match is run, its output is prefixed with a
+/// notice so that the user knows what they are looking at.
+///
 /// Since the parsing logic in the code base runs several layers deep across a number of different
 /// structs, this struct provides utilities for combining multiple CommandMatches instances of
 /// differing types, and for transforming the inner type as needed.
@@ -81,6 +91,7 @@ where
 pub struct CommandMatches<T> {
     pub canonical_match: Option<T>,
     pub fuzzy_matches: Vec<T>,
+    pub corrected: bool,
 }
 
 impl<T: std::fmt::Debug> CommandMatches<T> {
@@ -89,6 +100,7 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
         CommandMatches {
             canonical_match: Some(canonical_match),
             fuzzy_matches: Vec::new(),
+            corrected: false,
         }
     }
 
@@ -97,6 +109,16 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
         CommandMatches {
             canonical_match: None,
             fuzzy_matches: vec![fuzzy_match],
+            corrected: false,
+        }
+    }
+
+    /// Create a new instance of `CommandMatches` with a single corrected fuzzy match.
+    pub fn new_corrected(corrected_match: T) -> Self {
+        CommandMatches {
+            canonical_match: None,
+            fuzzy_matches: vec![corrected_match],
+            corrected: true,
         }
     }
 
@@ -117,6 +139,12 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
         self.fuzzy_matches.push(fuzzy_match);
     }
 
+    /// Add a new fuzzy match that corrects a misspelling in the input.
+    pub fn push_corrected(&mut self, corrected_match: T) {
+        self.push_fuzzy(corrected_match);
+        self.corrected = true;
+    }
+
     /// Combine the current CommandMatches with another object that can be massaged into the same
     /// type. The Vecs of fuzzy matches are combined. Panics if both objects lay claim to a
     /// canonical match.
@@ -128,6 +156,7 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
         let CommandMatches {
             canonical_match,
             fuzzy_matches,
+            corrected,
         } = other.into_subtype::<T>();
 
         if let Some(canonical_match) = canonical_match {
@@ -138,6 +167,7 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
         fuzzy_matches
             .into_iter()
             .for_each(|fuzzy_match| self.push_fuzzy(fuzzy_match));
+        self.corrected |= corrected;
 
         self
     }
@@ -178,6 +208,7 @@ impl<T: std::fmt::Debug> CommandMatches<T> {
                 .into_iter()
                 .map(|fuzzy_match| fuzzy_match.into())
                 .collect(),
+            corrected: self.corrected,
         }
     }
 
@@ -197,6 +228,7 @@ impl<T> From<T> for CommandMatches<T> {
         CommandMatches {
             canonical_match: Some(input),
             fuzzy_matches: Vec::default(),
+            corrected: false,
         }
     }
 }
@@ -206,6 +238,7 @@ impl<T> Default for CommandMatches<T> {
         CommandMatches {
             canonical_match: None,
             fuzzy_matches: Vec::default(),
+            corrected: false,
         }
     }
 }
@@ -226,6 +259,14 @@ mod test {
             let command_matches = CommandMatches::new_fuzzy(true);
             assert_eq!(Option::<bool>::None, command_matches.canonical_match);
             assert_eq!([true][..], command_matches.fuzzy_matches[..]);
+            assert!(!command_matches.corrected);
+        }
+
+        {
+            let command_matches = CommandMatches::new_corrected(true);
+            assert_eq!(Option::<bool>::None, command_matches.canonical_match);
+            assert_eq!([true][..], command_matches.fuzzy_matches[..]);
+            assert!(command_matches.corrected);
         }
 
         {
@@ -250,6 +291,11 @@ mod test {
             command_matches.push_fuzzy(2);
             assert_eq!(Option::<u8>::None, command_matches.canonical_match);
             assert_eq!([1u8, 2][..], command_matches.fuzzy_matches[..]);
+            assert!(!command_matches.corrected);
+
+            command_matches.push_corrected(3);
+            assert_eq!([1u8, 2, 3][..], command_matches.fuzzy_matches[..]);
+            assert!(command_matches.corrected);
         }
     }
 
@@ -271,12 +317,13 @@ mod test {
             command_matches.push_fuzzy(3);
             command_matches
         };
-        let command_matches_2 = CommandMatches::new_fuzzy(4u8);
+        let command_matches_2 = CommandMatches::new_corrected(4u8);
 
         let command_matches_result = command_matches_1.union(command_matches_2);
 
         assert_eq!(Some(1u16), command_matches_result.canonical_match);
         assert_eq!([2u16, 3, 4][..], command_matches_result.fuzzy_matches[..]);
+        assert!(command_matches_result.corrected);
     }
 
     #[test]
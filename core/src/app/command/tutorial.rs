@@ -1,8 +1,5 @@
 use super::CommandType;
-use crate::app::{
-    AppCommand, AppMeta, Autocomplete, AutocompleteSuggestion, Command, CommandAlias,
-    CommandMatches, ContextAwareParse, Runnable,
-};
+use crate::app::{AppCommand, AppMeta, Command, CommandAlias, Runnable};
 use crate::reference::{ItemCategory, ReferenceCommand, Spell};
 use crate::storage::{Change, Record, StorageCommand};
 use crate::time::TimeCommand;
@@ -180,7 +177,10 @@ impl TutorialCommand {
                 app_meta.command_aliases.insert(CommandAlias::literal(
                     "more",
                     format!("create {}", thing_data.display_description()),
-                    WorldCommand::CreateMultiple { thing_data }.into(),
+                    WorldCommand::CreateMultiple {
+                        thing_data: Box::new(thing_data),
+                    }
+                    .into(),
                 ));
 
                 output.push_str(&format!(
@@ -344,16 +344,10 @@ impl TutorialCommand {
                 }
             }
             Self::GeneratingAlternatives { .. } => {
-                if let Some(CommandType::World(WorldCommand::Create {
-                    parsed_thing_data:
-                        ParsedThing {
-                            thing_data,
-                            unknown_words: _,
-                            word_count: _,
-                        },
-                })) = command
+                if let Some(CommandType::World(WorldCommand::Create { parsed_thing_data })) =
+                    command
                 {
-                    thing_data.npc_data()
+                    parsed_thing_data.thing_data.npc_data()
                         == Some(&NpcData {
                             species: Species::Human.into(),
                             ethnicity: Ethnicity::Human.into(),
@@ -389,18 +383,10 @@ impl TutorialCommand {
                 }
             }
             Self::TheJournal { npc, .. } => {
-                if let Some(CommandType::World(WorldCommand::Edit {
-                    name,
-                    parsed_diff:
-                        ParsedThing {
-                            thing_data,
-                            unknown_words: _,
-                            word_count: _,
-                        },
-                })) = command
+                if let Some(CommandType::World(WorldCommand::Edit { name, parsed_diff })) = command
                 {
                     name.eq_ci(&npc.name)
-                        && thing_data.npc_data()
+                        && parsed_diff.thing_data.npc_data()
                             == Some(&NpcData {
                                 species: Species::HalfElf.into(),
                                 ..Default::default()
@@ -771,31 +757,6 @@ impl Runnable for TutorialCommand {
     }
 }
 
-#[async_trait(?Send)]
-impl ContextAwareParse for TutorialCommand {
-    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
-        if input.eq_ci("tutorial") {
-            CommandMatches::new_canonical(TutorialCommand::Introduction)
-        } else {
-            CommandMatches::default()
-        }
-    }
-}
-
-#[async_trait(?Send)]
-impl Autocomplete for TutorialCommand {
-    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        if "tutorial".starts_with_ci(input) {
-            vec![AutocompleteSuggestion::new(
-                "tutorial",
-                "feature walkthrough",
-            )]
-        } else {
-            Vec::new()
-        }
-    }
-}
-
 impl fmt::Display for TutorialCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
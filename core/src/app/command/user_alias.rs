@@ -0,0 +1,159 @@
+use crate::utils::CaseInsensitiveStr;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Shortcuts defined by the user with the `alias` and `macro` commands. The whole set is persisted
+/// as a single value in the key-value store.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct UserAliases(Vec<UserAlias>);
+
+/// A name that runs one or more commands when typed, eg. `fight` for `roll d20+5`. An alias with
+/// more than one command is a macro.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct UserAlias {
+    pub name: String,
+    pub commands: Vec<String>,
+}
+
+impl UserAliases {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &UserAlias> {
+        self.0.iter()
+    }
+
+    /// Get the alias with a given (case-insensitive) name.
+    pub fn get(&self, name: &str) -> Option<&UserAlias> {
+        self.0.iter().find(|alias| alias.name.eq_ci(name))
+    }
+
+    /// Add an alias, returning the alias that it replaced, if any.
+    pub fn insert(&mut self, alias: UserAlias) -> Option<UserAlias> {
+        let old_alias = self.remove(&alias.name);
+
+        let index = self
+            .0
+            .partition_point(|other| other.name.cmp_ci(&alias.name).is_lt());
+        self.0.insert(index, alias);
+
+        old_alias
+    }
+
+    /// Remove the alias with a given (case-insensitive) name, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<UserAlias> {
+        let index = self.0.iter().position(|alias| alias.name.eq_ci(name))?;
+        Some(self.0.remove(index))
+    }
+}
+
+impl UserAlias {
+    pub fn is_macro(&self) -> bool {
+        self.commands.len() > 1
+    }
+
+    /// The commands that the alias runs, as they would be typed when defining it.
+    pub fn summary(&self) -> String {
+        self.commands.join("; ")
+    }
+}
+
+impl fmt::Display for UserAlias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} = {}",
+            if self.is_macro() { "macro" } else { "alias" },
+            self.name,
+            self.summary(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_get_remove_test() {
+        let mut aliases = UserAliases::default();
+        assert!(aliases.is_empty());
+
+        assert_eq!(None, aliases.insert(fight()));
+        assert_eq!(None, aliases.insert(rest()));
+        assert_eq!(Some(&fight()), aliases.get("FIGHT"));
+        assert_eq!(None, aliases.get("flee"));
+
+        let new_fight = UserAlias {
+            name: "Fight".to_string(),
+            commands: vec!["roll d20+6".to_string()],
+        };
+        assert_eq!(Some(fight()), aliases.insert(new_fight.clone()));
+        assert_eq!(Some(&new_fight), aliases.get("fight"));
+
+        assert_eq!(Some(rest()), aliases.remove("Rest"));
+        assert_eq!(None, aliases.remove("rest"));
+        assert_eq!(vec![&new_fight], aliases.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn insert_test_sorted() {
+        let mut aliases = UserAliases::default();
+
+        for name in ["rest", "Fight", "attack", "Zap"] {
+            aliases.insert(UserAlias {
+                name: name.to_string(),
+                commands: vec!["now".to_string()],
+            });
+        }
+
+        assert_eq!(
+            vec!["attack", "Fight", "rest", "Zap"],
+            aliases
+                .iter()
+                .map(|alias| alias.name.as_str())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("alias fight = roll d20+5", fight().to_string());
+        assert_eq!("macro rest = +8h; now", rest().to_string());
+        assert!(!fight().is_macro());
+        assert!(rest().is_macro());
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        let mut aliases = UserAliases::default();
+        aliases.insert(fight());
+        aliases.insert(rest());
+
+        assert_eq!(
+            r#"[{"name":"fight","commands":["roll d20+5"]},{"name":"rest","commands":["+8h","now"]}]"#,
+            serde_json::to_string(&aliases).unwrap(),
+        );
+
+        assert_eq!(
+            aliases,
+            serde_json::from_str(&serde_json::to_string(&aliases).unwrap()).unwrap(),
+        );
+    }
+
+    fn fight() -> UserAlias {
+        UserAlias {
+            name: "fight".to_string(),
+            commands: vec!["roll d20+5".to_string()],
+        }
+    }
+
+    fn rest() -> UserAlias {
+        UserAlias {
+            name: "rest".to_string(),
+            commands: vec!["+8h".to_string(), "now".to_string()],
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use super::{CommandAlias, Event};
+use super::{CommandAlias, Event, RollHistory, UserAliases};
 use crate::storage::{DataStore, Repository};
+use crate::table::RandomTables;
 use crate::world;
+use futures::channel::mpsc;
 use rand::prelude::*;
 use std::collections::HashSet;
 use std::fmt;
@@ -8,32 +10,76 @@ use std::fmt;
 pub struct AppMeta {
     pub command_aliases: HashSet<CommandAlias>,
     pub demographics: world::Demographics,
-    pub event_dispatcher: &'static dyn Fn(Event),
+    event_dispatcher: Box<dyn Fn(Event)>,
+    event_subscribers: Vec<mpsc::UnboundedSender<Event>>,
+    pub random_tables: RandomTables,
     pub rng: SmallRng,
     pub repository: Repository,
+    pub roll_history: RollHistory,
+    pub user_aliases: UserAliases,
 }
 
 impl AppMeta {
-    pub fn new<F: Fn(Event)>(
+    pub fn new(
         data_store: impl DataStore + 'static,
-        event_dispatcher: &'static F,
+        event_dispatcher: impl Fn(Event) + 'static,
     ) -> Self {
         Self {
             command_aliases: HashSet::default(),
             demographics: world::Demographics::default(),
-            event_dispatcher,
+            event_dispatcher: Box::new(event_dispatcher),
+            event_subscribers: Vec::new(),
+            random_tables: RandomTables::default(),
             repository: Repository::new(data_store),
             rng: SmallRng::from_entropy(),
+            roll_history: RollHistory::default(),
+            user_aliases: UserAliases::default(),
         }
     }
+
+    /// Notify the event dispatcher and any subscribers of an event.
+    pub fn dispatch(&mut self, event: Event) {
+        self.event_subscribers
+            .retain(|subscriber| subscriber.unbounded_send(event.clone()).is_ok());
+
+        (self.event_dispatcher)(event);
+    }
+
+    /// Dispatch events for any changes made to the repository since this was last called.
+    pub fn dispatch_changes(&mut self) {
+        for event in self.repository.take_events() {
+            self.dispatch(event);
+        }
+    }
+
+    pub fn subscribe(&mut self) -> mpsc::UnboundedReceiver<Event> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.event_subscribers.push(sender);
+        receiver
+    }
+
+    /// Reseed the random number generator, making subsequent output reproducible. A seed of
+    /// `None` reverts to an unpredictable seed drawn from the system's entropy source.
+    pub fn set_seed(&mut self, seed: Option<u64>) {
+        self.rng = if let Some(seed) = seed {
+            SmallRng::seed_from_u64(seed)
+        } else {
+            SmallRng::from_entropy()
+        };
+    }
 }
 
 impl fmt::Debug for AppMeta {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "AppMeta {{ command_aliases: {:?}, demographics: {:?}, repository: {:?} }}",
-            self.command_aliases, self.demographics, self.repository,
+            "AppMeta {{ command_aliases: {:?}, demographics: {:?}, random_tables: {:?}, repository: {:?}, roll_history: {:?}, user_aliases: {:?} }}",
+            self.command_aliases,
+            self.demographics,
+            self.random_tables,
+            self.repository,
+            self.roll_history,
+            self.user_aliases,
         )
     }
 }
@@ -50,7 +96,7 @@ mod test {
         app_meta.demographics = Demographics::new(HashMap::new());
 
         assert_eq!(
-            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}) }, repository: Repository { data_store_enabled: false, recent: [] } }",
+            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}), vocabulary: Vocabulary { ethnicities: {}, inn_words: [] } }, random_tables: RandomTables([]), repository: Repository { data_store_enabled: false, recent: [] }, roll_history: RollHistory([]), user_aliases: UserAliases([]) }",
             format!("{:?}", app_meta),
         );
     }
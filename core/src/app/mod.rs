@@ -1,15 +1,23 @@
 pub use command::{
     AppCommand, Autocomplete, AutocompleteSuggestion, Command, CommandAlias, CommandMatches,
-    ContextAwareParse, Runnable,
+    CommandType, ContextAwareParse, RollHistory, RolledDice, Runnable, TutorialCommand, UserAlias,
+    UserAliases,
 };
 pub use meta::AppMeta;
+pub use output::{Block, Inline, Output};
 
 mod command;
 mod meta;
+mod output;
 
 use crate::storage::backup::{import, BackupData};
-use crate::utils::CaseInsensitiveStr;
+use crate::storage::KeyValue;
+use crate::time::Time;
+use crate::utils::{edit_distance_ci, CaseInsensitiveStr};
+use crate::world::thing::Thing;
+use futures::channel::mpsc;
 use initiative_macros::motd;
+use uuid::Uuid;
 
 /// The application wrapper. Its inner [`AppMeta`] object holds metadata associated with the
 /// application, including ephemeral storage of journal entries and the object representing the
@@ -20,21 +28,43 @@ use initiative_macros::motd;
 #[derive(Debug)]
 pub struct App {
     meta: AppMeta,
+
+    /// A seed provided by the frontend with [`App::set_seed`], which takes priority over any seed
+    /// stored with the `seed` command.
+    seed: Option<u64>,
 }
 
 /// An event that can occur while the app is running that may require special handling by the UI.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Event {
     /// The user typed the `export` command and the journal backup is ready to download.
     Export(BackupData),
 
     /// The user typed the `import` command and should be prompted to select a file to import.
     Import,
+
+    /// A thing was generated or otherwise added to recent entries or the journal.
+    ThingCreated(Thing),
+
+    /// A thing was modified, including being saved to or removed from the journal.
+    ThingEdited(Thing),
+
+    /// A thing was deleted from recent entries or the journal.
+    ThingDeleted { uuid: Uuid, name: String },
+
+    /// The in-game time was changed, whether by advancing it or by undoing a previous change.
+    TimeChanged(Time),
+
+    /// A change was undone. Followed by the events describing the effect of undoing it.
+    Undo,
+
+    /// A change was redone. Followed by the events describing the effect of redoing it.
+    Redo,
 }
 
 impl App {
     pub fn new(meta: AppMeta) -> App {
-        App { meta }
+        App { meta, seed: None }
     }
 
     /// Initialize a running application. This is done as a separate step from the constructor
@@ -42,6 +72,20 @@ impl App {
     /// store to initialize, which may involve opening a database connection.
     pub async fn init(&mut self) -> &'static str {
         self.meta.repository.init().await;
+
+        if let Some(seed) = self.seed {
+            self.meta.set_seed(Some(seed));
+        } else if let Ok(KeyValue::Seed(Some(seed))) = self
+            .meta
+            .repository
+            .get_key_value(&KeyValue::Seed(None))
+            .await
+        {
+            self.meta.set_seed(Some(seed));
+        }
+
+        self.load_user_data().await;
+
         let (motd, motd_len) = motd!("! Local storage is not available in your browser. You will be able to use initiative.sh, but anything you save will not persist beyond this session.");
 
         if self.meta.repository.data_store_enabled() {
@@ -51,14 +95,43 @@ impl App {
         }
     }
 
+    /// Seed the random number generator so that generated content is reproducible. Unlike the
+    /// `seed` command, this is not persisted to the data store, and it overrides any stored seed
+    /// when [`App::init`] runs.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.meta.set_seed(Some(seed));
+    }
+
     /// The user typed an input and pressed Enter. What happens?
     ///
     /// On success or failure, returns a String that can be displayed back to the user.
     pub async fn command(&mut self, input: &str) -> Result<String, String> {
-        Command::parse_input_irrefutable(input, &self.meta)
+        let result = Command::parse_input_irrefutable(input, &self.meta)
             .await
             .run(input, &mut self.meta)
+            .await;
+
+        self.meta.dispatch_changes();
+        result
+    }
+
+    /// Like [`App::command`], but returns structured output that the frontend can render natively
+    /// rather than a markdown string.
+    pub async fn command_output(&mut self, input: &str) -> Result<Output, Output> {
+        let result = Command::parse_input_irrefutable(input, &self.meta)
             .await
+            .run_output(input, &mut self.meta)
+            .await;
+
+        self.meta.dispatch_changes();
+        result
+    }
+
+    /// Receive all subsequent [`Event`]s, in addition to those passed to the event dispatcher
+    /// provided at construction. The subscription ends when the receiver is dropped.
+    pub fn subscribe(&mut self) -> mpsc::UnboundedReceiver<Event> {
+        self.meta.subscribe()
     }
 
     /// The user has updated their input and a new set of suggestions should be populated. This
@@ -67,10 +140,15 @@ impl App {
     /// used here to allow either `String` or `&'static str`, whatever is appropriate to a given
     /// case.
     ///
-    /// Returns a maximum of 10 results.
+    /// Suggestions that complete the input are listed first, followed by any that correct a
+    /// probable typo, closest first. Returns a maximum of 10 results.
     pub async fn autocomplete(&self, input: &str) -> Vec<AutocompleteSuggestion> {
         let mut suggestions: Vec<_> = Command::autocomplete(input, &self.meta).await;
-        suggestions.sort_by(|a, b| a.term.cmp_ci(&b.term));
+        suggestions.sort_by(|a, b| {
+            typo_rank(input, &a.term)
+                .cmp(&typo_rank(input, &b.term))
+                .then_with(|| a.term.cmp_ci(&b.term))
+        });
         suggestions.truncate(10);
         suggestions
     }
@@ -78,9 +156,58 @@ impl App {
     /// The part of the import flow that occurs after the user selects a file in response to the
     /// [`Event::Import`].
     pub async fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
-        import(&mut self.meta.repository, data)
+        let result = import(&mut self.meta.repository, data).await;
+        self.meta.dispatch_changes();
+        let stats = result.map_err(|_| "Failed to import.".to_string())?;
+
+        self.load_user_data().await;
+
+        Ok(stats.to_string())
+    }
+
+    /// Load the user's vocabulary, aliases and tables from the data store, eg. after an import
+    /// has changed them.
+    async fn load_user_data(&mut self) {
+        if let Ok(KeyValue::Vocabulary(Some(vocabulary))) = self
+            .meta
+            .repository
+            .get_key_value(&KeyValue::Vocabulary(None))
+            .await
+        {
+            self.meta.demographics.set_vocabulary(vocabulary);
+        }
+
+        if let Ok(KeyValue::Aliases(Some(aliases))) = self
+            .meta
+            .repository
+            .get_key_value(&KeyValue::Aliases(None))
             .await
-            .map(|stats| stats.to_string())
-            .map_err(|_| "Failed to import.".to_string())
+        {
+            self.meta.user_aliases = aliases;
+        }
+
+        if let Ok(KeyValue::Tables(Some(tables))) = self
+            .meta
+            .repository
+            .get_key_value(&KeyValue::Tables(None))
+            .await
+        {
+            self.meta.random_tables = tables;
+        }
+    }
+}
+
+/// 0 if the suggestion begins with the input, otherwise the number of typos between the input and
+/// the corresponding start of the suggestion.
+fn typo_rank(input: &str, term: &str) -> usize {
+    if term.starts_with_ci(input) {
+        0
+    } else {
+        let prefix_end = term
+            .char_indices()
+            .nth(input.chars().count())
+            .map_or(term.len(), |(i, _)| i);
+
+        edit_distance_ci(input, &term[..prefix_end]).max(1)
     }
 }
@@ -0,0 +1,222 @@
+//! Rendering for terminals that understand ANSI escape codes. Line wrapping is left to the
+//! terminal frontend.
+
+use super::{Block, Inline};
+
+const BOLD: (&str, &str) = ("\x1b[1m", "\x1b[22m");
+const ITALIC: (&str, &str) = ("\x1b[3m", "\x1b[23m");
+const UNDERLINE: (&str, &str) = ("\x1b[4m", "\x1b[24m");
+const RED: (&str, &str) = ("\x1b[31m", "\x1b[39m");
+const YELLOW: (&str, &str) = ("\x1b[33m", "\x1b[39m");
+const REVERSE: (&str, &str) = ("\x1b[7m", "\x1b[27m");
+
+/// Tracks which command link is being rendered, so that the selected one can be highlighted.
+struct Links {
+    selected: Option<usize>,
+    next: usize,
+}
+
+/// Render the blocks, highlighting the `selected`th command link (see [`Output::commands`]).
+///
+/// [`Output::commands`]: super::Output::commands
+pub fn render(blocks: &[Block], selected: Option<usize>) -> String {
+    render_blocks(blocks, &mut Links { selected, next: 0 })
+}
+
+fn render_blocks(blocks: &[Block], links: &mut Links) -> String {
+    blocks
+        .iter()
+        .map(|block| render_block(block, links))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn render_block(block: &Block, links: &mut Links) -> String {
+    match block {
+        Block::Heading { level: 1, content } => {
+            wrap(BOLD, &wrap(UNDERLINE, &render_inlines(content, links)))
+        }
+        Block::Heading { content, .. } => wrap(BOLD, &render_inlines(content, links)),
+        Block::Paragraph { content } => render_inlines(content, links),
+        Block::List { items } => items
+            .iter()
+            .map(|item| format!("• {}", render_inlines(item, links).replace('\n', "\n  ")))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Table { header, rows } => render_table(header, rows, links),
+        Block::Warning { content } => wrap(YELLOW, &render_inlines(content, links)),
+        Block::Error { content } => wrap(RED, &render_inlines(content, links)),
+        Block::Thing { blocks, .. } => render_blocks(blocks, links),
+    }
+}
+
+fn render_table(header: &[Vec<Inline>], rows: &[Vec<Vec<Inline>>], links: &mut Links) -> String {
+    let columns = rows
+        .iter()
+        .map(|row| row.len())
+        .chain(std::iter::once(header.len()))
+        .max()
+        .unwrap_or(0);
+
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .map(|row| &row[..])
+                .chain(std::iter::once(header))
+                .filter_map(|row| row.get(i))
+                .map(|cell| plain_text(cell).chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut render_row = |row: &[Vec<Inline>], bold: bool| {
+        row.iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| {
+                let rendered = render_inlines(cell, links);
+                let padding = " ".repeat(width - plain_text(cell).chars().count());
+
+                if bold {
+                    format!("{}{}", wrap(BOLD, &rendered), padding)
+                } else {
+                    format!("{}{}", rendered, padding)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = Vec::new();
+
+    if !header.is_empty() {
+        lines.push(render_row(header, true));
+    }
+
+    lines.extend(rows.iter().map(|row| render_row(row, false)));
+    lines.join("\n")
+}
+
+fn render_inlines(inlines: &[Inline], links: &mut Links) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text } => text.clone(),
+            Inline::Strong { content } => wrap(BOLD, &render_inlines(content, links)),
+            Inline::Emphasis { content } => wrap(ITALIC, &render_inlines(content, links)),
+            Inline::Command { command } | Inline::Suggestion { command } => {
+                let index = links.next;
+                links.next += 1;
+
+                if links.selected == Some(index) {
+                    wrap(REVERSE, command)
+                } else {
+                    wrap(UNDERLINE, command)
+                }
+            }
+            Inline::Link { text, url } => format!("{} ({})", wrap(UNDERLINE, text), url),
+            Inline::Roll { expression, total } if expression.is_empty() => total.to_string(),
+            Inline::Roll { expression, total } => {
+                format!("{} = {}", expression, wrap(BOLD, &total.to_string()))
+            }
+            Inline::LineBreak => "\n".to_string(),
+        })
+        .collect()
+}
+
+/// The text of a series of inlines without any formatting, used to measure its width.
+fn plain_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text } => text.clone(),
+            Inline::Strong { content } | Inline::Emphasis { content } => plain_text(content),
+            Inline::Command { command } | Inline::Suggestion { command } => command.clone(),
+            Inline::Link { text, url } => format!("{} ({})", text, url),
+            Inline::Roll { expression, total } if expression.is_empty() => total.to_string(),
+            Inline::Roll { expression, total } => format!("{} = {}", expression, total),
+            Inline::LineBreak => " ".to_string(),
+        })
+        .collect()
+}
+
+fn wrap((start, end): (&str, &str), text: &str) -> String {
+    format!("{}{}{}", start, text, end)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_table_test() {
+        assert_eq!(
+            "\x1b[1md6\x1b[22m  \x1b[1mResult\x1b[22m\n1   \x1b[4mFireball\x1b[24m\n10  Nothing",
+            render(
+                &[Block::Table {
+                    header: vec![vec![Inline::text("d6")], vec![Inline::text("Result")]],
+                    rows: vec![
+                        vec![
+                            vec![Inline::text("1")],
+                            vec![Inline::Command {
+                                command: "Fireball".to_string(),
+                            }],
+                        ],
+                        vec![vec![Inline::text("10")], vec![Inline::text("Nothing")]],
+                    ],
+                }],
+                None
+            ),
+        );
+    }
+
+    #[test]
+    fn render_list_test() {
+        assert_eq!(
+            "• One\n• Two\n  lines\n\n\x1b[31mOops\x1b[39m",
+            render(
+                &[
+                    Block::List {
+                        items: vec![vec![Inline::text("One")], vec![Inline::text("Two\nlines")]],
+                    },
+                    Block::Error {
+                        content: vec![Inline::text("Oops")],
+                    },
+                ],
+                None
+            ),
+        );
+    }
+
+    #[test]
+    fn render_selected_test() {
+        let blocks = [
+            Block::List {
+                items: vec![
+                    vec![Inline::Command {
+                        command: "one".to_string(),
+                    }],
+                    vec![Inline::Command {
+                        command: "two".to_string(),
+                    }],
+                ],
+            },
+            Block::Paragraph {
+                content: vec![Inline::Suggestion {
+                    command: "save".to_string(),
+                }],
+            },
+        ];
+
+        assert_eq!(
+            "• \x1b[4mone\x1b[24m\n• \x1b[7mtwo\x1b[27m\n\n\x1b[4msave\x1b[24m",
+            render(&blocks, Some(1)),
+        );
+        assert_eq!(
+            "• \x1b[4mone\x1b[24m\n• \x1b[4mtwo\x1b[24m\n\n\x1b[7msave\x1b[27m",
+            render(&blocks, Some(2)),
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Rendering to HTML, matching the markup produced by the web frontend's markdown renderer.
+
+use super::{Block, Inline};
+
+pub fn render(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(render_block)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_block(block: &Block) -> String {
+    match block {
+        Block::Heading { level, content } => {
+            format!("<h{0}>{1}</h{0}>", level, render_inlines(content))
+        }
+        Block::Paragraph { content } => format!("<p>{}</p>", render_inlines(content)),
+        Block::List { items } => format!(
+            "<ul>\n{}\n</ul>",
+            items
+                .iter()
+                .map(|item| format!("<li>{}</li>", render_inlines(item)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        Block::Table { header, rows } => {
+            let render_row = |row: &[Vec<Inline>], tag: &str| {
+                format!(
+                    "<tr>{}</tr>",
+                    row.iter()
+                        .map(|cell| format!("<{0}>{1}</{0}>", tag, render_inlines(cell)))
+                        .collect::<String>(),
+                )
+            };
+
+            let mut output = "<table>\n".to_string();
+
+            if !header.is_empty() {
+                output.push_str(&format!("<thead>{}</thead>\n", render_row(header, "th")));
+            }
+
+            output.push_str(&format!(
+                "<tbody>\n{}\n</tbody>\n</table>",
+                rows.iter()
+                    .map(|row| render_row(row, "td"))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ));
+
+            output
+        }
+        Block::Warning { content } | Block::Error { content } => {
+            format!("<p class=\"error\">{}</p>", render_inlines(content))
+        }
+        Block::Thing { kind, uuid, blocks } => format!(
+            "<div class=\"thing-box {}\" data-uuid=\"{}\">\n{}\n</div>",
+            escape(kind),
+            escape(uuid),
+            render(blocks),
+        ),
+    }
+}
+
+fn render_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text } => escape(text),
+            Inline::Strong { content } => format!("<strong>{}</strong>", render_inlines(content)),
+            Inline::Emphasis { content } => format!("<em>{}</em>", render_inlines(content)),
+            Inline::Command { command } => format!("<code>{}</code>", escape(command)),
+            Inline::Suggestion { command } => {
+                format!("<code class=\"temp-link\">{}</code>", escape(command))
+            }
+            Inline::Link { text, url } => format!(
+                "<a href=\"{}\" target=\"_blank\">{}</a>",
+                escape(url),
+                escape(text),
+            ),
+            Inline::Roll { expression, total } if expression.is_empty() => total.to_string(),
+            Inline::Roll { expression, total } => format!(
+                "<span class=\"roll\">{} = <strong>{}</strong></span>",
+                escape(expression),
+                total,
+            ),
+            Inline::LineBreak => "<br>\n".to_string(),
+        })
+        .collect()
+}
+
+fn escape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        assert_eq!(
+            "<ul>\n<li><a href=\"https://example.com/?a=1&amp;b=2\" target=\"_blank\">Link</a></li>\n<li>&lt;script&gt;</li>\n</ul>\n<table>\n<thead><tr><th>d6</th></tr></thead>\n<tbody>\n<tr><td><span class=\"roll\">[4] = <strong>4</strong></span></td></tr>\n</tbody>\n</table>",
+            render(&[
+                Block::List {
+                    items: vec![
+                        vec![Inline::Link {
+                            text: "Link".to_string(),
+                            url: "https://example.com/?a=1&b=2".to_string(),
+                        }],
+                        vec![Inline::text("<script>")],
+                    ],
+                },
+                Block::Table {
+                    header: vec![vec![Inline::text("d6")]],
+                    rows: vec![vec![vec![Inline::Roll {
+                        expression: "[4]".to_string(),
+                        total: 4,
+                    }]]],
+                },
+            ]),
+        );
+    }
+}
@@ -0,0 +1,650 @@
+//! Conversion to and from the subset of markdown produced by commands. In addition to the usual
+//! markdown syntax, `~command~` denotes a [`Inline::Suggestion`], a line beginning with `! `
+//! denotes a [`Block::Warning`], and thing boxes are delimited by `<div class="thing-box ...">`.
+
+use super::{Block, Inline};
+
+pub fn parse(input: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut open_things: Vec<(String, String, Vec<Block>)> = Vec::new();
+
+    for chunk in chunks(input) {
+        if let [line] = chunk[..] {
+            if let Some((kind, uuid)) = parse_thing_open(line) {
+                open_things.push((kind, uuid, Vec::new()));
+                continue;
+            } else if line == "</div>" {
+                if let Some((kind, uuid, thing_blocks)) = open_things.pop() {
+                    let thing = Block::Thing {
+                        kind,
+                        uuid,
+                        blocks: thing_blocks,
+                    };
+
+                    if let Some((_, _, parent)) = open_things.last_mut() {
+                        parent.push(thing);
+                    } else {
+                        blocks.push(thing);
+                    }
+
+                    continue;
+                }
+            }
+        }
+
+        let target = if let Some((_, _, thing_blocks)) = open_things.last_mut() {
+            thing_blocks
+        } else {
+            &mut blocks
+        };
+
+        parse_chunk(&chunk, target);
+    }
+
+    // Unclosed boxes are closed implicitly.
+    while let Some((kind, uuid, thing_blocks)) = open_things.pop() {
+        let thing = Block::Thing {
+            kind,
+            uuid,
+            blocks: thing_blocks,
+        };
+
+        if let Some((_, _, parent)) = open_things.last_mut() {
+            parent.push(thing);
+        } else {
+            blocks.push(thing);
+        }
+    }
+
+    blocks
+}
+
+pub fn render(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(render_block)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Split the input into groups of lines separated by blank lines.
+fn chunks(input: &str) -> Vec<Vec<&str>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !chunk.is_empty() {
+                chunks.push(std::mem::take(&mut chunk));
+            }
+        } else {
+            chunk.push(line);
+        }
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+fn parse_thing_open(line: &str) -> Option<(String, String)> {
+    let (kind, uuid) = line
+        .strip_prefix(r#"<div class="thing-box "#)?
+        .strip_suffix(r#"">"#)?
+        .split_once(r#"" data-uuid=""#)?;
+
+    Some((kind.to_string(), uuid.to_string()))
+}
+
+fn heading_level(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+
+    if (1..=6).contains(&level) {
+        line[level..]
+            .strip_prefix(' ')
+            .map(|rest| (level as u8, rest.trim()))
+    } else {
+        None
+    }
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    line.strip_prefix("* ").or_else(|| line.strip_prefix("- "))
+}
+
+fn starts_block(line: &str) -> bool {
+    heading_level(line).is_some() || list_item(line).is_some() || line.starts_with('|')
+}
+
+fn parse_chunk(lines: &[&str], blocks: &mut Vec<Block>) {
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        if let Some((level, text)) = heading_level(line) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(text),
+            });
+            i += 1;
+        } else if list_item(line).is_some() {
+            let mut items: Vec<String> = Vec::new();
+
+            while let Some(line) = lines.get(i) {
+                if let Some(item) = list_item(line) {
+                    items.push(item.to_string());
+                } else if line.starts_with(' ') && !items.is_empty() {
+                    let item = items.last_mut().unwrap();
+                    item.push('\n');
+                    item.push_str(line.trim_start());
+                } else {
+                    break;
+                }
+                i += 1;
+            }
+
+            blocks.push(Block::List {
+                items: items.iter().map(|item| parse_inlines(item)).collect(),
+            });
+        } else if line.starts_with('|') {
+            let mut rows: Vec<Vec<Vec<Inline>>> = Vec::new();
+            let mut header = None;
+
+            while let Some(line) = lines.get(i).filter(|line| line.starts_with('|')) {
+                let cells = table_cells(line);
+
+                if cells.iter().all(|cell| is_table_separator(cell)) {
+                    if header.is_none() && rows.len() == 1 {
+                        header = rows.pop();
+                    }
+                } else {
+                    rows.push(cells.iter().map(|cell| parse_inlines(cell)).collect());
+                }
+                i += 1;
+            }
+
+            blocks.push(Block::Table {
+                header: header.unwrap_or_default(),
+                rows,
+            });
+        } else {
+            let start = i;
+            i += 1;
+
+            while lines.get(i).is_some_and(|line| !starts_block(line)) {
+                i += 1;
+            }
+
+            let text = lines[start..i].join("\n");
+
+            blocks.push(if let Some(warning) = text.strip_prefix("! ") {
+                Block::Warning {
+                    content: parse_inlines(warning),
+                }
+            } else {
+                Block::Paragraph {
+                    content: parse_inlines(&text),
+                }
+            });
+        }
+    }
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+
+    let mut cells = vec![String::new()];
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let cell = cells.last_mut().unwrap();
+                cell.push(c);
+                if let Some(next) = chars.next() {
+                    cell.push(next);
+                }
+            }
+            '|' => cells.push(String::new()),
+            c => cells.last_mut().unwrap().push(c),
+        }
+    }
+
+    cells.iter().map(|cell| cell.trim().to_string()).collect()
+}
+
+fn is_table_separator(cell: &str) -> bool {
+    let cell = cell.strip_prefix(':').unwrap_or(cell);
+    let cell = cell.strip_suffix(':').unwrap_or(cell);
+    !cell.is_empty() && cell.chars().all(|c| c == '-')
+}
+
+pub fn parse_inlines(input: &str) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+
+    let flush = |text: &mut String, inlines: &mut Vec<Inline>| {
+        if !text.is_empty() {
+            inlines.push(Inline::text(std::mem::take(text)));
+        }
+    };
+
+    while let Some(c) = input[i..].chars().next() {
+        let rest = &input[i..];
+
+        let token = match c {
+            '\\' => match rest[1..].chars().next() {
+                Some('\n') => Some((Inline::LineBreak, 2)),
+                Some(next) if next.is_ascii_punctuation() => {
+                    text.push(next);
+                    i += 2;
+                    continue;
+                }
+                _ => None,
+            },
+            '`' => rest[1..].find('`').map(|end| {
+                (
+                    Inline::Command {
+                        command: rest[1..end + 1].to_string(),
+                    },
+                    end + 2,
+                )
+            }),
+            '~' => find_closing(&rest[1..], "~")
+                .filter(|end| *end > 0)
+                .map(|end| {
+                    (
+                        Inline::Suggestion {
+                            command: rest[1..end + 1].to_string(),
+                        },
+                        end + 2,
+                    )
+                }),
+            '*' if rest.starts_with("**") => find_closing(&rest[2..], "**")
+                .filter(|end| *end > 0)
+                .map(|end| {
+                    (
+                        Inline::Strong {
+                            content: parse_inlines(&rest[2..end + 2]),
+                        },
+                        end + 4,
+                    )
+                }),
+            '*' | '_' if can_open_emphasis(input, i) => {
+                find_emphasis_close(&rest[1..], c).map(|end| {
+                    (
+                        Inline::Emphasis {
+                            content: parse_inlines(&rest[1..end + 1]),
+                        },
+                        end + 2,
+                    )
+                })
+            }
+            '[' => find_closing(&rest[1..], "]").and_then(|text_end| {
+                let url_start = text_end + 3;
+                if !rest[text_end + 2..].starts_with('(') {
+                    return None;
+                }
+                rest[url_start..].find(')').map(|url_len| {
+                    (
+                        Inline::Link {
+                            text: rest[1..text_end + 1].to_string(),
+                            url: rest[url_start..url_start + url_len].to_string(),
+                        },
+                        url_start + url_len + 1,
+                    )
+                })
+            }),
+            _ => None,
+        };
+
+        if let Some((inline, len)) = token {
+            flush(&mut text, &mut inlines);
+            inlines.push(inline);
+            i += len;
+        } else {
+            text.push(c);
+            i += c.len_utf8();
+        }
+    }
+
+    flush(&mut text, &mut inlines);
+    inlines
+}
+
+/// Find the position of a closing delimiter, skipping over escaped characters and code spans.
+fn find_closing(input: &str, delimiter: &str) -> Option<usize> {
+    let mut i = 0;
+
+    while let Some(c) = input[i..].chars().next() {
+        if input[i..].starts_with(delimiter) {
+            return Some(i);
+        }
+
+        match c {
+            '\\' => i += 1 + input[i + 1..].chars().next().map_or(0, char::len_utf8),
+            '`' => i += input[i + 1..].find('`').map_or(1, |end| end + 2),
+            c => i += c.len_utf8(),
+        }
+    }
+
+    None
+}
+
+fn can_open_emphasis(input: &str, pos: usize) -> bool {
+    let delimiter = input[pos..].chars().next();
+    let prev = input[..pos].chars().next_back();
+    let next = input[pos + 1..].chars().next();
+
+    next.is_some_and(|c| !c.is_whitespace() && Some(c) != delimiter)
+        && !(delimiter == Some('_') && prev.is_some_and(char::is_alphanumeric))
+}
+
+fn find_emphasis_close(input: &str, delimiter: char) -> Option<usize> {
+    let mut i = 0;
+
+    while let Some(c) = input[i..].chars().next() {
+        let rest = &input[i..];
+
+        if c == delimiter {
+            if delimiter == '*' && rest.starts_with("**") {
+                // Skip over nested strong emphasis.
+                i += 2;
+                continue;
+            }
+
+            let prev = input[..i].chars().next_back();
+            let next = rest[1..].chars().next();
+
+            if prev.is_some_and(|c| !c.is_whitespace())
+                && !(delimiter == '_' && next.is_some_and(char::is_alphanumeric))
+            {
+                return Some(i);
+            }
+        }
+
+        match c {
+            '\\' => i += 1 + rest[1..].chars().next().map_or(0, char::len_utf8),
+            '`' => i += rest[1..].find('`').map_or(1, |end| end + 2),
+            c => i += c.len_utf8(),
+        }
+    }
+
+    None
+}
+
+fn render_block(block: &Block) -> String {
+    match block {
+        Block::Heading { level, content } => format!(
+            "{} {}",
+            "#".repeat(*level as usize),
+            render_inlines(content),
+        ),
+        Block::Paragraph { content } | Block::Error { content } => {
+            escape_line_starts(&render_inlines(content))
+        }
+        Block::List { items } => items
+            .iter()
+            .map(|item| format!("* {}", render_inlines(item).replace('\n', "\n  ")))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Table { header, rows } => {
+            let render_row = |row: &[Vec<Inline>]| {
+                format!(
+                    "| {} |",
+                    row.iter()
+                        .map(|cell| render_inlines(cell).replace('|', "\\|"))
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                )
+            };
+
+            let mut lines = Vec::new();
+
+            if !header.is_empty() {
+                lines.push(render_row(header));
+                lines.push(format!(
+                    "|{}|",
+                    header.iter().map(|_| "---").collect::<Vec<_>>().join("|"),
+                ));
+            }
+
+            lines.extend(rows.iter().map(|row| render_row(row)));
+            lines.join("\n")
+        }
+        Block::Warning { content } => format!("! {}", render_inlines(content)),
+        Block::Thing { kind, uuid, blocks } => format!(
+            "<div class=\"thing-box {}\" data-uuid=\"{}\">\n\n{}\n\n</div>",
+            kind,
+            uuid,
+            render(blocks),
+        ),
+    }
+}
+
+pub fn render_inlines(inlines: &[Inline]) -> String {
+    let mut output = String::new();
+
+    for inline in inlines {
+        match inline {
+            Inline::Text { text } => {
+                for c in text.chars() {
+                    if matches!(c, '\\' | '`' | '*' | '_' | '~') {
+                        output.push('\\');
+                    }
+                    output.push(c);
+                }
+            }
+            Inline::Strong { content } => {
+                output.push_str(&format!("**{}**", render_inlines(content)))
+            }
+            Inline::Emphasis { content } => {
+                output.push_str(&format!("_{}_", render_inlines(content)))
+            }
+            Inline::Command { command } => output.push_str(&format!("`{}`", command)),
+            Inline::Suggestion { command } => output.push_str(&format!("~{}~", command)),
+            Inline::Link { text, url } => output.push_str(&format!("[{}]({})", text, url)),
+            Inline::Roll { expression, total } if expression.is_empty() => {
+                output.push_str(&total.to_string())
+            }
+            Inline::Roll { expression, total } => {
+                output.push_str(&format!("{} = **{}**", expression, total))
+            }
+            Inline::LineBreak => output.push_str("\\\n"),
+        }
+    }
+
+    output
+}
+
+/// Escape characters at the start of a line that would otherwise begin a different type of block.
+fn escape_line_starts(input: &str) -> String {
+    input
+        .split('\n')
+        .map(|line| {
+            if line.starts_with(['>', '#', '|']) || line.starts_with("! ") || line.starts_with("- ")
+            {
+                format!("\\{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parsing rendered markdown should produce the same structure that was rendered.
+    fn assert_round_trip(input: &str) -> Vec<Block> {
+        let blocks = parse(input);
+        assert_eq!(blocks, parse(&render(&blocks)), "{}", render(&blocks));
+        blocks
+    }
+
+    #[test]
+    fn parse_inlines_test() {
+        assert_eq!(
+            vec![
+                Inline::text("Use "),
+                Inline::Suggestion {
+                    command: "save".to_string(),
+                },
+                Inline::text(" or "),
+                Inline::Command {
+                    command: "save Ana_Bob".to_string(),
+                },
+                Inline::text(" for snake_case "),
+                Inline::Strong {
+                    content: vec![
+                        Inline::text("bold "),
+                        Inline::Emphasis {
+                            content: vec![Inline::text("and")],
+                        },
+                        Inline::text(" italic"),
+                    ],
+                },
+                Inline::text(" * [1] \\ *"),
+                Inline::LineBreak,
+                Inline::Link {
+                    text: "Discord".to_string(),
+                    url: "https://discord.gg".to_string(),
+                },
+            ],
+            parse_inlines(
+                "Use ~save~ or `save Ana_Bob` for snake_case **bold *and* italic** \\* [1] \\ *\\\n[Discord](https://discord.gg)",
+            ),
+        );
+    }
+
+    #[test]
+    fn parse_test_lists() {
+        assert_eq!(
+            vec![
+                Block::Heading {
+                    level: 2,
+                    content: vec![Inline::text("Spells")],
+                },
+                Block::List {
+                    items: vec![
+                        vec![Inline::Command {
+                            command: "Fireball".to_string(),
+                        }],
+                        vec![Inline::text("Two\nlines")],
+                    ],
+                },
+                Block::Paragraph {
+                    content: vec![Inline::text("After")],
+                },
+            ],
+            assert_round_trip("## Spells\n* `Fireball`\n- Two\n  lines\n\nAfter"),
+        );
+    }
+
+    #[test]
+    fn parse_test_table() {
+        assert_eq!(
+            vec![Block::Table {
+                header: vec![vec![Inline::text("d6")], vec![Inline::text("Result")]],
+                rows: vec![
+                    vec![vec![Inline::text("1")], vec![Inline::text("Pipe | pipe")]],
+                    vec![
+                        vec![Inline::text("2")],
+                        vec![Inline::Strong {
+                            content: vec![Inline::text("Bold")],
+                        }],
+                    ],
+                ],
+            }],
+            assert_round_trip(
+                "| d6 | Result |\n|:---|---:|\n| 1 | Pipe \\| pipe |\n| 2 | **Bold** |"
+            ),
+        );
+    }
+
+    #[test]
+    fn parse_test_unknown_words() {
+        assert_eq!(
+            vec![
+                Block::Warning {
+                    content: vec![Inline::text("Didn't understand.")],
+                },
+                Block::Paragraph {
+                    content: vec![
+                        Inline::text("> npc "),
+                        Inline::Strong {
+                            content: vec![Inline::text("floopy")],
+                        },
+                        Inline::LineBreak,
+                        Inline::text("\u{a0}\u{a0}^"),
+                    ],
+                },
+            ],
+            assert_round_trip("! Didn't understand.\n\n\\> npc **floopy**\\\n\u{a0}\u{a0}^"),
+        );
+    }
+
+    #[test]
+    fn parse_test_nested_things() {
+        assert_eq!(
+            vec![Block::Thing {
+                kind: "place".to_string(),
+                uuid: "abc".to_string(),
+                blocks: vec![
+                    Block::Heading {
+                        level: 1,
+                        content: vec![Inline::text("Pylos")],
+                    },
+                    Block::Thing {
+                        kind: "npc".to_string(),
+                        uuid: "def".to_string(),
+                        blocks: Vec::new(),
+                    },
+                ],
+            }],
+            assert_round_trip(
+                "<div class=\"thing-box place\" data-uuid=\"abc\">\n\n# Pylos\n\n<div class=\"thing-box npc\" data-uuid=\"def\">\n\n</div>",
+            ),
+        );
+    }
+
+    #[test]
+    fn render_test() {
+        assert_eq!(
+            "[17] + 3 = **20**\\\n5\\\nSum: **25**",
+            render(&[Block::Paragraph {
+                content: vec![
+                    Inline::Roll {
+                        expression: "[17] + 3".to_string(),
+                        total: 20,
+                    },
+                    Inline::LineBreak,
+                    Inline::Roll {
+                        expression: String::new(),
+                        total: 5,
+                    },
+                    Inline::LineBreak,
+                    Inline::text("Sum: "),
+                    Inline::Strong {
+                        content: vec![Inline::text("25")],
+                    },
+                ],
+            }]),
+        );
+
+        assert_eq!(
+            "\\# Not a heading \\*",
+            render(&[Block::Paragraph {
+                content: vec![Inline::text("# Not a heading *")],
+            }]),
+        );
+    }
+}
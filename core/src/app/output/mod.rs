@@ -0,0 +1,517 @@
+//! A structured representation of the output of a command. Historically, commands have produced
+//! ad-hoc markdown strings, which frontends then needed to re-parse in order to display them. An
+//! [`Output`] can instead be rendered directly to markdown, ANSI-formatted terminal text, HTML, or
+//! JSON.
+//!
+//! Commands that haven't yet been converted to produce structured output natively still produce
+//! markdown, which is parsed into an `Output` on the way out (see [`Output::from_result`]). Content
+//! that is inherently markdown, such as SRD text and user-written descriptions, is parsed where it
+//! is embedded.
+
+mod ansi;
+mod html;
+mod markdown;
+
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Output {
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Block {
+    Heading {
+        level: u8,
+        content: Vec<Inline>,
+    },
+    Paragraph {
+        content: Vec<Inline>,
+    },
+    List {
+        items: Vec<Vec<Inline>>,
+    },
+    Table {
+        header: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+
+    /// A notice accompanying otherwise successful output, eg. that a change couldn't be saved.
+    Warning {
+        content: Vec<Inline>,
+    },
+
+    /// The reason that a command failed.
+    Error {
+        content: Vec<Inline>,
+    },
+
+    /// A character or place, displayed as a box in the web interface.
+    Thing {
+        kind: String,
+        uuid: String,
+        blocks: Vec<Block>,
+    },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Inline {
+    Text {
+        text: String,
+    },
+    Strong {
+        content: Vec<Inline>,
+    },
+    Emphasis {
+        content: Vec<Inline>,
+    },
+
+    /// A command that the user can click to run, eg. `journal`.
+    Command {
+        command: String,
+    },
+
+    /// A shortcut that is only valid immediately following this output, eg. ~save~ or ~1~.
+    Suggestion {
+        command: String,
+    },
+
+    Link {
+        text: String,
+        url: String,
+    },
+
+    /// The outcome of a dice roll, eg. "[4, 2] + 1 = 7".
+    Roll {
+        expression: String,
+        total: i64,
+    },
+
+    LineBreak,
+}
+
+impl Output {
+    pub fn new(blocks: Vec<Block>) -> Self {
+        Self { blocks }
+    }
+
+    /// Parse the markdown produced by a legacy command.
+    pub fn from_markdown(input: &str) -> Self {
+        Self::new(markdown::parse(input))
+    }
+
+    /// Parse the markdown of an error message, marking its paragraphs as errors.
+    pub fn error(message: &str) -> Self {
+        Self::new(
+            markdown::parse(message)
+                .into_iter()
+                .map(|block| match block {
+                    Block::Paragraph { content } | Block::Warning { content } => {
+                        Block::Error { content }
+                    }
+                    block => block,
+                })
+                .collect(),
+        )
+    }
+
+    pub fn from_result(result: Result<String, String>) -> Result<Self, Self> {
+        result
+            .map(|s| Self::from_markdown(&s))
+            .map_err(|e| Self::error(&e))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        markdown::render(&self.blocks)
+    }
+
+    /// The inverse of [`Output::from_result`], for commands that build their output natively but
+    /// are run via [`Runnable::run`](crate::app::Runnable::run).
+    pub fn to_markdown_result(result: Result<Self, Self>) -> Result<String, String> {
+        result
+            .map(|output| output.to_markdown())
+            .map_err(|output| output.to_markdown())
+    }
+
+    /// Render the output for a terminal that understands ANSI escape codes.
+    pub fn to_ansi(&self) -> String {
+        ansi::render(&self.blocks, None)
+    }
+
+    /// As [`Output::to_ansi`], but highlighting the command link at index `selected` in
+    /// [`Output::commands`].
+    pub fn to_ansi_with_selection(&self, selected: usize) -> String {
+        ansi::render(&self.blocks, Some(selected))
+    }
+
+    /// The commands linked from the output (including suggestions), in the order they appear.
+    pub fn commands(&self) -> Vec<&str> {
+        let mut commands = Vec::new();
+
+        for block in &self.blocks {
+            block.collect_commands(&mut commands);
+        }
+
+        commands
+    }
+
+    pub fn to_html(&self) -> String {
+        html::render(&self.blocks)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl Block {
+    pub fn paragraph(content: Vec<Inline>) -> Self {
+        Self::Paragraph {
+            content: Inline::normalize(content),
+        }
+    }
+
+    /// A paragraph of lines separated by line breaks, eg. the `**Label:** value` fields of a
+    /// character.
+    pub fn lines(lines: Vec<Vec<Inline>>) -> Self {
+        let mut content = Vec::new();
+
+        for (i, line) in lines.into_iter().enumerate() {
+            if i > 0 {
+                content.push(Inline::LineBreak);
+            }
+            content.extend(line);
+        }
+
+        Self::paragraph(content)
+    }
+
+    fn collect_commands<'a>(&'a self, commands: &mut Vec<&'a str>) {
+        let mut collect = |inlines: &'a [Inline]| {
+            inlines
+                .iter()
+                .for_each(|inline| inline.collect_commands(commands))
+        };
+
+        match self {
+            Self::Heading { content, .. }
+            | Self::Paragraph { content }
+            | Self::Warning { content }
+            | Self::Error { content } => collect(content),
+            Self::List { items } => items.iter().for_each(|item| collect(item)),
+            Self::Table { header, rows } => header
+                .iter()
+                .chain(rows.iter().flatten())
+                .for_each(|cell| collect(cell)),
+            Self::Thing { blocks, .. } => blocks
+                .iter()
+                .for_each(|block| block.collect_commands(commands)),
+        }
+    }
+}
+
+impl Inline {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Render a run of inlines (eg. a one-line summary) to markdown.
+    pub fn to_markdown(inlines: &[Inline]) -> String {
+        markdown::render_inlines(inlines)
+    }
+
+    pub fn strong(text: impl Into<String>) -> Self {
+        Self::Strong {
+            content: vec![Self::text(text)],
+        }
+    }
+
+    pub fn emphasis(content: Vec<Inline>) -> Self {
+        Self::Emphasis {
+            content: Self::normalize(content),
+        }
+    }
+
+    pub fn command(command: impl Into<String>) -> Self {
+        Self::Command {
+            command: command.into(),
+        }
+    }
+
+    pub fn suggestion(command: impl Into<String>) -> Self {
+        Self::Suggestion {
+            command: command.into(),
+        }
+    }
+
+    /// A `**Label:** value` field, as displayed in the details of a character or place.
+    pub fn field(label: &str, value: Vec<Inline>) -> Vec<Self> {
+        let mut inlines = vec![Self::strong(format!("{}:", label)), Self::text(" ")];
+        inlines.extend(value);
+        Self::normalize(inlines)
+    }
+
+    /// Parse a fragment of markdown, eg. user-provided text embedded in otherwise structured
+    /// output.
+    pub fn from_markdown(input: &str) -> Vec<Self> {
+        markdown::parse_inlines(input)
+    }
+
+    /// Merge adjacent runs of text and drop empty ones, as the markdown parser would.
+    fn normalize(inlines: Vec<Inline>) -> Vec<Inline> {
+        let mut result: Vec<Inline> = Vec::with_capacity(inlines.len());
+
+        for inline in inlines {
+            match (result.last_mut(), inline) {
+                (_, Self::Text { text }) if text.is_empty() => {}
+                (Some(Self::Text { text: last }), Self::Text { text }) => last.push_str(&text),
+                (_, inline) => result.push(inline),
+            }
+        }
+
+        result
+    }
+
+    fn collect_commands<'a>(&'a self, commands: &mut Vec<&'a str>) {
+        match self {
+            Self::Command { command } | Self::Suggestion { command } => commands.push(command),
+            Self::Strong { content } | Self::Emphasis { content } => content
+                .iter()
+                .for_each(|inline| inline.collect_commands(commands)),
+            Self::Text { .. } | Self::Link { .. } | Self::Roll { .. } | Self::LineBreak => {}
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_markdown())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn thing_output() -> Output {
+        Output::from_markdown(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Ana 🔒
+*young adult elf, he/him*
+
+**Species:** elf 🔒\
+**Age:** 122 years
+
+</div>
+
+_Ana has not yet been saved. Use ~save~ to save her to your `journal`._
+
+! Your changes could not be saved."#,
+        )
+    }
+
+    #[test]
+    fn from_markdown_test() {
+        assert_eq!(
+            Output::new(vec![
+                Block::Thing {
+                    kind: "npc".to_string(),
+                    uuid: "00000000-0000-0000-0000-000000000000".to_string(),
+                    blocks: vec![
+                        Block::Heading {
+                            level: 1,
+                            content: vec![Inline::text("Ana 🔒")],
+                        },
+                        Block::Paragraph {
+                            content: vec![Inline::Emphasis {
+                                content: vec![Inline::text("young adult elf, he/him")],
+                            }],
+                        },
+                        Block::Paragraph {
+                            content: vec![
+                                Inline::Strong {
+                                    content: vec![Inline::text("Species:")],
+                                },
+                                Inline::text(" elf 🔒"),
+                                Inline::LineBreak,
+                                Inline::Strong {
+                                    content: vec![Inline::text("Age:")],
+                                },
+                                Inline::text(" 122 years"),
+                            ],
+                        },
+                    ],
+                },
+                Block::Paragraph {
+                    content: vec![Inline::Emphasis {
+                        content: vec![
+                            Inline::text("Ana has not yet been saved. Use "),
+                            Inline::Suggestion {
+                                command: "save".to_string(),
+                            },
+                            Inline::text(" to save her to your "),
+                            Inline::Command {
+                                command: "journal".to_string(),
+                            },
+                            Inline::text("."),
+                        ],
+                    }],
+                },
+                Block::Warning {
+                    content: vec![Inline::text("Your changes could not be saved.")],
+                },
+            ]),
+            thing_output(),
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(
+            Output::new(vec![Block::Error {
+                content: vec![
+                    Inline::text(r#"There is no entity named "Nobody". Try "#),
+                    Inline::Command {
+                        command: "journal".to_string(),
+                    },
+                    Inline::text("."),
+                ],
+            }]),
+            Output::error(r#"There is no entity named "Nobody". Try `journal`."#),
+        );
+    }
+
+    #[test]
+    fn from_result_test() {
+        assert_eq!(
+            Ok(Output::new(vec![Block::Paragraph {
+                content: vec![Inline::text("Hello")],
+            }])),
+            Output::from_result(Ok("Hello".to_string())),
+        );
+        assert_eq!(
+            Err(Output::new(vec![Block::Error {
+                content: vec![Inline::text("Oops")],
+            }])),
+            Output::from_result(Err("Oops".to_string())),
+        );
+    }
+
+    #[test]
+    fn constructor_test() {
+        assert_eq!(
+            Block::Paragraph {
+                content: vec![
+                    Inline::Strong {
+                        content: vec![Inline::text("Location:")],
+                    },
+                    Inline::text(" 📍 "),
+                    Inline::Command {
+                        command: "Olympus".to_string(),
+                    },
+                    Inline::LineBreak,
+                    Inline::text("Use "),
+                    Inline::Suggestion {
+                        command: "save".to_string(),
+                    },
+                ],
+            },
+            Block::lines(vec![
+                Inline::field(
+                    "Location",
+                    vec![Inline::text("📍 "), Inline::command("Olympus")],
+                ),
+                vec![
+                    Inline::text("Use"),
+                    Inline::text(""),
+                    Inline::text(" "),
+                    Inline::suggestion("save"),
+                ],
+            ]),
+        );
+
+        assert_eq!(
+            Output::from_markdown("**Location:** 📍 `Olympus`\\\nUse ~save~"),
+            Output::new(vec![Block::lines(vec![
+                Inline::field("Location", Inline::from_markdown("📍 `Olympus`")),
+                Inline::from_markdown("Use ~save~"),
+            ])]),
+        );
+    }
+
+    #[test]
+    fn to_markdown_test() {
+        assert_eq!(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Ana 🔒
+
+_young adult elf, he/him_
+
+**Species:** elf 🔒\
+**Age:** 122 years
+
+</div>
+
+_Ana has not yet been saved. Use ~save~ to save her to your `journal`._
+
+! Your changes could not be saved."#,
+            thing_output().to_markdown(),
+        );
+    }
+
+    #[test]
+    fn to_ansi_test() {
+        assert_eq!(
+            "\x1b[1m\x1b[4mAna 🔒\x1b[24m\x1b[22m\n\n\x1b[3myoung adult elf, he/him\x1b[23m\n\n\x1b[1mSpecies:\x1b[22m elf 🔒\n\x1b[1mAge:\x1b[22m 122 years\n\n\x1b[3mAna has not yet been saved. Use \x1b[4msave\x1b[24m to save her to your \x1b[4mjournal\x1b[24m.\x1b[23m\n\n\x1b[33mYour changes could not be saved.\x1b[39m",
+            thing_output().to_ansi(),
+        );
+    }
+
+    #[test]
+    fn commands_test() {
+        assert_eq!(vec!["save", "journal"], thing_output().commands());
+        assert!(Output::from_markdown("No links here.")
+            .commands()
+            .is_empty());
+    }
+
+    #[test]
+    fn to_html_test() {
+        assert_eq!(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+<h1>Ana 🔒</h1>
+<p><em>young adult elf, he/him</em></p>
+<p><strong>Species:</strong> elf 🔒<br>
+<strong>Age:</strong> 122 years</p>
+</div>
+<p><em>Ana has not yet been saved. Use <code class="temp-link">save</code> to save her to your <code>journal</code>.</em></p>
+<p class="error">Your changes could not be saved.</p>"#,
+            thing_output().to_html(),
+        );
+    }
+
+    #[test]
+    fn to_json_test() {
+        assert_eq!(
+            r#"{"blocks":[{"type":"paragraph","content":[{"type":"roll","expression":"[4, 2] + 1","total":7},{"type":"lineBreak"},{"type":"command","command":"help"}]}]}"#,
+            Output::new(vec![Block::Paragraph {
+                content: vec![
+                    Inline::Roll {
+                        expression: "[4, 2] + 1".to_string(),
+                        total: 7,
+                    },
+                    Inline::LineBreak,
+                    Inline::Command {
+                        command: "help".to_string(),
+                    },
+                ],
+            }])
+            .to_json(),
+        );
+    }
+}
@@ -1,10 +1,11 @@
+use crate::app::AppCommand;
 use crate::command::prelude::*;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct About;
 
 impl Command for About {
-    fn token<'a>(&self) -> Token {
+    fn token(&self) -> Token {
         keyword("about")
     }
 
@@ -16,22 +17,21 @@ impl Command for About {
         Some(("about", "about initiative.sh").into())
     }
 
-    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
-        Some(CommandPriority::Canonical)
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("about", "about initiative.sh")
+            .example("about", "show the credits and license")
     }
 
-    fn get_canonical_form_of(&self, _token_match: &TokenMatch) -> Option<String> {
-        Some("about".to_string())
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
     }
 
-    async fn run(
+    async fn parse(
         &self,
         _token_match: TokenMatch<'_>,
-        _app_meta: &mut AppMeta,
-    ) -> Result<String, String> {
-        Ok(include_str!("../../../data/about.md")
-            .trim_end()
-            .to_string())
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(AppCommand::About.into())
     }
 }
 
@@ -39,15 +39,23 @@ impl Command for About {
 mod test {
     use super::*;
 
+    use crate::app::CommandMatches;
     use crate::test_utils as test;
     use futures::StreamExt as _;
 
     #[tokio::test]
-    async fn run_test() {
-        assert!(crate::command::run("about", &mut test::app_meta())
-            .await
-            .unwrap()
-            .contains("About initiative.sh"));
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::About.into()),
+            About.parse_input("ABOUT", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            About.parse_input("about potato", &app_meta).await,
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,196 @@
+use crate::command::prelude::*;
+use crate::utils::{capitalize, CaseInsensitiveStr};
+use crate::world::WorldCommand;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddWords;
+
+#[derive(Hash)]
+enum Marker {
+    Words,
+}
+
+impl Command for AddWords {
+    fn token(&self) -> Token {
+        sequence([keyword("add"), any_phrase_m(Marker::Words)])
+    }
+
+    /// There are several forms of the command to suggest, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            let mut token_matches = token.match_input(input, app_meta);
+
+            while let Some(token_match) = token_matches.next().await {
+                if matches!(token_match, FuzzyMatch::Overflow(..)) {
+                    continue;
+                }
+
+                for (term, summary) in [
+                    ("add [ethnicity] names: [names]", "add custom names"),
+                    ("add [ethnicity] surnames: [names]", "add custom surnames"),
+                    ("add inn words: [words]", "add custom inn words"),
+                ] {
+                    if term.starts_with_ci(input) {
+                        yield (term, summary).into();
+                    }
+                }
+
+                break;
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("add", "add custom names and words")
+            .placeholder(Marker::Words, "words")
+            .example(
+                "add valyrian feminine names: Daenerys, Rhaenyra",
+                "create a new ethnicity with two names",
+            )
+            .example(
+                "add inn words: Griffin, Wyvern",
+                "add words that can appear in inn names",
+            )
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        let (list, words) = token_match
+            .find_marker(Marker::Words)?
+            .meta_phrase()?
+            .split_once(':')?;
+
+        let words: Vec<String> = words
+            .split(',')
+            .map(|word| word.trim())
+            .filter(|word| !word.is_empty())
+            .map(capitalize)
+            .collect();
+
+        if words.is_empty() {
+            None
+        } else {
+            Some(
+                WorldCommand::AddWords {
+                    list: list.trim().parse().ok()?,
+                    words,
+                }
+                .into(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use crate::world::VocabularyList;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                WorldCommand::AddWords {
+                    list: "valyrian feminine names".parse().unwrap(),
+                    words: vec!["Daenerys".to_string(), "Rhaenyra".to_string()],
+                }
+                .into(),
+            ),
+            AddWords
+                .parse_input(
+                    "add Valyrian feminine names: daenerys, Rhaenyra,",
+                    &app_meta
+                )
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                WorldCommand::AddWords {
+                    list: VocabularyList::InnWords,
+                    words: vec!["Griffin".to_string()],
+                }
+                .into(),
+            ),
+            AddWords
+                .parse_input("add inn words: griffin", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            AddWords.parse_input("add valyrian names:", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            AddWords
+                .parse_input("add high valyrian names: Daenerys", &app_meta)
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        test::assert_autocomplete_eq!(
+            [
+                ("add [ethnicity] names: [names]", "add custom names"),
+                ("add [ethnicity] surnames: [names]", "add custom surnames"),
+                ("add inn words: [words]", "add custom inn words"),
+            ],
+            AddWords.parse_autocomplete("ad", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("add inn words: [words]", "add custom inn words")],
+            AddWords
+                .parse_autocomplete("ADD I", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = WorldCommand::AddWords {
+            list: "valyrian surnames".parse().unwrap(),
+            words: vec!["Targaryen".to_string()],
+        };
+
+        assert_eq!("add valyrian surnames: Targaryen", command.to_string());
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            AddWords.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::command::prelude::*;
+use crate::time::{Interval, TimeCommand};
+
+use std::iter;
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+/// Advance or rewind the clock, eg. `+1d` or `-2h 30m`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdjustTime;
+
+#[derive(Hash)]
+enum Marker {
+    Interval,
+}
+
+impl Command for AdjustTime {
+    fn token(&self) -> Token {
+        interval_m(Marker::Interval)
+    }
+
+    /// A single match can yield several suggestions, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                let word = match &fuzzy_match {
+                    FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => {
+                        token_match.meta_phrase()
+                    }
+                    FuzzyMatch::Overflow(..) => None,
+                };
+
+                if let Some(word) = word {
+                    for suggestion in suggest(word) {
+                        yield suggestion;
+                    }
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("time", "advance or rewind time")
+            .placeholder(Marker::Interval, "+/-interval")
+            .example("+8h", "advance time by 8 hours")
+            .example("+d", "advance to the next day")
+            .example("-10r", "rewind time by 10 rounds")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        parse_interval(token_match.meta_phrase()?).map(|_| CommandPriority::Canonical)
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        parse_interval(token_match.meta_phrase()?).map(CommandType::from)
+    }
+}
+
+fn parse_interval(word: &str) -> Option<TimeCommand> {
+    if let Some(interval) = word.strip_prefix('+') {
+        interval
+            .parse()
+            .ok()
+            .map(|interval| TimeCommand::Add { interval })
+    } else {
+        word.strip_prefix('-')?
+            .parse()
+            .ok()
+            .map(|interval| TimeCommand::Sub { interval })
+    }
+}
+
+fn suggest(word: &str) -> Vec<AutocompleteSuggestion> {
+    if !word.starts_with(&['+', '-'][..]) {
+        return Vec::new();
+    }
+
+    let suggest_suffix = |suffix: &str| -> Option<AutocompleteSuggestion> {
+        let term = format!("{}{}", word, suffix);
+        let interval = term[1..].parse::<Interval>().ok()?;
+        let summary = if word.starts_with('+') {
+            format!("advance time by {}", interval.display_long())
+        } else {
+            format!("rewind time by {}", interval.display_long())
+        };
+        Some(AutocompleteSuggestion::new(term, summary))
+    };
+
+    let suggest_all = || {
+        ["", "d", "h", "m", "s", "r"]
+            .iter()
+            .filter_map(|suffix| suggest_suffix(suffix))
+    };
+
+    match word {
+        "+" | "-" => iter::once(AutocompleteSuggestion::new(
+            format!("{}[number]", word),
+            if word == "+" {
+                "advance time"
+            } else {
+                "rewind time"
+            },
+        ))
+        .chain(suggest_all())
+        .collect(),
+        _ => suggest_all().collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TimeCommand::Add {
+                    interval: Interval::new(0, 0, 1, 0, 0),
+                }
+                .into(),
+            ),
+            AdjustTime.parse_input("+1m", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TimeCommand::Add {
+                    interval: Interval::new(1, 0, 0, 0, 0),
+                }
+                .into(),
+            ),
+            AdjustTime.parse_input("+d", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TimeCommand::Sub {
+                    interval: Interval::new(0, 10, 0, 0, 0),
+                }
+                .into(),
+            ),
+            AdjustTime.parse_input("-10h", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            AdjustTime.parse_input("1d2h", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        test::assert_empty!(
+            AdjustTime
+                .parse_autocomplete("", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("+[number]", "advance time"),
+                ("+d", "advance time by 1 day"),
+                ("+h", "advance time by 1 hour"),
+                ("+m", "advance time by 1 minute"),
+                ("+s", "advance time by 1 second"),
+                ("+r", "advance time by 1 round"),
+            ],
+            AdjustTime
+                .parse_autocomplete("+", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("-[number]", "rewind time"),
+                ("-d", "rewind time by 1 day"),
+                ("-h", "rewind time by 1 hour"),
+                ("-m", "rewind time by 1 minute"),
+                ("-s", "rewind time by 1 second"),
+                ("-r", "rewind time by 1 round"),
+            ],
+            AdjustTime
+                .parse_autocomplete("-", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("+10d", "advance time by 10 days"),
+                ("+10h", "advance time by 10 hours"),
+                ("+10m", "advance time by 10 minutes"),
+                ("+10s", "advance time by 10 seconds"),
+                ("+10r", "advance time by 10 rounds"),
+            ],
+            AdjustTime
+                .parse_autocomplete("+10", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("+10D5h", "advance time by 10 days, 5 hours"),
+                ("+10D5m", "advance time by 10 days, 5 minutes"),
+                ("+10D5s", "advance time by 10 days, 5 seconds"),
+                ("+10D5r", "advance time by 10 days, 5 rounds"),
+            ],
+            AdjustTime
+                .parse_autocomplete("+10D5", &app_meta)
+                .collect()
+                .await,
+        );
+
+        for input in ["+1d", "+1H", "+1m", "+1S", "+1r"] {
+            assert_eq!(
+                1,
+                AdjustTime
+                    .parse_autocomplete(input, &app_meta)
+                    .collect::<Vec<_>>()
+                    .await
+                    .len(),
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [
+            TimeCommand::Add {
+                interval: Interval::new(2, 3, 4, 5, 6),
+            },
+            TimeCommand::Sub {
+                interval: Interval::new(2, 3, 4, 5, 6),
+            },
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone().into()),
+                AdjustTime.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.into()),
+                AdjustTime
+                    .parse_input(&command_string.to_uppercase(), &app_meta)
+                    .await,
+                "{}",
+                command_string.to_uppercase(),
+            );
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use crate::app::{AppCommand, UserAlias};
+use crate::command::prelude::*;
+use crate::utils::CaseInsensitiveStr;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Alias;
+
+#[derive(Hash)]
+enum Marker {
+    AliasDefinition,
+    AliasSequence,
+    List,
+    MacroDefinition,
+    MacroSequence,
+    Name,
+    UnaliasSequence,
+}
+
+impl Command for Alias {
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::AliasSequence,
+                [keyword("alias"), any_phrase_m(Marker::AliasDefinition)],
+            ),
+            sequence_m(
+                Marker::MacroSequence,
+                [keyword("macro"), any_phrase_m(Marker::MacroDefinition)],
+            ),
+            keyword_m(Marker::List, "aliases"),
+            sequence_m(
+                Marker::UnaliasSequence,
+                [keyword("unalias"), any_phrase_m(Marker::Name)],
+            ),
+        ])
+    }
+
+    /// Every alias can be removed, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                let token_match = match fuzzy_match {
+                    FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => {
+                        token_match
+                    }
+                    FuzzyMatch::Overflow(..) => continue,
+                };
+
+                if token_match.contains_marker(Marker::List) {
+                    yield ("aliases", "list your aliases").into();
+                } else if token_match.contains_marker(Marker::UnaliasSequence) {
+                    let name = token_match
+                        .find_marker(Marker::Name)
+                        .and_then(|name| name.meta_phrase())
+                        .unwrap_or_default();
+
+                    for alias in app_meta
+                        .user_aliases
+                        .iter()
+                        .filter(|alias| alias.name.starts_with_ci(name))
+                    {
+                        yield AutocompleteSuggestion::new(
+                            format!("unalias {}", alias.name),
+                            if alias.is_macro() { "remove macro" } else { "remove alias" },
+                        );
+                    }
+                } else if token_match.contains_marker(Marker::AliasSequence)
+                    && !token_match.contains_marker(Marker::AliasDefinition)
+                {
+                    yield ("alias [name] = [command]", "create an alias").into();
+                } else if token_match.contains_marker(Marker::MacroSequence)
+                    && !token_match.contains_marker(Marker::MacroDefinition)
+                {
+                    yield ("macro [name] = [command]; [command]", "create a macro").into();
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("alias", "create shortcuts for commands you use often")
+            .placeholder(Marker::AliasDefinition, "name = command")
+            .placeholder(Marker::MacroDefinition, "name = command; command")
+            .placeholder(Marker::Name, "name")
+            .example(
+                "alias fight = roll d20+5",
+                "type `fight` to roll initiative",
+            )
+            .example(
+                "macro rest = +8h; now",
+                "type `rest` to advance the time and show it",
+            )
+            .example("aliases", "list your aliases and macros")
+            .example("unalias fight", "remove the alias named `fight`")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        if token_match.contains_marker(Marker::List) {
+            Some(AppCommand::Aliases.into())
+        } else if let Some(name) = token_match.find_marker(Marker::Name) {
+            Some(AppCommand::Unalias(name.meta_phrase()?.to_string()).into())
+        } else if let Some(definition) = token_match.find_marker(Marker::AliasDefinition) {
+            let (name, command) = definition.meta_phrase()?.split_once('=')?;
+            parse_definition(name, [command])
+        } else {
+            let definition = token_match.find_marker(Marker::MacroDefinition)?;
+            let (name, commands) = definition.meta_phrase()?.split_once('=')?;
+            parse_definition(name, commands.split(';'))
+        }
+    }
+}
+
+fn parse_definition<'a>(
+    name: &str,
+    commands: impl IntoIterator<Item = &'a str>,
+) -> Option<CommandType> {
+    let name = name.trim();
+
+    let commands: Vec<String> = commands
+        .into_iter()
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    if name.is_empty() || commands.is_empty() {
+        None
+    } else {
+        Some(
+            AppCommand::SetAlias(UserAlias {
+                name: name.to_string(),
+                commands,
+            })
+            .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                AppCommand::SetAlias(UserAlias {
+                    name: "fight".to_string(),
+                    commands: vec!["roll d20+5".to_string()],
+                })
+                .into(),
+            ),
+            Alias
+                .parse_input("alias fight = roll d20+5", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                AppCommand::SetAlias(UserAlias {
+                    name: "short rest".to_string(),
+                    commands: vec!["+1h".to_string(), "now".to_string()],
+                })
+                .into(),
+            ),
+            Alias
+                .parse_input("MACRO short rest=+1h;now; ", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Aliases.into()),
+            Alias.parse_input("Aliases", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Unalias("fight".to_string()).into()),
+            Alias.parse_input("unalias fight", &app_meta).await,
+        );
+
+        for input in [
+            "alias fight",
+            "alias = roll d20",
+            "alias fight =",
+            "macro rest = ;",
+            "alias",
+        ] {
+            assert_eq!(
+                CommandMatches::default(),
+                Alias.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let mut app_meta = test::app_meta();
+
+        for name in ["fight", "flee"] {
+            app_meta.user_aliases.insert(UserAlias {
+                name: name.to_string(),
+                commands: vec!["roll d20".to_string()],
+            });
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("alias [name] = [command]", "create an alias"),
+                ("aliases", "list your aliases"),
+            ],
+            Alias.parse_autocomplete("al", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("macro [name] = [command]; [command]", "create a macro")],
+            Alias.parse_autocomplete("MACRO", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("unalias fight", "remove alias"),
+                ("unalias flee", "remove alias"),
+            ],
+            Alias
+                .parse_autocomplete("unalias", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("unalias fight", "remove alias")],
+            Alias
+                .parse_autocomplete("unalias FI", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [
+            AppCommand::Aliases,
+            AppCommand::SetAlias(UserAlias {
+                name: "fight".to_string(),
+                commands: vec!["roll d20+5".to_string()],
+            }),
+            AppCommand::SetAlias(UserAlias {
+                name: "rest".to_string(),
+                commands: vec!["+8h".to_string(), "now".to_string()],
+            }),
+            AppCommand::Unalias("fight".to_string()),
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.into()),
+                Alias.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::app::AppCommand;
+use crate::command::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Changelog;
+
+impl Command for Changelog {
+    fn token(&self) -> Token {
+        keyword("changelog")
+    }
+
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        Some(("changelog", "show latest updates").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("changelog", "show latest updates")
+            .example("changelog", "list recent changes to initiative.sh")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(AppCommand::Changelog.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Changelog.into()),
+            Changelog.parse_input("changelog", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Changelog.into()),
+            Changelog
+                .parse_input(&AppCommand::Changelog.to_string().to_uppercase(), &app_meta)
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["changelog", "CHANGELOG", "ch"] {
+            test::assert_autocomplete_eq!(
+                [("changelog", "show latest updates")],
+                Changelog
+                    .parse_autocomplete(input, &app_meta)
+                    .collect()
+                    .await,
+            );
+        }
+    }
+}
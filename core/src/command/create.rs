@@ -0,0 +1,297 @@
+use crate::app::Autocomplete;
+use crate::command::prelude::*;
+use crate::utils::{quoted_words, CaseInsensitiveStr};
+use crate::world::npc::NpcData;
+use crate::world::place::PlaceData;
+use crate::world::thing::ThingData;
+use crate::world::{ParsedThing, WorldCommand};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::join;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Create;
+
+#[derive(Hash)]
+enum Marker {
+    Description,
+    Keyword,
+}
+
+impl Command for Create {
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::Keyword,
+                [
+                    keyword_list(["create", "generate"]),
+                    any_phrase_m(Marker::Description),
+                ],
+            ),
+            any_phrase_m(Marker::Description),
+        ])
+    }
+
+    /// Suggestions come from the vocabulary rather than the grammar, so see parse_autocomplete
+    /// instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                let Some(description) = fuzzy_match
+                    .into_exact()
+                    .filter(|token_match| !token_match.contains_marker(Marker::Keyword))
+                    .and_then(|token_match| {
+                        token_match
+                            .find_marker(Marker::Description)
+                            .and_then(TokenMatch::meta_phrase)
+                            .map(str::to_string)
+                    })
+                else {
+                    continue;
+                };
+
+                let (place_suggestions, npc_suggestions) = join!(
+                    PlaceData::autocomplete(&description, app_meta),
+                    NpcData::autocomplete(&description, app_meta),
+                );
+
+                for suggestion in place_suggestions.into_iter().chain(npc_suggestions) {
+                    yield suggestion;
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("create", "generate a character, place, etc.")
+            .placeholder(Marker::Description, "description")
+            .example("inn", "generate a random inn")
+            .example(
+                "a human boy named Roger",
+                "generate a character with some details filled in",
+            )
+            .example(
+                "create Nevermoor, a moor",
+                "generate a place with a given name",
+            )
+            .example(
+                "generate guard with stats",
+                "generate a character with an SRD stat block",
+            )
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Keyword) {
+            Some(CommandPriority::Canonical)
+        } else if token_match
+            .find_marker(Marker::Description)
+            .and_then(TokenMatch::meta_phrase)
+            .and_then(|phrase| quoted_words(phrase).next())
+            .is_some_and(|word| word.as_str().in_ci(&["create", "generate"]))
+        {
+            // Already covered by the canonical branch.
+            None
+        } else {
+            Some(CommandPriority::Fuzzy)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, app_meta: &AppMeta) -> Option<CommandType> {
+        let description = token_match
+            .find_marker(Marker::Description)?
+            .meta_phrase()?;
+
+        ParsedThing::<ThingData>::parse_with(description, app_meta.demographics.vocabulary())
+            .ok()
+            .map(|parsed_thing_data| {
+                WorldCommand::Create {
+                    parsed_thing_data: Box::new(parsed_thing_data),
+                }
+                .into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use crate::world::npc::{Species, StatBlock};
+    use crate::world::place::PlaceType;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(create(NpcData::default())),
+            Create.parse_input("npc", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(create(NpcData::default())),
+            Create.parse_input("create npc", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(create(NpcData::default())),
+            Create.parse_input("generate npc", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                WorldCommand::Create {
+                    parsed_thing_data: Box::new(ParsedThing {
+                        thing_data: NpcData {
+                            stats: StatBlock::Guard.into(),
+                            hit_points: 11.into(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        unknown_words: Vec::new(),
+                        word_count: 3,
+                    }),
+                }
+                .into()
+            ),
+            Create
+                .parse_input("generate guard with stats", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(create(NpcData {
+                species: Species::Elf.into(),
+                ..Default::default()
+            })),
+            Create.parse_input("elf", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Create.parse_input("potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for (word, summary) in [
+            ("npc", "create person"),
+            // Species
+            ("dragonborn", "create dragonborn"),
+            ("dwarf", "create dwarf"),
+            ("elf", "create elf"),
+            ("gnome", "create gnome"),
+            ("half-elf", "create half-elf"),
+            ("half-orc", "create half-orc"),
+            ("halfling", "create halfling"),
+            ("human", "create human"),
+            ("tiefling", "create tiefling"),
+            // PlaceType
+            ("inn", "create inn"),
+        ] {
+            test::assert_autocomplete_eq!(
+                [(word, summary)],
+                Create.parse_autocomplete(word, &app_meta).collect().await,
+            );
+
+            test::assert_autocomplete_eq!(
+                [(word, summary)],
+                Create
+                    .parse_autocomplete(&word.to_uppercase(), &app_meta)
+                    .collect()
+                    .await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("baby", "create infant"),
+                ("bakery", "create bakery"),
+                ("bandit", "create person"),
+                ("bandit-captain", "create person"),
+                ("bank", "create bank"),
+                ("bar", "create bar"),
+                ("barony", "create barony"),
+                ("barracks", "create barracks"),
+                ("barrens", "create barrens"),
+                ("base", "create base"),
+                ("bathhouse", "create bathhouse"),
+                ("beach", "create beach"),
+                ("bear", "create bear"),
+                ("berserker", "create person"),
+                ("blacksmith", "create blacksmith"),
+                ("blunt", "create person"),
+                ("boy", "create child, he/him"),
+                ("brewery", "create brewery"),
+                ("bridge", "create bridge"),
+                ("building", "create building"),
+                ("business", "create business"),
+            ],
+            Create.parse_autocomplete("b", &app_meta).collect().await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [
+            create(PlaceData {
+                subtype: "inn".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }),
+            create(NpcData::default()),
+            create(test::npc().species(Species::Elf).build()),
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone()),
+                Create.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+
+            assert_eq!(
+                CommandMatches::new_canonical(command),
+                Create
+                    .parse_input(&command_string.to_uppercase(), &app_meta)
+                    .await,
+                "{}",
+                command_string.to_uppercase(),
+            );
+        }
+    }
+
+    fn create(thing_data: impl Into<ThingData>) -> CommandType {
+        WorldCommand::Create {
+            parsed_thing_data: Box::new(ParsedThing {
+                thing_data: thing_data.into(),
+                unknown_words: Vec::new(),
+                word_count: 1,
+            }),
+        }
+        .into()
+    }
+}
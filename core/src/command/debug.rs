@@ -0,0 +1,72 @@
+use crate::app::AppCommand;
+use crate::command::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Debug;
+
+impl Command for Debug {
+    fn token(&self) -> Token {
+        keyword("debug")
+    }
+
+    /// The debug command is deliberately excluded from the autocomplete results.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("debug", "show the internal state of the app")
+            .example("debug", "dump the app state and journal")
+            .unlisted()
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(AppCommand::Debug.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Debug.into()),
+            Debug.parse_input("debug", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Debug.into()),
+            Debug.parse_input("DEBUG", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        test::assert_empty!(
+            Debug
+                .parse_autocomplete("debug", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await
+        );
+    }
+}
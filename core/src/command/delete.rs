@@ -0,0 +1,215 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delete;
+
+#[derive(Hash)]
+enum Marker {
+    Name,
+    NameSequence,
+    Phrase,
+    PhraseSequence,
+    SimilarName,
+}
+
+impl Command for Delete {
+    /// The phrase is what gets parsed, since the command needs to be able to report that a thing
+    /// doesn't exist. The name is only there to power autocomplete.
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::PhraseSequence,
+                [keyword("delete"), any_phrase_m(Marker::Phrase)],
+            ),
+            sequence_m(
+                Marker::NameSequence,
+                [keyword("delete"), name_m(Marker::Name)],
+            ),
+            sequence([keyword("delete"), similar_name_m(Marker::SimilarName)]),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            if record.is_unsaved() {
+                None
+            } else {
+                Some(AutocompleteSuggestion::new(
+                    format!("delete {}", record.thing.name()),
+                    format!("remove {} from journal", record.thing.as_str()),
+                ))
+            }
+        } else if token_match.contains_marker(Marker::PhraseSequence)
+            && !token_match.contains_marker(Marker::Phrase)
+        {
+            Some(("delete [name]", "remove an entry from journal").into())
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("delete", "remove an entry from your journal")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Phrase, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .example("delete Penelope", "delete the journal entry named Penelope")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Phrase) {
+            Some(CommandPriority::Canonical)
+        } else if token_match
+            .find_marker(Marker::SimilarName)
+            .and_then(|token_match| token_match.meta_record())
+            .is_some_and(|record| record.is_saved())
+        {
+            Some(CommandPriority::Corrected)
+        } else {
+            None
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        let name = if let Some(phrase) = token_match.find_marker(Marker::Phrase) {
+            phrase.meta_phrase()?.to_string()
+        } else {
+            token_match
+                .find_marker(Marker::SimilarName)?
+                .meta_record()?
+                .thing
+                .name()
+                .to_string()
+        };
+
+        Some(StorageCommand::Delete { name }.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["delete Odysseus", "DELETE Odysseus", "delete odysseus"] {
+            assert_eq!(
+                CommandMatches::new_canonical(
+                    StorageCommand::Delete {
+                        name: input[7..].to_string(),
+                    }
+                    .into(),
+                ),
+                Delete.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                StorageCommand::Delete {
+                    name: "Nobody".to_string(),
+                }
+                .into(),
+            ),
+            Delete.parse_input("delete Nobody", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches {
+                canonical_match: Some(
+                    StorageCommand::Delete {
+                        name: "Penelpoe".to_string(),
+                    }
+                    .into(),
+                ),
+                fuzzy_matches: vec![StorageCommand::Delete {
+                    name: "Penelope".to_string(),
+                }
+                .into()],
+                corrected: true,
+            },
+            Delete.parse_input("delete Penelpoe", &app_meta).await,
+        );
+
+        // Unsaved entries aren't in the journal, so they aren't suggested.
+        assert_eq!(
+            CommandMatches::new_canonical(
+                StorageCommand::Delete {
+                    name: "Odyseus".to_string(),
+                }
+                .into(),
+            ),
+            Delete.parse_input("delete Odyseus", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Delete.parse_input("delete", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        test::assert_empty!(
+            Delete
+                .parse_autocomplete("delete z", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+
+        for input in ["del", "DELete"] {
+            test::assert_autocomplete_eq!(
+                [("delete [name]", "remove an entry from journal")],
+                Delete.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("delete Penelope", "remove character from journal"),
+                ("delete Phoenicia", "remove place from journal"),
+            ],
+            Delete
+                .parse_autocomplete("delete P", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = StorageCommand::Delete {
+            name: "Odysseus".to_string(),
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Delete.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::app::Autocomplete;
+use crate::command::prelude::*;
+use crate::storage::Record;
+use crate::utils::{quoted_words, CaseInsensitiveStr};
+use crate::world::npc::NpcData;
+use crate::world::place::PlaceData;
+use crate::world::thing::{Thing, ThingData};
+use crate::world::{ParsedThing, WorldCommand};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edit;
+
+#[derive(Hash)]
+enum Marker {
+    Description,
+    Name,
+}
+
+impl Command for Edit {
+    fn token(&self) -> Token {
+        sequence([
+            any_phrase_m(Marker::Name),
+            keyword("is"),
+            any_phrase_m(Marker::Description),
+        ])
+    }
+
+    /// Suggestions depend on the type of the thing being edited, so see parse_autocomplete
+    /// instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            for suggestion in autocomplete(input, app_meta).await {
+                yield suggestion;
+            }
+        })
+    }
+
+    /// Only split on the first "is", since the description may well contain another.
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("edit", "change the details of a character, place, etc.")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Description, "description")
+            .example("Odysseus is a halfling", "change the species of Odysseus")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        let name = token_match.find_marker(Marker::Name)?.meta_phrase()?;
+
+        if quoted_words(name).any(|word| word.as_str().eq_ci("is")) {
+            None
+        } else {
+            Some(CommandPriority::Fuzzy)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, app_meta: &AppMeta) -> Option<CommandType> {
+        let name = token_match.find_marker(Marker::Name)?.meta_phrase()?;
+        let description = token_match
+            .find_marker(Marker::Description)?
+            .meta_phrase()?;
+
+        let (diff, thing): (Result<ParsedThing<ThingData>, ()>, Option<Thing>) =
+            if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(name).await {
+                (
+                    match thing.data {
+                        ThingData::Npc(_) => ParsedThing::<NpcData>::parse_with(
+                            description,
+                            app_meta.demographics.vocabulary(),
+                        )
+                        .map(|t| t.into_thing_data()),
+                        ThingData::Place(_) => description
+                            .parse::<ParsedThing<PlaceData>>()
+                            .map(|t| t.into_thing_data()),
+                    }
+                    .or_else(|_| {
+                        ParsedThing::<ThingData>::parse_with(
+                            description,
+                            app_meta.demographics.vocabulary(),
+                        )
+                    }),
+                    Some(thing),
+                )
+            } else {
+                // This will be an error when we try to run the command, but for now we'll pretend
+                // it's valid so that we can provide a more coherent message.
+                (
+                    ParsedThing::<ThingData>::parse_with(
+                        description,
+                        app_meta.demographics.vocabulary(),
+                    ),
+                    None,
+                )
+            };
+
+        // The unknown words are relative to the description, but are highlighted in the full
+        // input.
+        let offset = name.len() + " is ".len();
+
+        let mut diff = diff.ok()?;
+        let name = thing
+            .map(|t| t.name().to_string())
+            .unwrap_or_else(|| name.to_string());
+
+        diff.unknown_words.iter_mut().for_each(|range| {
+            *range = range.start + offset..range.end + offset;
+        });
+
+        Some(
+            WorldCommand::Edit {
+                name,
+                parsed_diff: Box::new(diff),
+            }
+            .into(),
+        )
+    }
+}
+
+async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+    let mut suggestions = Vec::new();
+    let mut input_words = quoted_words(input).skip(1);
+
+    if let Some((is_word, next_word)) = input_words
+        .find(|word| word.as_str().eq_ci("is"))
+        .and_then(|word| input_words.next().map(|next_word| (word, next_word)))
+    {
+        if let Ok(Record { thing, .. }) = app_meta
+            .repository
+            .get_by_name(input[..is_word.range().start].trim())
+            .await
+        {
+            let split_pos = input.len() - input[is_word.range().end..].trim_start().len();
+
+            let edit_suggestions = match thing.data {
+                ThingData::Npc(_) => {
+                    NpcData::autocomplete(input[split_pos..].trim_start(), app_meta)
+                }
+                ThingData::Place(_) => {
+                    PlaceData::autocomplete(input[split_pos..].trim_start(), app_meta)
+                }
+            }
+            .await;
+
+            suggestions.extend(edit_suggestions.into_iter().map(|suggestion| {
+                AutocompleteSuggestion::new(
+                    format!("{}{}", &input[..split_pos], suggestion.term),
+                    format!("edit {}", thing.as_str()),
+                )
+            }));
+
+            if next_word.as_str().in_ci(&["named", "called"]) && input_words.next().is_some() {
+                suggestions.push(AutocompleteSuggestion::new(
+                    input.to_string(),
+                    format!("rename {}", thing.as_str()),
+                ));
+            }
+        }
+    }
+
+    if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(input.trim_end()).await {
+        suggestions.push(AutocompleteSuggestion::new(
+            if input.ends_with(char::is_whitespace) {
+                format!("{}is [{} description]", input, thing.as_str())
+            } else {
+                format!("{} is [{} description]", input, thing.as_str())
+            },
+            format!("edit {}", thing.as_str()),
+        ));
+    } else if let Some((last_word_index, last_word)) =
+        quoted_words(input).enumerate().skip(1).last()
+    {
+        if "is".starts_with_ci(last_word.as_str()) {
+            if let Ok(Record { thing, .. }) = app_meta
+                .repository
+                .get_by_name(input[..last_word.range().start].trim())
+                .await
+            {
+                suggestions.push(AutocompleteSuggestion::new(
+                    if last_word.range().end == input.len() {
+                        format!(
+                            "{}is [{} description]",
+                            &input[..last_word.range().start],
+                            thing.as_str(),
+                        )
+                    } else {
+                        format!("{}[{} description]", &input, thing.as_str())
+                    },
+                    format!("edit {}", thing.as_str()),
+                ))
+            }
+        } else if let Some(suggestion) = ["named", "called"]
+            .iter()
+            .find(|s| s.starts_with_ci(last_word.as_str()))
+        {
+            let second_last_word = quoted_words(input).nth(last_word_index - 1).unwrap();
+
+            if second_last_word.as_str().eq_ci("is") {
+                if let Ok(Record { thing, .. }) = app_meta
+                    .repository
+                    .get_by_name(input[..second_last_word.range().start].trim())
+                    .await
+                {
+                    suggestions.push(AutocompleteSuggestion::new(
+                        if last_word.range().end == input.len() {
+                            format!("{}{} [name]", &input[..last_word.range().start], suggestion)
+                        } else {
+                            format!("{}[name]", input)
+                        },
+                        format!("rename {}", thing.as_str()),
+                    ));
+                }
+            }
+        }
+    }
+
+    suggestions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::storage::Change;
+    use crate::test_utils as test;
+    use crate::world::npc::{Age, Gender};
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let mut app_meta = test::app_meta();
+
+        app_meta
+            .repository
+            .modify(Change::Create {
+                thing_data: NpcData {
+                    name: "Spot".into(),
+                    ..Default::default()
+                }
+                .into(),
+                uuid: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(
+                WorldCommand::Edit {
+                    name: "Spot".into(),
+                    parsed_diff: Box::new(ParsedThing {
+                        thing_data: NpcData {
+                            age: Age::Child.into(),
+                            gender: Gender::Masculine.into(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        #[expect(clippy::single_range_in_vec_init)]
+                        unknown_words: vec![10..14],
+                        word_count: 2,
+                    }),
+                }
+                .into(),
+            ),
+            Edit.parse_input("Spot is a good boy", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        test::assert_autocomplete_eq!(
+            [("penelope is [character description]", "edit character")],
+            Edit.parse_autocomplete("penelope", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("PENELOPE is a [character description]", "edit character")],
+            Edit.parse_autocomplete("PENELOPE is a ", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("penelope is an elderly", "edit character"),
+                ("penelope is an elf", "edit character"),
+                ("penelope is an elvish", "edit character"),
+                ("penelope is an enby", "edit character"),
+            ],
+            Edit.parse_autocomplete("penelope is an e", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Export;
+
+impl Command for Export {
+    fn token(&self) -> Token {
+        keyword("export")
+    }
+
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        Some(("export", "export the journal contents").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("export", "export the journal contents")
+            .example("export", "download a backup of your journal")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(StorageCommand::Export.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Export.into()),
+            Export.parse_input("export", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Export.into()),
+            Export.parse_input("EXPORT", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Export.parse_input("export potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["export", "EXPORT", "e"] {
+            test::assert_autocomplete_eq!(
+                [("export", "export the journal contents")],
+                Export.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+    }
+}
@@ -0,0 +1,504 @@
+use super::CommandList;
+use crate::app::AppCommand;
+use crate::command::prelude::*;
+use crate::utils::capitalize;
+
+use std::fmt::Write as _;
+use std::hash::Hash;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Help;
+
+#[derive(Hash)]
+enum Marker {
+    Index,
+    Topic,
+}
+
+type TokenFilter = Box<dyn Fn(&Token) -> bool>;
+
+/// The documentation of a single command, as shown by `help [command]`. The syntax isn't part of
+/// this struct, but is rather generated from the command's [`Token`] tree.
+pub struct CommandHelp {
+    topic: &'static str,
+    summary: &'static str,
+    placeholders: Vec<(TokenFilter, &'static str)>,
+    examples: Vec<(&'static str, &'static str)>,
+    listed: bool,
+}
+
+impl Command for Help {
+    fn token(&self) -> Token {
+        or([
+            keyword_m(Marker::Index, "help"),
+            sequence([
+                keyword("help"),
+                or_m(
+                    Marker::Topic,
+                    CommandList::get_all()
+                        .iter()
+                        .map(|command| keyword(command.help().topic))
+                        .collect::<Vec<_>>(),
+                ),
+            ]),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if token_match.contains_marker(Marker::Index) {
+            Some(("help", "how to use initiative.sh").into())
+        } else {
+            let help = get_help(topic(&token_match)?)?;
+
+            if help.listed {
+                Some(AutocompleteSuggestion::new(
+                    format!("help {}", help.topic),
+                    help.summary,
+                ))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("help", "how to use initiative.sh")
+            .example("help", "list all commands")
+            .example("help roll", "explain the syntax of the roll command")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        if token_match.contains_marker(Marker::Index) {
+            Some(AppCommand::Help.into())
+        } else {
+            Some(AppCommand::HelpTopic(topic(&token_match)?.to_string()).into())
+        }
+    }
+}
+
+impl CommandHelp {
+    /// The `topic` is the word used to look up the command with `help [topic]`, while the
+    /// `summary` is a short lowercase description in the style of an autocomplete suggestion.
+    pub fn new(topic: &'static str, summary: &'static str) -> Self {
+        CommandHelp {
+            topic,
+            summary,
+            placeholders: Vec::new(),
+            examples: Vec::new(),
+            listed: true,
+        }
+    }
+
+    /// Name the free-form part of the syntax marked with `marker`, eg. `[name]` instead of the
+    /// generic `[text]`.
+    pub fn placeholder<M>(mut self, marker: M, label: &'static str) -> Self
+    where
+        M: Hash + 'static,
+    {
+        self.placeholders
+            .push((Box::new(move |token| token.is_marked_with(&marker)), label));
+        self
+    }
+
+    pub fn example(mut self, input: &'static str, description: &'static str) -> Self {
+        self.examples.push((input, description));
+        self
+    }
+
+    /// Omit the command from the index shown by `help`. Its page is still available directly.
+    pub fn unlisted(mut self) -> Self {
+        self.listed = false;
+        self
+    }
+
+    /// Render every possible form of the token as a line of syntax. Alternatives are expanded into
+    /// separate lines, optional parts are shown in (parentheses) and free-form parts in [brackets].
+    fn syntax(&self, token: &Token) -> Vec<String> {
+        let lines = match &token.token_type {
+            TokenType::AnyOf(tokens) => vec![tokens
+                .iter()
+                .map(|token| format!("({})", self.syntax(token).join("|")))
+                .collect::<Vec<_>>()
+                .join(" ")],
+            TokenType::AnyPhrase => vec![self.placeholder_for(token, "text")],
+            TokenType::AnyWord => vec![self.placeholder_for(token, "word")],
+            TokenType::Dice => vec![self.placeholder_for(token, "dice")],
+            TokenType::Interval => vec![self.placeholder_for(token, "interval")],
+            TokenType::Keyword(keyword) => vec![keyword.to_string()],
+            TokenType::KeywordList(keywords) => keywords.iter().map(|s| s.to_string()).collect(),
+            TokenType::Name => vec![self.placeholder_for(token, "name")],
+            TokenType::Number => vec![self.placeholder_for(token, "number")],
+            TokenType::NumberRange(range) => {
+                vec![self.placeholder_for(token, &format!("{}-{}", range.start(), range.end()))]
+            }
+            TokenType::Optional(token) => self
+                .syntax(token)
+                .into_iter()
+                .map(|line| format!("({})", line))
+                .collect(),
+            TokenType::Or(tokens) => tokens.iter().flat_map(|token| self.syntax(token)).collect(),
+            TokenType::Sequence(tokens) => {
+                tokens.iter().fold(vec![String::new()], |lines, token| {
+                    let parts = self.syntax(token);
+
+                    lines
+                        .iter()
+                        .flat_map(|line| {
+                            parts.iter().map(move |part| {
+                                if line.is_empty() {
+                                    part.clone()
+                                } else {
+                                    format!("{} {}", line, part)
+                                }
+                            })
+                        })
+                        .collect()
+                })
+            }
+            TokenType::SimilarName => vec![self.placeholder_for(token, "name")],
+            TokenType::TableName => vec![self.placeholder_for(token, "table")],
+        };
+
+        dedup(lines)
+    }
+
+    fn placeholder_for(&self, token: &Token, default: &str) -> String {
+        let label = self
+            .placeholders
+            .iter()
+            .find(|(is_match, _)| is_match(token))
+            .map_or(default, |(_, label)| *label);
+
+        format!("[{}]", label)
+    }
+
+    /// Render the page shown by `help [topic]`.
+    fn page(&self, token: &Token) -> String {
+        let syntax = self.syntax(token);
+        let keywords = dedup(keywords(token));
+
+        let mut output = format!(
+            "# {}\n\n{}{}\n\n## Syntax\n",
+            capitalize(self.topic),
+            capitalize(self.summary),
+            if self.summary.ends_with('.') { "" } else { "." },
+        );
+
+        for line in &syntax {
+            write!(output, "\n* `{}`", line).unwrap();
+        }
+
+        if syntax.iter().any(|line| line.contains('(')) {
+            output.push_str("\n\n_Parts in (parentheses) are optional._");
+        }
+
+        if !keywords.is_empty() {
+            output.push_str("\n\n**Keywords:** ");
+            output.push_str(
+                &keywords
+                    .iter()
+                    .map(|keyword| format!("`{}`", keyword))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+
+        if !self.examples.is_empty() {
+            output.push_str("\n\n## Examples\n");
+
+            for (input, description) in &self.examples {
+                write!(output, "\n* `{}`: {}", input, description).unwrap();
+            }
+        }
+
+        output
+    }
+}
+
+/// The output of `help`: the general introduction followed by a list of all commands.
+pub fn help_index() -> String {
+    let mut helps: Vec<_> = CommandList::get_all()
+        .iter()
+        .map(|command| command.help())
+        .filter(|help| help.listed)
+        .collect();
+    helps.sort_by_key(|help| help.topic);
+
+    let mut output = include_str!("../../../data/help.md").trim_end().to_string();
+    output.push_str("\n\n## Commands\n");
+
+    for help in helps {
+        write!(output, "\n* `help {}`: {}", help.topic, help.summary).unwrap();
+    }
+
+    output.push_str("\n\n_Use `help [command]` to see the syntax of a specific command._");
+    output
+}
+
+/// The output of `help [topic]`, or `None` if no command has that topic.
+pub fn help_topic(topic: &str) -> Option<String> {
+    CommandList::get_all().iter().find_map(|command| {
+        let help = command.help();
+
+        if help.topic.eq_ignore_ascii_case(topic) {
+            Some(help.page(&command.token()))
+        } else {
+            None
+        }
+    })
+}
+
+fn get_help(topic: &str) -> Option<CommandHelp> {
+    CommandList::get_all()
+        .iter()
+        .map(|command| command.help())
+        .find(|help| help.topic == topic)
+}
+
+/// Get the topic keyword selected by a match of the `help [topic]` branch.
+fn topic<'a>(token_match: &'a TokenMatch) -> Option<&'a str> {
+    if let TokenType::Keyword(topic) = token_match
+        .find_marker(Marker::Topic)?
+        .meta_single()?
+        .token
+        .token_type
+    {
+        Some(topic)
+    } else {
+        None
+    }
+}
+
+fn keywords(token: &Token) -> Vec<&'static str> {
+    match &token.token_type {
+        TokenType::Keyword(keyword) => vec![keyword],
+        TokenType::KeywordList(keywords) => keywords.clone(),
+        TokenType::Optional(token) => keywords(token),
+        TokenType::AnyOf(tokens) | TokenType::Or(tokens) | TokenType::Sequence(tokens) => {
+            tokens.iter().flat_map(keywords).collect()
+        }
+        TokenType::AnyPhrase
+        | TokenType::AnyWord
+        | TokenType::Dice
+        | TokenType::Interval
+        | TokenType::Name
+        | TokenType::Number
+        | TokenType::NumberRange(..)
+        | TokenType::SimilarName
+        | TokenType::TableName => Vec::new(),
+    }
+}
+
+/// Remove duplicates while preserving the original order.
+fn dedup<T: PartialEq>(items: Vec<T>) -> Vec<T> {
+    let mut result = Vec::with_capacity(items.len());
+
+    for item in items {
+        if !result.contains(&item) {
+            result.push(item);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[derive(Hash)]
+    enum TestMarker {
+        Name,
+    }
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Help.into()),
+            Help.parse_input("help", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Help.into()),
+            Help.parse_input(&AppCommand::Help.to_string().to_uppercase(), &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::HelpTopic("roll".to_string()).into()),
+            Help.parse_input("HELP Roll", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Help.parse_input("help potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["help", "HELP", "he"] {
+            test::assert_autocomplete_eq!(
+                [("help", "how to use initiative.sh")],
+                Help.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("help load", "load an entry from your journal"),
+                ("help lock", "prevent a detail from being rerolled"),
+            ],
+            Help.parse_autocomplete("help l", &app_meta).collect().await,
+        );
+
+        assert_eq!(
+            Vec::<AutocompleteSuggestion>::new(),
+            Help.parse_autocomplete("help deb", &app_meta)
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [AppCommand::Help, AppCommand::HelpTopic("roll".to_string())] {
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone().into()),
+                Help.parse_input(&command.to_string(), &app_meta).await,
+            );
+        }
+    }
+
+    #[test]
+    fn syntax_test() {
+        let help =
+            CommandHelp::new("badger", "badger badger").placeholder(TestMarker::Name, "mushroom");
+
+        assert_eq!(
+            vec!["badger [mushroom]", "badger [text]", "[word]"],
+            help.syntax(&or([
+                sequence([keyword("badger"), name_m(TestMarker::Name)]),
+                sequence([keyword("badger"), any_phrase()]),
+                sequence([keyword("badger"), any_phrase_m(TestMarker::Name)]),
+                any_word(),
+            ])),
+        );
+
+        assert_eq!(
+            vec!["badger (snake)", "mushroom (snake)"],
+            help.syntax(&sequence([
+                keyword_list(["badger", "mushroom"]),
+                optional(keyword("snake")),
+            ])),
+        );
+
+        assert_eq!(
+            vec![
+                "roll [dice]",
+                "wait [interval]",
+                "hp [number]",
+                "level [1-20]"
+            ],
+            help.syntax(&or([
+                sequence([keyword("roll"), dice()]),
+                sequence([keyword("wait"), interval()]),
+                sequence([keyword("hp"), number()]),
+                sequence([keyword("level"), number_range(1..=20)]),
+            ])),
+        );
+
+        assert_eq!(
+            vec!["(badger) (mushroom|snake)"],
+            help.syntax(&any_of([
+                keyword("badger"),
+                or([keyword("mushroom"), keyword("snake")]),
+            ])),
+        );
+    }
+
+    #[test]
+    fn page_test() {
+        let help = CommandHelp::new("badger", "badger badger")
+            .placeholder(TestMarker::Name, "mushroom")
+            .example("badger snake", "oh, it's a snake");
+
+        assert_eq!(
+            "# Badger\n\n\
+            Badger badger.\n\n\
+            ## Syntax\n\n\
+            * `badger (snake)`\n\
+            * `badger [mushroom]`\n\n\
+            _Parts in (parentheses) are optional._\n\n\
+            **Keywords:** `badger`, `snake`\n\n\
+            ## Examples\n\n\
+            * `badger snake`: oh, it's a snake",
+            help.page(&sequence([
+                keyword("badger"),
+                or([optional(keyword("snake")), any_phrase_m(TestMarker::Name)]),
+            ])),
+        );
+    }
+
+    #[tokio::test]
+    async fn every_command_has_help_test() {
+        let mut app_meta = test::app_meta::with_test_data().await;
+        app_meta.random_tables.insert(test::table::weather());
+        let mut topics = Vec::new();
+
+        for command in CommandList::get_all() {
+            let help = command.help();
+
+            assert!(!help.summary.is_empty(), "{}", help.topic);
+            assert!(!help.examples.is_empty(), "{}", help.topic);
+            assert!(!topics.contains(&help.topic), "{}", help.topic);
+            topics.push(help.topic);
+
+            assert!(
+                help_topic(help.topic).is_some_and(|page| page.contains("## Syntax")),
+                "{}",
+                help.topic,
+            );
+
+            for (input, _) in &help.examples {
+                assert_ne!(
+                    CommandMatches::default(),
+                    command.parse_input(input, &app_meta).await,
+                    "{}",
+                    input,
+                );
+            }
+
+            if help.listed {
+                assert!(
+                    help_index().contains(&format!("`help {}`", help.topic)),
+                    "{}",
+                    help.topic,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Import;
+
+impl Command for Import {
+    fn token(&self) -> Token {
+        keyword("import")
+    }
+
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        Some(("import", "import a journal backup").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("import", "import a journal backup")
+            .example("import", "upload a backup created with `export`")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(StorageCommand::Import.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Import.into()),
+            Import.parse_input("import", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Import.into()),
+            Import.parse_input("IMPORT", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Import.parse_input("import potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["import", "IMPORT", "im"] {
+            test::assert_autocomplete_eq!(
+                [("import", "import a journal backup")],
+                Import.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+    }
+}
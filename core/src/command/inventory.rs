@@ -0,0 +1,200 @@
+use crate::command::prelude::*;
+use crate::world::thing::ThingData;
+use crate::world::WorldCommand;
+
+use super::is_name;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Inventory;
+
+#[derive(Hash)]
+enum Marker {
+    Name,
+    Phrase,
+    PhraseSequence,
+    SimilarName,
+}
+
+impl Command for Inventory {
+    /// Names (and misspellings of names) are matched by their own tokens. The phrase only matches
+    /// names that don't exist, so that the command can report as much.
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::PhraseSequence,
+                [
+                    keyword("inventory"),
+                    keyword("of"),
+                    any_phrase_m(Marker::Phrase),
+                ],
+            ),
+            sequence([keyword("inventory"), keyword("of"), name_m(Marker::Name)]),
+            sequence([
+                keyword("inventory"),
+                keyword("of"),
+                similar_name_m(Marker::SimilarName),
+            ]),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            match &record.thing.data {
+                ThingData::Place(place)
+                    if place.subtype.value().is_some_and(|t| t.keeps_inventory()) =>
+                {
+                    Some(AutocompleteSuggestion::new(
+                        format!("inventory of {}", record.thing.name()),
+                        "browse shop stock",
+                    ))
+                }
+                _ => None,
+            }
+        } else if token_match.contains_marker(Marker::PhraseSequence)
+            && !token_match.contains_marker(Marker::Phrase)
+        {
+            Some(("inventory of [name]", "browse a shop's stock").into())
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("inventory", "browse the stock of a shop")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Phrase, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .example(
+                "inventory of The Rusty Anvil",
+                "list the items for sale at The Rusty Anvil",
+            )
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::SimilarName) {
+            Some(CommandPriority::Corrected)
+        } else {
+            Some(CommandPriority::Canonical)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, app_meta: &AppMeta) -> Option<CommandType> {
+        let name = if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            record.thing.name().to_string()
+        } else {
+            let phrase = token_match.find_marker(Marker::Phrase)?.meta_phrase()?;
+
+            if is_name(phrase, app_meta).await {
+                return None;
+            }
+
+            phrase.to_string()
+        };
+
+        Some(WorldCommand::Inventory { name }.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in [
+            "inventory of Pylos",
+            "INVENTORY OF Pylos",
+            "inventory of Nowhere",
+        ] {
+            assert_eq!(
+                CommandMatches::new_canonical(
+                    WorldCommand::Inventory {
+                        name: input[13..].to_string(),
+                    }
+                    .into(),
+                ),
+                Inventory.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::new_corrected(
+                WorldCommand::Inventory {
+                    name: "Pylos".to_string(),
+                }
+                .into(),
+            ),
+            Inventory.parse_input("inventory of Pyols", &app_meta).await,
+        );
+
+        for input in ["inventory", "inventory of", "inventory Pylos"] {
+            assert_eq!(
+                CommandMatches::default(),
+                Inventory.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["inv", "INVENTORY o"] {
+            test::assert_autocomplete_eq!(
+                [("inventory of [name]", "browse a shop's stock")],
+                Inventory
+                    .parse_autocomplete(input, &app_meta)
+                    .collect()
+                    .await,
+            );
+        }
+
+        // None of the test places are shops.
+        test::assert_empty!(
+            Inventory
+                .parse_autocomplete("inventory of P", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = WorldCommand::Inventory {
+            name: "The Rusty Anvil".to_string(),
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Inventory.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Journal;
+
+impl Command for Journal {
+    fn token(&self) -> Token {
+        keyword("journal")
+    }
+
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        Some(("journal", "list journal contents").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("journal", "list journal contents")
+            .example("journal", "list all saved characters, places, etc.")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(StorageCommand::Journal.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Journal.into()),
+            Journal.parse_input("journal", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Journal.into()),
+            Journal.parse_input("JOURNAL", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Journal.parse_input("journal potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["journal", "JOURNAL", "j"] {
+            test::assert_autocomplete_eq!(
+                [("journal", "list journal contents")],
+                Journal.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use crate::command::prelude::*;
+use crate::storage::{Record, StorageCommand};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Load;
+
+#[derive(Hash)]
+enum Marker {
+    BareName,
+    BareSimilarName,
+    Name,
+    NameSequence,
+    Phrase,
+    PhraseSequence,
+    SimilarName,
+}
+
+impl Command for Load {
+    /// As with `delete` and `save`, the phrase is what gets parsed after the keyword and the name
+    /// only powers autocomplete. A bare name, on the other hand, must exist to match at all.
+    /// Misspelled names are offered as fuzzy alternatives.
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::PhraseSequence,
+                [keyword("load"), any_phrase_m(Marker::Phrase)],
+            ),
+            sequence_m(
+                Marker::NameSequence,
+                [keyword("load"), name_m(Marker::Name)],
+            ),
+            sequence([keyword("load"), similar_name_m(Marker::SimilarName)]),
+            name_m(Marker::BareName),
+            similar_name_m(Marker::BareSimilarName),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            Some(AutocompleteSuggestion::new(
+                format!("load {}", record.thing.name()),
+                describe(record),
+            ))
+        } else if let Some(record) = token_match
+            .find_marker(Marker::BareName)
+            .or_else(|| token_match.find_marker(Marker::BareSimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            Some(AutocompleteSuggestion::new(
+                record.thing.name().to_string(),
+                describe(record),
+            ))
+        } else if token_match.contains_marker(Marker::PhraseSequence)
+            && !token_match.contains_marker(Marker::Phrase)
+        {
+            Some(("load [name]", "load an entry").into())
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("load", "load an entry from your journal")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Phrase, "name")
+            .placeholder(Marker::BareName, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .placeholder(Marker::BareSimilarName, "name")
+            .example("load Odysseus", "show the details of Odysseus")
+            .example("Odysseus", "the same, without the `load` keyword")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Phrase) {
+            Some(CommandPriority::Canonical)
+        } else if token_match.contains_marker(Marker::BareName) {
+            Some(CommandPriority::Fuzzy)
+        } else if token_match.contains_marker(Marker::SimilarName)
+            || token_match.contains_marker(Marker::BareSimilarName)
+        {
+            Some(CommandPriority::Corrected)
+        } else {
+            None
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        let name = if let Some(phrase) = token_match.find_marker(Marker::Phrase) {
+            phrase.meta_phrase()?.to_string()
+        } else {
+            token_match
+                .find_markers(&[
+                    Marker::BareName,
+                    Marker::SimilarName,
+                    Marker::BareSimilarName,
+                ])
+                .find_map(|token_match| token_match.meta_record())?
+                .thing
+                .name()
+                .to_string()
+        };
+
+        Some(StorageCommand::Load { name }.into())
+    }
+}
+
+fn describe(record: &Record) -> String {
+    if record.is_saved() {
+        format!("{}", record.thing.display_description())
+    } else {
+        format!("{} (unsaved)", record.thing.display_description())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["load Odysseus", "LOAD Odysseus"] {
+            assert_eq!(
+                CommandMatches::new_canonical(
+                    StorageCommand::Load {
+                        name: "Odysseus".to_string(),
+                    }
+                    .into(),
+                ),
+                Load.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(
+                StorageCommand::Load {
+                    name: "Odysseus".to_string(),
+                }
+                .into(),
+            ),
+            Load.parse_input("odysseus", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches {
+                canonical_match: Some(
+                    StorageCommand::Load {
+                        name: "Odyseus".to_string(),
+                    }
+                    .into(),
+                ),
+                fuzzy_matches: vec![StorageCommand::Load {
+                    name: "Odysseus".to_string(),
+                }
+                .into()],
+                corrected: true,
+            },
+            Load.parse_input("load Odyseus", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_corrected(
+                StorageCommand::Load {
+                    name: "Penelope".to_string(),
+                }
+                .into(),
+            ),
+            Load.parse_input("penelpoe", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Load.parse_input("Nobody", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Load.parse_input("Odysseus", &test::app_meta()).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["lo", "LOad", "load"] {
+            test::assert_autocomplete_eq!(
+                [("load [name]", "load an entry")],
+                Load.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("load Penelope", "middle-aged human, she/her"),
+                ("load Phoenicia", "territory"),
+                ("load Polyphemus", "adult half-orc, he/him (unsaved)"),
+                ("load Pylos", "city (unsaved)"),
+            ],
+            Load.parse_autocomplete("load P", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("Penelope", "middle-aged human, she/her"),
+                ("Phoenicia", "territory"),
+                ("Polyphemus", "adult half-orc, he/him (unsaved)"),
+                ("Pylos", "city (unsaved)"),
+            ],
+            Load.parse_autocomplete("p", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("Odysseus", "middle-aged human, he/him (unsaved)")],
+            Load.parse_autocomplete("oDYSSEUS", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("load Penelope", "middle-aged human, she/her")],
+            Load.parse_autocomplete("load Penal", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("Odysseus", "middle-aged human, he/him (unsaved)")],
+            Load.parse_autocomplete("Odyseus", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = StorageCommand::Load {
+            name: "Odysseus".to_string(),
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Load.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
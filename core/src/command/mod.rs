@@ -5,22 +5,48 @@ mod prelude;
 pub mod prelude;
 
 mod about;
+mod add_words;
+mod adjust_time;
+mod alias;
+mod changelog;
+mod create;
+mod debug;
+mod delete;
+mod edit;
+mod export;
+mod help;
+mod import;
+mod inventory;
+mod journal;
+mod load;
+mod lock;
+mod now;
+mod redo;
+mod reference;
+mod reroll;
+mod restock;
+mod roll;
+mod save;
+mod seed;
+mod table;
+mod tutorial;
+mod undo;
+mod unlock;
 
 mod token;
 
-use std::fmt::{self, Write};
-use std::iter;
+pub use help::{help_index, help_topic};
+
 use std::pin::Pin;
 
-use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
-};
+use crate::app::{AppMeta, AutocompleteSuggestion, CommandMatches, CommandType};
 use initiative_macros::CommandList;
 
+use help::CommandHelp;
+use token::constructors::{name, or, similar_name};
 use token::{FuzzyMatch, Token, TokenMatch};
 
 use async_stream::stream;
-use async_trait::async_trait;
 use futures::prelude::*;
 
 pub trait Command {
@@ -34,16 +60,18 @@ pub trait Command {
     /// TokenMatch object.
     fn autocomplete(&self, fuzzy_match: FuzzyMatch, input: &str) -> Option<AutocompleteSuggestion>;
 
+    /// Describe the command for the `help` page. Only the topic, summary and examples need to be
+    /// provided here; the syntax is generated from `token()` so that the two can't drift apart.
+    fn help(&self) -> CommandHelp;
+
     /// Get the priority of the command with a given input. See CommandPriority for details.
     fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority>;
 
-    /// Run the command represented by a matched token, returning the success or failure output to
-    /// be displayed to the user.
+    /// Convert a matched token into the command that it represents, which can then be run. Return
+    /// None if the match is invalid. The canonical form of the input is the `Display` form of the
+    /// returned command.
     #[cfg_attr(feature = "integration-tests", expect(async_fn_in_trait))]
-    async fn run(&self, token_match: TokenMatch, app_meta: &mut AppMeta) -> Result<String, String>;
-
-    /// Get the canonical form of the provided token match. Return None if the match is invalid.
-    fn get_canonical_form_of(&self, token_match: &TokenMatch) -> Option<String>;
+    async fn parse(&self, token_match: TokenMatch, app_meta: &AppMeta) -> Option<CommandType>;
 
     /// A helper function to roughly provide Command::autocomplete(Command::token().match_input()),
     /// except that that wouldn't compile for all sorts of exciting reasons.
@@ -63,11 +91,61 @@ pub trait Command {
             }
         })
     }
+
+    /// Parse the input into all of its possible interpretations as this command, distinguishing
+    /// between canonical and fuzzy matches.
+    #[cfg_attr(feature = "integration-tests", expect(async_fn_in_trait))]
+    async fn parse_input(&self, input: &str, app_meta: &AppMeta) -> CommandMatches<CommandType> {
+        let token = self.token();
+        let token_matches: Vec<_> = token.match_input_exact(input, app_meta).collect().await;
+        let mut command_matches = CommandMatches::default();
+
+        for token_match in token_matches {
+            if let Some(priority) = self.get_priority(&token_match) {
+                if let Some(command) = self.parse(token_match, app_meta).await {
+                    match priority {
+                        CommandPriority::Canonical => command_matches.push_canonical(command),
+                        CommandPriority::Fuzzy => command_matches.push_fuzzy(command),
+                        CommandPriority::Corrected => command_matches.push_corrected(command),
+                    }
+                }
+            }
+        }
+
+        command_matches
+    }
 }
 
 #[derive(Clone, CommandList, Debug)]
 enum CommandList {
     About(about::About),
+    AddWords(add_words::AddWords),
+    AdjustTime(adjust_time::AdjustTime),
+    Alias(alias::Alias),
+    Changelog(changelog::Changelog),
+    Create(create::Create),
+    Debug(debug::Debug),
+    Delete(delete::Delete),
+    Edit(edit::Edit),
+    Export(export::Export),
+    Help(help::Help),
+    Import(import::Import),
+    Inventory(inventory::Inventory),
+    Journal(journal::Journal),
+    Load(load::Load),
+    Lock(lock::Lock),
+    Now(now::Now),
+    Redo(redo::Redo),
+    Reference(reference::Reference),
+    Reroll(reroll::Reroll),
+    Restock(restock::Restock),
+    Roll(roll::Roll),
+    Save(save::Save),
+    Seed(seed::Seed),
+    Table(table::Table),
+    Tutorial(tutorial::Tutorial),
+    Undo(undo::Undo),
+    Unlock(unlock::Unlock),
 }
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -81,86 +159,37 @@ pub enum CommandPriority {
     /// only one fuzzy match is found, that match will run. If multiple fuzzy matches are found,
     /// the user will be prompted which canonical form they wish to run.
     Fuzzy,
-}
-
-/// Interfaces with the legacy command traits.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct TransitionalCommand {
-    canonical: String,
-}
 
-impl TransitionalCommand {
-    pub fn new<S>(canonical: S) -> Self
-    where
-        S: AsRef<str>,
-    {
-        Self {
-            canonical: canonical.as_ref().to_string(),
-        }
-    }
-}
-
-#[async_trait(?Send)]
-impl Runnable for TransitionalCommand {
-    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
-        run(&self.canonical, app_meta).await
-    }
+    /// A fuzzy match that corrects a misspelling in the input, eg. `load Elendor` for "Elandor".
+    /// It is treated like any other fuzzy match, except that if it runs, the user is told what
+    /// the input was corrected to.
+    Corrected,
 }
 
-#[async_trait(?Send)]
-impl ContextAwareParse for TransitionalCommand {
-    async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
-        let mut command_matches = CommandMatches::default();
-
-        let commands_tokens: Vec<(&CommandList, Token)> = CommandList::get_all()
-            .iter()
-            .map(|c| (c, c.token()))
-            .collect();
-
-        {
-            let mut match_streams = stream::SelectAll::default();
-
-            // Indexing the array avoids lifetime issues that would occur with an iterator
-            #[expect(clippy::needless_range_loop)]
-            for i in 0..commands_tokens.len() {
-                match_streams.push(
-                    stream::repeat(commands_tokens[i].0)
-                        .zip(commands_tokens[i].1.match_input(input, app_meta)),
-                );
-            }
-
-            while let Some((command, fuzzy_match)) = match_streams.next().await {
-                if let FuzzyMatch::Exact(token_match) = fuzzy_match {
-                    if let Some(priority) = command.get_priority(&token_match) {
-                        if let Some(canonical) = command.get_canonical_form_of(&token_match) {
-                            if priority == CommandPriority::Canonical {
-                                command_matches.push_canonical(Self { canonical });
-                            } else {
-                                command_matches.push_fuzzy(Self { canonical });
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        command_matches
-    }
-}
-
-#[async_trait(?Send)]
-impl Autocomplete for TransitionalCommand {
-    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        autocomplete(input, app_meta).await
-    }
+/// Parse the input against every command, returning all of its possible interpretations.
+pub async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<CommandType> {
+    stream::iter(CommandList::get_all())
+        .then(|command| command.parse_input(input, app_meta))
+        .fold(CommandMatches::default(), |matches, command_matches| {
+            future::ready(matches.union(command_matches))
+        })
+        .await
 }
 
-impl fmt::Display for TransitionalCommand {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", self.canonical)
-    }
+/// Whether the input would be matched by the `name` or `similar_name` tokens, ie. whether it is the
+/// name of a thing or a likely misspelling of one. Commands that fall back to a bare phrase in
+/// order to report missing things use this to avoid matching names twice.
+async fn is_name(input: &str, app_meta: &AppMeta) -> bool {
+    let token = or([name(), similar_name()]);
+    let is_name = token
+        .match_input_exact(input, app_meta)
+        .next()
+        .await
+        .is_some();
+    is_name
 }
 
+/// Get the autocomplete suggestions of every command for the input.
 pub async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
     let mut suggestions: Vec<_> = stream::select_all(
         CommandList::get_all()
@@ -171,100 +200,6 @@ pub async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSu
     .await;
 
     suggestions.sort();
-    suggestions.truncate(10);
+    suggestions.dedup();
     suggestions
 }
-
-pub async fn run(input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
-    // The only reason this vec exists is to ensure that the Tokens referenced by TokenMatch et al
-    // outlive their references.
-    let commands_tokens: Vec<(&CommandList, Token)> = CommandList::get_all()
-        .iter()
-        .map(|c| (c, c.token()))
-        .collect();
-
-    let mut token_matches: Vec<(&CommandList, CommandPriority, TokenMatch)> = Vec::new();
-
-    {
-        let mut match_streams = stream::SelectAll::default();
-
-        // Indexing the array avoids lifetime issues that would occur with an iterator
-        #[expect(clippy::needless_range_loop)]
-        for i in 0..commands_tokens.len() {
-            match_streams.push(
-                stream::repeat(commands_tokens[i].0)
-                    .zip(commands_tokens[i].1.match_input_exact(input, app_meta)),
-            );
-        }
-
-        while let Some((command, token_match)) = match_streams.next().await {
-            if let Some(priority) = command.get_priority(&token_match) {
-                token_matches.push((command, priority, token_match));
-            }
-        }
-    }
-
-    token_matches.sort_by_key(|&(_, command_priority, _)| command_priority);
-
-    match token_matches.len() {
-        0 => return Err(format!("Unknown command: \"{}\"", input)),
-        1 => {
-            let (command, _, token_match) = token_matches.pop().unwrap();
-            return command.run(token_match, app_meta).await;
-        }
-        _ => {} // continue
-    }
-
-    if token_matches[0].1 == CommandPriority::Canonical {
-        assert_ne!(token_matches[1].1, CommandPriority::Canonical);
-
-        let (command, _, token_match) = token_matches.remove(0);
-        let result = command.run(token_match, app_meta).await;
-
-        let mut iter = token_matches
-            .iter()
-            .take_while(|(_, command_priority, _)| command_priority == &CommandPriority::Fuzzy)
-            .peekable();
-
-        if iter.peek().is_none() {
-            result
-        } else {
-            let f = |s| {
-                iter
-                    .filter_map(|(command, _, token_match)| command.get_canonical_form_of(token_match))
-                    .fold(
-                        format!("{}\n\n! There are other possible interpretations of this command. Did you mean:\n", s),
-                        |mut s, c| { write!(s, "\n* `{}`", c).unwrap(); s }
-                    )
-            };
-
-            match result {
-                Ok(s) => Ok(f(s)),
-                Err(s) => Err(f(s)),
-            }
-        }
-    } else {
-        let first_token_match = token_matches.remove(0);
-
-        let mut iter =
-            iter::once(&first_token_match)
-                .chain(token_matches.iter().take_while(|(_, command_priority, _)| {
-                    command_priority == &first_token_match.1
-                }))
-                .filter_map(|(command, _, token_match)| command.get_canonical_form_of(token_match))
-                .peekable();
-
-        if iter.peek().is_none() {
-            Err(format!("Unknown command: \"{}\"", input))
-        } else {
-            Err(iter.fold(
-                "There are several possible interpretations of this command. Did you mean:\n"
-                    .to_string(),
-                |mut s, c| {
-                    write!(s, "\n* `{}`", c).unwrap();
-                    s
-                },
-            ))
-        }
-    }
-}
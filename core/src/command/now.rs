@@ -0,0 +1,118 @@
+use crate::command::prelude::*;
+use crate::time::TimeCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Now;
+
+#[derive(Hash)]
+enum Marker {
+    Date,
+    Now,
+    Time,
+}
+
+impl Command for Now {
+    fn token(&self) -> Token {
+        or([
+            keyword_m(Marker::Now, "now"),
+            keyword_m(Marker::Time, "time"),
+            keyword_m(Marker::Date, "date"),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        let term = if token_match.contains_marker(Marker::Now) {
+            "now"
+        } else if token_match.contains_marker(Marker::Time) {
+            "time"
+        } else {
+            "date"
+        };
+
+        Some((term, "get the current time").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("now", "get the current time")
+            .example("now", "show the current day and time")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Now) {
+            Some(CommandPriority::Canonical)
+        } else {
+            Some(CommandPriority::Fuzzy)
+        }
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(TimeCommand::Now.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(TimeCommand::Now.into()),
+            Now.parse_input("NOW", &app_meta).await,
+        );
+
+        for input in ["time", "Date"] {
+            assert_eq!(
+                CommandMatches::new_fuzzy(TimeCommand::Now.into()),
+                Now.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        test::assert_empty!(
+            Now.parse_autocomplete("", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+
+        test::assert_autocomplete_eq!(
+            [("now", "get the current time")],
+            Now.parse_autocomplete("N", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("time", "get the current time")],
+            Now.parse_autocomplete("time", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("date", "get the current time")],
+            Now.parse_autocomplete("d", &app_meta).collect().await,
+        );
+    }
+}
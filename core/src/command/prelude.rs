@@ -1,4 +1,5 @@
+pub use super::help::CommandHelp;
 pub use super::token::constructors::*;
 pub use super::token::{FuzzyMatch, MatchMeta, Token, TokenMatch, TokenType};
 pub use super::{Command, CommandPriority};
-pub use crate::app::{AppMeta, AutocompleteSuggestion};
+pub use crate::app::{AppMeta, AutocompleteSuggestion, CommandType};
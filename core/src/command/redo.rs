@@ -0,0 +1,101 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redo;
+
+impl Command for Redo {
+    fn token(&self) -> Token {
+        keyword("redo")
+    }
+
+    /// The summary depends on the repository state, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await token_match in token.match_input(input, app_meta) {
+                if !matches!(token_match, FuzzyMatch::Overflow(..)) {
+                    yield if let Some(change) = app_meta.repository.get_redo() {
+                        ("redo", format!("redo {}", change.display_redo())).into()
+                    } else {
+                        ("redo", "Nothing to redo.").into()
+                    };
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("redo", "redo the most recently undone change")
+            .example("redo", "reapply the last change that was undone")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(StorageCommand::Redo.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Redo.into()),
+            Redo.parse_input("REDO", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let mut app_meta = test::app_meta::with_test_data().await;
+
+        test::assert_autocomplete_eq!(
+            [("redo", "Nothing to redo.")],
+            Redo.parse_autocomplete("redo", &app_meta).collect().await,
+        );
+
+        // undo Polyphemus
+        app_meta.repository.undo().await.unwrap().unwrap();
+        // undo Pylos
+        app_meta.repository.undo().await.unwrap().unwrap();
+        // undo Odysseus
+        app_meta.repository.undo().await.unwrap().unwrap();
+
+        test::assert_autocomplete_eq!(
+            [("redo", "redo creating Odysseus")],
+            Redo.parse_autocomplete("RE", &app_meta).collect().await,
+        );
+    }
+}
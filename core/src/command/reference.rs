@@ -0,0 +1,377 @@
+use crate::command::prelude::*;
+use crate::reference::{Condition, Item, ItemCategory, MagicItem, ReferenceCommand, Spell, Trait};
+use crate::utils::{prefix_similarity_ci, similarity_ci, CaseInsensitiveStr};
+
+use std::iter::repeat;
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reference;
+
+#[derive(Hash)]
+enum Marker {
+    Condition,
+    Item,
+    ItemCategory,
+    MagicItem,
+    Name,
+    OpenGameLicense,
+    Spell,
+    Spells,
+    SrdSpells,
+    Trait,
+}
+
+impl Command for Reference {
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::OpenGameLicense,
+                [keyword("open"), keyword("game"), keyword("license")],
+            ),
+            sequence_m(Marker::SrdSpells, [keyword("srd"), keyword("spells")]),
+            keyword_m(Marker::Spells, "spells"),
+            sequence([
+                keyword("srd"),
+                or([
+                    keyword_m(Marker::Condition, "condition"),
+                    sequence_m(Marker::ItemCategory, [keyword("item"), keyword("category")]),
+                    keyword_m(Marker::Item, "item"),
+                    sequence_m(Marker::MagicItem, [keyword("magic"), keyword("item")]),
+                    keyword_m(Marker::Spell, "spell"),
+                    keyword_m(Marker::Trait, "trait"),
+                ]),
+                any_phrase_m(Marker::Name),
+            ]),
+            any_phrase_m(Marker::Condition),
+            any_phrase_m(Marker::Item),
+            any_phrase_m(Marker::ItemCategory),
+            any_phrase_m(Marker::MagicItem),
+            any_phrase_m(Marker::Spell),
+            any_phrase_m(Marker::Trait),
+        ])
+    }
+
+    /// A single match can yield many suggestions, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(
+            stream! {
+                let token = self.token();
+                for await fuzzy_match in token.match_input(input, app_meta) {
+                    let (token_match, is_exact) = match fuzzy_match {
+                        FuzzyMatch::Exact(token_match) => (token_match, true),
+                        FuzzyMatch::Partial(token_match, _) => (token_match, false),
+                        FuzzyMatch::Overflow(..) => continue,
+                    };
+
+                    if token_match.contains_marker(Marker::OpenGameLicense) {
+                        yield ("Open Game License", "SRD license").into();
+                    } else if token_match.contains_marker(Marker::Spells) {
+                        yield ("spells", "SRD index").into();
+                    } else if is_exact && !token_match.contains_marker(Marker::Name) {
+                        // The rest of the tree is a bare phrase marked with its category.
+                        let Some(phrase) = token_match.meta_single().and_then(TokenMatch::meta_phrase) else {
+                            continue;
+                        };
+
+                        let category = token_match.meta_single().unwrap();
+                        let mut suggestions = get_words(category)
+                            .filter(|(term, _)| term.starts_with_ci(phrase))
+                            .peekable();
+
+                        if suggestions.peek().is_some() {
+                            for (term, summary) in suggestions {
+                                yield AutocompleteSuggestion::new(term, summary);
+                            }
+                        } else {
+                            // Nothing starts with the input, so it may have been misspelled.
+                            for (term, summary) in get_words(category).filter(|(term, _)| {
+                                similarity_ci(phrase, term).is_some()
+                                    || prefix_similarity_ci(phrase, term).is_some()
+                            }) {
+                                yield AutocompleteSuggestion::new(term, summary);
+                            }
+                        }
+                    }
+                }
+            }
+            .take(10),
+        )
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("reference", "look up the System Reference Document")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Condition, "name")
+            .placeholder(Marker::Item, "name")
+            .placeholder(Marker::ItemCategory, "name")
+            .placeholder(Marker::MagicItem, "name")
+            .placeholder(Marker::Spell, "name")
+            .placeholder(Marker::Trait, "name")
+            .example("spells", "list all spells in the SRD")
+            .example(
+                "Fireball",
+                "show the description of a spell, item, condition, etc.",
+            )
+            .example("srd condition blinded", "the same, specifying the category")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Name) {
+            parse_name(token_match)
+                .map(|_| CommandPriority::Canonical)
+                .or_else(|| parse_similar_name(token_match).map(|_| CommandPriority::Corrected))
+        } else if token_match.contains_marker(Marker::OpenGameLicense)
+            || token_match.contains_marker(Marker::SrdSpells)
+        {
+            Some(CommandPriority::Canonical)
+        } else if token_match.contains_marker(Marker::Spells) {
+            Some(CommandPriority::Fuzzy)
+        } else {
+            parse_name(token_match)
+                .map(|_| CommandPriority::Fuzzy)
+                .or_else(|| parse_similar_name(token_match).map(|_| CommandPriority::Corrected))
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        if token_match.contains_marker(Marker::OpenGameLicense) {
+            Some(ReferenceCommand::OpenGameLicense.into())
+        } else if token_match.contains_marker(Marker::SrdSpells)
+            || token_match.contains_marker(Marker::Spells)
+        {
+            Some(ReferenceCommand::Spells.into())
+        } else {
+            parse_name(&token_match)
+                .or_else(|| parse_similar_name(&token_match))
+                .map(CommandType::from)
+        }
+    }
+}
+
+/// Parse the phrase into whichever category it was marked with, either by the `srd [category]`
+/// prefix or by the bare phrase token itself.
+fn parse_name(token_match: &TokenMatch) -> Option<ReferenceCommand> {
+    parse_phrase(token_match, name_phrase(token_match)?)
+}
+
+/// If the phrase doesn't name anything in the SRD, find the closest entry in the same category
+/// that it might be a misspelling of. A bare phrase that names something in another category is
+/// left alone, since the user probably meant that instead.
+fn parse_similar_name(token_match: &TokenMatch) -> Option<ReferenceCommand> {
+    let phrase = name_phrase(token_match)?;
+
+    if !token_match.contains_marker(Marker::Name)
+        && (phrase.parse::<Condition>().is_ok()
+            || phrase.parse::<Item>().is_ok()
+            || phrase.parse::<ItemCategory>().is_ok()
+            || phrase.parse::<MagicItem>().is_ok()
+            || phrase.parse::<Spell>().is_ok()
+            || phrase.parse::<Trait>().is_ok())
+    {
+        return None;
+    }
+
+    let category = token_match
+        .find_markers(&[
+            Marker::Condition,
+            Marker::Item,
+            Marker::ItemCategory,
+            Marker::MagicItem,
+            Marker::Spell,
+            Marker::Trait,
+        ])
+        .next()?;
+
+    let (_, term) = get_words(category)
+        .filter_map(|(term, _)| similarity_ci(phrase, term).map(|distance| (distance, term)))
+        .min()?;
+
+    parse_phrase(token_match, term)
+}
+
+fn name_phrase<'a>(token_match: &'a TokenMatch) -> Option<&'a str> {
+    match token_match.find_marker(Marker::Name) {
+        Some(name) => name.meta_phrase(),
+        None => token_match.meta_single()?.meta_phrase(),
+    }
+}
+
+fn parse_phrase(token_match: &TokenMatch, phrase: &str) -> Option<ReferenceCommand> {
+    if token_match.contains_marker(Marker::Condition) {
+        phrase.parse().ok().map(ReferenceCommand::Condition)
+    } else if token_match.contains_marker(Marker::ItemCategory) {
+        phrase.parse().ok().map(ReferenceCommand::ItemCategory)
+    } else if token_match.contains_marker(Marker::Item) {
+        phrase.parse().ok().map(ReferenceCommand::Item)
+    } else if token_match.contains_marker(Marker::MagicItem) {
+        phrase.parse().ok().map(ReferenceCommand::MagicItem)
+    } else if token_match.contains_marker(Marker::Spell) {
+        phrase.parse().ok().map(ReferenceCommand::Spell)
+    } else if token_match.contains_marker(Marker::Trait) {
+        phrase.parse().ok().map(ReferenceCommand::Trait)
+    } else {
+        None
+    }
+}
+
+fn get_words(token_match: &TokenMatch) -> Box<dyn Iterator<Item = (&'static str, &'static str)>> {
+    if token_match.is_marked_with(Marker::Condition) {
+        Box::new(Condition::get_words().zip(repeat("SRD condition")))
+    } else if token_match.is_marked_with(Marker::Item) {
+        Box::new(Item::get_words().zip(repeat("SRD item")))
+    } else if token_match.is_marked_with(Marker::ItemCategory) {
+        Box::new(ItemCategory::get_words().zip(repeat("SRD item category")))
+    } else if token_match.is_marked_with(Marker::MagicItem) {
+        Box::new(MagicItem::get_words().zip(repeat("SRD magic item")))
+    } else if token_match.is_marked_with(Marker::Spell) {
+        Box::new(Spell::get_words().zip(repeat("SRD spell")))
+    } else if token_match.is_marked_with(Marker::Trait) {
+        Box::new(Trait::get_words().zip(repeat("SRD trait")))
+    } else {
+        Box::new(std::iter::empty())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(ReferenceCommand::OpenGameLicense.into()),
+            Reference.parse_input("open game license", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(ReferenceCommand::Spells.into()),
+            Reference.parse_input("Spells", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(ReferenceCommand::Spell(Spell::Shield).into()),
+            Reference.parse_input("srd spell shield", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches {
+                canonical_match: None,
+                fuzzy_matches: vec![
+                    ReferenceCommand::Item(Item::Shield).into(),
+                    ReferenceCommand::Spell(Spell::Shield).into(),
+                ],
+                corrected: false,
+            },
+            Reference.parse_input("Shield", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Reference.parse_input("srd spell potato", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_corrected(ReferenceCommand::Spell(Spell::Fireball).into()),
+            Reference.parse_input("srd spell firebal", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_corrected(ReferenceCommand::Spell(Spell::Fireball).into()),
+            Reference.parse_input("Fierball", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        test::assert_autocomplete_eq!(
+            [("Open Game License", "SRD license")],
+            Reference
+                .parse_autocomplete("open game", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("Shield", "SRD item"),
+                ("Shield", "SRD spell"),
+                ("shields", "SRD item category"),
+            ],
+            Reference
+                .parse_autocomplete("shield", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("Fireball", "SRD spell")],
+            Reference
+                .parse_autocomplete("fyreb", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("spells", "SRD index")],
+            Reference
+                .parse_autocomplete("SPELLS", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [
+            ReferenceCommand::Spell(Spell::Shield),
+            ReferenceCommand::Spells,
+            ReferenceCommand::Item(Item::Shield),
+            ReferenceCommand::ItemCategory(ItemCategory::Shields),
+            ReferenceCommand::MagicItem(MagicItem::DeckOfManyThings),
+            ReferenceCommand::OpenGameLicense,
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone().into()),
+                Reference.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.into()),
+                Reference
+                    .parse_input(&command_string.to_uppercase(), &app_meta)
+                    .await,
+                "{}",
+                command_string.to_uppercase(),
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::command::prelude::*;
+use crate::world::thing::ThingData;
+use crate::world::WorldCommand;
+
+use super::is_name;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Restock;
+
+#[derive(Hash)]
+enum Marker {
+    Name,
+    Phrase,
+    PhraseSequence,
+    SimilarName,
+}
+
+impl Command for Restock {
+    /// Names (and misspellings of names) are matched by their own tokens. The phrase only matches
+    /// names that don't exist, so that the command can report as much.
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::PhraseSequence,
+                [keyword("restock"), any_phrase_m(Marker::Phrase)],
+            ),
+            sequence([keyword("restock"), name_m(Marker::Name)]),
+            sequence([keyword("restock"), similar_name_m(Marker::SimilarName)]),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            match &record.thing.data {
+                ThingData::Place(place)
+                    if place.subtype.value().is_some_and(|t| t.keeps_inventory()) =>
+                {
+                    Some(AutocompleteSuggestion::new(
+                        format!("restock {}", record.thing.name()),
+                        "replace shop stock",
+                    ))
+                }
+                _ => None,
+            }
+        } else if token_match.contains_marker(Marker::PhraseSequence)
+            && !token_match.contains_marker(Marker::Phrase)
+        {
+            Some(("restock [name]", "replace a shop's stock").into())
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("restock", "replace the stock of a shop with new items")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Phrase, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .example(
+                "restock The Rusty Anvil",
+                "generate a fresh inventory for The Rusty Anvil",
+            )
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::SimilarName) {
+            Some(CommandPriority::Corrected)
+        } else {
+            Some(CommandPriority::Canonical)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, app_meta: &AppMeta) -> Option<CommandType> {
+        let name = if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            record.thing.name().to_string()
+        } else {
+            let phrase = token_match.find_marker(Marker::Phrase)?.meta_phrase()?;
+
+            if is_name(phrase, app_meta).await {
+                return None;
+            }
+
+            phrase.to_string()
+        };
+
+        Some(WorldCommand::Restock { name }.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["restock Pylos", "RESTOCK Pylos", "restock Nowhere"] {
+            assert_eq!(
+                CommandMatches::new_canonical(
+                    WorldCommand::Restock {
+                        name: input[8..].to_string(),
+                    }
+                    .into(),
+                ),
+                Restock.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::default(),
+            Restock.parse_input("restock", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["rest", "RESTOCK"] {
+            test::assert_autocomplete_eq!(
+                [("restock [name]", "replace a shop's stock")],
+                Restock.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        // None of the test places are shops.
+        test::assert_empty!(
+            Restock
+                .parse_autocomplete("restock P", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = WorldCommand::Restock {
+            name: "The Rusty Anvil".to_string(),
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Restock.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use crate::app::AppCommand;
+use crate::command::prelude::*;
+use crate::table::TableCommand;
+use crate::utils::CaseInsensitiveStr;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Roll;
+
+#[derive(Hash)]
+enum Marker {
+    Formula,
+    History,
+    Keyword,
+    Stats,
+    StatsFormula,
+    Table,
+    TableName,
+    TableSuggestion,
+    TableSuggestionName,
+}
+
+impl Command for Roll {
+    fn token(&self) -> Token {
+        or([
+            sequence_m(Marker::History, [keyword("roll"), keyword("history")]),
+            sequence_m(
+                Marker::Stats,
+                [
+                    keyword("roll"),
+                    keyword("stats"),
+                    any_phrase_m(Marker::StatsFormula),
+                ],
+            ),
+            sequence_m(
+                Marker::Table,
+                [
+                    keyword("roll"),
+                    keyword("on"),
+                    any_phrase_m(Marker::TableName),
+                ],
+            ),
+            sequence_m(
+                Marker::TableSuggestion,
+                [
+                    keyword("roll"),
+                    keyword("on"),
+                    table_name_m(Marker::TableSuggestionName),
+                ],
+            ),
+            sequence_m(
+                Marker::Keyword,
+                [keyword("roll"), any_phrase_m(Marker::Formula)],
+            ),
+            dice_m(Marker::Formula),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        // Only suggest the commands themselves; there's no sense in guessing at a formula.
+        match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _)
+                if token_match.contains_marker(Marker::History) =>
+            {
+                Some(("roll history", "show your recent rolls").into())
+            }
+            FuzzyMatch::Partial(token_match, _)
+                if token_match.contains_marker(Marker::Stats)
+                    && !token_match.contains_marker(Marker::StatsFormula) =>
+            {
+                Some(("roll stats [dice]", "show the odds of a roll").into())
+            }
+            FuzzyMatch::Partial(token_match, _)
+                if token_match.contains_marker(Marker::Table)
+                    && !token_match.contains_marker(Marker::TableName) =>
+            {
+                Some(("roll on [table]", "roll on one of your tables").into())
+            }
+            FuzzyMatch::Partial(token_match, _)
+                if token_match.contains_marker(Marker::Keyword)
+                    && !token_match.contains_marker(Marker::Formula) =>
+            {
+                Some(("roll [dice]", "roll eg. 8d6 or d20+3").into())
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                // Suggest tables by their own names rather than by however the user typed them.
+                let table_name = match &fuzzy_match {
+                    FuzzyMatch::Exact(token_match) => token_match
+                        .find_marker(Marker::TableSuggestionName)
+                        .and_then(|name| name.meta_phrase())
+                        .map(|name| name.to_string()),
+                    FuzzyMatch::Partial(token_match, completion) => token_match
+                        .find_marker(Marker::TableSuggestionName)
+                        .and_then(|name| name.meta_phrase())
+                        .map(|name| format!("{}{}", name, completion.as_deref().unwrap_or_default())),
+                    FuzzyMatch::Overflow(..) => continue,
+                };
+
+                if let Some(table_name) = table_name {
+                    if let Some(table) = app_meta.random_tables.get(&table_name) {
+                        yield AutocompleteSuggestion::new(
+                            format!("roll on {}", table.name),
+                            format!("d{} table", table.die_size()),
+                        );
+                    }
+                } else if let Some(suggestion) = self.autocomplete(fuzzy_match, input) {
+                    yield suggestion;
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("roll", "roll dice")
+            .placeholder(Marker::Formula, "formula")
+            .placeholder(Marker::StatsFormula, "formula")
+            .example("8d6: Fireball", "roll 8d6 with a reason")
+            .example("d20+3", "dexterity check with +3 bonus")
+            .example(
+                "2d20k1+5",
+                "+5 attack roll with disadvantage (k = keep low)",
+            )
+            .example("2d20d1+5", "+5 attack roll with advantage (d = drop low)")
+            .example("roll (d4+1)^3", "magic missile (rolls 3 times)")
+            .example("roll adv d20+5", "+5 attack roll with advantage")
+            .example("roll dis", "a d20 with disadvantage")
+            .example("roll d20+5 stealth", "a labelled roll")
+            .example("roll 4d6 drop lowest x6", "roll a set of ability scores")
+            .example("roll history", "show your recent rolls")
+            .example("roll stats 3d6", "show the range, average and odds of 3d6")
+            .example("roll on Weather", "roll on a table you've created")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::History)
+            || token_match.contains_marker(Marker::Stats)
+            || token_match.contains_marker(Marker::Table)
+        {
+            Some(CommandPriority::Canonical)
+        } else if token_match.contains_marker(Marker::TableSuggestion) {
+            // Only used for autocomplete; `Marker::Table` covers the same input.
+            None
+        } else if token_match.contains_marker(Marker::Keyword) {
+            // `roll history`, `roll stats` and `roll on` would otherwise also match as (invalid)
+            // formulae.
+            let formula = token_match.find_marker(Marker::Formula)?.meta_phrase()?;
+
+            if formula.eq_ci("history")
+                || formula.eq_ci("on")
+                || formula
+                    .split_once(char::is_whitespace)
+                    .is_some_and(|(first, _)| first.eq_ci("stats") || first.eq_ci("on"))
+            {
+                None
+            } else {
+                Some(CommandPriority::Canonical)
+            }
+        } else {
+            // Plain numbers are valid formulae, but are unlikely to be intended as such.
+            let formula = token_match.find_marker(Marker::Formula)?.meta_phrase()?;
+
+            if formula.chars().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                Some(CommandPriority::Fuzzy)
+            }
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        if token_match.contains_marker(Marker::History) {
+            Some(AppCommand::RollHistory.into())
+        } else if let Some(formula) = token_match.find_marker(Marker::StatsFormula) {
+            Some(AppCommand::RollStats(formula.meta_phrase()?.to_string()).into())
+        } else if let Some(name) = token_match.find_marker(Marker::TableName) {
+            Some(
+                TableCommand::Roll {
+                    name: name.meta_phrase()?.to_string(),
+                }
+                .into(),
+            )
+        } else {
+            let formula = token_match.find_marker(Marker::Formula)?.meta_phrase()?;
+            Some(AppCommand::Roll(formula.to_string()).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Roll("d20".to_string()).into()),
+            Roll.parse_input("roll d20", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Roll("D20".to_string()).into()),
+            Roll.parse_input("ROLL D20", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Roll("banana".to_string()).into()),
+            Roll.parse_input("roll banana", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(AppCommand::Roll("d20".to_string()).into()),
+            Roll.parse_input("d20", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(AppCommand::Roll("2d6 + 3".to_string()).into()),
+            Roll.parse_input("2d6 + 3", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Roll("adv d20+5 stealth".to_string()).into()),
+            Roll.parse_input("roll adv d20+5 stealth", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::RollHistory.into()),
+            Roll.parse_input("Roll History", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::RollStats("3d6".to_string()).into()),
+            Roll.parse_input("roll STATS 3d6", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Roll("stats".to_string()).into()),
+            Roll.parse_input("roll stats", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Roll {
+                    name: "Weather".to_string(),
+                }
+                .into(),
+            ),
+            Roll.parse_input("roll on Weather", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Roll.parse_input("roll on", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Roll.parse_input("20", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Roll.parse_input("potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let mut app_meta = test::app_meta();
+        app_meta.random_tables.insert(test::table::weather());
+
+        for input in ["r", "roll", "ROLL"] {
+            test::assert_autocomplete_eq!(
+                [
+                    ("roll [dice]", "roll eg. 8d6 or d20+3"),
+                    ("roll history", "show your recent rolls"),
+                    ("roll on [table]", "roll on one of your tables"),
+                    ("roll stats [dice]", "show the odds of a roll"),
+                ],
+                Roll.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [("roll history", "show your recent rolls")],
+            Roll.parse_autocomplete("roll h", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("roll stats [dice]", "show the odds of a roll")],
+            Roll.parse_autocomplete("roll stats", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("roll on [table]", "roll on one of your tables")],
+            Roll.parse_autocomplete("roll o", &app_meta).collect().await,
+        );
+
+        for input in ["roll on w", "ROLL ON WEATHER"] {
+            test::assert_autocomplete_eq!(
+                [("roll on Weather", "d6 table")],
+                Roll.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_empty!(
+            Roll.parse_autocomplete("roll d20", &app_meta)
+                .collect::<Vec<_>>()
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = AppCommand::Roll("d20".to_string());
+
+        assert_eq!("roll d20", command.to_string());
+
+        for command in [
+            command,
+            AppCommand::RollHistory,
+            AppCommand::RollStats("adv d20+5".to_string()),
+        ] {
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone().into()),
+                Roll.parse_input(&command.to_string(), &app_meta).await,
+                "{}",
+                command,
+            );
+        }
+
+        let command = TableCommand::Roll {
+            name: "Weather".to_string(),
+        };
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Roll.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Save;
+
+#[derive(Hash)]
+enum Marker {
+    Name,
+    NameSequence,
+    Phrase,
+    PhraseSequence,
+    SimilarName,
+}
+
+impl Command for Save {
+    /// The phrase is what gets parsed, since the command needs to be able to report that a thing
+    /// doesn't exist. The name is only there to power autocomplete.
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::PhraseSequence,
+                [keyword("save"), any_phrase_m(Marker::Phrase)],
+            ),
+            sequence_m(
+                Marker::NameSequence,
+                [keyword("save"), name_m(Marker::Name)],
+            ),
+            sequence([keyword("save"), similar_name_m(Marker::SimilarName)]),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            if record.is_saved() {
+                None
+            } else {
+                Some(AutocompleteSuggestion::new(
+                    format!("save {}", record.thing.name()),
+                    format!("save {} to journal", record.thing.as_str()),
+                ))
+            }
+        } else if token_match.contains_marker(Marker::PhraseSequence)
+            && !token_match.contains_marker(Marker::Phrase)
+        {
+            Some(("save [name]", "save an entry to journal").into())
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("save", "save an entry to your journal")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::Phrase, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .example("save Odysseus", "save Odysseus to your journal")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::Phrase) {
+            Some(CommandPriority::Canonical)
+        } else if token_match
+            .find_marker(Marker::SimilarName)
+            .and_then(|token_match| token_match.meta_record())
+            .is_some_and(|record| record.is_unsaved())
+        {
+            Some(CommandPriority::Corrected)
+        } else {
+            None
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        let name = if let Some(phrase) = token_match.find_marker(Marker::Phrase) {
+            phrase.meta_phrase()?.to_string()
+        } else {
+            token_match
+                .find_marker(Marker::SimilarName)?
+                .meta_record()?
+                .thing
+                .name()
+                .to_string()
+        };
+
+        Some(StorageCommand::Save { name }.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["save Odysseus", "SAVE Odysseus"] {
+            assert_eq!(
+                CommandMatches::new_canonical(
+                    StorageCommand::Save {
+                        name: "Odysseus".to_string(),
+                    }
+                    .into(),
+                ),
+                Save.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn parse_input_test_similar_name() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        assert_eq!(
+            CommandMatches {
+                canonical_match: Some(
+                    StorageCommand::Save {
+                        name: "Polyphemos".to_string(),
+                    }
+                    .into(),
+                ),
+                fuzzy_matches: vec![StorageCommand::Save {
+                    name: "Polyphemus".to_string(),
+                }
+                .into()],
+                corrected: true,
+            },
+            Save.parse_input("save Polyphemos", &app_meta).await,
+        );
+
+        // Penelope is already saved, so isn't suggested.
+        assert_eq!(
+            CommandMatches::new_canonical(
+                StorageCommand::Save {
+                    name: "Penelpoe".to_string(),
+                }
+                .into(),
+            ),
+            Save.parse_input("save Penelpoe", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["sa", "SA", "save"] {
+            test::assert_autocomplete_eq!(
+                [("save [name]", "save an entry to journal")],
+                Save.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [
+                ("save Polyphemus", "save character to journal"),
+                ("save Pylos", "save place to journal"),
+            ],
+            Save.parse_autocomplete("save P", &app_meta).collect().await,
+        );
+
+        assert_eq!(
+            Save.parse_autocomplete("save P", &app_meta)
+                .collect::<Vec<_>>()
+                .await,
+            Save.parse_autocomplete("SAve p", &app_meta)
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+        let command = StorageCommand::Save {
+            name: "Odysseus".to_string(),
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Save.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::app::AppCommand;
+use crate::command::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seed;
+
+#[derive(Hash)]
+enum Marker {
+    Number,
+    NumberSequence,
+    Random,
+    Show,
+}
+
+impl Command for Seed {
+    fn token(&self) -> Token {
+        or([
+            keyword_m(Marker::Show, "seed"),
+            sequence_m(Marker::Random, [keyword("seed"), keyword("random")]),
+            sequence_m(
+                Marker::NumberSequence,
+                [keyword("seed"), any_word_m(Marker::Number)],
+            ),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        match fuzzy_match {
+            FuzzyMatch::Partial(token_match, _) if token_match.contains_marker(Marker::Random) => {
+                Some(("seed random", "make results unpredictable").into())
+            }
+            FuzzyMatch::Partial(token_match, _)
+                if token_match.contains_marker(Marker::NumberSequence) =>
+            {
+                Some(("seed [number]", "make results reproducible").into())
+            }
+            _ => None,
+        }
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("seed", "make generated results reproducible")
+            .placeholder(Marker::Number, "number")
+            .example("seed 1234", "generate the same results every time")
+            .example("seed random", "return to unpredictable results")
+            .example("seed", "show the current seed")
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if let Some(number) = token_match.find_marker(Marker::Number) {
+            number
+                .meta_phrase()?
+                .parse::<u64>()
+                .ok()
+                .map(|_| CommandPriority::Canonical)
+        } else {
+            Some(CommandPriority::Canonical)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        if token_match.contains_marker(Marker::Show) {
+            Some(AppCommand::Seed.into())
+        } else if token_match.contains_marker(Marker::Random) {
+            Some(AppCommand::SetSeed(None).into())
+        } else {
+            let seed = token_match.find_marker(Marker::Number)?.meta_phrase()?;
+            Some(AppCommand::SetSeed(Some(seed.parse().ok()?)).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::SetSeed(Some(1234)).into()),
+            Seed.parse_input("SEED 1234", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::SetSeed(None).into()),
+            Seed.parse_input("seed random", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Seed.parse_input("seed potato", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta();
+
+        for input in ["se", "seed", "SEED"] {
+            test::assert_autocomplete_eq!(
+                [
+                    ("seed [number]", "make results reproducible"),
+                    ("seed random", "make results unpredictable"),
+                ],
+                Seed.parse_autocomplete(input, &app_meta).collect().await,
+            );
+        }
+
+        test::assert_autocomplete_eq!(
+            [("seed random", "make results unpredictable")],
+            Seed.parse_autocomplete("seed r", &app_meta).collect().await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta();
+
+        for command in [
+            AppCommand::Seed,
+            AppCommand::SetSeed(Some(1234)),
+            AppCommand::SetSeed(None),
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone().into()),
+                Seed.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.into()),
+                Seed.parse_input(&command_string.to_uppercase(), &app_meta)
+                    .await,
+                "{}",
+                command_string.to_uppercase(),
+            );
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use crate::command::prelude::*;
+use crate::table::TableCommand;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Table;
+
+#[derive(Hash)]
+enum Marker {
+    Add,
+    Create,
+    CreateName,
+    Delete,
+    DeleteName,
+    Entries,
+    Entry,
+    List,
+    Name,
+    Remove,
+    Show,
+}
+
+impl Command for Table {
+    fn token(&self) -> Token {
+        or([
+            sequence_m(
+                Marker::Create,
+                [
+                    keyword("table"),
+                    keyword("create"),
+                    any_phrase_m(Marker::CreateName),
+                ],
+            ),
+            sequence_m(
+                Marker::Delete,
+                [
+                    keyword("table"),
+                    keyword("delete"),
+                    any_phrase_m(Marker::DeleteName),
+                ],
+            ),
+            sequence_m(
+                Marker::Add,
+                [
+                    keyword("table"),
+                    table_name_m(Marker::Name),
+                    keyword("add"),
+                    any_phrase_m(Marker::Entries),
+                ],
+            ),
+            sequence_m(
+                Marker::Remove,
+                [
+                    keyword("table"),
+                    table_name_m(Marker::Name),
+                    keyword("remove"),
+                    any_phrase_m(Marker::Entry),
+                ],
+            ),
+            sequence_m(Marker::Show, [keyword("table"), table_name_m(Marker::Name)]),
+            keyword_m(Marker::List, "tables"),
+        ])
+    }
+
+    /// Tables are suggested by name, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                let (token_match, completion) = match fuzzy_match {
+                    FuzzyMatch::Exact(token_match) => (token_match, None),
+                    FuzzyMatch::Partial(token_match, completion) => (token_match, completion),
+                    FuzzyMatch::Overflow(..) => continue,
+                };
+
+                if token_match.contains_marker(Marker::List) {
+                    yield ("tables", "list your random tables").into();
+                } else if token_match.contains_marker(Marker::Create) {
+                    if !token_match.contains_marker(Marker::CreateName) {
+                        yield ("table create [name]", "create a random table").into();
+                    }
+                } else if token_match.contains_marker(Marker::Delete) {
+                    if !token_match.contains_marker(Marker::DeleteName) {
+                        yield ("table delete [name]", "delete a random table").into();
+                    }
+                } else if let Some(table) = token_match
+                    .find_marker(Marker::Name)
+                    .and_then(|name| name.meta_phrase())
+                    .and_then(|name| {
+                        // Tables are suggested by their own names rather than by however the user
+                        // typed them.
+                        let name = match (&completion, token_match.contains_marker(Marker::Show)) {
+                            (Some(completion), true) => format!("{}{}", name, completion),
+                            _ => name.to_string(),
+                        };
+                        app_meta.random_tables.get(&name)
+                    })
+                {
+                    if token_match.contains_marker(Marker::Show) {
+                        yield AutocompleteSuggestion::new(
+                            format!("table {}", table.name),
+                            format!("d{} table", table.die_size()),
+                        );
+                    } else if token_match.contains_marker(Marker::Add)
+                        && !token_match.contains_marker(Marker::Entries)
+                    {
+                        yield AutocompleteSuggestion::new(
+                            format!("table {} add [entry]; [entry]", table.name),
+                            "add entries to the table",
+                        );
+                    } else if token_match.contains_marker(Marker::Remove)
+                        && !token_match.contains_marker(Marker::Entry)
+                    {
+                        yield AutocompleteSuggestion::new(
+                            format!("table {} remove [entry]", table.name),
+                            "remove an entry from the table",
+                        );
+                    }
+                } else if token_match.contains_marker(Marker::Show)
+                    && !token_match.contains_marker(Marker::Name)
+                {
+                    yield ("table [name]", "show a random table").into();
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("table", "create your own random tables to roll on")
+            .placeholder(Marker::CreateName, "name")
+            .placeholder(Marker::DeleteName, "name")
+            .placeholder(Marker::Entries, "entry; entry")
+            .placeholder(Marker::Entry, "entry")
+            .example("table create Weather", "create an empty table")
+            .example(
+                "table Weather add 1-3 rain; 4 snow; 2x {Wind} wind",
+                "add entries by range, number or weight, which can refer to other tables",
+            )
+            .example("table Weather", "show the table")
+            .example("table Weather remove snow", "remove an entry")
+            .example("tables", "list your tables")
+            .example("table delete Weather", "delete the table")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, _app_meta: &AppMeta) -> Option<CommandType> {
+        let name = |marker| {
+            token_match
+                .find_marker(marker)
+                .and_then(|name| name.meta_phrase())
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+        };
+
+        let command = if token_match.contains_marker(Marker::List) {
+            TableCommand::List
+        } else if token_match.contains_marker(Marker::Create) {
+            TableCommand::Create {
+                name: name(Marker::CreateName)?,
+            }
+        } else if token_match.contains_marker(Marker::Delete) {
+            TableCommand::Delete {
+                name: name(Marker::DeleteName)?,
+            }
+        } else if token_match.contains_marker(Marker::Add) {
+            let entries: Vec<String> = token_match
+                .find_marker(Marker::Entries)?
+                .meta_phrase()?
+                .split(';')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(str::to_string)
+                .collect();
+
+            if entries.is_empty() {
+                return None;
+            }
+
+            TableCommand::Add {
+                name: name(Marker::Name)?,
+                entries,
+            }
+        } else if token_match.contains_marker(Marker::Remove) {
+            TableCommand::Remove {
+                name: name(Marker::Name)?,
+                entry: name(Marker::Entry)?,
+            }
+        } else {
+            TableCommand::Show {
+                name: name(Marker::Name)?,
+            }
+        };
+
+        Some(command.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    fn app_meta() -> AppMeta {
+        let mut app_meta = test::app_meta();
+        app_meta.random_tables.insert(test::table::weather());
+        app_meta
+    }
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Create {
+                    name: "Loot".to_string(),
+                }
+                .into(),
+            ),
+            Table.parse_input("table create Loot", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Delete {
+                    name: "weather".to_string(),
+                }
+                .into(),
+            ),
+            Table.parse_input("TABLE DELETE weather", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Add {
+                    name: "weather".to_string(),
+                    entries: vec!["fog".to_string(), "2x hail".to_string()],
+                }
+                .into(),
+            ),
+            Table
+                .parse_input("table weather add fog;2x hail; ", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Remove {
+                    name: "Weather".to_string(),
+                    entry: "snow".to_string(),
+                }
+                .into(),
+            ),
+            Table
+                .parse_input("table Weather remove snow", &app_meta)
+                .await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                TableCommand::Show {
+                    name: "weather".to_string(),
+                }
+                .into(),
+            ),
+            Table.parse_input("table weather", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(TableCommand::List.into()),
+            Table.parse_input("tables", &app_meta).await,
+        );
+
+        for input in [
+            "table",
+            "table create",
+            "table Loot",
+            "table Loot add sword",
+            "table weather add ;",
+        ] {
+            assert_eq!(
+                CommandMatches::default(),
+                Table.parse_input(input, &app_meta).await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = app_meta();
+
+        test::assert_autocomplete_eq!(
+            [
+                ("table [name]", "show a random table"),
+                ("table create [name]", "create a random table"),
+                ("table delete [name]", "delete a random table"),
+                ("tables", "list your random tables"),
+            ],
+            Table.parse_autocomplete("ta", &app_meta).collect().await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [("table Weather", "d6 table")],
+            Table
+                .parse_autocomplete("table w", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [
+                ("table Weather", "d6 table"),
+                (
+                    "table Weather add [entry]; [entry]",
+                    "add entries to the table"
+                ),
+                (
+                    "table Weather remove [entry]",
+                    "remove an entry from the table"
+                ),
+            ],
+            Table
+                .parse_autocomplete("table weather", &app_meta)
+                .collect()
+                .await,
+        );
+
+        test::assert_autocomplete_eq!(
+            [(
+                "table Weather remove [entry]",
+                "remove an entry from the table"
+            )],
+            Table
+                .parse_autocomplete("table weather rem", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = app_meta();
+
+        for command in [
+            TableCommand::Add {
+                name: "Weather".to_string(),
+                entries: vec!["1-3 rain".to_string(), "4 snow".to_string()],
+            },
+            TableCommand::Create {
+                name: "Loot".to_string(),
+            },
+            TableCommand::Delete {
+                name: "Weather".to_string(),
+            },
+            TableCommand::List,
+            TableCommand::Remove {
+                name: "Weather".to_string(),
+                entry: "snow".to_string(),
+            },
+            TableCommand::Show {
+                name: "Weather".to_string(),
+            },
+        ] {
+            let command_string = command.to_string();
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.into()),
+                Table.parse_input(&command_string, &app_meta).await,
+                "{}",
+                command_string,
+            );
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::{Token, TokenType};
 use std::hash::Hash;
+use std::ops::RangeInclusive;
 
 /// Matches one or more of a set of tokens, in any order but without repetition.
 ///
@@ -225,6 +226,139 @@ where
     Token::new_m(marker, TokenType::AnyWord)
 }
 
+/// Matches a dice formula such as `8d6` or `2d20k1+5: attack roll`, as understood by the dice
+/// roller. The formula may span several words. Incomplete formulae like `2d` are reported as
+/// partial matches so that commands can hint at the syntax.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// let token = dice();
+///
+/// assert_eq!(
+///     Some(TokenMatch::new(&token, "2d6 + 3")),
+///     token
+///         .match_input_exact("2d6 + 3", &app_meta)
+///         .next()
+///         .await,
+/// );
+///
+/// assert_eq!(
+///     None,
+///     token
+///         .match_input_exact("banana", &app_meta)
+///         .next()
+///         .await,
+/// );
+/// # })
+/// ```
+pub fn dice() -> Token {
+    Token::new(TokenType::Dice)
+}
+
+/// A variant of `dice` with a marker assigned, making it easy to jump directly to the
+/// matched result within the token tree.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// #[derive(Hash)]
+/// enum Marker {
+///     Dice,
+/// }
+///
+/// let query = "roll 4d6";
+/// let token = sequence([keyword("roll"), dice_m(Marker::Dice)]);
+/// let token_match = token.match_input_exact(query, &app_meta).next().await.unwrap();
+///
+/// assert_eq!(
+///     Some("4d6"),
+///     token_match.find_marker(Marker::Dice).unwrap().meta_phrase(),
+/// );
+/// # })
+/// ```
+pub fn dice_m<M>(marker: M) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::Dice)
+}
+
+/// Matches a span of time such as `2d` or `1h 30m`, optionally preceded by `+` or `-`. The
+/// interval may span several words. Incomplete intervals like `+` or `+10` are reported as
+/// partial matches so that commands can hint at the syntax.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// let token = interval();
+///
+/// assert_eq!(
+///     Some(TokenMatch::new(&token, "+1h 30m")),
+///     token
+///         .match_input_exact("+1h 30m", &app_meta)
+///         .next()
+///         .await,
+/// );
+///
+/// assert_eq!(
+///     Some(FuzzyMatch::Partial(TokenMatch::new(&token, "+10"), None)),
+///     token.match_input("+10", &app_meta).next().await,
+/// );
+/// # })
+/// ```
+pub fn interval() -> Token {
+    Token::new(TokenType::Interval)
+}
+
+/// A variant of `interval` with a marker assigned, making it easy to jump directly to the
+/// matched result within the token tree.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// #[derive(Hash)]
+/// enum Marker {
+///     Interval,
+/// }
+///
+/// let query = "wait 8h";
+/// let token = sequence([keyword("wait"), interval_m(Marker::Interval)]);
+/// let token_match = token.match_input_exact(query, &app_meta).next().await.unwrap();
+///
+/// assert_eq!(
+///     Some("8h"),
+///     token_match.find_marker(Marker::Interval).unwrap().meta_phrase(),
+/// );
+/// # })
+/// ```
+pub fn interval_m<M>(marker: M) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::Interval)
+}
+
 /// A single keyword, matched case-insensitively.
 ///
 /// # Examples
@@ -495,6 +629,140 @@ where
     Token::new_m(marker, TokenType::Name)
 }
 
+/// Matches a single word that is a whole number, such as `23` or `-5`.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// let token = number();
+///
+/// assert_eq!(
+///     Some(TokenMatch::new(&token, "23")),
+///     token
+///         .match_input_exact("23", &app_meta)
+///         .next()
+///         .await,
+/// );
+///
+/// assert_eq!(
+///     None,
+///     token
+///         .match_input_exact("twenty-three", &app_meta)
+///         .next()
+///         .await,
+/// );
+/// # })
+/// ```
+pub fn number() -> Token {
+    Token::new(TokenType::Number)
+}
+
+/// A variant of `number` with a marker assigned, making it easy to jump directly to the
+/// matched result within the token tree.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// #[derive(Hash)]
+/// enum Marker {
+///     Number,
+/// }
+///
+/// let query = "hp 23";
+/// let token = sequence([keyword("hp"), number_m(Marker::Number)]);
+/// let token_match = token.match_input_exact(query, &app_meta).next().await.unwrap();
+///
+/// assert_eq!(
+///     Some(23),
+///     token_match.find_marker(Marker::Number).unwrap().meta_number(),
+/// );
+/// # })
+/// ```
+pub fn number_m<M>(marker: M) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::Number)
+}
+
+/// Matches a single word that is a whole number within the given range (inclusive). A number
+/// that is too small but could be completed by typing more digits, such as `1` for the range
+/// 10-20, is reported as a partial match.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// let token = number_range(1..=20);
+///
+/// assert_eq!(
+///     Some(TokenMatch::new(&token, "20")),
+///     token
+///         .match_input_exact("20", &app_meta)
+///         .next()
+///         .await,
+/// );
+///
+/// assert_eq!(
+///     None,
+///     token
+///         .match_input_exact("21", &app_meta)
+///         .next()
+///         .await,
+/// );
+/// # })
+/// ```
+pub fn number_range(range: RangeInclusive<i64>) -> Token {
+    Token::new(TokenType::NumberRange(range))
+}
+
+/// A variant of `number_range` with a marker assigned, making it easy to jump directly to the
+/// matched result within the token tree.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta();
+/// use initiative_core::command::prelude::*;
+///
+/// #[derive(Hash)]
+/// enum Marker {
+///     Level,
+/// }
+///
+/// let query = "level 3";
+/// let token = sequence([keyword("level"), number_range_m(Marker::Level, 1..=20)]);
+/// let token_match = token.match_input_exact(query, &app_meta).next().await.unwrap();
+///
+/// assert_eq!(
+///     Some(3),
+///     token_match.find_marker(Marker::Level).unwrap().meta_number(),
+/// );
+/// # })
+/// ```
+pub fn number_range_m<M>(marker: M, range: RangeInclusive<i64>) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::NumberRange(range))
+}
+
 /// Matches the input with and without the contained token.
 ///
 /// # Examples
@@ -663,3 +931,95 @@ where
 {
     Token::new_m(marker, TokenType::Sequence(tokens.into()))
 }
+
+/// Matches the name of a Thing found in the journal or recent entities that looks like a
+/// misspelling of the input, such as "Odyseus" for "Odysseus". Names that `name` would match are
+/// never matched by this token, so a grammar can offer both without duplicating results.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let app_meta = initiative_core::test_utils::app_meta::with_test_data().await;
+/// use initiative_core::command::prelude::*;
+///
+/// let token = similar_name();
+/// let odysseus = app_meta.repository.get_by_name("Odysseus").await.unwrap();
+///
+/// assert_eq!(
+///     vec![FuzzyMatch::Exact(TokenMatch::new(&token, odysseus))],
+///     token
+///         .match_input("Odyseus", &app_meta)
+///         .collect::<Vec<_>>()
+///         .await,
+/// );
+///
+/// // Correctly spelled names are left to the `name` token.
+/// assert_eq!(
+///     Vec::<FuzzyMatch>::new(),
+///     token
+///         .match_input("Odysseus", &app_meta)
+///         .collect::<Vec<_>>()
+///         .await,
+/// );
+/// # })
+/// ```
+pub fn similar_name() -> Token {
+    Token::new(TokenType::SimilarName)
+}
+
+/// A variant of `similar_name` with a marker assigned.
+pub fn similar_name_m<M>(marker: M) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::SimilarName)
+}
+
+/// Matches the name of one of the user's random tables, case-insensitively. The meta field
+/// contains the matched part of the input, which can be looked up with
+/// `app_meta.random_tables.get()`.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::StreamExt as _;
+/// # tokio_test::block_on(async {
+/// # let mut app_meta = initiative_core::test_utils::app_meta();
+/// # app_meta.random_tables.insert(initiative_core::test_utils::table::weather());
+/// use initiative_core::command::prelude::*;
+///
+/// let token = table_name();
+///
+/// assert_eq!(
+///     vec![FuzzyMatch::Exact(TokenMatch::new(&token, "weather"))],
+///     token
+///         .match_input("weather", &app_meta)
+///         .collect::<Vec<_>>()
+///         .await,
+/// );
+///
+/// assert_eq!(
+///     vec![FuzzyMatch::Partial(
+///         TokenMatch::new(&token, "wea"),
+///         Some("ther".to_string()),
+///     )],
+///     token
+///         .match_input("wea", &app_meta)
+///         .collect::<Vec<_>>()
+///         .await,
+/// );
+/// # })
+/// ```
+pub fn table_name() -> Token {
+    Token::new(TokenType::TableName)
+}
+
+/// A variant of `table_name` with a marker assigned.
+pub fn table_name_m<M>(marker: M) -> Token
+where
+    M: Hash,
+{
+    Token::new_m(marker, TokenType::TableName)
+}
@@ -0,0 +1,112 @@
+use crate::command::prelude::*;
+use crate::utils::{is_valid_formula, quoted_phrases};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a>(
+    token: &'a Token,
+    input: &'a str,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'a>> {
+    assert!(matches!(token.token_type, TokenType::Dice));
+
+    Box::pin(stream! {
+        let mut phrases = quoted_phrases(input).peekable();
+
+        while let Some(phrase) = phrases.next() {
+            let token_match = TokenMatch::new(token, phrase.as_str());
+
+            if is_valid_formula(phrase.as_str()) {
+                if phrases.peek().is_none() {
+                    yield FuzzyMatch::Exact(token_match);
+                } else {
+                    yield FuzzyMatch::Overflow(token_match, phrase.after());
+                }
+            } else if phrase.can_complete() && is_valid_formula(&format!("{}1", phrase.as_str())) {
+                // Incomplete formulae like "2d" or "d20+".
+                yield FuzzyMatch::Partial(token_match, None);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = dice_m(Marker::Token);
+
+        for input in ["d20", "8d6: Fireball", "2d20k1+5", "(d4+1)^3"] {
+            assert_eq!(
+                vec![TokenMatch::new(&token, input)],
+                token
+                    .match_input_exact(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_overflow() {
+        let token = dice();
+
+        test::assert_eq_unordered!(
+            [
+                FuzzyMatch::Overflow(TokenMatch::new(&token, "2d6"), " + 3".into()),
+                FuzzyMatch::Exact(TokenMatch::new(&token, "2d6 + 3")),
+            ],
+            token
+                .match_input("2d6 + 3", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = dice();
+
+        for input in ["2d", "d20+"] {
+            assert_eq!(
+                vec![FuzzyMatch::Partial(TokenMatch::new(&token, input), None)],
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let token = dice();
+
+        for input in ["", "banana", "2d "] {
+            assert_eq!(
+                Vec::<FuzzyMatch>::new(),
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+}
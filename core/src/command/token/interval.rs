@@ -0,0 +1,136 @@
+use crate::command::prelude::*;
+use crate::time::Interval;
+use crate::utils::quoted_phrases;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a>(
+    token: &'a Token,
+    input: &'a str,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'a>> {
+    assert!(matches!(token.token_type, TokenType::Interval));
+
+    Box::pin(stream! {
+        let mut phrases = quoted_phrases(input).peekable();
+
+        while let Some(phrase) = phrases.next() {
+            let token_match = TokenMatch::new(token, phrase.as_str());
+
+            if is_valid(phrase.as_str()) {
+                if phrases.peek().is_none() {
+                    yield FuzzyMatch::Exact(token_match);
+                } else {
+                    yield FuzzyMatch::Overflow(token_match, phrase.after());
+                }
+            } else if phrase.can_complete()
+                && ["d", "h", "m", "s", "r"]
+                    .iter()
+                    .any(|unit| is_valid(&format!("{}{}", phrase.as_str(), unit)))
+            {
+                // Incomplete intervals like "+" or "+2d 3".
+                yield FuzzyMatch::Partial(token_match, None);
+            }
+        }
+    })
+}
+
+fn is_valid(phrase: &str) -> bool {
+    phrase
+        .strip_prefix(['+', '-'])
+        .unwrap_or(phrase)
+        .parse::<Interval>()
+        .is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = interval_m(Marker::Token);
+
+        for input in ["2d", "+2d", "-h", "+1d 2h 3m 4s 5r"] {
+            assert_eq!(
+                vec![TokenMatch::new(&token, input)],
+                token
+                    .match_input_exact(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_overflow() {
+        let token = interval();
+
+        test::assert_eq_unordered!(
+            [
+                FuzzyMatch::Overflow(TokenMatch::new(&token, "+1d"), " 2h".into()),
+                FuzzyMatch::Exact(TokenMatch::new(&token, "+1d 2h")),
+            ],
+            token
+                .match_input("+1d 2h", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = interval();
+
+        for input in ["+", "-10"] {
+            assert_eq!(
+                vec![FuzzyMatch::Partial(TokenMatch::new(&token, input), None)],
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+
+        test::assert_eq_unordered!(
+            [
+                FuzzyMatch::Overflow(TokenMatch::new(&token, "+1d"), " 2".into()),
+                FuzzyMatch::Partial(TokenMatch::new(&token, "+1d 2"), None),
+            ],
+            token
+                .match_input("+1d 2", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let token = interval();
+
+        for input in ["", "badger", "+1x", "+1d1d", "+ "] {
+            assert_eq!(
+                Vec::<FuzzyMatch>::new(),
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+}
@@ -3,15 +3,22 @@ pub mod constructors;
 mod any_of;
 mod any_phrase;
 mod any_word;
+mod dice;
+mod interval;
 mod keyword;
 mod keyword_list;
 mod name;
+mod number;
+mod number_range;
 mod optional;
 mod or;
 mod sequence;
+mod similar_name;
+mod table_name;
 mod token_match_iterator;
 
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::RangeInclusive;
 
 use token_match_iterator::TokenMatchIterator;
 
@@ -54,6 +61,12 @@ pub enum TokenType {
     /// See [`token_constructors::any_word`].
     AnyWord,
 
+    /// See [`token_constructors::dice`].
+    Dice,
+
+    /// See [`token_constructors::interval`].
+    Interval,
+
     /// See [`token_constructors::keyword`].
     Keyword(&'static str),
 
@@ -63,6 +76,12 @@ pub enum TokenType {
     /// See [`token_constructors::name`].
     Name,
 
+    /// See [`token_constructors::number`].
+    Number,
+
+    /// See [`token_constructors::number_range`].
+    NumberRange(RangeInclusive<i64>),
+
     /// See [`token_constructors::optional`].
     Optional(Box<Token>),
 
@@ -71,13 +90,19 @@ pub enum TokenType {
 
     /// See [`token_constructors::sequence`].
     Sequence(Vec<Token>),
+
+    /// See [`token_constructors::similar_name`].
+    SimilarName,
+
+    /// See [`token_constructors::table_name`].
+    TableName,
 }
 
 #[derive(Clone, Debug, Eq, From, PartialEq)]
 pub enum MatchMeta<'a> {
     None,
     Phrase(&'a str),
-    Record(Record),
+    Record(Box<Record>),
     Sequence(Vec<TokenMatch<'a>>),
     Single(Box<TokenMatch<'a>>),
 }
@@ -109,15 +134,31 @@ impl Token {
             TokenType::AnyOf(..) => any_of::match_input(self, input, app_meta),
             TokenType::AnyPhrase => any_phrase::match_input(self, input),
             TokenType::AnyWord => any_word::match_input(self, input),
+            TokenType::Dice => dice::match_input(self, input),
+            TokenType::Interval => interval::match_input(self, input),
             TokenType::Keyword(..) => keyword::match_input(self, input),
             TokenType::KeywordList(..) => keyword_list::match_input(self, input),
             TokenType::Name => name::match_input(self, input, app_meta),
+            TokenType::Number => number::match_input(self, input),
+            TokenType::NumberRange(..) => number_range::match_input(self, input),
             TokenType::Optional(..) => optional::match_input(self, input, app_meta),
             TokenType::Or(..) => or::match_input(self, input, app_meta),
             TokenType::Sequence(..) => sequence::match_input(self, input, app_meta),
+            TokenType::SimilarName => similar_name::match_input(self, input, app_meta),
+            TokenType::TableName => table_name::match_input(self, input, app_meta),
         }
     }
 
+    /// Returns `true` if the token has the given `marker`.
+    ///
+    /// Returns `false` if it does not.
+    pub fn is_marked_with<M>(&self, marker: M) -> bool
+    where
+        M: Hash,
+    {
+        self.marker != 0 && self.marker == hash_marker(marker)
+    }
+
     pub fn match_input_exact<'a, 'b>(
         &'a self,
         input: &'a str,
@@ -179,7 +220,6 @@ impl<'a> TokenMatch<'a> {
     /// Returns `true` if the `TokenMatch` or any of its descendents contain the given marker.
     ///
     /// Returns `false` if the marker is not present.
-    #[cfg_attr(not(any(test, feature = "integration-tests")), expect(dead_code))]
     pub fn contains_marker<M>(&'a self, marker: M) -> bool
     where
         M: Hash,
@@ -198,7 +238,6 @@ impl<'a> TokenMatch<'a> {
     }
 
     /// Iterate through all TokenMatch objects in the tree with a given set of markers.
-    #[cfg_attr(not(any(test, feature = "integration-tests")), expect(dead_code))]
     pub fn find_markers<'b, M>(
         &'a self,
         markers: &'b [M],
@@ -218,25 +257,29 @@ impl<'a> TokenMatch<'a> {
     where
         M: Hash,
     {
-        self.token.marker != 0 && self.token.marker == hash_marker(marker)
+        self.token.is_marked_with(marker)
     }
 
-    #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
     pub fn meta_phrase(&self) -> Option<&str> {
         self.match_meta.phrase()
     }
 
-    #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
     pub fn meta_record(&self) -> Option<&Record> {
         self.match_meta.record()
     }
 
+    /// The matched phrase as a number, for use with [`constructors::number`] and
+    /// [`constructors::number_range`].
+    #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
+    pub fn meta_number(&self) -> Option<i64> {
+        self.meta_phrase()?.parse().ok()
+    }
+
     #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
     pub fn meta_sequence(&self) -> Option<&[TokenMatch<'a>]> {
         self.match_meta.sequence()
     }
 
-    #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
     pub fn meta_single(&self) -> Option<&TokenMatch<'a>> {
         self.match_meta.single()
     }
@@ -302,7 +345,7 @@ impl<'a> MatchMeta<'a> {
     #[cfg_attr(not(feature = "integration-tests"), expect(dead_code))]
     pub fn into_record(self) -> Option<Record> {
         if let MatchMeta::Record(r) = self {
-            Some(r)
+            Some(*r)
         } else {
             None
         }
@@ -339,6 +382,12 @@ impl<'a> From<TokenMatch<'a>> for MatchMeta<'a> {
     }
 }
 
+impl From<Record> for MatchMeta<'_> {
+    fn from(input: Record) -> Self {
+        Box::new(input).into()
+    }
+}
+
 fn hash_marker<M: Hash>(marker: M) -> u64 {
     let mut hasher = DefaultHasher::default();
     marker.hash(&mut hasher);
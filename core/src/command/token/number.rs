@@ -0,0 +1,106 @@
+use crate::command::prelude::*;
+use crate::utils::quoted_words;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a>(
+    token: &'a Token,
+    input: &'a str,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'a>> {
+    assert!(matches!(token.token_type, TokenType::Number));
+
+    Box::pin(stream! {
+        let mut iter = quoted_words(input);
+        if let Some(word) = iter.next() {
+            let token_match = TokenMatch::new(token, word.as_str());
+
+            if word.as_str().parse::<i64>().is_ok() {
+                if word.is_at_end() {
+                    yield FuzzyMatch::Exact(token_match);
+                } else {
+                    yield FuzzyMatch::Overflow(token_match, word.after());
+                }
+            } else if word.can_complete() && matches!(word.as_str(), "-" | "+") {
+                yield FuzzyMatch::Partial(token_match, None);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = number_m(Marker::Token);
+
+        for input in ["23", "-23", "+23", "0"] {
+            assert_eq!(
+                vec![FuzzyMatch::Exact(TokenMatch::new(&token, input))],
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_overflow() {
+        let token = number();
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Overflow(
+                TokenMatch::new(&token, "23"),
+                " badgers".into(),
+            )],
+            token
+                .match_input("23 badgers", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = number();
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Partial(TokenMatch::new(&token, "-"), None)],
+            token
+                .match_input("-", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let token = number();
+
+        for input in ["", "badger", "2.5", "1d6", "- ", "99999999999999999999"] {
+            assert_eq!(
+                Vec::<FuzzyMatch>::new(),
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::command::prelude::*;
+use crate::utils::quoted_words;
+
+use std::ops::RangeInclusive;
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a>(
+    token: &'a Token,
+    input: &'a str,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'a>> {
+    let TokenType::NumberRange(range) = &token.token_type else {
+        unreachable!();
+    };
+
+    Box::pin(stream! {
+        let mut iter = quoted_words(input);
+        if let Some(word) = iter.next() {
+            let token_match = TokenMatch::new(token, word.as_str());
+
+            if word
+                .as_str()
+                .parse::<i64>()
+                .is_ok_and(|number| range.contains(&number))
+            {
+                if word.is_at_end() {
+                    yield FuzzyMatch::Exact(token_match);
+                } else {
+                    yield FuzzyMatch::Overflow(token_match, word.after());
+                }
+            } else if word.can_complete() && can_extend(word.as_str(), range) {
+                yield FuzzyMatch::Partial(token_match, None);
+            }
+        }
+    })
+}
+
+/// Can more digits be typed after `prefix` to produce a number within the range? For instance,
+/// "1" can be extended to "15" for the range 10-20, but "3" can't.
+fn can_extend(prefix: &str, range: &RangeInclusive<i64>) -> bool {
+    let (negative, digits) = match prefix.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, prefix.strip_prefix('+').unwrap_or(prefix)),
+    };
+
+    let (start, end) = (i128::from(*range.start()), i128::from(*range.end()));
+
+    if digits.is_empty() {
+        return if negative { start < 0 } else { end >= 0 };
+    } else if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let Ok(value) = digits.parse::<i64>().map(i128::from) else {
+        return false;
+    };
+
+    // Appending n digits gives a value between value * 10^n and value * 10^n + 10^n - 1.
+    (1..=19).any(|exponent| {
+        let scale = 10i128.pow(exponent);
+        let (low, high) = (value * scale, value * scale + scale - 1);
+        let (low, high) = if negative { (-high, -low) } else { (low, high) };
+        low <= end && high >= start
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = number_range_m(Marker::Token, -5..=20);
+
+        for input in ["-5", "0", "20", "+7"] {
+            assert_eq!(
+                vec![FuzzyMatch::Exact(TokenMatch::new(&token, input))],
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_overflow() {
+        let token = number_range(1..=20);
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Overflow(
+                TokenMatch::new(&token, "12"),
+                " goblins".into(),
+            )],
+            token
+                .match_input("12 goblins", &test::app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = number_range(10..=20);
+
+        for input in ["1", "2", "+1"] {
+            assert_eq!(
+                vec![FuzzyMatch::Partial(TokenMatch::new(&token, input), None)],
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let token = number_range(10..=20);
+
+        for input in ["3", "-", "-1", "21", "9", "1 ", "badger"] {
+            assert_eq!(
+                Vec::<FuzzyMatch>::new(),
+                token
+                    .match_input(input, &test::app_meta())
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[test]
+    fn can_extend_test() {
+        assert!(can_extend("", &(1..=20)));
+        assert!(can_extend("-", &(-20..=-1)));
+        assert!(can_extend("-1", &(-20..=-10)));
+        assert!(can_extend("1", &(100..=100)));
+        assert!(can_extend("10", &(100..=100)));
+        assert!(can_extend("9", &(i64::MAX - 1..=i64::MAX)));
+
+        assert!(!can_extend("-", &(0..=20)));
+        assert!(!can_extend("2", &(10..=19)));
+        assert!(!can_extend("-2", &(-19..=-10)));
+        assert!(!can_extend("100", &(100..=100)));
+        assert!(!can_extend("1.", &(1..=20)));
+    }
+}
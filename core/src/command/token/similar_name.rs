@@ -0,0 +1,144 @@
+//! Matches the names of Things in recent or journal that look like a misspelling of the input, eg.
+//! "Elandor" for "Elendor". Nothing is matched if the input is the start of an existing name, so
+//! the `name` token can sit alongside it in a grammar without the two competing.
+//!
+//! Unlike `name`, only the full input is considered; the token never overflows.
+
+use crate::app::AppMeta;
+use crate::command::prelude::*;
+use crate::utils::{quoted_phrases, similarity_ci};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a, 'b>(
+    token: &'a Token,
+    input: &'a str,
+    app_meta: &'b AppMeta,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'b>>
+where
+    'a: 'b,
+{
+    assert!(matches!(token.token_type, TokenType::SimilarName));
+
+    let Some(full_phrase) = quoted_phrases(input).last() else {
+        return Box::pin(stream::empty());
+    };
+
+    Box::pin(stream! {
+        // If anything is spelled the way the user typed it, they probably didn't make a typo.
+        if app_meta
+            .repository
+            .get_by_name_start((full_phrase.as_str(), Some(1)))
+            .await
+            .is_ok_and(|records| !records.is_empty())
+        {
+            return;
+        }
+
+        let records = app_meta
+            .repository
+            .get_by_similar_name(full_phrase.as_str())
+            .await
+            .unwrap_or_default();
+
+        for record in records.into_iter() {
+            // unwrap: result of get_by_similar_name(), so it must have a name
+            let thing_name = record.thing.name().value().unwrap();
+
+            if similarity_ci(full_phrase.as_str(), thing_name).is_some() {
+                yield FuzzyMatch::Exact(TokenMatch::new(token, record));
+            } else if full_phrase.can_complete() {
+                yield FuzzyMatch::Partial(TokenMatch::new(token, record), None);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::storage::{Record, RecordStatus};
+    use crate::test_utils as test;
+    use uuid::Uuid;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = similar_name_m(Marker::Token);
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Exact(TokenMatch::new(
+                &token,
+                Record {
+                    status: RecordStatus::Unsaved,
+                    thing: test::thing::odysseus(),
+                },
+            ))],
+            match_input(&token, "Odyseus", &test::app_meta::with_test_data().await)
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = similar_name();
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Partial(
+                TokenMatch::new(
+                    &token,
+                    Record {
+                        status: RecordStatus::Saved,
+                        thing: test::thing::penelope(),
+                    },
+                ),
+                None,
+            )],
+            match_input(&token, "Penal", &test::app_meta::with_test_data().await)
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        for input in ["", "Odysseus", "odysseus", "Ody", "Penal ", "Nobody"] {
+            assert_eq!(
+                Vec::<FuzzyMatch>::new(),
+                match_input(&similar_name(), input, &app_meta)
+                    .collect::<Vec<_>>()
+                    .await,
+                "{}",
+                input,
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn match_input_test_existing_name() {
+        let app_meta = test::app_meta::with_data_store::memory::with(
+            [
+                test::npc().name("Elendor").build_thing(Uuid::new_v4()),
+                test::npc().name("Elandor").build_thing(Uuid::new_v4()),
+            ],
+            [],
+        );
+
+        test::assert_empty!(
+            match_input(&similar_name(), "Elandor", &app_meta)
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Matches the name of a random table created by the user. The meta field contains the matched
+//! part of the input, which may differ in case from the name of the table itself.
+
+use crate::app::AppMeta;
+use crate::command::prelude::*;
+use crate::utils::{quoted_phrases, CaseInsensitiveStr};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+pub fn match_input<'a, 'b>(
+    token: &'a Token,
+    input: &'a str,
+    app_meta: &'b AppMeta,
+) -> Pin<Box<dyn Stream<Item = FuzzyMatch<'a>> + 'b>>
+where
+    'a: 'b,
+{
+    assert!(matches!(token.token_type, TokenType::TableName));
+
+    let phrases: Vec<_> = quoted_phrases(input).collect();
+
+    Box::pin(stream! {
+        let Some(full_phrase) = phrases.last() else {
+            return;
+        };
+
+        for table in app_meta.random_tables.iter() {
+            if table.name.eq_ci(full_phrase) {
+                yield FuzzyMatch::Exact(TokenMatch::new(token, full_phrase.as_str()));
+                continue;
+            } else if full_phrase.can_complete() {
+                if let Some(completion) = table.name.strip_prefix_ci(full_phrase) {
+                    yield FuzzyMatch::Partial(
+                        TokenMatch::new(token, full_phrase.as_str()),
+                        Some(completion.to_string()),
+                    );
+                    continue;
+                }
+            }
+
+            if let Some(phrase) = phrases[..phrases.len() - 1]
+                .iter()
+                .find(|phrase| table.name.eq_ci(phrase))
+            {
+                yield FuzzyMatch::Overflow(TokenMatch::new(token, phrase.as_str()), phrase.after());
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::table::RandomTable;
+    use crate::test_utils as test;
+
+    #[derive(Hash)]
+    enum Marker {
+        Token,
+    }
+
+    fn app_meta() -> AppMeta {
+        let mut app_meta = test::app_meta();
+        app_meta.random_tables.insert(test::table::weather());
+        app_meta
+            .random_tables
+            .insert(RandomTable::new("Weather Omens".to_string()));
+        app_meta
+    }
+
+    #[tokio::test]
+    async fn match_input_test_exact() {
+        let token = table_name_m(Marker::Token);
+
+        test::assert_eq_unordered!(
+            [
+                FuzzyMatch::Exact(TokenMatch::new(&token, "weather")),
+                FuzzyMatch::Partial(
+                    TokenMatch::new(&token, "weather"),
+                    Some(" Omens".to_string())
+                ),
+            ],
+            match_input(&token, "weather", &app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_partial() {
+        let token = table_name();
+
+        test::assert_eq_unordered!(
+            [
+                FuzzyMatch::Partial(TokenMatch::new(&token, "WEA"), Some("ther".to_string())),
+                FuzzyMatch::Partial(
+                    TokenMatch::new(&token, "WEA"),
+                    Some("ther Omens".to_string()),
+                ),
+            ],
+            match_input(&token, "WEA", &app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_overflow() {
+        let token = table_name();
+
+        test::assert_eq_unordered!(
+            [FuzzyMatch::Overflow(
+                TokenMatch::new(&token, "Weather"),
+                " add snow".into(),
+            )],
+            match_input(&token, "Weather add snow", &app_meta())
+                .collect::<Vec<_>>()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn match_input_test_no_match() {
+        let app_meta = app_meta();
+
+        for input in ["", "   ", "Loot", "Weath er", "Wind"] {
+            test::assert_empty!(
+                match_input(&table_name(), input, &app_meta)
+                    .collect::<Vec<_>>()
+                    .await,
+            );
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::app::TutorialCommand;
+use crate::command::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tutorial;
+
+impl Command for Tutorial {
+    fn token(&self) -> Token {
+        keyword("tutorial")
+    }
+
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        Some(("tutorial", "feature walkthrough").into())
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("tutorial", "feature walkthrough")
+            .example("tutorial", "start the interactive tutorial")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(TutorialCommand::Introduction.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use futures::StreamExt as _;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(TutorialCommand::Introduction.into()),
+            Tutorial.parse_input("TUTORIAL", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        test::assert_autocomplete_eq!(
+            [("tutorial", "feature walkthrough")],
+            Tutorial
+                .parse_autocomplete("tut", &test::app_meta())
+                .collect()
+                .await,
+        );
+    }
+}
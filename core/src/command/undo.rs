@@ -0,0 +1,101 @@
+use crate::command::prelude::*;
+use crate::storage::StorageCommand;
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Undo;
+
+impl Command for Undo {
+    fn token(&self) -> Token {
+        keyword("undo")
+    }
+
+    /// The summary depends on the repository state, so see parse_autocomplete instead.
+    fn autocomplete(
+        &self,
+        _fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        None
+    }
+
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await token_match in token.match_input(input, app_meta) {
+                if !matches!(token_match, FuzzyMatch::Overflow(..)) {
+                    yield if let Some(change) = app_meta.repository.undo_history().next() {
+                        ("undo", format!("undo {}", change.display_undo())).into()
+                    } else {
+                        ("undo", "Nothing to undo.").into()
+                    };
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("undo", "undo the most recent change")
+            .example("undo", "reverse the last change to your journal")
+    }
+
+    fn get_priority(&self, _token_match: &TokenMatch) -> Option<CommandPriority> {
+        Some(CommandPriority::Canonical)
+    }
+
+    async fn parse(
+        &self,
+        _token_match: TokenMatch<'_>,
+        _app_meta: &AppMeta,
+    ) -> Option<CommandType> {
+        Some(StorageCommand::Undo.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Undo.into()),
+            Undo.parse_input("UNDO", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let mut app_meta = test::app_meta::with_test_data().await;
+
+        // undo Polyphemus
+        app_meta.repository.undo().await.unwrap().unwrap();
+        // undo Pylos
+        app_meta.repository.undo().await.unwrap().unwrap();
+
+        test::assert_autocomplete_eq!(
+            [("undo", "undo creating Odysseus")],
+            Undo.parse_autocomplete("un", &app_meta).collect().await,
+        );
+
+        app_meta.repository.undo().await.unwrap().unwrap();
+
+        test::assert_autocomplete_eq!(
+            [("undo", "Nothing to undo.")],
+            Undo.parse_autocomplete("UNDO", &app_meta).collect().await,
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::command::prelude::*;
+use crate::world::{autocomplete_name_field, parse_name_field, WorldCommand};
+
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::prelude::*;
+
+use super::is_name;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unlock;
+
+#[derive(Hash)]
+enum Marker {
+    Name,
+    NameField,
+    NameFieldSequence,
+    SimilarName,
+}
+
+impl Command for Unlock {
+    /// Names (and misspellings of names) are matched by their own tokens, leaving the phrase for
+    /// "[name]'s [field]" and for names that don't exist, so that the command can report as much.
+    fn token(&self) -> Token {
+        or([
+            sequence([keyword("unlock"), name_m(Marker::Name)]),
+            sequence([keyword("unlock"), similar_name_m(Marker::SimilarName)]),
+            sequence_m(
+                Marker::NameFieldSequence,
+                [keyword("unlock"), any_phrase_m(Marker::NameField)],
+            ),
+        ])
+    }
+
+    fn autocomplete(
+        &self,
+        fuzzy_match: FuzzyMatch,
+        _input: &str,
+    ) -> Option<AutocompleteSuggestion> {
+        let token_match = match fuzzy_match {
+            FuzzyMatch::Exact(token_match) | FuzzyMatch::Partial(token_match, _) => token_match,
+            FuzzyMatch::Overflow(..) => return None,
+        };
+
+        if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            Some(AutocompleteSuggestion::new(
+                format!("unlock {}", record.thing.name()),
+                format!("unlock {}", record.thing.as_str()),
+            ))
+        } else if token_match.contains_marker(Marker::NameFieldSequence)
+            && !token_match.contains_marker(Marker::NameField)
+        {
+            Some(("unlock [name]", "allow a detail to be rerolled").into())
+        } else {
+            None
+        }
+    }
+
+    /// Suggesting fields requires a repository lookup, so they are added here.
+    fn parse_autocomplete<'a>(
+        &'a self,
+        input: &'a str,
+        app_meta: &'a AppMeta,
+    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
+        Box::pin(stream! {
+            let token = self.token();
+            for await fuzzy_match in token.match_input(input, app_meta) {
+                if let FuzzyMatch::Exact(token_match) = &fuzzy_match {
+                    if let Some(name_field) = token_match
+                        .find_marker(Marker::NameField)
+                        .and_then(TokenMatch::meta_phrase)
+                    {
+                        for suggestion in
+                            autocomplete_name_field(input, name_field, "unlock", app_meta).await
+                        {
+                            yield suggestion;
+                        }
+                        continue;
+                    }
+                }
+
+                if let Some(suggestion) = self.autocomplete(fuzzy_match, input) {
+                    yield suggestion;
+                }
+            }
+        })
+    }
+
+    fn help(&self) -> CommandHelp {
+        CommandHelp::new("unlock", "allow a detail to be rerolled")
+            .placeholder(Marker::Name, "name")
+            .placeholder(Marker::SimilarName, "name")
+            .placeholder(Marker::NameField, "name's detail")
+            .example(
+                "unlock Odysseus's name",
+                "let the name of Odysseus be rerolled",
+            )
+    }
+
+    fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+        if token_match.contains_marker(Marker::SimilarName) {
+            Some(CommandPriority::Corrected)
+        } else {
+            Some(CommandPriority::Canonical)
+        }
+    }
+
+    async fn parse(&self, token_match: TokenMatch<'_>, app_meta: &AppMeta) -> Option<CommandType> {
+        let (name, field) = if let Some(record) = token_match
+            .find_marker(Marker::Name)
+            .or_else(|| token_match.find_marker(Marker::SimilarName))
+            .and_then(|token_match| token_match.meta_record())
+        {
+            (record.thing.name().to_string(), None)
+        } else {
+            let name_field = token_match.find_marker(Marker::NameField)?.meta_phrase()?;
+
+            if is_name(name_field, app_meta).await {
+                return None;
+            }
+
+            parse_name_field(name_field, app_meta).await?
+        };
+
+        Some(WorldCommand::Unlock { name, field }.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::app::CommandMatches;
+    use crate::test_utils as test;
+    use crate::world::place::PlaceField;
+
+    #[tokio::test]
+    async fn parse_input_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                WorldCommand::Unlock {
+                    name: "Pylos".to_string(),
+                    field: Some(PlaceField::Subtype.into()),
+                }
+                .into(),
+            ),
+            Unlock.parse_input("UNLOCK PYLOS'S TYPE", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(
+                WorldCommand::Unlock {
+                    name: "Odysseus".to_string(),
+                    field: None,
+                }
+                .into(),
+            ),
+            Unlock.parse_input("unlock Odysseus", &app_meta).await,
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            Unlock.parse_input("unlock ", &app_meta).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+
+        test::assert_autocomplete_eq!(
+            [("unlock Pylos's type", "unlock place type")],
+            Unlock
+                .parse_autocomplete("unlock Pylos's t", &app_meta)
+                .collect()
+                .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn display_test() {
+        let app_meta = test::app_meta::with_test_data().await;
+        let command = WorldCommand::Unlock {
+            name: "Pylos".to_string(),
+            field: None,
+        };
+
+        assert_eq!(
+            CommandMatches::new_canonical(command.clone().into()),
+            Unlock.parse_input(&command.to_string(), &app_meta).await,
+        );
+    }
+}
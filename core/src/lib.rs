@@ -24,6 +24,7 @@ pub mod command;
 
 mod reference;
 mod storage;
+mod table;
 mod time;
 
 #[cfg(not(feature = "integration-tests"))]
@@ -36,9 +37,10 @@ mod world;
 /// Creates a new instance of the application wrapper. The `data_store` is used to save and load
 /// data from storage, and the `event_dispatcher` is a callback function invoked whenever an
 /// event occurs in-app that may require special handling by the UI. See [`Event`] for details.
-pub fn app<F: Fn(Event)>(
+/// Events can also be received as a stream using [`App::subscribe`].
+pub fn app(
     data_store: impl DataStore + 'static,
-    event_dispatcher: &'static F,
+    event_dispatcher: impl Fn(Event) + 'static,
 ) -> app::App {
     let app_meta = app::AppMeta::new(data_store, event_dispatcher);
     app::App::new(app_meta)
@@ -1,12 +1,8 @@
 use super::{Condition, Item, ItemCategory, MagicItem, Spell, Trait};
-use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
-};
-use crate::utils::CaseInsensitiveStr;
+use crate::app::{AppMeta, Block, Inline, Output, Runnable};
+use crate::utils::is_valid_formula;
 use async_trait::async_trait;
-use caith::Roller;
 use std::fmt;
-use std::iter::repeat;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReferenceCommand {
@@ -23,114 +19,52 @@ pub enum ReferenceCommand {
 #[async_trait(?Send)]
 impl Runnable for ReferenceCommand {
     async fn run(self, _input: &str, _app_meta: &mut AppMeta) -> Result<String, String> {
+        Ok(match self.content() {
+            Some((output, name)) => format!(
+                "{}\n\n*{} is Open Game Content subject to the `Open Game License`.*",
+                output, name,
+            ),
+            None => OPEN_GAME_LICENSE.trim_end().to_string(),
+        })
+    }
+
+    async fn run_output(self, _input: &str, _app_meta: &mut AppMeta) -> Result<Output, Output> {
+        // SRD content is stored as markdown, so only the attribution is built directly.
+        Ok(match self.content() {
+            Some((output, name)) => {
+                let mut output = Output::from_markdown(&output);
+                output
+                    .blocks
+                    .push(Block::paragraph(vec![Inline::emphasis(vec![
+                        Inline::text(format!("{} is Open Game Content subject to the ", name)),
+                        Inline::command("Open Game License"),
+                        Inline::text("."),
+                    ])]));
+                output
+            }
+            None => Output::from_markdown(OPEN_GAME_LICENSE.trim_end()),
+        })
+    }
+}
+
+const OPEN_GAME_LICENSE: &str = include_str!("../../../data/ogl-1.0a.md");
+
+impl ReferenceCommand {
+    /// The markdown content of the reference entry and the name used to attribute it, or `None`
+    /// for the license itself.
+    fn content(&self) -> Option<(String, &'static str)> {
         let (output, name) = match self {
             Self::Condition(condition) => (format!("{}", condition), condition.get_name()),
             Self::Item(item) => (format!("{}", item), item.get_name()),
             Self::ItemCategory(category) => (format!("{}", category), "This listing"),
             Self::MagicItem(magic_item) => (format!("{}", magic_item), magic_item.get_name()),
-            Self::OpenGameLicense => {
-                return Ok(include_str!("../../../data/ogl-1.0a.md")
-                    .trim_end()
-                    .to_string());
-            }
+            Self::OpenGameLicense => return None,
             Self::Spell(spell) => (format!("{}", spell), spell.get_name()),
             Self::Spells => (Spell::get_list().to_string(), "This listing"),
             Self::Trait(t) => (t.to_string(), t.get_name()),
         };
 
-        Ok(format!(
-            "{}\n\n*{} is Open Game Content subject to the `Open Game License`.*",
-            linkify_dice(&output),
-            name,
-        ))
-    }
-}
-
-#[async_trait(?Send)]
-impl ContextAwareParse for ReferenceCommand {
-    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
-        let mut matches = if input.eq_ci("Open Game License") {
-            CommandMatches::new_canonical(Self::OpenGameLicense)
-        } else if input.eq_ci("srd spells") {
-            CommandMatches::new_canonical(Self::Spells)
-        } else if let Some(condition) = input
-            .strip_prefix_ci("srd condition ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::Condition(condition))
-        } else if let Some(item_category) = input
-            .strip_prefix_ci("srd item category ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::ItemCategory(item_category))
-        } else if let Some(item) = input
-            .strip_prefix_ci("srd item ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::Item(item))
-        } else if let Some(magic_item) = input
-            .strip_prefix_ci("srd magic item ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::MagicItem(magic_item))
-        } else if let Some(spell) = input
-            .strip_prefix_ci("srd spell ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::Spell(spell))
-        } else if let Some(character_trait) = input
-            .strip_prefix_ci("srd trait ")
-            .and_then(|s| s.parse().ok())
-        {
-            CommandMatches::new_canonical(Self::Trait(character_trait))
-        } else {
-            CommandMatches::default()
-        };
-
-        if let Ok(condition) = input.parse() {
-            matches.push_fuzzy(Self::Condition(condition));
-        }
-        if let Ok(item) = input.parse() {
-            matches.push_fuzzy(Self::Item(item));
-        }
-        if let Ok(category) = input.parse() {
-            matches.push_fuzzy(Self::ItemCategory(category));
-        }
-        if let Ok(magic_item) = input.parse() {
-            matches.push_fuzzy(Self::MagicItem(magic_item));
-        }
-        if let Ok(spell) = input.parse() {
-            matches.push_fuzzy(Self::Spell(spell));
-        }
-        if let Ok(character_trait) = input.parse() {
-            matches.push_fuzzy(Self::Trait(character_trait));
-        }
-        if input.eq_ci("spells") {
-            matches.push_fuzzy(Self::Spells);
-        }
-
-        matches
-    }
-}
-
-#[async_trait(?Send)]
-impl Autocomplete for ReferenceCommand {
-    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        [
-            ("Open Game License", "SRD license"),
-            ("spells", "SRD index"),
-        ]
-        .into_iter()
-        .chain(Spell::get_words().zip(repeat("SRD spell")))
-        .chain(Condition::get_words().zip(repeat("SRD condition")))
-        .chain(Item::get_words().zip(repeat("SRD item")))
-        .chain(ItemCategory::get_words().zip(repeat("SRD item category")))
-        .chain(MagicItem::get_words().zip(repeat("SRD magic item")))
-        .chain(Trait::get_words().zip(repeat("SRD trait")))
-        .filter(|(term, _)| term.starts_with_ci(input))
-        .take(10)
-        .map(|(term, summary)| AutocompleteSuggestion::new(term, summary))
-        .collect()
+        Some((linkify_dice(&output), name))
     }
 }
 
@@ -173,9 +107,7 @@ fn linkify_dice(input: &str) -> String {
         } else {
             while !hold.is_empty() {
                 let hold_trimmed = hold.trim();
-                if hold_trimmed.contains(&['d', 'D'][..])
-                    && Roller::new(hold_trimmed).is_ok_and(|r| r.roll().is_ok())
-                {
+                if hold_trimmed.contains(&['d', 'D'][..]) && is_valid_formula(hold_trimmed) {
                     result.push('`');
                     result.push_str(hold_trimmed);
                     result.push('`');
@@ -207,46 +139,3 @@ fn linkify_dice(input: &str) -> String {
     result.push_str(&hold);
     result
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::test_utils as test;
-    use tokio_test::block_on;
-
-    #[test]
-    fn display_test() {
-        let app_meta = test::app_meta();
-
-        [
-            ReferenceCommand::Spell(Spell::Shield),
-            ReferenceCommand::Spells,
-            ReferenceCommand::Item(Item::Shield),
-            ReferenceCommand::ItemCategory(ItemCategory::Shields),
-            ReferenceCommand::MagicItem(MagicItem::DeckOfManyThings),
-            ReferenceCommand::OpenGameLicense,
-        ]
-        .into_iter()
-        .for_each(|command| {
-            let command_string = command.to_string();
-            assert_ne!("", command_string);
-
-            assert_eq!(
-                CommandMatches::new_canonical(command.clone()),
-                block_on(ReferenceCommand::parse_input(&command_string, &app_meta)),
-                "{}",
-                command_string,
-            );
-
-            assert_eq!(
-                CommandMatches::new_canonical(command),
-                block_on(ReferenceCommand::parse_input(
-                    &command_string.to_uppercase(),
-                    &app_meta,
-                )),
-                "{}",
-                command_string.to_uppercase(),
-            );
-        });
-    }
-}
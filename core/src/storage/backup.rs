@@ -1,5 +1,8 @@
 use super::repository::{Change, Error as RepositoryError, KeyValue, Repository};
+use crate::app::UserAliases;
+use crate::table::RandomTables;
 use crate::world::thing::{Thing, ThingData};
+use crate::world::Vocabulary;
 use futures::join;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -18,6 +21,15 @@ pub struct BackupData {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct KeyValueBackup {
     pub time: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vocabulary: Option<Vocabulary>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<UserAliases>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tables: Option<RandomTables>,
 }
 
 #[derive(Default)]
@@ -35,13 +47,22 @@ struct ImportStat {
 }
 
 pub async fn export(repo: &Repository) -> BackupData {
-    let (things, time) = join!(repo.journal(), repo.get_key_value(&KeyValue::Time(None)));
+    let (things, time, vocabulary, aliases, tables) = join!(
+        repo.journal(),
+        repo.get_key_value(&KeyValue::Time(None)),
+        repo.get_key_value(&KeyValue::Vocabulary(None)),
+        repo.get_key_value(&KeyValue::Aliases(None)),
+        repo.get_key_value(&KeyValue::Tables(None)),
+    );
 
     BackupData {
         comment: "This document is exported from initiative.sh. Please note that this format is currently undocumented and no guarantees of forward compatibility are provided, although a reasonable effort will be made to ensure that older backups can be safely imported.",
         things: things.unwrap_or_default(),
         key_value: KeyValueBackup {
             time: time.ok().and_then(|t| t.time()).map(|t| t.display_short().to_string()),
+            vocabulary: vocabulary.ok().and_then(|v| v.vocabulary()),
+            aliases: aliases.ok().and_then(|a| a.aliases()),
+            tables: tables.ok().and_then(|t| t.tables()),
         },
     }
 }
@@ -108,9 +129,125 @@ pub async fn import(
         }
     }
 
+    if let Some(vocabulary) = data.key_value.vocabulary.take() {
+        import_key_value(
+            repo,
+            &mut stats.key_value_stats,
+            vocabulary,
+            KeyValue::Vocabulary,
+            KeyValue::vocabulary,
+        )
+        .await;
+    }
+
+    if let Some(aliases) = data.key_value.aliases.take() {
+        import_key_value(
+            repo,
+            &mut stats.key_value_stats,
+            aliases,
+            KeyValue::Aliases,
+            KeyValue::aliases,
+        )
+        .await;
+    }
+
+    if let Some(tables) = data.key_value.tables.take() {
+        import_key_value(
+            repo,
+            &mut stats.key_value_stats,
+            tables,
+            KeyValue::Tables,
+            KeyValue::tables,
+        )
+        .await;
+    }
+
     Ok(stats)
 }
 
+/// Merge an imported collection into the one already in the key-value store, so that importing
+/// a backup adds to the user's aliases (say) rather than replacing them. Each entry of the
+/// collection counts towards the stats as created or updated, depending on whether an entry with
+/// the same key already existed.
+async fn import_key_value<T: Default + MergeEntries>(
+    repo: &mut Repository,
+    stat: &mut ImportStat,
+    imported: T,
+    key_value: fn(Option<T>) -> KeyValue,
+    value: fn(KeyValue) -> Option<T>,
+) {
+    let mut entries = ImportStat::default();
+
+    let result = match repo.get_key_value(&key_value(None)).await {
+        Ok(existing) => {
+            let mut merged = value(existing).unwrap_or_default();
+            merged.merge_entries(imported, &mut entries);
+
+            repo.modify_without_undo(Change::SetKeyValue {
+                key_value: key_value(Some(merged)),
+            })
+            .await
+            .map(|_| ())
+            .map_err(|_| ())
+        }
+        Err(_) => {
+            T::default().merge_entries(imported, &mut entries);
+            Err(())
+        }
+    };
+
+    if result.is_ok() {
+        stat.created += entries.created;
+        stat.updated += entries.updated;
+    } else {
+        stat.failed += entries.created + entries.updated;
+    }
+}
+
+/// A collection stored as a single key-value whose entries can be merged individually on import.
+trait MergeEntries {
+    /// Add the entries of `other` to `self`, replacing (or extending) any with the same key.
+    fn merge_entries(&mut self, other: Self, stat: &mut ImportStat);
+}
+
+impl MergeEntries for Vocabulary {
+    fn merge_entries(&mut self, other: Self, stat: &mut ImportStat) {
+        for (list, words) in other.into_lists() {
+            if self.get(&list).is_empty() {
+                stat.created += 1;
+            } else {
+                stat.updated += 1;
+            }
+
+            self.add(&list, &words);
+        }
+    }
+}
+
+impl MergeEntries for UserAliases {
+    fn merge_entries(&mut self, other: Self, stat: &mut ImportStat) {
+        for alias in other.iter() {
+            if self.insert(alias.clone()).is_some() {
+                stat.updated += 1;
+            } else {
+                stat.created += 1;
+            }
+        }
+    }
+}
+
+impl MergeEntries for RandomTables {
+    fn merge_entries(&mut self, other: Self, stat: &mut ImportStat) {
+        for table in other.iter() {
+            if self.insert(table.clone()).is_some() {
+                stat.updated += 1;
+            } else {
+                stat.created += 1;
+            }
+        }
+    }
+}
+
 impl fmt::Display for ImportStats {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let mut first = true;
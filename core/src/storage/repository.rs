@@ -1,9 +1,12 @@
+use crate::app::{Event, UserAliases};
 use crate::storage::{DataStore, MemoryDataStore};
+use crate::table::RandomTables;
 use crate::time::Time;
-use crate::utils::CaseInsensitiveStr;
+use crate::utils::{prefix_similarity_ci, similarity_ci, CaseInsensitiveStr};
 use crate::world::npc::{NpcData, NpcRelations};
 use crate::world::place::{Place, PlaceData, PlaceRelations};
 use crate::world::thing::{Thing, ThingData, ThingRelations};
+use crate::world::Vocabulary;
 use crate::Uuid;
 use futures::join;
 use std::collections::VecDeque;
@@ -20,6 +23,7 @@ pub struct Repository {
     recent: VecDeque<Thing>,
     redo_change: Option<Change>,
     undo_history: VecDeque<Change>,
+    events: Vec<Event>,
 }
 
 /// Represents a modification to be applied to the Repository. This is passed to
@@ -68,6 +72,27 @@ pub enum Change {
         diff: ThingData,
     },
 
+    /// Replace fields on a Thing along with their lock state, as when rerolling fields or locking
+    /// and unlocking them. Unlike Edit, which only applies locked values and locks whatever it
+    /// touches, unlocked values in the diff are applied and stay unlocked, and the reverse restores
+    /// each field's previous lock state.
+    ///
+    /// Reverse: Reroll (already in journal) or RerollAndUnsave (in recent)
+    Reroll {
+        uuid: Uuid,
+        name: Name,
+        diff: ThingData,
+    },
+
+    /// Reroll a Thing and move it from journal to recent. The reverse of reroll with autosave.
+    ///
+    /// Reverse: Reroll
+    RerollAndUnsave {
+        uuid: Uuid,
+        name: Name,
+        diff: ThingData,
+    },
+
     /// Transfer a thing from recent to journal.
     ///
     /// Reverse: Unsave
@@ -92,14 +117,18 @@ pub struct DisplayRedo<'a>(&'a Change);
 pub enum Error {
     DataStoreFailed,
     MissingName,
-    UuidAlreadyExists(Thing),
-    NameAlreadyExists(Thing),
+    UuidAlreadyExists(Box<Thing>),
+    NameAlreadyExists(Box<Thing>),
     NotFound,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum KeyValue {
+    Aliases(Option<UserAliases>),
+    Seed(Option<u64>),
+    Tables(Option<RandomTables>),
     Time(Option<Time>),
+    Vocabulary(Option<Vocabulary>),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -137,6 +166,7 @@ impl Repository {
             recent: VecDeque::default(),
             redo_change: None,
             undo_history: VecDeque::default(),
+            events: Vec::new(),
         }
     }
 
@@ -160,6 +190,8 @@ impl Repository {
                 uuid: Some(uuid), ..
             }
             | Change::EditAndUnsave { uuid, .. }
+            | Change::Reroll { uuid, .. }
+            | Change::RerollAndUnsave { uuid, .. }
             | Change::Save {
                 uuid: Some(uuid), ..
             }
@@ -352,6 +384,53 @@ impl Repository {
         })
     }
 
+    /// Get all saved and recent Things with names that look like a misspelling of the given name,
+    /// or whose names begin with a misspelling of it. Things that would be matched by
+    /// `get_by_name_start()` are excluded. Results are sorted by similarity, closest first.
+    ///
+    /// This scans the entire journal, so should only be used once the cheaper lookups have come up
+    /// empty.
+    pub async fn get_by_similar_name(&self, name: &str) -> Result<Vec<Record>, Error> {
+        let recent_iter = self.recent().map(|thing| Record {
+            status: RecordStatus::Unsaved,
+            thing: thing.clone(),
+        });
+
+        let journal_iter = self.journal().await?.into_iter().map(|thing| Record {
+            status: RecordStatus::Saved,
+            thing,
+        });
+
+        let mut records: Vec<_> = journal_iter
+            .chain(recent_iter)
+            .filter_map(|record| {
+                let thing_name = record.thing.name().value()?;
+
+                if thing_name.starts_with_ci(name) {
+                    None
+                } else {
+                    similarity_ci(name, thing_name)
+                        .into_iter()
+                        .chain(prefix_similarity_ci(name, thing_name))
+                        .min()
+                        .map(|distance| (distance, record))
+                }
+            })
+            .collect();
+
+        records.sort_by(|(a_distance, a_record), (b_distance, b_record)| {
+            a_distance.cmp(b_distance).then_with(|| {
+                a_record
+                    .thing
+                    .name()
+                    .to_string()
+                    .cmp_ci(b_record.thing.name().to_string())
+            })
+        });
+
+        Ok(records.into_iter().map(|(_, record)| record).collect())
+    }
+
     /// Get the Thing from saved or recent with a given UUID. (There should be only one.)
     pub async fn get_by_uuid(&self, uuid: &Uuid) -> Result<Record, Error> {
         let (recent_thing, saved_thing) = join!(
@@ -405,8 +484,11 @@ impl Repository {
     /// the Result of the modify() operation.
     pub async fn undo(&mut self) -> Option<Result<Option<Record>, Error>> {
         if let Some(change) = self.undo_history.pop_back() {
+            let events_len = self.events.len();
+
             match self.modify_without_undo(change).await {
                 Ok(redo_change) => {
+                    self.events.insert(events_len, Event::Undo);
                     let record = self.get_by_change(&redo_change).await.ok();
                     self.redo_change = Some(redo_change);
                     Some(Ok(record))
@@ -432,8 +514,13 @@ impl Repository {
     /// Change is stored in history at a time.
     pub async fn redo(&mut self) -> Option<Result<Option<Record>, Error>> {
         if let Some(change) = self.redo_change.take() {
+            let events_len = self.events.len();
+
             match self.modify(change).await {
-                Ok(option_record) => Some(Ok(option_record)),
+                Ok(option_record) => {
+                    self.events.insert(events_len, Event::Redo);
+                    Some(Ok(option_record))
+                }
                 Err((redo_change, e)) => {
                     self.redo_change = Some(redo_change);
                     Some(Err(e))
@@ -453,6 +540,54 @@ impl Repository {
     /// the reverse operation on success (what would be otherwise inserted into the undo history),
     /// or a tuple of the failed Change and error message on failure.
     pub async fn modify_without_undo(&mut self, change: Change) -> Result<Change, (Change, Error)> {
+        let key_value = if let Change::SetKeyValue { key_value } = &change {
+            Some(key_value.clone())
+        } else {
+            None
+        };
+
+        let reverse = self.apply_change(change).await?;
+
+        let event = match (&reverse, key_value) {
+            (_, Some(KeyValue::Time(time))) => Some(Event::TimeChanged(time.unwrap_or_default())),
+            (_, Some(_)) => None,
+            (Change::Delete { .. }, None) => self
+                .get_by_change(&reverse)
+                .await
+                .ok()
+                .map(|record| Event::ThingCreated(record.thing)),
+            (
+                Change::Create {
+                    thing_data,
+                    uuid: Some(uuid),
+                }
+                | Change::CreateAndSave {
+                    thing_data,
+                    uuid: Some(uuid),
+                },
+                None,
+            ) => Some(Event::ThingDeleted {
+                uuid: *uuid,
+                name: thing_data.name().to_string(),
+            }),
+            (_, None) => self
+                .get_by_change(&reverse)
+                .await
+                .ok()
+                .map(|record| Event::ThingEdited(record.thing)),
+        };
+
+        self.events.extend(event);
+
+        Ok(reverse)
+    }
+
+    /// Take the events resulting from changes made since this was last called.
+    pub fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    async fn apply_change(&mut self, change: Change) -> Result<Change, (Change, Error)> {
         match change {
             Change::Create { thing_data, uuid } => {
                 let name = thing_data.name().to_string();
@@ -569,6 +704,42 @@ impl Repository {
                     Err((_, diff, e)) => Err((Change::EditAndUnsave { uuid, name, diff }, e)),
                 }
             }
+            Change::Reroll { uuid, name, diff } => {
+                match self.reroll_thing_by_uuid(&uuid, diff).await {
+                    Ok((Record { thing, status }, name)) => {
+                        let diff = thing.data;
+
+                        if status == RecordStatus::Saved {
+                            Ok(Change::Reroll { uuid, name, diff })
+                        } else {
+                            Ok(Change::RerollAndUnsave { uuid, name, diff })
+                        }
+                    }
+                    Err((_, diff, e)) => Err((Change::Reroll { uuid, name, diff }, e)),
+                }
+            }
+            Change::RerollAndUnsave { uuid, name, diff } => {
+                match self.reroll_thing_by_uuid(&uuid, diff).await {
+                    Ok((Record { thing, .. }, name)) => self
+                        .unsave_thing_by_uuid(&uuid)
+                        .await
+                        .map(|name| Change::Reroll {
+                            uuid,
+                            name,
+                            diff: thing.data,
+                        })
+                        .map_err(|(s, e)| {
+                            (
+                                Change::Unsave {
+                                    uuid,
+                                    name: s.unwrap_or(name),
+                                },
+                                e,
+                            )
+                        }),
+                    Err((_, diff, e)) => Err((Change::RerollAndUnsave { uuid, name, diff }, e)),
+                }
+            }
             Change::Save {
                 name,
                 uuid: Some(uuid),
@@ -613,9 +784,30 @@ impl Repository {
         let value_str = self.data_store.get_value(key.key_raw()).await;
 
         match key {
+            KeyValue::Aliases(_) => value_str
+                .and_then(|o| {
+                    o.map(|s| serde_json::from_str(&s).map_err(|_| ()))
+                        .transpose()
+                })
+                .map(KeyValue::Aliases),
+            KeyValue::Seed(_) => value_str
+                .and_then(|o| o.map(|s| s.parse().map_err(|_| ())).transpose())
+                .map(KeyValue::Seed),
+            KeyValue::Tables(_) => value_str
+                .and_then(|o| {
+                    o.map(|s| serde_json::from_str(&s).map_err(|_| ()))
+                        .transpose()
+                })
+                .map(KeyValue::Tables),
             KeyValue::Time(_) => value_str
                 .and_then(|o| o.map(|s| s.parse()).transpose())
                 .map(KeyValue::Time),
+            KeyValue::Vocabulary(_) => value_str
+                .and_then(|o| {
+                    o.map(|s| serde_json::from_str(&s).map_err(|_| ()))
+                        .transpose()
+                })
+                .map(KeyValue::Vocabulary),
         }
         .map_err(|_| Error::DataStoreFailed)
     }
@@ -798,7 +990,7 @@ impl Repository {
     ) -> Result<(Record, Name), (Option<Record>, ThingData, Error)> {
         match self.get_by_name(name).await {
             Ok(record) => self
-                .edit_thing(record, diff)
+                .edit_thing(record, diff, ThingData::try_apply_diff)
                 .await
                 .map_err(|(record, data, e)| (Some(record), data, e)),
             Err(e) => Err((None, diff, e)),
@@ -815,26 +1007,45 @@ impl Repository {
     ) -> Result<(Record, Name), (Option<Record>, ThingData, Error)> {
         match self.get_by_uuid(uuid).await {
             Ok(record) => self
-                .edit_thing(record, diff)
+                .edit_thing(record, diff, ThingData::try_apply_diff)
                 .await
                 .map_err(|(record, data, e)| (Some(record), data, e)),
             Err(e) => Err((None, diff, e)),
         }
     }
 
-    /// Apply a diff to a given Record. Returns a tuple consisting of a Record containing *the
-    /// modified fields* and the matched Thing's actual name on success, or a tuple consisting of
-    /// an optional Record of the matched Thing, the attempted diff, and an error message on
-    /// failure. Note that the successful response includes only the old values of any modified
-    /// fields, so re-applying the diff will revert the Thing back to its original state.
+    /// Apply a reroll diff to a Thing matched by UUID. See edit_thing() for details.
     ///
-    /// Supports the edit_thing_by_* functions.
+    /// Publicly this is invoked using modify() with Change::Reroll.
+    async fn reroll_thing_by_uuid(
+        &mut self,
+        uuid: &Uuid,
+        diff: ThingData,
+    ) -> Result<(Record, Name), (Option<Record>, ThingData, Error)> {
+        match self.get_by_uuid(uuid).await {
+            Ok(record) => self
+                .edit_thing(record, diff, ThingData::try_apply_reroll_diff)
+                .await
+                .map_err(|(record, data, e)| (Some(record), data, e)),
+            Err(e) => Err((None, diff, e)),
+        }
+    }
+
+    /// Apply a diff to a given Record using the provided merge function. Returns a tuple
+    /// consisting of a Record containing *the modified fields* and the matched Thing's actual name
+    /// on success, or a tuple consisting of an optional Record of the matched Thing, the attempted
+    /// diff, and an error message on failure. Note that the successful response includes only the
+    /// old values of any modified fields, so re-applying the diff will revert the Thing back to
+    /// its original state.
+    ///
+    /// Supports the edit_thing_by_* functions and reroll_thing_by_uuid.
     async fn edit_thing(
         &mut self,
         mut record: Record,
         mut diff: ThingData,
+        apply_diff: fn(&mut ThingData, &mut ThingData) -> Result<(), ()>,
     ) -> Result<(Record, Name), (Record, ThingData, Error)> {
-        if record.thing.try_apply_diff(&mut diff).is_err() {
+        if apply_diff(&mut record.thing.data, &mut diff).is_err() {
             // This fails when the thing types don't match, eg. applying an Npc diff to a
             // Place.
             return Err((record, diff, Error::NotFound));
@@ -894,10 +1105,10 @@ impl Repository {
         let uuid = uuid.unwrap_or_else(Uuid::new_v4);
 
         if let Ok(record) = self.get_by_uuid(&uuid).await {
-            Err((thing_data, Error::UuidAlreadyExists(record.thing)))
+            Err((thing_data, Error::UuidAlreadyExists(Box::new(record.thing))))
         } else if let Some(name) = thing_data.name().value() {
             if let Ok(record) = self.get_by_name(name).await {
-                Err((thing_data, Error::NameAlreadyExists(record.thing)))
+                Err((thing_data, Error::NameAlreadyExists(Box::new(record.thing))))
             } else {
                 Ok(Thing {
                     uuid,
@@ -1008,7 +1219,11 @@ impl<'a> From<(&'a str, RecordSource, ThingType)> for GetByNameArgs<'a> {
 impl KeyValue {
     pub const fn key_raw(&self) -> &'static str {
         match self {
+            Self::Aliases(_) => "aliases",
+            Self::Seed(_) => "seed",
+            Self::Tables(_) => "tables",
             Self::Time(_) => "time",
+            Self::Vocabulary(_) => "vocabulary",
         }
     }
 
@@ -1016,19 +1231,58 @@ impl KeyValue {
         (
             self.key_raw(),
             match self {
+                Self::Aliases(aliases) => {
+                    aliases.as_ref().and_then(|a| serde_json::to_string(a).ok())
+                }
+                Self::Seed(seed) => seed.map(|s| s.to_string()),
+                Self::Tables(tables) => tables.as_ref().and_then(|t| serde_json::to_string(t).ok()),
                 Self::Time(time) => time.as_ref().map(|t| t.display_short().to_string()),
+                Self::Vocabulary(vocabulary) => vocabulary
+                    .as_ref()
+                    .and_then(|v| serde_json::to_string(v).ok()),
             },
         )
     }
 
-    pub const fn time(self) -> Option<Time> {
-        #[expect(irrefutable_let_patterns)]
+    pub fn aliases(self) -> Option<UserAliases> {
+        if let Self::Aliases(aliases) = self {
+            aliases
+        } else {
+            None
+        }
+    }
+
+    pub fn seed(self) -> Option<u64> {
+        if let Self::Seed(seed) = self {
+            seed
+        } else {
+            None
+        }
+    }
+
+    pub fn tables(self) -> Option<RandomTables> {
+        if let Self::Tables(tables) = self {
+            tables
+        } else {
+            None
+        }
+    }
+
+    pub fn time(self) -> Option<Time> {
         if let Self::Time(time) = self {
             time
         } else {
             None
         }
     }
+
+    pub fn vocabulary(self) -> Option<Vocabulary> {
+        if let Self::Vocabulary(vocabulary) = self {
+            vocabulary
+        } else {
+            None
+        }
+    }
 }
 
 impl Change {
@@ -1097,9 +1351,11 @@ impl fmt::Display for DisplayUndo<'_> {
             Change::Unsave { name, .. } => write!(f, "saving {} to journal", name),
 
             // These changes are symmetric, so we can provide the same output in both cases.
-            Change::Edit { .. } | Change::EditAndUnsave { .. } | Change::SetKeyValue { .. } => {
-                write!(f, "{}", DisplayRedo(change))
-            }
+            Change::Edit { .. }
+            | Change::EditAndUnsave { .. }
+            | Change::Reroll { .. }
+            | Change::RerollAndUnsave { .. }
+            | Change::SetKeyValue { .. } => write!(f, "{}", DisplayRedo(change)),
         }
     }
 }
@@ -1113,13 +1369,18 @@ impl fmt::Display for DisplayRedo<'_> {
                 write!(f, "creating {}", thing_data.name())
             }
             Change::Delete { name, .. } => write!(f, "deleting {}", name),
-            Change::Edit { name, .. } | Change::EditAndUnsave { name, .. } => {
-                write!(f, "editing {}", name)
-            }
+            Change::Edit { name, .. }
+            | Change::EditAndUnsave { name, .. }
+            | Change::Reroll { name, .. }
+            | Change::RerollAndUnsave { name, .. } => write!(f, "editing {}", name),
             Change::Save { name, .. } => write!(f, "saving {} to journal", name),
             Change::Unsave { name, .. } => write!(f, "removing {} from journal", name),
             Change::SetKeyValue { key_value } => match key_value {
+                KeyValue::Aliases(_) => write!(f, "changing the aliases"),
+                KeyValue::Seed(_) => write!(f, "changing the seed"),
+                KeyValue::Tables(_) => write!(f, "changing the random tables"),
                 KeyValue::Time(_) => write!(f, "changing the time"),
+                KeyValue::Vocabulary(_) => write!(f, "changing the custom names and words"),
             },
         }
     }
@@ -1142,6 +1403,7 @@ mod test {
     use crate::test_utils as test;
     use crate::world::npc::Npc;
     use crate::world::place::Place;
+    use crate::world::Field;
     use tokio_test::block_on;
     use uuid::Uuid;
 
@@ -1367,6 +1629,42 @@ data_store.snapshot() = {:?}",
         assert_eq!(Err(Error::NotFound), block_on(repo().get_by_name("NOBODY")));
     }
 
+    #[test]
+    fn get_by_similar_name_test() {
+        let names = |name| {
+            block_on(repo().get_by_similar_name(name))
+                .unwrap()
+                .into_iter()
+                .map(|record| (record.status, record.thing.name().to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![(RecordStatus::Unsaved, "Odysseus".to_string())],
+            names("odyseus"),
+        );
+        assert_eq!(
+            vec![(RecordStatus::Saved, "Thessaly".to_string())],
+            names("thesa"),
+        );
+        assert_eq!(
+            vec![(RecordStatus::Saved, "Olympus".to_string())],
+            names("Olimpus"),
+        );
+
+        assert!(names("Olympus").is_empty());
+        assert!(names("Olym").is_empty());
+        assert!(names("Nobody").is_empty());
+    }
+
+    #[test]
+    fn get_by_similar_name_test_data_store_failed() {
+        assert_eq!(
+            Err(Error::DataStoreFailed),
+            block_on(null_repo().get_by_similar_name("Odyseus")),
+        );
+    }
+
     #[test]
     fn get_by_uuid_test_from_recent() {
         let result = block_on(repo().get_by_uuid(&ODYSSEUS_UUID)).unwrap();
@@ -1727,6 +2025,120 @@ data_store.snapshot() = {:?}",
         );
     }
 
+    #[test]
+    fn change_test_reroll_from_recent_success() {
+        assert_change_success!(
+            Change::Reroll {
+                uuid: ODYSSEUS_UUID,
+                name: "blah".into(),
+                diff: NpcData {
+                    name: Field::Unlocked(Some("Nobody".into())),
+                    ..Default::default()
+                }
+                .into(),
+            },
+            |repo, ds| {
+                block_on(ds.get_thing_by_uuid(&ODYSSEUS_UUID))
+                    .map(|opt_t| opt_t.map(|t| t.name().to_string()))
+                    == Ok(Some("Nobody".to_string()))
+                    && !repo.recent().any(|t| t.uuid == ODYSSEUS_UUID)
+            },
+            "editing blah",
+            "editing Nobody"
+        );
+    }
+
+    #[test]
+    fn change_test_reroll_from_journal_success() {
+        assert_change_success!(
+            Change::Reroll {
+                uuid: OLYMPUS_UUID,
+                name: "blah".into(),
+                diff: PlaceData {
+                    name: Field::Unlocked(Some("Hades".into())),
+                    ..Default::default()
+                }
+                .into(),
+            },
+            |_, ds| {
+                block_on(ds.get_thing_by_uuid(&OLYMPUS_UUID))
+                    .map(|opt_t| opt_t.map(|t| t.name().to_string()))
+                    == Ok(Some("Hades".to_string()))
+            },
+            "editing blah",
+            "editing Hades"
+        );
+    }
+
+    #[test]
+    fn change_test_reroll_undo_redo_lock_state() {
+        let (mut repo, data_store) = repo_data_store();
+        let olympus_name = || {
+            block_on(data_store.get_thing_by_uuid(&OLYMPUS_UUID))
+                .unwrap()
+                .unwrap()
+                .name()
+                .clone()
+        };
+
+        block_on(
+            repo.modify(Change::Reroll {
+                uuid: OLYMPUS_UUID,
+                name: "Olympus".into(),
+                diff: PlaceData {
+                    name: Field::Unlocked(Some("Hades".into())),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+        assert_eq!(Field::Unlocked(Some("Hades".to_string())), olympus_name());
+        assert!(olympus_name().is_unlocked());
+
+        block_on(repo.undo()).unwrap().unwrap();
+        assert_eq!(Field::Locked(Some("Olympus".to_string())), olympus_name());
+        assert!(olympus_name().is_locked());
+
+        block_on(repo.redo()).unwrap().unwrap();
+        assert_eq!(Field::Unlocked(Some("Hades".to_string())), olympus_name());
+        assert!(olympus_name().is_unlocked());
+    }
+
+    #[test]
+    fn change_test_reroll_not_found() {
+        assert_change_error!(
+            repo_data_store(),
+            Change::Reroll {
+                uuid: Uuid::nil(),
+                name: "Nobody".into(),
+                diff: NpcData::default().into(),
+            },
+            Error::NotFound
+        );
+    }
+
+    #[test]
+    fn change_test_reroll_and_unsave_success() {
+        assert_change_success!(
+            Change::RerollAndUnsave {
+                uuid: OLYMPUS_UUID,
+                name: "blah".into(),
+                diff: PlaceData {
+                    name: Field::Unlocked(Some("Hades".into())),
+                    ..Default::default()
+                }
+                .into(),
+            },
+            |repo, ds| {
+                repo.recent().any(|t| t.name().to_string() == "Hades")
+                    && block_on(ds.get_thing_by_uuid(&OLYMPUS_UUID)) == Ok(None)
+            },
+            "editing blah",
+            "editing Hades"
+        );
+    }
+
     #[test]
     fn change_test_create_success() {
         assert_change_success!(
@@ -1762,7 +2174,7 @@ data_store.snapshot() = {:?}",
                 .into(),
                 uuid: None,
             },
-            Error::NameAlreadyExists(existing_thing)
+            Error::NameAlreadyExists(Box::new(existing_thing))
         );
     }
 
@@ -1785,7 +2197,7 @@ data_store.snapshot() = {:?}",
                 .into(),
                 uuid: None,
             },
-            Error::NameAlreadyExists(existing_thing)
+            Error::NameAlreadyExists(Box::new(existing_thing))
         );
     }
 
@@ -1914,7 +2326,7 @@ data_store.snapshot() = {:?}",
                 .into(),
                 uuid: None,
             },
-            Error::NameAlreadyExists(existing_thing)
+            Error::NameAlreadyExists(Box::new(existing_thing))
         );
     }
 
@@ -1937,7 +2349,7 @@ data_store.snapshot() = {:?}",
                 .into(),
                 uuid: None,
             },
-            Error::NameAlreadyExists(existing_thing)
+            Error::NameAlreadyExists(Box::new(existing_thing))
         );
     }
 
@@ -2041,6 +2453,84 @@ data_store.snapshot() = {:?}",
         );
     }
 
+    #[test]
+    fn take_events_test() {
+        let mut repo = repo();
+
+        block_on(repo.modify(Change::Save {
+            name: "Odysseus".to_string(),
+            uuid: None,
+        }))
+        .unwrap();
+        assert!(
+            matches!(
+                &repo.take_events()[..],
+                [Event::ThingEdited(thing)] if thing.uuid == ODYSSEUS_UUID,
+            ),
+            "{:?}",
+            repo.events,
+        );
+        assert!(repo.take_events().is_empty());
+
+        block_on(repo.modify(Change::Delete {
+            uuid: ODYSSEUS_UUID,
+            name: "Odysseus".to_string(),
+        }))
+        .unwrap();
+        assert!(
+            matches!(
+                &repo.take_events()[..],
+                [Event::ThingDeleted { uuid, name }] if uuid == &ODYSSEUS_UUID && name == "Odysseus",
+            ),
+            "{:?}",
+            repo.events,
+        );
+
+        block_on(repo.undo()).unwrap().unwrap();
+        assert!(
+            matches!(
+                &repo.take_events()[..],
+                [Event::Undo, Event::ThingCreated(thing)] if thing.uuid == ODYSSEUS_UUID,
+            ),
+            "{:?}",
+            repo.events,
+        );
+
+        block_on(repo.redo()).unwrap().unwrap();
+        assert!(
+            matches!(
+                &repo.take_events()[..],
+                [Event::Redo, Event::ThingDeleted { uuid, .. }] if uuid == &ODYSSEUS_UUID,
+            ),
+            "{:?}",
+            repo.events,
+        );
+
+        let time = Time::try_new(2, 0, 0, 0).unwrap();
+        block_on(repo.modify(Change::SetKeyValue {
+            key_value: KeyValue::Time(Some(time.clone())),
+        }))
+        .unwrap();
+        assert!(
+            matches!(&repo.take_events()[..], [Event::TimeChanged(t)] if t == &time),
+            "{:?}",
+            repo.events,
+        );
+    }
+
+    #[test]
+    fn take_events_test_failure() {
+        let mut repo = repo();
+
+        block_on(repo.modify(Change::Delete {
+            uuid: Uuid::nil(),
+            name: "Nobody".to_string(),
+        }))
+        .unwrap_err();
+
+        assert!(repo.take_events().is_empty());
+    }
+
     #[test]
     fn load_relations_test_with_parent_success() {
         let repo = repo();
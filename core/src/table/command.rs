@@ -0,0 +1,359 @@
+use super::RandomTable;
+use crate::app::{AppMeta, Runnable};
+use crate::storage::{Change, KeyValue};
+use crate::utils::CaseInsensitiveStr;
+use async_trait::async_trait;
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableCommand {
+    Add { name: String, entries: Vec<String> },
+    Create { name: String },
+    Delete { name: String },
+    List,
+    Remove { name: String, entry: String },
+    Roll { name: String },
+    Show { name: String },
+}
+
+#[async_trait(?Send)]
+impl Runnable for TableCommand {
+    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+        match self {
+            Self::Add { name, entries } => {
+                let mut table = get_table(&name, app_meta)?.clone();
+
+                for entry in entries.iter() {
+                    table.add_entry(entry)?;
+                }
+
+                let message = format!(
+                    "{}\n\n_Use `roll on {}` to roll on this table._",
+                    table, table.name,
+                );
+                app_meta.random_tables.insert(table);
+                Ok(save_tables(app_meta, message).await)
+            }
+            Self::Create { name } => {
+                if let Some(table) = app_meta.random_tables.get(&name) {
+                    return Err(format!(
+                        "There is already a table named {}. Use `table delete {}` if you want to start over.",
+                        table.name, table.name,
+                    ));
+                } else if name.split_whitespace().any(|word| {
+                    ["add", "create", "delete", "remove"]
+                        .iter()
+                        .any(|keyword| word.eq_ci(keyword))
+                }) {
+                    return Err(format!(
+                        "\"{}\" can't be used as the name of a table, since it contains one of the words \"add\", \"create\", \"delete\" or \"remove\".",
+                        name,
+                    ));
+                }
+
+                let message = format!(
+                    "Created the table {}. Add some entries with `table {} add [entry]; [entry]`, where each entry looks like `1-3 rain`, `4 snow`, `2x fog` or just `sun`.",
+                    name, name,
+                );
+                app_meta.random_tables.insert(RandomTable::new(name));
+                Ok(save_tables(app_meta, message).await)
+            }
+            Self::Delete { name } => {
+                let table = app_meta
+                    .random_tables
+                    .remove(&name)
+                    .ok_or_else(|| no_table(&name))?;
+
+                let message = format!("Deleted the table {}.", table.name);
+                Ok(save_tables(app_meta, message).await)
+            }
+            Self::List => {
+                if app_meta.random_tables.is_empty() {
+                    return Ok(
+                        "You haven't created any tables yet. Try `table create Weather`."
+                            .to_string(),
+                    );
+                }
+
+                let mut output = "# Tables\n".to_string();
+                for table in app_meta.random_tables.iter() {
+                    output.push_str(&format!(
+                        "\n* `table {}` (d{}, {} {})",
+                        table.name,
+                        table.die_size(),
+                        table.entries.len(),
+                        if table.entries.len() == 1 {
+                            "entry"
+                        } else {
+                            "entries"
+                        },
+                    ));
+                }
+                Ok(output)
+            }
+            Self::Remove { name, entry } => {
+                let mut table = get_table(&name, app_meta)?.clone();
+
+                let removed = table.remove_entry(&entry).ok_or_else(|| {
+                    format!(
+                        "The table {} doesn't have an entry \"{}\".",
+                        table.name, entry
+                    )
+                })?;
+
+                let message = format!(
+                    "Removed \"{}\" from the table {}.\n\n{}",
+                    removed.text, table.name, table,
+                );
+                app_meta.random_tables.insert(table);
+                Ok(save_tables(app_meta, message).await)
+            }
+            Self::Roll { name } => {
+                let table = get_table(&name, app_meta)?;
+                let (name, die_size) = (table.name.clone(), table.die_size());
+
+                let (result, text) = app_meta
+                    .random_tables
+                    .roll(&name, &mut app_meta.rng)
+                    .ok_or_else(|| {
+                        format!(
+                            "The table {} doesn't have any entries yet. Add some with `table {} add [entry]`.",
+                            name, name,
+                        )
+                    })?;
+
+                Ok(format!(
+                    "**{}** ({} on a d{}): {}",
+                    name, result, die_size, text,
+                ))
+            }
+            Self::Show { name } => {
+                let table = get_table(&name, app_meta)?;
+                Ok(format!(
+                    "{}\n\n_Use `roll on {}` to roll on this table._",
+                    table, table.name,
+                ))
+            }
+        }
+    }
+}
+
+fn get_table<'a>(name: &str, app_meta: &'a AppMeta) -> Result<&'a RandomTable, String> {
+    app_meta
+        .random_tables
+        .get(name)
+        .ok_or_else(|| no_table(name))
+}
+
+fn no_table(name: &str) -> String {
+    format!(
+        "There is no table named \"{}\". Use `tables` to see the tables you've created.",
+        name,
+    )
+}
+
+async fn save_tables(app_meta: &mut AppMeta, message: String) -> String {
+    match app_meta
+        .repository
+        .modify_without_undo(Change::SetKeyValue {
+            key_value: KeyValue::Tables(Some(app_meta.random_tables.clone())),
+        })
+        .await
+    {
+        Ok(_) => message,
+        Err(_) => format!(
+            "{}\n\n! Your changes could not be saved and will only apply until you close initiative.sh.",
+            message,
+        ),
+    }
+}
+
+impl fmt::Display for TableCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Add { name, entries } => write!(f, "table {} add {}", name, entries.join("; ")),
+            Self::Create { name } => write!(f, "table create {}", name),
+            Self::Delete { name } => write!(f, "table delete {}", name),
+            Self::List => write!(f, "tables"),
+            Self::Remove { name, entry } => write!(f, "table {} remove {}", name, entry),
+            Self::Roll { name } => write!(f, "roll on {}", name),
+            Self::Show { name } => write!(f, "table {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+
+    #[tokio::test]
+    async fn run_test_create_add_remove() {
+        let mut app_meta = test::app_meta::with_data_store::memory();
+
+        assert_eq!(
+            Ok("Created the table Loot. Add some entries with `table Loot add [entry]; [entry]`, where each entry looks like `1-3 rain`, `4 snow`, `2x fog` or just `sun`.".to_string()),
+            TableCommand::Create {
+                name: "Loot".to_string(),
+            }
+            .run("table create Loot", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Err("There is already a table named Loot. Use `table delete Loot` if you want to start over.".to_string()),
+            TableCommand::Create {
+                name: "loot".to_string(),
+            }
+            .run("table create loot", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Ok("# Loot\n\n| d3 | Loot |\n|---:|---|\n| 1-2 | {d6} gold |\n| 3 | a sword |\n\n_Use `roll on Loot` to roll on this table._".to_string()),
+            TableCommand::Add {
+                name: "loot".to_string(),
+                entries: vec!["1-2 {d6} gold".to_string(), "a sword".to_string()],
+            }
+            .run("table loot add 1-2 {d6} gold; a sword", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Err("The next entry of Loot should be numbered 5, not 1.".to_string()),
+            TableCommand::Add {
+                name: "Loot".to_string(),
+                entries: vec!["a shield".to_string(), "1 a bow".to_string()],
+            }
+            .run("table Loot add a shield; 1 a bow", &mut app_meta)
+            .await,
+        );
+        assert_eq!(3, app_meta.random_tables.get("Loot").unwrap().die_size());
+
+        assert_eq!(
+            Ok("Removed \"a sword\" from the table Loot.\n\n# Loot\n\n| d2 | Loot |\n|---:|---|\n| 1-2 | {d6} gold |".to_string()),
+            TableCommand::Remove {
+                name: "Loot".to_string(),
+                entry: "A Sword".to_string(),
+            }
+            .run("table Loot remove A Sword", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Err("The table Loot doesn't have an entry \"a sword\".".to_string()),
+            TableCommand::Remove {
+                name: "Loot".to_string(),
+                entry: "a sword".to_string(),
+            }
+            .run("table Loot remove a sword", &mut app_meta)
+            .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn run_test_create_invalid_name() {
+        let mut app_meta = test::app_meta();
+
+        assert_eq!(
+            Err("\"Add Ons\" can't be used as the name of a table, since it contains one of the words \"add\", \"create\", \"delete\" or \"remove\".".to_string()),
+            TableCommand::Create {
+                name: "Add Ons".to_string(),
+            }
+            .run("table create Add Ons", &mut app_meta)
+            .await,
+        );
+
+        assert!(app_meta.random_tables.is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_test_list_show_delete() {
+        let mut app_meta = test::app_meta::with_data_store::memory();
+
+        assert_eq!(
+            Ok("You haven't created any tables yet. Try `table create Weather`.".to_string()),
+            TableCommand::List.run("tables", &mut app_meta).await,
+        );
+
+        app_meta.random_tables.insert(test::table::weather());
+        app_meta
+            .random_tables
+            .insert(RandomTable::new("Loot".to_string()));
+
+        assert_eq!(
+            Ok(
+                "# Tables\n\n* `table Loot` (d0, 0 entries)\n* `table Weather` (d6, 3 entries)"
+                    .to_string()
+            ),
+            TableCommand::List.run("tables", &mut app_meta).await,
+        );
+
+        assert_eq!(
+            Ok("# Weather\n\n| d6 | Weather |\n|---:|---|\n| 1-3 | rain |\n| 4 | snow |\n| 5-6 | {Wind} wind |\n\n_Use `roll on Weather` to roll on this table._".to_string()),
+            TableCommand::Show {
+                name: "WEATHER".to_string(),
+            }
+            .run("table WEATHER", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Ok("Deleted the table Weather.".to_string()),
+            TableCommand::Delete {
+                name: "weather".to_string(),
+            }
+            .run("table delete weather", &mut app_meta)
+            .await,
+        );
+
+        assert_eq!(
+            Err("There is no table named \"Weather\". Use `tables` to see the tables you've created.".to_string()),
+            TableCommand::Show {
+                name: "Weather".to_string(),
+            }
+            .run("table Weather", &mut app_meta)
+            .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn run_test_roll() {
+        let mut app_meta = test::app_meta();
+        app_meta.random_tables.insert(test::table::weather());
+        app_meta
+            .random_tables
+            .insert(RandomTable::new("Loot".to_string()));
+
+        let output = TableCommand::Roll {
+            name: "weather".to_string(),
+        }
+        .run("roll on weather", &mut app_meta)
+        .await
+        .unwrap();
+
+        assert!(
+            [
+                "**Weather** (1 on a d6): rain",
+                "**Weather** (2 on a d6): rain",
+                "**Weather** (3 on a d6): rain",
+                "**Weather** (4 on a d6): snow",
+                "**Weather** (5 on a d6): {Wind} wind",
+                "**Weather** (6 on a d6): {Wind} wind",
+            ]
+            .contains(&output.as_str()),
+            "{}",
+            output,
+        );
+
+        assert_eq!(
+            Err("The table Loot doesn't have any entries yet. Add some with `table Loot add [entry]`.".to_string()),
+            TableCommand::Roll {
+                name: "Loot".to_string(),
+            }
+            .run("roll on Loot", &mut app_meta)
+            .await,
+        );
+    }
+}
@@ -0,0 +1,519 @@
+//! Random tables created by the user, eg. a d6 weather table, which can be rolled on with
+//! `roll on Weather`. Entries can refer to other tables or dice formulae in braces, eg. `{2d4}
+//! {Monster}`, which are rolled in turn when the entry comes up.
+
+pub use command::TableCommand;
+
+mod command;
+
+use crate::utils::{expand_formula, CaseInsensitiveStr};
+use caith::Roller;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// References to other tables are followed at most this deep, which also guards against tables
+/// that refer to themselves.
+const MAX_DEPTH: usize = 5;
+
+/// A single roll follows at most this many references in total. Without it, an entry like
+/// `{Hydra} {Hydra} {Hydra}` would grow exponentially with each level of depth.
+const MAX_EXPANSIONS: usize = 100;
+
+/// The largest die a table can use. Keeping well clear of `u32::MAX` means that sums of weights
+/// can never overflow.
+const MAX_DIE_SIZE: u32 = 1_000_000;
+
+/// Every table created by the user. The whole set is persisted as a single value in the
+/// key-value store.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct RandomTables(Vec<RandomTable>);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RandomTable {
+    pub name: String,
+    pub entries: Vec<TableEntry>,
+}
+
+/// A possible result of a table. An entry with a weight of 3 occupies a range of three numbers
+/// on the table's die, eg. `1-3 rain` on a d6.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TableEntry {
+    pub weight: u32,
+    pub text: String,
+}
+
+impl RandomTables {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RandomTable> {
+        self.0.iter()
+    }
+
+    /// Get the table with a given (case-insensitive) name.
+    pub fn get(&self, name: &str) -> Option<&RandomTable> {
+        self.0.iter().find(|table| table.name.eq_ci(name))
+    }
+
+    /// Add a table, returning the table that it replaced, if any.
+    pub fn insert(&mut self, table: RandomTable) -> Option<RandomTable> {
+        let old_table = self.remove(&table.name);
+
+        let index = self
+            .0
+            .partition_point(|other| other.name.cmp_ci(&table.name).is_lt());
+        self.0.insert(index, table);
+
+        old_table
+    }
+
+    /// Remove the table with a given (case-insensitive) name, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<RandomTable> {
+        let index = self.0.iter().position(|table| table.name.eq_ci(name))?;
+        Some(self.0.remove(index))
+    }
+
+    /// Roll on a table, returning the number rolled and the text of the resulting entry with any
+    /// references to other tables or dice resolved.
+    pub fn roll(&self, name: &str, rng: &mut impl Rng) -> Option<(u32, String)> {
+        self.roll_depth(name, rng, 0, &mut 0)
+    }
+
+    fn roll_depth(
+        &self,
+        name: &str,
+        rng: &mut impl Rng,
+        depth: usize,
+        expansions: &mut usize,
+    ) -> Option<(u32, String)> {
+        let table = self.get(name)?;
+        let die_size = table.die_size();
+
+        if die_size == 0 {
+            return None;
+        }
+
+        *expansions += 1;
+
+        let result = rng.gen_range(1..=die_size);
+        let (_, entry) = table.ranges().find(|(range, _)| range.contains(&result))?;
+
+        Some((result, self.resolve(&entry.text, rng, depth, expansions)))
+    }
+
+    /// Replace `{Table}` and `{2d6}` references in entry text with their results. Anything in
+    /// braces that is neither (or that would exceed the depth or expansion limits) is left alone.
+    fn resolve(
+        &self,
+        text: &str,
+        rng: &mut impl Rng,
+        depth: usize,
+        expansions: &mut usize,
+    ) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some((before, after)) = rest.split_once('{') {
+            result.push_str(before);
+
+            let Some((reference, after)) = after.split_once('}') else {
+                rest = after;
+                result.push('{');
+                break;
+            };
+
+            if let Some((_, text)) = (depth < MAX_DEPTH && *expansions < MAX_EXPANSIONS)
+                .then(|| self.roll_depth(reference, rng, depth + 1, expansions))
+                .flatten()
+            {
+                result.push_str(&text);
+            } else if let Some(total) = expand_formula(reference)
+                .and_then(|formula| Roller::new(&formula).ok())
+                .and_then(|roller| roller.roll_with(rng).ok())
+                .and_then(|roll| roll.as_single().map(|roll| roll.get_total()))
+            {
+                result.push_str(&total.to_string());
+            } else {
+                result.push('{');
+                result.push_str(reference);
+                result.push('}');
+            }
+
+            rest = after;
+        }
+
+        result.push_str(rest);
+        result
+    }
+}
+
+impl RandomTable {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            entries: Vec::new(),
+        }
+    }
+
+    /// The size of die needed to roll on the table, ie. the sum of the weights of its entries.
+    pub fn die_size(&self) -> u32 {
+        self.entries.iter().map(|entry| entry.weight).sum()
+    }
+
+    /// The range of numbers on the die occupied by each entry.
+    pub fn ranges(&self) -> impl Iterator<Item = (RangeInclusive<u32>, &TableEntry)> {
+        self.entries.iter().scan(0, |start, entry| {
+            let range = *start + 1..=*start + entry.weight;
+            *start += entry.weight;
+            Some((range, entry))
+        })
+    }
+
+    /// Add an entry in one of the forms `1-3 rain` or `4 snow` (numbered like a printed table,
+    /// continuing from the previous entry), `2x fog` (with a weight), or just `sun` (with a weight
+    /// of 1).
+    pub fn add_entry(&mut self, input: &str) -> Result<(), String> {
+        let input = input.trim();
+        let (first, rest) = input
+            .split_once(char::is_whitespace)
+            .map_or((input, ""), |(first, rest)| (first, rest.trim()));
+
+        let range = if let Some((start, end)) = first.split_once(['-', '–']) {
+            start.parse::<u32>().ok().zip(end.parse::<u32>().ok())
+        } else {
+            first.parse::<u32>().ok().map(|n| (n, n))
+        };
+
+        let (weight, text) = if let Some((start, end)) = range {
+            let next = self.die_size() + 1;
+
+            if start != next {
+                return Err(format!(
+                    "The next entry of {} should be numbered {}, not {}.",
+                    self.name, next, first,
+                ));
+            } else if end < start {
+                return Err(format!("\"{}\" isn't a valid range.", first));
+            }
+
+            (end - start + 1, rest)
+        } else if let Some(weight) = first
+            .strip_suffix(['x', 'X'])
+            .and_then(|weight| weight.parse::<u32>().ok())
+            .filter(|weight| *weight > 0)
+        {
+            (weight, rest)
+        } else {
+            (1, input)
+        };
+
+        if text.is_empty() {
+            return Err(format!("The entry \"{}\" doesn't have any text.", input));
+        }
+
+        if self
+            .die_size()
+            .checked_add(weight)
+            .is_none_or(|die_size| die_size > MAX_DIE_SIZE)
+        {
+            return Err(format!(
+                "The entry \"{}\" would make {} bigger than a d{}.",
+                input, self.name, MAX_DIE_SIZE,
+            ));
+        }
+
+        self.entries.push(TableEntry {
+            weight,
+            text: text.to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Remove the entry with the given (case-insensitive) text, returning it if it existed.
+    pub fn remove_entry(&mut self, text: &str) -> Option<TableEntry> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.text.eq_ci(text.trim()))?;
+        Some(self.entries.remove(index))
+    }
+}
+
+impl fmt::Display for RandomTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "# {}", self.name)?;
+
+        if self.entries.is_empty() {
+            return write!(f, "\n\nThis table doesn't have any entries yet.");
+        }
+
+        write!(
+            f,
+            "\n\n| d{} | {} |\n|---:|---|",
+            self.die_size(),
+            self.name
+        )?;
+
+        for (range, entry) in self.ranges() {
+            if range.start() == range.end() {
+                write!(f, "\n| {} | {} |", range.start(), entry.text)?;
+            } else {
+                write!(
+                    f,
+                    "\n| {}-{} | {} |",
+                    range.start(),
+                    range.end(),
+                    entry.text
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils as test;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn insert_get_remove_test() {
+        let mut tables = RandomTables::default();
+        assert!(tables.is_empty());
+
+        assert_eq!(None, tables.insert(test::table::weather()));
+        assert_eq!(
+            None,
+            tables.insert(RandomTable::new("Encounter".to_string()))
+        );
+        assert_eq!(Some(&test::table::weather()), tables.get("WEATHER"));
+        assert_eq!(None, tables.get("Loot"));
+
+        assert_eq!(
+            vec!["Encounter", "Weather"],
+            tables
+                .iter()
+                .map(|table| table.name.as_str())
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(
+            Some(test::table::weather()),
+            tables.insert(RandomTable::new("weather".to_string())),
+        );
+        assert!(tables.remove("Weather").is_some());
+        assert_eq!(None, tables.remove("weather"));
+    }
+
+    #[test]
+    fn add_entry_test() {
+        let mut table = RandomTable::new("Weather".to_string());
+
+        table.add_entry("1-3 rain").unwrap();
+        table.add_entry(" 4  snow ").unwrap();
+        table.add_entry("2x fog").unwrap();
+        table.add_entry("sun").unwrap();
+        table.add_entry("8–9 a {Storm}").unwrap();
+
+        assert_eq!(
+            vec![
+                (3, "rain"),
+                (1, "snow"),
+                (2, "fog"),
+                (1, "sun"),
+                (2, "a {Storm}"),
+            ],
+            table
+                .entries
+                .iter()
+                .map(|entry| (entry.weight, entry.text.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(9, table.die_size());
+
+        assert_eq!(
+            Err("The next entry of Weather should be numbered 10, not 11-12.".to_string()),
+            table.add_entry("11-12 hail"),
+        );
+        assert_eq!(
+            Err("\"10-9\" isn't a valid range.".to_string()),
+            table.add_entry("10-9 hail"),
+        );
+        assert_eq!(
+            Err("The entry \"3x\" doesn't have any text.".to_string()),
+            table.add_entry("3x"),
+        );
+        assert_eq!(9, table.die_size());
+    }
+
+    #[test]
+    fn add_entry_test_too_big() {
+        let mut table = RandomTable::new("Weather".to_string());
+        table.add_entry("999999x fog").unwrap();
+
+        assert_eq!(
+            Err(
+                "The entry \"4294967295x fog\" would make Weather bigger than a d1000000."
+                    .to_string()
+            ),
+            table.add_entry("4294967295x fog"),
+        );
+        assert_eq!(
+            Err("The entry \"2x sun\" would make Weather bigger than a d1000000.".to_string()),
+            table.add_entry("2x sun"),
+        );
+        assert_eq!(
+            Err(
+                "The entry \"1000000-4294967295 hail\" would make Weather bigger than a d1000000."
+                    .to_string()
+            ),
+            table.add_entry("1000000-4294967295 hail"),
+        );
+
+        table.add_entry("sun").unwrap();
+        assert_eq!(1_000_000, table.die_size());
+        assert_eq!(
+            Some(1_000_000..=1_000_000),
+            table.ranges().last().map(|(range, _)| range),
+        );
+
+        let mut tables = RandomTables::default();
+        tables.insert(table);
+        assert!(tables
+            .roll("Weather", &mut SmallRng::seed_from_u64(0))
+            .is_some());
+    }
+
+    #[test]
+    fn remove_entry_test() {
+        let mut table = test::table::weather();
+
+        assert_eq!(
+            Some(TableEntry {
+                weight: 3,
+                text: "rain".to_string(),
+            }),
+            table.remove_entry("Rain"),
+        );
+        assert_eq!(None, table.remove_entry("rain"));
+        assert_eq!(3, table.die_size());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(
+            "# Weather\n\n| d6 | Weather |\n|---:|---|\n| 1-3 | rain |\n| 4 | snow |\n| 5-6 | {Wind} wind |",
+            test::table::weather().to_string(),
+        );
+
+        assert_eq!(
+            "# Loot\n\nThis table doesn't have any entries yet.",
+            RandomTable::new("Loot".to_string()).to_string(),
+        );
+    }
+
+    #[test]
+    fn roll_test() {
+        let mut tables = RandomTables::default();
+        tables.insert(test::table::weather());
+
+        let mut wind = RandomTable::new("Wind".to_string());
+        wind.add_entry("{1d1+2} knots of").unwrap();
+        tables.insert(wind);
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut results: Vec<_> = (0..100)
+            .map(|_| tables.roll("weather", &mut rng).unwrap())
+            .collect();
+        results.sort();
+        results.dedup();
+
+        assert_eq!(
+            vec![
+                (1, "rain".to_string()),
+                (2, "rain".to_string()),
+                (3, "rain".to_string()),
+                (4, "snow".to_string()),
+                (5, "3 knots of wind".to_string()),
+                (6, "3 knots of wind".to_string()),
+            ],
+            results,
+        );
+
+        assert_eq!(None, tables.roll("Loot", &mut rng));
+        tables.insert(RandomTable::new("Loot".to_string()));
+        assert_eq!(None, tables.roll("Loot", &mut rng));
+    }
+
+    #[test]
+    fn roll_test_unresolved() {
+        let mut tables = RandomTables::default();
+
+        let mut recursive = RandomTable::new("Turtles".to_string());
+        recursive.add_entry("turtles {Turtles}").unwrap();
+        tables.insert(recursive);
+
+        let mut unknown = RandomTable::new("Unknown".to_string());
+        unknown
+            .add_entry("{nothing} and {d0} and {unclosed")
+            .unwrap();
+        tables.insert(unknown);
+
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            Some((
+                1,
+                "turtles turtles turtles turtles turtles turtles {Turtles}".to_string()
+            )),
+            tables.roll("Turtles", &mut rng),
+        );
+
+        assert_eq!(
+            Some((1, "{nothing} and {d0} and {unclosed".to_string())),
+            tables.roll("Unknown", &mut rng),
+        );
+    }
+
+    #[test]
+    fn roll_test_fan_out() {
+        let mut tables = RandomTables::default();
+
+        let mut hydra = RandomTable::new("Hydra".to_string());
+        hydra
+            .add_entry("head {Hydra} {Hydra} {Hydra} {Hydra} {Hydra} {Hydra} {Hydra} {Hydra}")
+            .unwrap();
+        tables.insert(hydra);
+
+        let (_, result) = tables
+            .roll("Hydra", &mut SmallRng::seed_from_u64(0))
+            .unwrap();
+
+        assert_eq!(MAX_EXPANSIONS, result.matches("head").count());
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        let mut tables = RandomTables::default();
+        tables.insert(test::table::weather());
+
+        assert_eq!(
+            r#"[{"name":"Weather","entries":[{"weight":3,"text":"rain"},{"weight":1,"text":"snow"},{"weight":2,"text":"{Wind} wind"}]}]"#,
+            serde_json::to_string(&tables).unwrap(),
+        );
+
+        assert_eq!(
+            tables,
+            serde_json::from_str(&serde_json::to_string(&tables).unwrap()).unwrap(),
+        );
+    }
+}
@@ -1,12 +1,8 @@
 use super::Interval;
-use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
-};
+use crate::app::{AppMeta, Runnable};
 use crate::storage::{Change, KeyValue};
-use crate::utils::CaseInsensitiveStr;
 use async_trait::async_trait;
 use std::fmt;
-use std::iter;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TimeCommand {
@@ -62,82 +58,6 @@ impl Runnable for TimeCommand {
     }
 }
 
-#[async_trait(?Send)]
-impl ContextAwareParse for TimeCommand {
-    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
-        if input.eq_ci("now") {
-            CommandMatches::new_canonical(Self::Now)
-        } else if input.in_ci(&["time", "date"]) {
-            CommandMatches::new_fuzzy(Self::Now)
-        } else if let Some(canonical_match) = input
-            .strip_prefix('+')
-            .and_then(|s| s.parse().ok())
-            .map(|interval| Self::Add { interval })
-            .or_else(|| {
-                input
-                    .strip_prefix('-')
-                    .and_then(|s| s.parse().ok())
-                    .map(|interval| Self::Sub { interval })
-            })
-        {
-            CommandMatches::new_canonical(canonical_match)
-        } else {
-            CommandMatches::default()
-        }
-    }
-}
-
-#[async_trait(?Send)]
-impl Autocomplete for TimeCommand {
-    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        if input.starts_with(&['+', '-'][..]) {
-            let suggest = |suffix: &str| -> Result<AutocompleteSuggestion, ()> {
-                let term = format!("{}{}", input, suffix);
-                let summary = if input.starts_with('+') {
-                    format!(
-                        "advance time by {}",
-                        &term[1..].parse::<Interval>()?.display_long(),
-                    )
-                } else {
-                    format!(
-                        "rewind time by {}",
-                        &term[1..].parse::<Interval>()?.display_long(),
-                    )
-                };
-                Ok(AutocompleteSuggestion::new(term, summary))
-            };
-
-            let suggest_all = || {
-                ["", "d", "h", "m", "s", "r"]
-                    .iter()
-                    .filter_map(|suffix| suggest(suffix).ok())
-            };
-
-            match input {
-                "+" | "-" => iter::once(AutocompleteSuggestion::new(
-                    format!("{}[number]", input),
-                    if input == "+" {
-                        "advance time"
-                    } else {
-                        "rewind time"
-                    },
-                ))
-                .chain(suggest_all())
-                .collect(),
-                _ => suggest_all().collect(),
-            }
-        } else if !input.is_empty() {
-            ["now", "time", "date"]
-                .into_iter()
-                .filter(|term| term.starts_with_ci(input))
-                .map(|term| AutocompleteSuggestion::new(term, "get the current time"))
-                .collect()
-        } else {
-            Vec::new()
-        }
-    }
-}
-
 impl fmt::Display for TimeCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
@@ -147,186 +67,3 @@ impl fmt::Display for TimeCommand {
         }
     }
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::test_utils as test;
-
-    #[tokio::test]
-    async fn parse_input_test() {
-        let app_meta = test::app_meta();
-
-        assert_eq!(
-            CommandMatches::new_canonical(TimeCommand::Add {
-                interval: Interval::new(0, 0, 1, 0, 0),
-            }),
-            TimeCommand::parse_input("+1m", &app_meta).await,
-        );
-
-        assert_eq!(
-            CommandMatches::new_canonical(TimeCommand::Add {
-                interval: Interval::new(1, 0, 0, 0, 0),
-            }),
-            TimeCommand::parse_input("+d", &app_meta).await,
-        );
-
-        assert_eq!(
-            CommandMatches::new_canonical(TimeCommand::Sub {
-                interval: Interval::new(0, 10, 0, 0, 0),
-            }),
-            TimeCommand::parse_input("-10h", &app_meta).await,
-        );
-
-        assert_eq!(
-            CommandMatches::default(),
-            TimeCommand::parse_input("1d2h", &app_meta).await,
-        );
-    }
-
-    #[tokio::test]
-    async fn autocomplete_test() {
-        let app_meta = test::app_meta();
-
-        test::assert_empty!(TimeCommand::autocomplete("", &app_meta).await);
-
-        test::assert_autocomplete_eq!(
-            [
-                ("+[number]", "advance time"),
-                ("+d", "advance time by 1 day"),
-                ("+h", "advance time by 1 hour"),
-                ("+m", "advance time by 1 minute"),
-                ("+s", "advance time by 1 second"),
-                ("+r", "advance time by 1 round"),
-            ],
-            TimeCommand::autocomplete("+", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [
-                ("-[number]", "rewind time"),
-                ("-d", "rewind time by 1 day"),
-                ("-h", "rewind time by 1 hour"),
-                ("-m", "rewind time by 1 minute"),
-                ("-s", "rewind time by 1 second"),
-                ("-r", "rewind time by 1 round"),
-            ],
-            TimeCommand::autocomplete("-", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [
-                ("+1d", "advance time by 1 day"),
-                ("+1h", "advance time by 1 hour"),
-                ("+1m", "advance time by 1 minute"),
-                ("+1s", "advance time by 1 second"),
-                ("+1r", "advance time by 1 round"),
-            ],
-            TimeCommand::autocomplete("+1", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [
-                ("+10d", "advance time by 10 days"),
-                ("+10h", "advance time by 10 hours"),
-                ("+10m", "advance time by 10 minutes"),
-                ("+10s", "advance time by 10 seconds"),
-                ("+10r", "advance time by 10 rounds"),
-            ],
-            TimeCommand::autocomplete("+10", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [
-                ("+10d5h", "advance time by 10 days, 5 hours"),
-                ("+10d5m", "advance time by 10 days, 5 minutes"),
-                ("+10d5s", "advance time by 10 days, 5 seconds"),
-                ("+10d5r", "advance time by 10 days, 5 rounds"),
-            ],
-            TimeCommand::autocomplete("+10d5", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [
-                ("+10D5h", "advance time by 10 days, 5 hours"),
-                ("+10D5m", "advance time by 10 days, 5 minutes"),
-                ("+10D5s", "advance time by 10 days, 5 seconds"),
-                ("+10D5r", "advance time by 10 days, 5 rounds"),
-            ],
-            TimeCommand::autocomplete("+10D5", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [("+1d", "advance time by 1 day")],
-            TimeCommand::autocomplete("+1d", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1D", "advance time by 1 day")],
-            TimeCommand::autocomplete("+1D", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1h", "advance time by 1 hour")],
-            TimeCommand::autocomplete("+1h", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1H", "advance time by 1 hour")],
-            TimeCommand::autocomplete("+1H", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1m", "advance time by 1 minute")],
-            TimeCommand::autocomplete("+1m", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1M", "advance time by 1 minute")],
-            TimeCommand::autocomplete("+1M", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1s", "advance time by 1 second")],
-            TimeCommand::autocomplete("+1s", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1S", "advance time by 1 second")],
-            TimeCommand::autocomplete("+1S", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1r", "advance time by 1 round")],
-            TimeCommand::autocomplete("+1r", &app_meta).await,
-        );
-        test::assert_autocomplete_eq!(
-            [("+1R", "advance time by 1 round")],
-            TimeCommand::autocomplete("+1R", &app_meta).await,
-        );
-    }
-
-    #[tokio::test]
-    async fn display_test() {
-        let app_meta = test::app_meta();
-
-        for command in [
-            TimeCommand::Add {
-                interval: Interval::new(2, 3, 4, 5, 6),
-            },
-            TimeCommand::Now,
-            TimeCommand::Sub {
-                interval: Interval::new(2, 3, 4, 5, 6),
-            },
-        ] {
-            let command_string = command.to_string();
-            assert_ne!("", command_string);
-
-            assert_eq!(
-                CommandMatches::new_canonical(command.clone()),
-                TimeCommand::parse_input(&command_string, &app_meta).await,
-                "{}",
-                command_string,
-            );
-
-            assert_eq!(
-                CommandMatches::new_canonical(command),
-                TimeCommand::parse_input(&command_string.to_uppercase(), &app_meta).await,
-                "{}",
-                command_string.to_uppercase(),
-            );
-        }
-    }
-}
@@ -0,0 +1,513 @@
+//! The dice syntax accepted by `roll`. Formulae are ultimately rolled by caith, but users can also
+//! type some friendlier forms, eg. `adv d20+3` or `4d6 drop lowest x6`, which are translated into
+//! caith's syntax by [`expand_formula`].
+
+use crate::utils::CaseInsensitiveStr;
+use caith::Roller;
+use std::collections::BTreeMap;
+
+/// Dice enumerated exhaustively when working out the distribution of a keep/drop roll are capped
+/// to keep `roll stats` responsive.
+const MAX_OUTCOMES: usize = 100_000;
+
+/// Some formulae (eg. "d0") parse successfully but fail when rolled, so we have to try both.
+pub fn is_valid_formula(formula: &str) -> bool {
+    Roller::new(formula).is_ok_and(|roller| roller.roll().is_ok())
+}
+
+/// Translate user input into a formula that caith can roll. In addition to caith's own syntax,
+/// this understands:
+///
+/// * a leading `adv`/`advantage` or `dis`/`disadvantage`, which roll the d20 twice and keep the
+///   higher or lower result respectively (`roll adv` on its own is a plain d20 with advantage);
+/// * `drop lowest`, `drop highest`, `keep lowest` and `keep highest`, optionally followed by a
+///   number of dice;
+/// * a trailing `x6`, which repeats the roll six times;
+/// * a label following the formula, eg. `d20+5 stealth`, equivalent to `d20+5: stealth`.
+pub fn expand_formula(input: &str) -> Option<String> {
+    let (input, reason) = match input.split_once(':') {
+        Some((input, reason)) => (input, Some(reason.trim())),
+        None => (input, None),
+    };
+
+    let mut words: Vec<&str> = input.split_whitespace().collect();
+
+    let advantage = match words.first() {
+        Some(word) if word.eq_ci("adv") || word.eq_ci("advantage") => Some("K"),
+        Some(word) if word.eq_ci("dis") || word.eq_ci("disadvantage") => Some("k"),
+        _ => None,
+    };
+
+    if advantage.is_some() {
+        words.remove(0);
+    }
+
+    // The longest run of words that makes a valid formula wins; whatever is left is the label. A
+    // bare `adv` is a formula in itself, but not if it's followed by some other dice.
+    let (formula, label) = (0..=words.len()).rev().find_map(|len| {
+        if len == 0
+            && (advantage.is_none()
+                || words
+                    .first()
+                    .is_some_and(|word| word.contains(|c: char| c.is_ascii_digit())))
+        {
+            return None;
+        }
+
+        let formula = build_formula(&words[..len], advantage)?;
+        is_valid_formula(&formula).then(|| (formula, words[len..].join(" ")))
+    })?;
+
+    match (label.is_empty(), reason) {
+        (true, None) => Some(formula),
+        (true, Some(reason)) => Some(format!("{}: {}", formula, reason)),
+        (false, None) => Some(format!("{}: {}", formula, label)),
+        (false, Some(_)) => None,
+    }
+}
+
+fn build_formula(words: &[&str], advantage: Option<&str>) -> Option<String> {
+    let mut parts: Vec<String> = Vec::with_capacity(words.len());
+    let mut repeat = None;
+    let mut words = words.iter().peekable();
+
+    while let Some(word) = words.next() {
+        let modifier = match (word.to_lowercase().as_str(), words.peek()) {
+            ("drop", Some(next)) if next.eq_ci("lowest") => Some("d"),
+            ("drop", Some(next)) if next.eq_ci("highest") => Some("D"),
+            ("keep", Some(next)) if next.eq_ci("lowest") => Some("k"),
+            ("keep", Some(next)) if next.eq_ci("highest") => Some("K"),
+            _ => None,
+        };
+
+        if let Some(modifier) = modifier {
+            words.next();
+
+            let count = words
+                .next_if(|word| word.parse::<u32>().is_ok())
+                .unwrap_or(&"1");
+
+            parts.push(format!("{}{}", modifier, count));
+        } else if let Some(count) = word
+            .strip_prefix(['x', 'X'])
+            .filter(|count| !count.is_empty() && count.chars().all(|c| c.is_ascii_digit()))
+            .filter(|_| words.peek().is_none())
+        {
+            repeat = Some(count);
+        } else {
+            parts.push(word.to_string());
+        }
+    }
+
+    let mut formula = parts.join(" ");
+
+    if let Some(keep) = advantage {
+        let rest = if formula.is_empty() || formula.starts_with(['+', '-']) {
+            formula.as_str()
+        } else {
+            ["1d20", "d20"]
+                .iter()
+                .find_map(|prefix| {
+                    formula
+                        .get(..prefix.len())
+                        .filter(|start| start.eq_ci(prefix))
+                        .map(|_| &formula[prefix.len()..])
+                })
+                .filter(|rest| !rest.starts_with(|c: char| c.is_ascii_digit()))?
+        };
+
+        formula = format!("2d20{}1{}", keep, rest);
+    }
+
+    if let Some(count) = repeat {
+        formula = format!("({})^{}", formula, count);
+    }
+
+    Some(formula)
+}
+
+/// Why [`distribution`] couldn't work out the odds of a formula.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DistributionError {
+    /// The formula isn't a plain sum of dice and numbers.
+    Unsupported,
+
+    /// The formula has too many possible totals (or keep/drop combinations) to compute in a
+    /// reasonable amount of time.
+    TooManyOutcomes,
+}
+
+/// The probability of each possible total of a formula, which must be a sum of dice and numbers,
+/// eg. `3d6`, `2d20K1+5` or `d8 + d6 - 1`. Labels are ignored. Anything fancier (exploding dice,
+/// repetition, multiplication...) is unsupported.
+pub fn distribution(formula: &str) -> Result<BTreeMap<i64, f64>, DistributionError> {
+    let formula = formula
+        .split(':')
+        .next()
+        .ok_or(DistributionError::Unsupported)?;
+    let formula: String = formula.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if formula.is_empty() {
+        return Err(DistributionError::Unsupported);
+    }
+
+    let mut terms = Vec::new();
+    let mut rest = formula.as_str();
+
+    while !rest.is_empty() {
+        let (sign, term) = if let Some(term) = rest.strip_prefix('-') {
+            (-1, term)
+        } else {
+            (1, rest.strip_prefix('+').unwrap_or(rest))
+        };
+
+        let term_len = term.find(['+', '-']).unwrap_or(term.len());
+        terms.push((sign, &term[..term_len]));
+        rest = &term[term_len..];
+    }
+
+    // Check the size of the whole formula up front, so that something like `100d100+100d100`
+    // fails straight away rather than after working out the first term.
+    let mut span = 1usize;
+    for (_, term) in terms.iter() {
+        span = term_span(term)?
+            .checked_add(span - 1)
+            .filter(|span| *span <= MAX_TOTALS)
+            .ok_or(DistributionError::TooManyOutcomes)?;
+    }
+
+    let mut result = Distribution::constant(0);
+    for (sign, term) in terms {
+        let term = term_distribution(term)?;
+        result = result.add(&if sign < 0 { term.negate() } else { term });
+    }
+
+    Ok(result.into_map())
+}
+
+/// Formulae with more possible totals than this are refused by [`distribution`]. Working out the
+/// odds of `NdS` takes roughly `N² × S` steps, so this keeps `roll stats` responsive.
+const MAX_TOTALS: usize = 5_000;
+
+/// The probabilities of a contiguous range of totals, starting at `min`.
+struct Distribution {
+    min: i64,
+    probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    fn constant(value: i64) -> Self {
+        Self {
+            min: value,
+            probabilities: vec![1.],
+        }
+    }
+
+    /// The distribution of the sum of two independent distributions.
+    fn add(&self, other: &Self) -> Self {
+        let mut probabilities = vec![0.; self.probabilities.len() + other.probabilities.len() - 1];
+
+        for (i, a) in self.probabilities.iter().enumerate() {
+            for (j, b) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += a * b;
+            }
+        }
+
+        Self {
+            min: self.min + other.min,
+            probabilities,
+        }
+    }
+
+    /// The distribution of the sum of this and a single die with the given number of sides. Each
+    /// new total is the average of a window of `sides` old totals, so a running sum does the job
+    /// in a single pass.
+    fn add_die(&self, sides: usize) -> Self {
+        let len = self.probabilities.len() + sides - 1;
+        let mut probabilities = Vec::with_capacity(len);
+        let mut window = 0.;
+
+        for i in 0..len {
+            if let Some(p) = self.probabilities.get(i) {
+                window += p;
+            }
+            if let Some(p) = i.checked_sub(sides).and_then(|i| self.probabilities.get(i)) {
+                window -= p;
+            }
+            probabilities.push(window / sides as f64);
+        }
+
+        Self {
+            min: self.min + 1,
+            probabilities,
+        }
+    }
+
+    fn negate(mut self) -> Self {
+        self.min = -(self.min + self.probabilities.len() as i64 - 1);
+        self.probabilities.reverse();
+        self
+    }
+
+    fn into_map(self) -> BTreeMap<i64, f64> {
+        (self.min..).zip(self.probabilities).collect()
+    }
+}
+
+/// A single term such as `5`, `d20` or `4d6d1`, split into the number of dice, the number of
+/// sides and any keep/drop modifier.
+fn parse_term(term: &str) -> Result<(usize, usize, &str), DistributionError> {
+    let (count, rest) = term
+        .split_once(['d', 'D'])
+        .ok_or(DistributionError::Unsupported)?;
+    let count: usize = if count.is_empty() {
+        1
+    } else {
+        count.parse().map_err(|_| DistributionError::Unsupported)?
+    };
+
+    let sides_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let sides: usize = rest[..sides_len]
+        .parse()
+        .map_err(|_| DistributionError::Unsupported)?;
+
+    if count == 0 || sides == 0 {
+        return Err(DistributionError::Unsupported);
+    }
+
+    Ok((count, sides, &rest[sides_len..]))
+}
+
+/// The number of possible totals of a single term, without working out their odds.
+fn term_span(term: &str) -> Result<usize, DistributionError> {
+    if term.parse::<i64>().is_ok() {
+        return Ok(1);
+    }
+
+    let (count, sides, _) = parse_term(term)?;
+
+    // Keeping some dice can't produce more totals than keeping all of them.
+    count
+        .checked_mul(sides - 1)
+        .and_then(|span| span.checked_add(1))
+        .ok_or(DistributionError::TooManyOutcomes)
+}
+
+/// The distribution of a single term such as `5`, `d20` or `4d6d1`.
+fn term_distribution(term: &str) -> Result<Distribution, DistributionError> {
+    if let Ok(value) = term.parse::<i64>() {
+        return Ok(Distribution::constant(value));
+    }
+
+    let (count, sides, modifier) = parse_term(term)?;
+
+    if modifier.is_empty() {
+        let mut result = Distribution::constant(0);
+
+        for _ in 0..count {
+            result = result.add_die(sides);
+        }
+
+        return Ok(result);
+    }
+
+    let (kind, n) = modifier.split_at(1);
+    let n: usize = n.parse().map_err(|_| DistributionError::Unsupported)?;
+    let kept = match kind {
+        "K" | "k" => n.min(count),
+        "D" | "d" => count.saturating_sub(n),
+        _ => return Err(DistributionError::Unsupported),
+    };
+    let keep_highest = matches!(kind, "K" | "d");
+
+    let outcomes = u32::try_from(count)
+        .ok()
+        .and_then(|count| sides.checked_pow(count))
+        .filter(|outcomes| *outcomes <= MAX_OUTCOMES)
+        .ok_or(DistributionError::TooManyOutcomes)?;
+
+    let mut result = BTreeMap::new();
+    let mut dice = vec![1; count];
+
+    for _ in 0..outcomes {
+        let mut sorted = dice.clone();
+        sorted.sort_unstable();
+
+        let total: i64 = if keep_highest {
+            sorted[count - kept..].iter().sum()
+        } else {
+            sorted[..kept].iter().sum()
+        };
+        *result.entry(total).or_insert(0.) += 1. / outcomes as f64;
+
+        // Advance to the next combination, like an odometer.
+        for die in dice.iter_mut() {
+            if *die < sides as i64 {
+                *die += 1;
+                break;
+            } else {
+                *die = 1;
+            }
+        }
+    }
+
+    // unwrap: there's always at least one outcome
+    let min = *result.keys().next().unwrap();
+    let max = *result.keys().next_back().unwrap();
+
+    Ok(Distribution {
+        min,
+        probabilities: (min..=max)
+            .map(|total| result.get(&total).copied().unwrap_or(0.))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_valid_formula_test() {
+        for formula in ["d20", "8d6: Fireball", "2d20k1+5", "(d4+1)^3", "4d6 d1"] {
+            assert!(is_valid_formula(formula), "{}", formula);
+        }
+
+        for formula in ["", "d0", "banana", "d20+5 stealth"] {
+            assert!(!is_valid_formula(formula), "{}", formula);
+        }
+    }
+
+    #[test]
+    fn expand_formula_test() {
+        for (input, expected) in [
+            ("d20", "d20"),
+            ("8d6: Fireball", "8d6: Fireball"),
+            ("(d4+1)^3", "(d4+1)^3"),
+            ("adv", "2d20K1"),
+            ("ADVANTAGE +3", "2d20K1+3"),
+            ("adv d20+3", "2d20K1+3"),
+            ("dis 1d20 - 1", "2d20k1 - 1"),
+            ("disadvantage", "2d20k1"),
+            ("4d6 drop lowest", "4d6 d1"),
+            ("4d6 drop lowest x6", "(4d6 d1)^6"),
+            ("5d6 keep highest 3", "5d6 K3"),
+            ("2d20 Drop Highest", "2d20 D1"),
+            ("d20+5 stealth", "d20+5: stealth"),
+            ("d20 + 5 sleight of hand", "d20 + 5: sleight of hand"),
+            ("adv d20+5 stealth", "2d20K1+5: stealth"),
+            ("adv perception", "2d20K1: perception"),
+            ("d6 x3 damage", "(d6)^3: damage"),
+        ] {
+            assert_eq!(
+                Some(expected.to_string()),
+                expand_formula(input),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn expand_formula_test_invalid() {
+        for input in [
+            "",
+            "banana",
+            "stealth d20",
+            "adv d6",
+            "adv d200",
+            "d20 stealth: check",
+            "x6",
+        ] {
+            assert_eq!(None, expand_formula(input), "{}", input);
+        }
+    }
+
+    #[test]
+    fn distribution_test() {
+        let d20 = distribution("d20").unwrap();
+        assert_eq!(20, d20.len());
+        assert_eq!(Some(&1), d20.keys().next());
+        assert!((d20[&20] - 0.05).abs() < 1e-9);
+
+        let three_d6 = distribution("3d6").unwrap();
+        assert_eq!(
+            (3..=18).collect::<Vec<_>>(),
+            three_d6.keys().copied().collect::<Vec<_>>()
+        );
+        assert!((three_d6[&3] - 1. / 216.).abs() < 1e-9);
+        assert!((three_d6[&10] - 27. / 216.).abs() < 1e-9);
+        assert!((three_d6.values().sum::<f64>() - 1.).abs() < 1e-9);
+
+        let advantage = distribution("2d20K1 + 5: stealth").unwrap();
+        assert_eq!(Some((&6, &(1. / 400.))), advantage.first_key_value());
+        assert!((advantage[&25] - 39. / 400.).abs() < 1e-9);
+
+        let disadvantage = distribution("2d20k1").unwrap();
+        assert!((disadvantage[&1] - 39. / 400.).abs() < 1e-9);
+
+        let ability = distribution("4d6 d1").unwrap();
+        assert!((ability[&18] - 21. / 1296.).abs() < 1e-9);
+        assert!((ability[&3] - 1. / 1296.).abs() < 1e-9);
+
+        let negative = distribution("10 - 2d4").unwrap();
+        assert_eq!(Some((&2, &(1. / 16.))), negative.first_key_value());
+        assert!((negative[&5] - 4. / 16.).abs() < 1e-9);
+        assert_eq!(Some(&8), negative.keys().next_back());
+
+        let mixed = distribution("d8 + d6 - 1").unwrap();
+        assert_eq!(Some(&1), mixed.keys().next());
+        assert_eq!(Some(&13), mixed.keys().last());
+    }
+
+    #[test]
+    fn distribution_test_unsupported() {
+        for formula in ["", "banana", "(d4+1)^3", "2d6*2", "3d6 e6", "d0"] {
+            assert_eq!(
+                Err(DistributionError::Unsupported),
+                distribution(formula),
+                "{}",
+                formula
+            );
+        }
+    }
+
+    #[test]
+    fn distribution_test_too_many_outcomes() {
+        let start = std::time::Instant::now();
+
+        for formula in [
+            "1000d1000",
+            "300d300",
+            "100d100",
+            "50d60+50d60",
+            "20d20K1",
+            "4294967296d6",
+            "d18446744073709551615",
+        ] {
+            assert_eq!(
+                Err(DistributionError::TooManyOutcomes),
+                distribution(formula),
+                "{}",
+                formula
+            );
+        }
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn distribution_test_large() {
+        let start = std::time::Instant::now();
+
+        let distribution = distribution("50d100").unwrap();
+        assert_eq!(Some(&50), distribution.keys().next());
+        assert_eq!(Some(&5000), distribution.keys().next_back());
+        assert!((distribution.values().sum::<f64>() - 1.).abs() < 1e-9);
+        assert!((distribution[&50] - 100f64.powi(-50)).abs() < 1e-100);
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+}
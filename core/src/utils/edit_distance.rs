@@ -0,0 +1,120 @@
+/// The number of single-character insertions, deletions, substitutions or transpositions of
+/// adjacent characters needed to turn `a` into `b`, ignoring case. This is the optimal string
+/// alignment variant of Damerau-Levenshtein distance, so no substring is edited more than once.
+pub fn edit_distance_ci(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().flat_map(char::to_lowercase).collect();
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+
+    let mut row_before_previous = vec![0; b.len() + 1];
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current_row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            current_row[j + 1] = if a_char == b_char {
+                previous_row[j]
+            } else {
+                1 + previous_row[j].min(previous_row[j + 1]).min(current_row[j])
+            };
+
+            if i > 0 && j > 0 && a_char == &b[j - 1] && a[i - 1] == *b_char {
+                current_row[j + 1] = current_row[j + 1].min(row_before_previous[j - 1] + 1);
+            }
+        }
+
+        std::mem::swap(&mut row_before_previous, &mut previous_row);
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b.len()]
+}
+
+/// If `target` is a plausible misspelling of `input` (or vice versa), returns the edit distance
+/// between them. Identical strings (ignoring case) are not considered similar, since they would
+/// already have matched exactly.
+///
+/// The number of typos tolerated grows with the length of the input: none for fewer than four
+/// characters, then one more for every four characters, up to a maximum of three.
+pub fn similarity_ci(input: &str, target: &str) -> Option<usize> {
+    let distance = edit_distance_ci(input, target);
+
+    if distance > 0 && distance <= max_typos(input) {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+/// As `similarity_ci`, but compares `input` against the beginning of `target`, allowing
+/// misspelled partial input to be completed. Returns `None` if `target` already starts with
+/// `input`.
+pub fn prefix_similarity_ci(input: &str, target: &str) -> Option<usize> {
+    let input_len = input.chars().count();
+
+    (input_len.saturating_sub(1)..=input_len + 1)
+        .filter_map(|prefix_len| {
+            let prefix_end = target
+                .char_indices()
+                .nth(prefix_len)
+                .map_or(target.len(), |(i, _)| i);
+
+            if prefix_end == target.len() && prefix_len > 0 {
+                None
+            } else {
+                Some(edit_distance_ci(input, &target[..prefix_end]))
+            }
+        })
+        .min()
+        .filter(|&distance| distance > 0 && distance <= max_typos(input))
+}
+
+fn max_typos(input: &str) -> usize {
+    (input.chars().count() / 4).min(3)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn edit_distance_ci_test() {
+        assert_eq!(0, edit_distance_ci("", ""));
+        assert_eq!(0, edit_distance_ci("Elendor", "eLENDOR"));
+        assert_eq!(1, edit_distance_ci("Elandor", "Elendor"));
+        assert_eq!(1, edit_distance_ci("Elndor", "Elendor"));
+        assert_eq!(1, edit_distance_ci("Eleendor", "Elendor"));
+        assert_eq!(1, edit_distance_ci("Shiled", "Shield"));
+        assert_eq!(1, edit_distance_ci("eLNEdor", "Elendor"));
+        assert_eq!(2, edit_distance_ci("Fierbal", "Fireball"));
+        assert_eq!(3, edit_distance_ci("ca", "abc"));
+        assert_eq!(3, edit_distance_ci("kitten", "sitting"));
+        assert_eq!(5, edit_distance_ci("", "Ódìnn"));
+    }
+
+    #[test]
+    fn similarity_ci_test() {
+        assert_eq!(Some(1), similarity_ci("Elandor", "Elendor"));
+        assert_eq!(Some(1), similarity_ci("fireball", "Firebal"));
+        assert_eq!(Some(2), similarity_ci("Polyphemos", "Polyphemus "));
+        assert_eq!(Some(1), similarity_ci("Shiled", "Shield"));
+
+        assert_eq!(None, similarity_ci("Elendor", "elendor"));
+        assert_eq!(None, similarity_ci("Elandor", "Elindir"));
+        assert_eq!(None, similarity_ci("Inn", "Ian"));
+        assert_eq!(None, similarity_ci("Pylos", "Polyphemus"));
+    }
+
+    #[test]
+    fn prefix_similarity_ci_test() {
+        assert_eq!(Some(1), prefix_similarity_ci("Elan", "Elendor"));
+        assert_eq!(Some(1), prefix_similarity_ci("Elnd", "Elendor"));
+        assert_eq!(Some(1), prefix_similarity_ci("Polyphen", "Polyphemus"));
+
+        assert_eq!(None, prefix_similarity_ci("Elen", "Elendor"));
+        assert_eq!(None, prefix_similarity_ci("Ela", "Elendor"));
+        assert_eq!(None, prefix_similarity_ci("Elandor", "Elendor"));
+        assert_eq!(None, prefix_similarity_ci("Odis", "Pylos"));
+    }
+}
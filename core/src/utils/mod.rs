@@ -2,10 +2,14 @@
 pub mod test_utils;
 
 pub use case_insensitive_str::CaseInsensitiveStr;
+pub use dice::{distribution, expand_formula, is_valid_formula, DistributionError};
+pub use edit_distance::{edit_distance_ci, prefix_similarity_ci, similarity_ci};
 pub use quoted_word_iter::{quoted_phrases, quoted_words};
 pub use substr::Substr;
 
 mod case_insensitive_str;
+mod dice;
+mod edit_distance;
 mod quoted_word_iter;
 mod substr;
 
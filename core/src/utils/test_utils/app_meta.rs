@@ -60,7 +60,7 @@ pub mod with_data_store {
 }
 
 pub fn with_data_store(data_store: impl DataStore + 'static) -> AppMeta {
-    AppMeta::new(data_store, &event_dispatcher)
+    AppMeta::new(data_store, event_dispatcher)
 }
 
 pub fn with_repository(repository: Repository) -> AppMeta {
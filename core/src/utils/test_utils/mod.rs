@@ -4,6 +4,8 @@ pub mod app_meta;
 pub use data_store::null as data_store;
 pub mod data_store;
 
+pub mod table;
+
 pub use world::npc;
 pub use world::place;
 pub use world::thing;
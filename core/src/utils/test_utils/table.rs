@@ -0,0 +1,21 @@
+use crate::table::{RandomTable, TableEntry};
+
+pub fn weather() -> RandomTable {
+    RandomTable {
+        name: "Weather".to_string(),
+        entries: vec![
+            TableEntry {
+                weight: 3,
+                text: "rain".to_string(),
+            },
+            TableEntry {
+                weight: 1,
+                text: "snow".to_string(),
+            },
+            TableEntry {
+                weight: 2,
+                text: "{Wind} wind".to_string(),
+            },
+        ],
+    }
+}
@@ -79,7 +79,10 @@ macro_rules! relations_builder {
 
 pub use npc::builder as npc;
 pub mod npc {
-    use crate::world::npc::{Age, Ethnicity, Gender, Npc, NpcData, NpcRelations, Size, Species};
+    use crate::world::npc::{
+        Age, Creature, Ethnicity, Gender, Npc, NpcData, NpcRelations, Personality, Size, Species,
+        StatBlock,
+    };
     use crate::world::place::Place;
     use uuid::Uuid;
 
@@ -107,6 +110,15 @@ pub mod npc {
                 location_uuid: Uuid,
                 size: Size,
                 species: Species,
+                creature: Creature,
+                personality: Personality,
+                ideal: String,
+                bond: String,
+                flaw: String,
+                feature: String,
+                mannerism: String,
+                stats: StatBlock,
+                hit_points: u16,
             ),
         );
 
@@ -116,7 +128,9 @@ pub mod npc {
 
 pub use place::builder as place;
 pub mod place {
-    use crate::world::place::{Place, PlaceData, PlaceRelations, PlaceType};
+    use crate::world::place::{
+        InventoryItem, Place, PlaceData, PlaceRelations, PlaceType, Price, Quality,
+    };
     use uuid::Uuid;
 
     pub use super::odyssey::{greece, ithaca, styx};
@@ -138,7 +152,13 @@ pub mod place {
             (
                 location_uuid: Uuid,
                 subtype: PlaceType,
-                description: String
+                description: String,
+                quality: Quality,
+                price: Price,
+                wares: Vec<String>,
+                atmosphere: String,
+                hook: String,
+                inventory: Vec<InventoryItem>,
             )
         );
 
@@ -183,7 +203,9 @@ pub mod thing {
 mod odyssey {
     use super::*;
 
-    use crate::world::npc::{Age, Ethnicity, Gender, Npc, NpcData, NpcRelations, Size, Species};
+    use crate::world::npc::{
+        Age, Ethnicity, Gender, Npc, NpcData, NpcRelations, Personality, Size, Species,
+    };
     use crate::world::place::{Place, PlaceData, PlaceRelations, PlaceType};
     use uuid::Uuid;
 
@@ -320,6 +342,12 @@ mod odyssey {
                 weight: 180,
             })
             .species(Species::Human)
+            .personality(Personality::Curious)
+            .ideal("Family. Blood is thicker than water.".to_string())
+            .bond("Will do anything to return home to Ithaca.".to_string())
+            .flaw("Can't resist taunting a defeated foe.".to_string())
+            .feature("A scar on the thigh from a boar hunt.".to_string())
+            .mannerism("Spins a tall tale at every opportunity.".to_string())
             .build(),
         NpcRelations {
             location: Some((styx(), None)),
@@ -1,8 +1,10 @@
 use super::ParsedThing;
 use crate::app::{AppMeta, Autocomplete, AutocompleteSuggestion};
 use crate::utils::{quoted_words, CaseInsensitiveStr};
-use crate::world::npc::{Age, Ethnicity, Gender, NpcData, Species};
-use crate::world::place::{PlaceData, PlaceType};
+use crate::world::npc::{
+    Age, Creature, Ethnicity, Gender, NpcData, Personality, Species, StatBlock,
+};
+use crate::world::place::{PlaceData, PlaceType, Price, Quality};
 use crate::world::thing::ThingData;
 use async_trait::async_trait;
 use std::collections::HashSet;
@@ -241,11 +243,23 @@ impl Autocomplete for PlaceData {
         autocomplete_terms::<ParsedThing<PlaceData>>(
             input,
             &["place"],
-            &[(
-                "place type",
-                "specify a place type (eg. inn)",
-                &PlaceType::get_words().collect::<Vec<_>>(),
-            )],
+            &[
+                (
+                    "place type",
+                    "specify a place type (eg. inn)",
+                    &PlaceType::get_words().collect::<Vec<_>>(),
+                ),
+                (
+                    "quality",
+                    "specify a quality (eg. \"shabby\")",
+                    &Quality::get_words().collect::<Vec<_>>(),
+                ),
+                (
+                    "prices",
+                    "specify a price level (eg. \"cheap\")",
+                    &Price::get_words().collect::<Vec<_>>(),
+                ),
+            ],
         )
     }
 }
@@ -303,10 +317,22 @@ impl Autocomplete for NpcData {
                         "specify a gender",
                         &Gender::get_words().collect::<Vec<_>>(),
                     ),
+                    (
+                        "personality",
+                        "specify a personality (eg. \"grumpy\")",
+                        &Personality::get_words().collect::<Vec<_>>(),
+                    ),
                     (
                         "species",
                         "specify a species (eg. \"dwarf\")",
-                        &Species::get_words().collect::<Vec<_>>(),
+                        &Species::get_words()
+                            .chain(Creature::get_words())
+                            .collect::<Vec<_>>(),
+                    ),
+                    (
+                        "stat block",
+                        "specify a stat block (eg. \"guard\")",
+                        &StatBlock::get_words().collect::<Vec<_>>(),
                     ),
                 ],
             )
@@ -412,6 +438,7 @@ mod test {
             [
                 ("inn", "create inn"),
                 ("imports-shop", "create imports-shop"),
+                ("inexpensive", "create place"),
                 ("island", "create island"),
             ],
             PlaceData::autocomplete("i", &test::app_meta()).await,
@@ -421,6 +448,7 @@ mod test {
             [
                 ("an inn", "create inn"),
                 ("an imports-shop", "create imports-shop"),
+                ("an inexpensive", "create place"),
                 ("an island", "create island"),
             ],
             PlaceData::autocomplete("an i", &test::app_meta()).await,
@@ -476,6 +504,11 @@ mod test {
                 ("elf [age]", "specify an age (eg. \"elderly\")"),
                 ("elf [ethnicity]", "specify an ethnicity (eg. \"elvish\")"),
                 ("elf [gender]", "specify a gender"),
+                (
+                    "elf [personality]",
+                    "specify a personality (eg. \"grumpy\")"
+                ),
+                ("elf [stat block]", "specify a stat block (eg. \"guard\")"),
                 ("elf named [name]", "specify a name"),
             ],
             NpcData::autocomplete("elf ", &test::app_meta()).await,
@@ -485,6 +518,11 @@ mod test {
             [
                 ("human [age]", "specify an age (eg. \"elderly\")"),
                 ("human [gender]", "specify a gender"),
+                (
+                    "human [personality]",
+                    "specify a personality (eg. \"grumpy\")"
+                ),
+                ("human [stat block]", "specify a stat block (eg. \"guard\")"),
                 ("human named [name]", "specify a name"),
             ],
             NpcData::autocomplete("human ", &test::app_meta()).await,
@@ -1,15 +1,11 @@
-use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandAlias, CommandMatches, ContextAwareParse,
-    Runnable,
-};
-use crate::storage::{Change, Record, RepositoryError, StorageCommand};
-use crate::utils::{quoted_words, CaseInsensitiveStr};
-use crate::world::npc::NpcData;
-use crate::world::place::PlaceData;
-use crate::world::thing::{Thing, ThingData};
-use crate::world::Field;
+use crate::app::{AppMeta, AutocompleteSuggestion, Block, CommandAlias, Inline, Output, Runnable};
+use crate::storage::{Change, KeyValue, Record, RepositoryError, StorageCommand};
+use crate::utils::{capitalize, CaseInsensitiveStr};
+use crate::world::place::{InventoryView, PlaceRelations, SettlementSize};
+use crate::world::thing::{Thing, ThingData, ThingField, ThingRelations};
+use crate::world::{Field, Vocabulary, VocabularyList};
 use async_trait::async_trait;
-use futures::join;
+use rand::Rng;
 use std::fmt;
 use std::ops::Range;
 
@@ -18,15 +14,37 @@ mod parse;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WorldCommand {
+    AddWords {
+        list: VocabularyList,
+        words: Vec<String>,
+    },
     Create {
-        parsed_thing_data: ParsedThing<ThingData>,
+        parsed_thing_data: Box<ParsedThing<ThingData>>,
     },
     CreateMultiple {
-        thing_data: ThingData,
+        thing_data: Box<ThingData>,
     },
     Edit {
         name: String,
-        parsed_diff: ParsedThing<ThingData>,
+        parsed_diff: Box<ParsedThing<ThingData>>,
+    },
+    Inventory {
+        name: String,
+    },
+    Lock {
+        name: String,
+        field: Option<ThingField>,
+    },
+    Restock {
+        name: String,
+    },
+    Reroll {
+        name: String,
+        field: Option<ThingField>,
+    },
+    Unlock {
+        name: String,
+        field: Option<ThingField>,
     },
 }
 
@@ -41,103 +59,9 @@ pub struct ParsedThing<T> {
 impl Runnable for WorldCommand {
     async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         match self {
-            Self::Create { parsed_thing_data } => {
-                let original_thing_data = parsed_thing_data.thing_data;
-                let unknown_words = parsed_thing_data.unknown_words.to_owned();
-                let mut output = None;
-
-                for _ in 0..10 {
-                    let mut thing_data = original_thing_data.clone();
-                    thing_data.regenerate(&mut app_meta.rng, &app_meta.demographics);
-                    let mut command_alias = None;
-
-                    let (message, change) = match thing_data.name() {
-                        Field::Locked(Some(name)) => {
-                            (
-                                Some(format!(
-                                    "\n\n_Because you specified a name, {name} has been automatically added to your `journal`. Use `undo` to remove {them}._",
-                                    name = name,
-                                    them = thing_data.gender().them(),
-                                )),
-                                Change::CreateAndSave { thing_data, uuid: None },
-                            )
-                        }
-                        Field::Unlocked(Some(name)) => {
-                            command_alias = Some(CommandAlias::literal(
-                                "save",
-                                format!("save {}", name),
-                                StorageCommand::Save {
-                                    name: name.to_string(),
-                                }
-                                .into(),
-                            ));
-
-                            app_meta.command_aliases.insert(CommandAlias::literal(
-                                "more",
-                                format!("create {}", original_thing_data.display_description()),
-                                WorldCommand::CreateMultiple {
-                                    thing_data: original_thing_data.clone(),
-                                }
-                                .into(),
-                            ));
-
-                            (
-                                Some(format!(
-                                    "\n\n_{name} has not yet been saved. Use ~save~ to save {them} to your `journal`. For more suggestions, type ~more~._",
-                                    name = name,
-                                    them = thing_data.gender().them(),
-                                )),
-                                Change::Create { thing_data, uuid: None },
-                            )
-                        }
-                        _ => (None, Change::Create { thing_data, uuid: None }),
-                    };
-
-                    match app_meta.repository.modify(change).await {
-                        Ok(Some(Record { thing, .. })) => {
-                            output = Some(format!(
-                                "{}{}",
-                                thing.display_details(
-                                    app_meta
-                                        .repository
-                                        .load_relations(&thing)
-                                        .await
-                                        .unwrap_or_default(),
-                                ),
-                                message.as_ref().map_or("", String::as_str),
-                            ));
-
-                            if let Some(alias) = command_alias {
-                                app_meta.command_aliases.insert(alias);
-                            }
-
-                            break;
-                        }
-
-                        Err((
-                            Change::Create { thing_data, .. } | Change::CreateAndSave { thing_data, .. },
-                            RepositoryError::NameAlreadyExists(other_thing),
-                        )) => if thing_data.name().is_locked() {
-                            return Err(format!(
-                                "That name is already in use by {}.",
-                                other_thing.display_summary(),
-                            ));
-                        },
-
-                        Err((Change::Create { thing_data, .. }, RepositoryError::MissingName)) => return Err(format!("There is no name generator implemented for that type. You must specify your own name using `{} named [name]`.", thing_data.display_description())),
-
-                        Ok(None) | Err(_) => return Err("An error occurred.".to_string()),
-                    }
-                }
-
-                if let Some(output) = output {
-                    Ok(append_unknown_words_notice(output, input, unknown_words))
-                } else {
-                    Err(format!(
-                        "Couldn't create a unique {} name.",
-                        original_thing_data.display_description(),
-                    ))
-                }
+            Self::AddWords { list, words } => add_words(app_meta, list, words).await,
+            command @ (Self::Create { .. } | Self::Edit { .. }) => {
+                Output::to_markdown_result(command.run_output(input, app_meta).await)
             }
             Self::CreateMultiple { thing_data } => {
                 let mut output = format!(
@@ -149,8 +73,8 @@ impl Runnable for WorldCommand {
                     let mut thing_output = None;
 
                     for _ in 0..10 {
-                        let mut thing_data = thing_data.clone();
-                        thing_data.regenerate(&mut app_meta.rng, &app_meta.demographics);
+                        let mut thing_data = (*thing_data).clone();
+                        thing_data.regenerate_with_seed(app_meta.rng.gen(), &app_meta.demographics);
 
                         match app_meta
                             .repository
@@ -200,230 +124,353 @@ impl Runnable for WorldCommand {
 
                 Ok(output)
             }
-            Self::Edit { name, parsed_diff } => {
-                let ParsedThing {
-                    thing_data: thing_diff,
-                    unknown_words,
-                    word_count: _,
-                } = parsed_diff;
+            Self::Reroll { name, field } => {
+                let thing = match app_meta.repository.get_by_name(&name).await {
+                    Ok(Record { thing, .. }) => thing,
+                    Err(_) => return Err(format!(r#"There is no entity named "{}"."#, name)),
+                };
+
+                let fields: Vec<ThingField> = field.into_iter().collect();
+
+                if fields.is_empty()
+                    && thing
+                        .data
+                        .fields()
+                        .into_iter()
+                        .all(|field| thing.data.is_field_locked(field))
+                {
+                    return Err(format!(
+                        "All of {}'s fields are locked. To reroll one anyway, specify it, eg. `reroll {}'s name`.",
+                        name, name,
+                    ));
+                }
+
+                let mut diff = None;
 
-                let thing_type = thing_diff.as_str();
+                for _ in 0..10 {
+                    let candidate =
+                        thing
+                            .data
+                            .reroll(&fields, &mut app_meta.rng, &app_meta.demographics);
+
+                    // A rerolled name must not collide with that of another thing.
+                    if let Some(new_name) = candidate.name().value() {
+                        if app_meta
+                            .repository
+                            .get_by_name(new_name)
+                            .await
+                            .is_ok_and(|record| record.thing.uuid != thing.uuid)
+                        {
+                            continue;
+                        }
+                    }
 
-                match app_meta.repository.modify(Change::Edit {
+                    diff = Some(candidate);
+                    break;
+                }
+
+                let diff =
+                    diff.ok_or_else(|| format!("Couldn't generate a unique name for {}.", name))?;
+
+                // The diff came from this thing, so the types are guaranteed to match.
+                let mut rerolled_data = thing.data.clone();
+                let _ = rerolled_data.try_apply_reroll_diff(&mut diff.clone());
+
+                let changes: Vec<String> = thing
+                    .data
+                    .fields()
+                    .into_iter()
+                    .filter_map(|field| {
+                        let (before, after) = (
+                            thing.data.describe_field(field),
+                            rerolled_data.describe_field(field),
+                        );
+
+                        if before == after {
+                            None
+                        } else {
+                            Some(format!(
+                                "**{}:** {} → {}",
+                                capitalize(field.as_str()),
+                                before.as_deref().unwrap_or("N/A"),
+                                after.as_deref().unwrap_or("N/A"),
+                            ))
+                        }
+                    })
+                    .collect();
+
+                if changes.is_empty() {
+                    return Err(format!("Rerolling {} didn't change anything.", name));
+                }
+
+                match app_meta
+                    .repository
+                    .modify(Change::Reroll {
+                        uuid: thing.uuid,
                         name: name.clone(),
-                        uuid: None,
-                        diff: thing_diff,
-                    }).await {
-                    Ok(Some(Record { thing, .. })) => Ok(
-                        if matches!(app_meta.repository.undo_history().next(), Some(Change::EditAndUnsave { .. })) {
-                            format!(
-                                "{}\n\n_{} was successfully edited and automatically saved to your `journal`. Use `undo` to reverse this._",
-                                thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
-                                name,
-                            )
+                        diff,
+                    })
+                    .await
+                {
+                    Ok(Some(Record { thing, .. })) => Ok(format!(
+                        "{}\n\n{}\n\n{}",
+                        thing.display_details(
+                            app_meta
+                                .repository
+                                .load_relations(&thing)
+                                .await
+                                .unwrap_or_default(),
+                        ),
+                        changes.join("\\\n"),
+                        if matches!(
+                            app_meta.repository.undo_history().next(),
+                            Some(Change::RerollAndUnsave { .. }),
+                        ) {
+                            format!("_{} was successfully rerolled and automatically saved to your `journal`. Use `undo` to reverse this._", name)
                         } else {
                             format!(
-                                "{}\n\n_{} was successfully edited. Use `undo` to reverse this._",
-                                thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                                "_{} was successfully rerolled. Use `undo` to reverse this._",
                                 name,
                             )
-                        }
-                    ),
-                    Err((_, RepositoryError::NotFound)) => Err(format!(r#"There is no {} named "{}"."#, thing_type, name)),
-                    _ => Err(format!("Couldn't edit `{}`.", name)),
+                        },
+                    )),
+                    _ => Err(format!("Couldn't reroll `{}`.", name)),
                 }
-                .map(|s| append_unknown_words_notice(s, input, unknown_words))
             }
+            Self::Inventory { name } => inventory(app_meta, name, false).await,
+            Self::Restock { name } => inventory(app_meta, name, true).await,
+            Self::Lock { name, field } => set_locked(app_meta, name, field, true).await,
+            Self::Unlock { name, field } => set_locked(app_meta, name, field, false).await,
         }
     }
-}
 
-#[async_trait(?Send)]
-impl ContextAwareParse for WorldCommand {
-    async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
-        let mut matches = CommandMatches::default();
-
-        if let Some(Ok(parsed_thing_data)) = input
-            .strip_prefix_ci("create ")
-            .map(|s| s.parse::<ParsedThing<ThingData>>())
-        {
-            if parsed_thing_data.unknown_words.is_empty() {
-                matches.push_canonical(Self::Create { parsed_thing_data });
-            } else {
-                matches.push_fuzzy(Self::Create { parsed_thing_data });
-            }
-        } else if let Ok(parsed_thing_data) = input.parse::<ParsedThing<ThingData>>() {
-            matches.push_fuzzy(Self::Create { parsed_thing_data });
-        }
+    async fn run_output(self, input: &str, app_meta: &mut AppMeta) -> Result<Output, Output> {
+        match self {
+            Self::Create { parsed_thing_data } => {
+                let ParsedThing {
+                    thing_data,
+                    unknown_words,
+                    word_count: _,
+                } = *parsed_thing_data;
 
-        if let Some(word) = quoted_words(input)
-            .skip(1)
-            .find(|word| word.as_str().eq_ci("is"))
-        {
-            let (name, description) = (
-                input[..word.range().start].trim(),
-                input[word.range().end..].trim(),
-            );
+                let (thing, relations, note) = create(thing_data, app_meta)
+                    .await
+                    .map_err(|message| Output::error(&message))?;
+                let mut blocks = vec![thing.display_details(relations).to_block()];
+
+                let (name, them) = (thing.name().to_string(), thing.gender().them());
+                match note {
+                    Some(CreateNote::Saved) => {
+                        blocks.push(Block::paragraph(vec![Inline::emphasis(vec![
+                            Inline::text(format!(
+                                "Because you specified a name, {} has been automatically added to your ",
+                                name,
+                            )),
+                            Inline::command("journal"),
+                            Inline::text(". Use "),
+                            Inline::command("undo"),
+                            Inline::text(format!(" to remove {}.", them)),
+                        ])]))
+                    }
+                    Some(CreateNote::Unsaved) => {
+                        blocks.push(Block::paragraph(vec![Inline::emphasis(vec![
+                            Inline::text(format!("{} has not yet been saved. Use ", name)),
+                            Inline::suggestion("save"),
+                            Inline::text(format!(" to save {} to your ", them)),
+                            Inline::command("journal"),
+                            Inline::text(". For more suggestions, type "),
+                            Inline::suggestion("more"),
+                            Inline::text("."),
+                        ])]))
+                    }
+                    None => {}
+                }
 
-            let (diff, thing): (Result<ParsedThing<ThingData>, ()>, Option<Thing>) =
-                if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(name).await {
-                    (
-                        match thing.data {
-                            ThingData::Npc(_) => description
-                                .parse::<ParsedThing<NpcData>>()
-                                .map(|t| t.into_thing_data()),
-                            ThingData::Place(_) => description
-                                .parse::<ParsedThing<PlaceData>>()
-                                .map(|t| t.into_thing_data()),
-                        }
-                        .or_else(|_| description.parse()),
-                        Some(thing),
-                    )
+                blocks.extend(unknown_words_notice(input, &unknown_words));
+                Ok(Output::new(blocks))
+            }
+            Self::Edit { name, parsed_diff } => {
+                let ParsedThing {
+                    thing_data: thing_diff,
+                    unknown_words,
+                    word_count: _,
+                } = *parsed_diff;
+
+                let (thing, relations, autosaved) = edit(&name, thing_diff, app_meta)
+                    .await
+                    .map_err(|message| Output::error(&message))?;
+
+                let mut note = if autosaved {
+                    vec![
+                        Inline::text(format!(
+                            "{} was successfully edited and automatically saved to your ",
+                            name,
+                        )),
+                        Inline::command("journal"),
+                        Inline::text(". Use "),
+                    ]
                 } else {
-                    // This will be an error when we try to run the command, but for now we'll pretend
-                    // it's valid so that we can provide a more coherent message.
-                    (description.parse(), None)
+                    vec![Inline::text(format!(
+                        "{} was successfully edited. Use ",
+                        name,
+                    ))]
                 };
-
-            if let Ok(mut diff) = diff {
-                let name = thing
-                    .map(|t| t.name().to_string())
-                    .unwrap_or_else(|| name.to_string());
-
-                diff.unknown_words.iter_mut().for_each(|range| {
-                    *range = range.start + word.range().end + 1..range.end + word.range().end + 1
-                });
-
-                matches.push_fuzzy(Self::Edit {
-                    name,
-                    parsed_diff: diff,
-                });
+                note.extend([Inline::command("undo"), Inline::text(" to reverse this.")]);
+
+                let mut blocks = vec![
+                    thing.display_details(relations).to_block(),
+                    Block::paragraph(vec![Inline::emphasis(note)]),
+                ];
+                blocks.extend(unknown_words_notice(input, &unknown_words));
+                Ok(Output::new(blocks))
             }
+            command => Output::from_result(command.run(input, app_meta).await),
         }
-
-        matches
     }
 }
 
-#[async_trait(?Send)]
-impl Autocomplete for WorldCommand {
-    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        let mut suggestions = Vec::new();
-
-        let (mut place_suggestions, mut npc_suggestions) = join!(
-            PlaceData::autocomplete(input, app_meta),
-            NpcData::autocomplete(input, app_meta),
-        );
-
-        suggestions.append(&mut place_suggestions);
-        suggestions.append(&mut npc_suggestions);
-
-        let mut input_words = quoted_words(input).skip(1);
-
-        if let Some((is_word, next_word)) = input_words
-            .find(|word| word.as_str().eq_ci("is"))
-            .and_then(|word| input_words.next().map(|next_word| (word, next_word)))
-        {
-            if let Ok(Record { thing, .. }) = app_meta
-                .repository
-                .get_by_name(input[..is_word.range().start].trim())
-                .await
-            {
-                let split_pos = input.len() - input[is_word.range().end..].trim_start().len();
+/// The note following a newly created thing, depending on whether it was saved to the journal.
+enum CreateNote {
+    Saved,
+    Unsaved,
+}
 
-                let edit_suggestions = match thing.data {
-                    ThingData::Npc(_) => {
-                        NpcData::autocomplete(input[split_pos..].trim_start(), app_meta)
+/// Generate a thing from a (possibly partial) description. Named things are saved to the journal
+/// straight away, while generated names are retried a few times if they're already in use.
+async fn create(
+    original_thing_data: ThingData,
+    app_meta: &mut AppMeta,
+) -> Result<(Thing, ThingRelations, Option<CreateNote>), String> {
+    for _ in 0..10 {
+        let mut thing_data = original_thing_data.clone();
+        thing_data.regenerate_with_seed(app_meta.rng.gen(), &app_meta.demographics);
+        let mut command_alias = None;
+
+        let (note, change) = match thing_data.name() {
+            Field::Locked(Some(_)) => (
+                Some(CreateNote::Saved),
+                Change::CreateAndSave {
+                    thing_data,
+                    uuid: None,
+                },
+            ),
+            Field::Unlocked(Some(name)) => {
+                command_alias = Some(CommandAlias::literal(
+                    "save",
+                    format!("save {}", name),
+                    StorageCommand::Save {
+                        name: name.to_string(),
                     }
-                    ThingData::Place(_) => {
-                        PlaceData::autocomplete(input[split_pos..].trim_start(), app_meta)
+                    .into(),
+                ));
+
+                app_meta.command_aliases.insert(CommandAlias::literal(
+                    "more",
+                    format!("create {}", original_thing_data.display_description()),
+                    WorldCommand::CreateMultiple {
+                        thing_data: Box::new(original_thing_data.clone()),
                     }
-                }
-                .await;
-
-                suggestions.extend(edit_suggestions.into_iter().map(|suggestion| {
-                    AutocompleteSuggestion::new(
-                        format!("{}{}", &input[..split_pos], suggestion.term),
-                        format!("edit {}", thing.as_str()),
-                    )
-                }));
-
-                if next_word.as_str().in_ci(&["named", "called"]) && input_words.next().is_some() {
-                    suggestions.push(AutocompleteSuggestion::new(
-                        input.to_string(),
-                        format!("rename {}", thing.as_str()),
-                    ));
-                }
-            }
-        }
+                    .into(),
+                ));
 
-        if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(input.trim_end()).await {
-            suggestions.push(AutocompleteSuggestion::new(
-                if input.ends_with(char::is_whitespace) {
-                    format!("{}is [{} description]", input, thing.as_str())
-                } else {
-                    format!("{} is [{} description]", input, thing.as_str())
+                (
+                    Some(CreateNote::Unsaved),
+                    Change::Create {
+                        thing_data,
+                        uuid: None,
+                    },
+                )
+            }
+            _ => (
+                None,
+                Change::Create {
+                    thing_data,
+                    uuid: None,
                 },
-                format!("edit {}", thing.as_str()),
-            ));
-        } else if let Some((last_word_index, last_word)) =
-            quoted_words(input).enumerate().skip(1).last()
-        {
-            if "is".starts_with_ci(last_word.as_str()) {
-                if let Ok(Record { thing, .. }) = app_meta
+            ),
+        };
+
+        match app_meta.repository.modify(change).await {
+            Ok(Some(Record { thing, .. })) => {
+                let relations = app_meta
                     .repository
-                    .get_by_name(input[..last_word.range().start].trim())
+                    .load_relations(&thing)
                     .await
-                {
-                    suggestions.push(AutocompleteSuggestion::new(
-                        if last_word.range().end == input.len() {
-                            format!(
-                                "{}is [{} description]",
-                                &input[..last_word.range().start],
-                                thing.as_str(),
-                            )
-                        } else {
-                            format!("{}[{} description]", &input, thing.as_str())
-                        },
-                        format!("edit {}", thing.as_str()),
-                    ))
+                    .unwrap_or_default();
+
+                if let Some(alias) = command_alias {
+                    app_meta.command_aliases.insert(alias);
                 }
-            } else if let Some(suggestion) = ["named", "called"]
-                .iter()
-                .find(|s| s.starts_with_ci(last_word.as_str()))
-            {
-                let second_last_word = quoted_words(input).nth(last_word_index - 1).unwrap();
-
-                if second_last_word.as_str().eq_ci("is") {
-                    if let Ok(Record { thing, .. }) = app_meta
-                        .repository
-                        .get_by_name(input[..second_last_word.range().start].trim())
-                        .await
-                    {
-                        suggestions.push(AutocompleteSuggestion::new(
-                            if last_word.range().end == input.len() {
-                                format!(
-                                    "{}{} [name]",
-                                    &input[..last_word.range().start],
-                                    suggestion,
-                                )
-                            } else {
-                                format!("{}[name]", input)
-                            },
-                            format!("rename {}", thing.as_str()),
-                        ));
-                    }
+
+                return Ok((thing, relations, note));
+            }
+
+            Err((
+                Change::Create { thing_data, .. } | Change::CreateAndSave { thing_data, .. },
+                RepositoryError::NameAlreadyExists(other_thing),
+            )) => {
+                if thing_data.name().is_locked() {
+                    return Err(format!(
+                        "That name is already in use by {}.",
+                        other_thing.display_summary(),
+                    ));
                 }
             }
+
+            Err((Change::Create { thing_data, .. }, RepositoryError::MissingName)) => return Err(format!("There is no name generator implemented for that type. You must specify your own name using `{} named [name]`.", thing_data.display_description())),
+
+            Ok(None) | Err(_) => return Err("An error occurred.".to_string()),
         }
+    }
+
+    Err(format!(
+        "Couldn't create a unique {} name.",
+        original_thing_data.display_description(),
+    ))
+}
+
+/// Apply a diff to the thing with the given name, returning the edited thing and whether it was
+/// automatically saved to the journal as a result.
+async fn edit(
+    name: &str,
+    thing_diff: ThingData,
+    app_meta: &mut AppMeta,
+) -> Result<(Thing, ThingRelations, bool), String> {
+    let thing_type = thing_diff.as_str();
+
+    match app_meta
+        .repository
+        .modify(Change::Edit {
+            name: name.to_string(),
+            uuid: None,
+            diff: thing_diff,
+        })
+        .await
+    {
+        Ok(Some(Record { thing, .. })) => {
+            let autosaved = matches!(
+                app_meta.repository.undo_history().next(),
+                Some(Change::EditAndUnsave { .. }),
+            );
+            let relations = app_meta
+                .repository
+                .load_relations(&thing)
+                .await
+                .unwrap_or_default();
 
-        suggestions
+            Ok((thing, relations, autosaved))
+        }
+        Err((_, RepositoryError::NotFound)) => {
+            Err(format!(r#"There is no {} named "{}"."#, thing_type, name))
+        }
+        _ => Err(format!("Couldn't edit `{}`.", name)),
     }
 }
 
 impl fmt::Display for WorldCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::AddWords { list, words } => write!(f, "add {}: {}", list, words.join(", ")),
             Self::Create { parsed_thing_data } => write!(
                 f,
                 "create {}",
@@ -440,6 +487,23 @@ impl fmt::Display for WorldCommand {
                     parsed_diff.thing_data.display_description()
                 )
             }
+            Self::Inventory { name } => write!(f, "inventory of {}", name),
+            Self::Restock { name } => write!(f, "restock {}", name),
+            Self::Reroll {
+                name,
+                field: Some(field),
+            } => write!(f, "reroll {}'s {}", name, field),
+            Self::Reroll { name, field: None } => write!(f, "reroll {}", name),
+            Self::Lock {
+                name,
+                field: Some(field),
+            } => write!(f, "lock {}'s {}", name, field),
+            Self::Lock { name, field: None } => write!(f, "lock {}", name),
+            Self::Unlock {
+                name,
+                field: Some(field),
+            } => write!(f, "unlock {}'s {}", name, field),
+            Self::Unlock { name, field: None } => write!(f, "unlock {}", name),
         }
     }
 }
@@ -470,48 +534,334 @@ impl<T: Into<ThingData>> From<ParsedThing<T>> for ThingData {
     }
 }
 
-fn append_unknown_words_notice(
-    mut output: String,
+/// Parse input of the form "[name]" or "[name]'s [field]", as used by the `reroll`, `lock`, and
+/// `unlock` commands. If a matching thing exists, its canonical name is returned.
+pub(crate) async fn parse_name_field(
     input: &str,
-    unknown_words: Vec<Range<usize>>,
-) -> String {
-    if !unknown_words.is_empty() {
-        output.push_str(
-            "\n\n! initiative.sh doesn't know some of those words, but it did its best.\n\n\\> ",
-        );
+    app_meta: &AppMeta,
+) -> Option<(String, Option<ThingField>)> {
+    let input = input.trim();
 
-        {
-            let mut pos = 0;
-            for word_range in unknown_words.iter() {
-                output.push_str(&input[pos..word_range.start]);
-                pos = word_range.end;
-                output.push_str("**");
-                output.push_str(&input[word_range.clone()]);
-                output.push_str("**");
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Some(pos) = input.rfind("'s ").or_else(|| input.rfind("'S ")) {
+        if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(&input[..pos]).await {
+            if let Some(field) = thing.data.parse_field(input[pos + 3..].trim()) {
+                return Some((thing.name().to_string(), Some(field)));
             }
-            output.push_str(&input[pos..]);
         }
+    }
+
+    if let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(input).await {
+        Some((thing.name().to_string(), None))
+    } else {
+        Some((input.to_string(), None))
+    }
+}
+
+/// Autocomplete the field of a `reroll`, `lock`, or `unlock` command once the input reads
+/// "[name]'s ". Names themselves are suggested by the commands' `name` tokens.
+pub(crate) async fn autocomplete_name_field(
+    input: &str,
+    rest: &str,
+    verb: &str,
+    app_meta: &AppMeta,
+) -> Vec<AutocompleteSuggestion> {
+    let Some(pos) = rest.rfind("'s ").or_else(|| rest.rfind("'S ")) else {
+        return Vec::new();
+    };
+    let Ok(Record { thing, .. }) = app_meta.repository.get_by_name(&rest[..pos]).await else {
+        return Vec::new();
+    };
+
+    let partial = &rest[pos + 3..];
+    let prefix = &input[..input.len() - partial.len()];
+
+    thing
+        .data
+        .field_words()
+        .into_iter()
+        .filter(|word| word.starts_with_ci(partial))
+        .map(|word| {
+            AutocompleteSuggestion::new(
+                format!("{}{}", prefix, word),
+                format!("{} {} {}", verb, thing.as_str(), word),
+            )
+        })
+        .collect()
+}
+
+/// Show the stock of a shop, generating it if it doesn't exist yet or if a restock has been
+/// requested. The stock is stored on the place so that it stays the same between visits.
+async fn inventory(app_meta: &mut AppMeta, name: String, restock: bool) -> Result<String, String> {
+    let thing = match app_meta.repository.get_by_name(&name).await {
+        Ok(Record { thing, .. }) => thing,
+        Err(_) => return Err(format!(r#"There is no place named "{}"."#, name)),
+    };
+    let name = thing.name().to_string();
+
+    let place = match &thing.data {
+        ThingData::Place(place) => place,
+        ThingData::Npc(_) => return Err(format!("{} is not a shop.", name)),
+    };
+
+    if !restock {
+        if let Some(inventory) = place.inventory.value() {
+            return Ok(InventoryView::new(&name, inventory).to_string());
+        }
+    }
 
-        output.push_str("\\\n\u{a0}\u{a0}");
+    let settlement_size = PlaceRelations::from(
+        app_meta
+            .repository
+            .load_relations(&thing)
+            .await
+            .unwrap_or_default(),
+    )
+    .settlement_size()
+    .unwrap_or(SettlementSize::Medium);
+
+    let Some(diff) = place.generate_inventory(settlement_size, &mut app_meta.rng) else {
+        return Err(format!(
+            "{} doesn't keep an inventory. Only armorers, blacksmiths, general stores, magic shops, and weaponsmiths have stock to browse.",
+            name,
+        ));
+    };
+
+    match app_meta
+        .repository
+        .modify(Change::Reroll {
+            uuid: thing.uuid,
+            name: name.clone(),
+            diff: diff.into(),
+        })
+        .await
+    {
+        Ok(Some(Record {
+            thing:
+                Thing {
+                    data: ThingData::Place(place),
+                    ..
+                },
+            ..
+        })) => Ok(format!(
+            "{}\n\n_{}{} Use `undo` to reverse this._",
+            InventoryView::new(
+                &name,
+                place.inventory.value().map_or(&[][..], Vec::as_slice)
+            ),
+            if restock {
+                format!("{} was successfully restocked.", name)
+            } else {
+                format!("Use `restock {}` to replace this stock.", name)
+            },
+            if matches!(
+                app_meta.repository.undo_history().next(),
+                Some(Change::RerollAndUnsave { .. }),
+            ) {
+                format!(
+                    " {} was automatically saved to your `journal` so that it keeps its stock.",
+                    name,
+                )
+            } else {
+                String::new()
+            },
+        )),
+        _ => Err(format!("Couldn't stock `{}`.", name)),
+    }
+}
 
-        {
-            let mut words = unknown_words.into_iter();
-            let mut unknown_word = words.next();
-            for (i, _) in input.char_indices() {
-                if unknown_word.as_ref().is_some_and(|word| i >= word.end) {
-                    unknown_word = words.next();
-                }
+/// Lock or unlock a field (or all fields) of a thing, recording the change as an edit so that it
+/// can be undone.
+async fn set_locked(
+    app_meta: &mut AppMeta,
+    name: String,
+    field: Option<ThingField>,
+    locked: bool,
+) -> Result<String, String> {
+    let thing = match app_meta.repository.get_by_name(&name).await {
+        Ok(Record { thing, .. }) => thing,
+        Err(_) => return Err(format!(r#"There is no entity named "{}"."#, name)),
+    };
+
+    let fields: Vec<ThingField> = field.into_iter().collect();
+    let state = if locked { "locked" } else { "unlocked" };
+    let (subject, verb) = if let Some(field) = field {
+        (format!("{}'s {}", name, field), "is")
+    } else {
+        (format!("All of {}'s fields", name), "are")
+    };
+
+    let affected_fields = if fields.is_empty() {
+        thing.data.fields()
+    } else {
+        fields.clone()
+    };
+
+    if affected_fields
+        .into_iter()
+        .all(|field| thing.data.is_field_locked(field) == locked)
+    {
+        return Err(format!("{} {} already {}.", subject, verb, state));
+    }
 
-                if let Some(word) = &unknown_word {
-                    output.push(if i >= word.start { '^' } else { '\u{a0}' });
-                } else {
-                    break;
-                }
+    match app_meta
+        .repository
+        .modify(Change::Reroll {
+            uuid: thing.uuid,
+            name: name.clone(),
+            diff: thing.data.lock_diff(&fields, locked),
+        })
+        .await
+    {
+        Ok(Some(Record { thing, .. })) => Ok(format!(
+            "{}\n\n{}",
+            thing.display_details(
+                app_meta
+                    .repository
+                    .load_relations(&thing)
+                    .await
+                    .unwrap_or_default(),
+            ),
+            if matches!(
+                app_meta.repository.undo_history().next(),
+                Some(Change::RerollAndUnsave { .. }),
+            ) {
+                format!(
+                    "_{} {} now {}, and {} has been automatically saved to your `journal`. Use `undo` to reverse this._",
+                    subject, verb, state, name,
+                )
+            } else {
+                format!(
+                    "_{} {} now {}. Use `undo` to reverse this._",
+                    subject, verb, state,
+                )
+            },
+        )),
+        _ => Err(format!("Couldn't edit `{}`.", name)),
+    }
+}
+
+async fn add_words(
+    app_meta: &mut AppMeta,
+    list: VocabularyList,
+    words: Vec<String>,
+) -> Result<String, String> {
+    let example = match &list {
+        VocabularyList::Names { ethnicity, .. } => {
+            // Don't let custom ethnicities shadow words that already mean something, since the
+            // built-in meaning would always win when parsing.
+            if app_meta
+                .demographics
+                .vocabulary()
+                .ethnicity(ethnicity)
+                .is_none()
+                && ParsedThing::<ThingData>::parse_with(ethnicity, &Vocabulary::default())
+                    .is_ok_and(|parsed| parsed.unknown_words.is_empty())
+            {
+                return Err(format!(
+                    r#""{}" already means something to initiative.sh, so it can't be used as the name of a custom ethnicity."#,
+                    ethnicity,
+                ));
             }
+
+            format!("{} person", ethnicity)
         }
+        VocabularyList::InnWords => "inn".to_string(),
+    };
+
+    let mut vocabulary = app_meta.demographics.vocabulary().clone();
+    let added = vocabulary.add(&list, &words);
+
+    if added.is_empty() {
+        return Err(format!("Those are all already in the {} list.", list));
+    }
+
+    app_meta.demographics.set_vocabulary(vocabulary.clone());
+
+    let message = format!(
+        "Added {} to the {} list. Try it out: `{}`",
+        added.join(", "),
+        list,
+        example,
+    );
+
+    match app_meta
+        .repository
+        .modify_without_undo(Change::SetKeyValue {
+            key_value: KeyValue::Vocabulary(Some(vocabulary)),
+        })
+        .await
+    {
+        Ok(_) => Ok(message),
+        Err(_) => Ok(format!(
+            "{}
+
+! Your changes could not be saved and will only apply until you close initiative.sh.",
+            message,
+        )),
+    }
+}
 
-        output.push_str("\\\nWant to help improve its vocabulary? Join us [on Discord](https://discord.gg/ZrqJPpxXVZ) and suggest your new words!");
+/// A warning listing the words in the input that weren't recognized, if any.
+fn unknown_words_notice(input: &str, unknown_words: &[Range<usize>]) -> Vec<Block> {
+    if unknown_words.is_empty() {
+        return Vec::new();
     }
+
+    let mut content = vec![Inline::text("> ")];
+
+    let mut pos = 0;
+    for word_range in unknown_words.iter() {
+        content.push(Inline::text(&input[pos..word_range.start]));
+        content.push(Inline::strong(&input[word_range.clone()]));
+        pos = word_range.end;
+    }
+    content.push(Inline::text(&input[pos..]));
+
+    content.extend([
+        Inline::LineBreak,
+        Inline::text(unknown_words_carets(input, unknown_words)),
+        Inline::LineBreak,
+        Inline::text("Want to help improve its vocabulary? Join us "),
+        Inline::Link {
+            text: "on Discord".to_string(),
+            url: "https://discord.gg/ZrqJPpxXVZ".to_string(),
+        },
+        Inline::text(" and suggest your new words!"),
+    ]);
+
+    vec![
+        Block::Warning {
+            content: vec![Inline::text(
+                "initiative.sh doesn't know some of those words, but it did its best.",
+            )],
+        },
+        Block::paragraph(content),
+    ]
+}
+
+/// A line of carets pointing out the unknown words in the input, indented to line up with the
+/// quoted input (which starts with "> ").
+fn unknown_words_carets(input: &str, unknown_words: &[Range<usize>]) -> String {
+    let mut output = "\u{a0}\u{a0}".to_string();
+    let mut words = unknown_words.iter();
+    let mut unknown_word = words.next();
+
+    for (i, _) in input.char_indices() {
+        if unknown_word.is_some_and(|word| i >= word.end) {
+            unknown_word = words.next();
+        }
+
+        if let Some(word) = unknown_word {
+            output.push(if i >= word.start { '^' } else { '\u{a0}' });
+        } else {
+            break;
+        }
+    }
+
     output
 }
 
@@ -519,185 +869,103 @@ fn append_unknown_words_notice(
 mod test {
     use super::*;
     use crate::test_utils as test;
-    use crate::world::npc::{Age, Gender, NpcData, Species};
-    use crate::world::place::{PlaceData, PlaceType};
+    use crate::world::npc::NpcField;
 
     #[tokio::test]
-    async fn parse_input_test() {
-        let mut app_meta = test::app_meta();
-
-        assert_eq!(
-            CommandMatches::new_fuzzy(create(NpcData::default())),
-            WorldCommand::parse_input("npc", &app_meta).await,
-        );
+    async fn run_test_add_words() {
+        let mut app_meta = test::app_meta::with_test_data().await;
 
         assert_eq!(
-            CommandMatches::new_canonical(create(NpcData::default())),
-            WorldCommand::parse_input("create npc", &app_meta).await,
+            Ok(
+                "Added Daenerys to the valyrian names list. Try it out: `valyrian person`"
+                    .to_string()
+            ),
+            WorldCommand::AddWords {
+                list: "valyrian names".parse().unwrap(),
+                words: vec!["Daenerys".to_string()],
+            }
+            .run("add valyrian names: Daenerys", &mut app_meta)
+            .await,
         );
 
         assert_eq!(
-            CommandMatches::new_fuzzy(create(NpcData {
-                species: Species::Elf.into(),
-                ..Default::default()
-            })),
-            WorldCommand::parse_input("elf", &app_meta).await,
+            ["Daenerys"],
+            app_meta
+                .demographics
+                .vocabulary()
+                .get(&"valyrian names".parse().unwrap()),
         );
 
         assert_eq!(
-            CommandMatches::default(),
-            WorldCommand::parse_input("potato", &app_meta).await,
+            Err(r#""human" already means something to initiative.sh, so it can't be used as the name of a custom ethnicity."#.to_string()),
+            WorldCommand::AddWords {
+                list: "human names".parse().unwrap(),
+                words: vec!["Bob".to_string()],
+            }
+            .run("add human names: Bob", &mut app_meta)
+            .await,
         );
-
-        {
-            app_meta
-                .repository
-                .modify(Change::Create {
-                    thing_data: NpcData {
-                        name: "Spot".into(),
-                        ..Default::default()
-                    }
-                    .into(),
-                    uuid: None,
-                })
-                .await
-                .unwrap();
-
-            assert_eq!(
-                CommandMatches::new_fuzzy(WorldCommand::Edit {
-                    name: "Spot".into(),
-                    parsed_diff: ParsedThing {
-                        thing_data: NpcData {
-                            age: Age::Child.into(),
-                            gender: Gender::Masculine.into(),
-                            ..Default::default()
-                        }
-                        .into(),
-                        #[expect(clippy::single_range_in_vec_init)]
-                        unknown_words: vec![10..14],
-                        word_count: 2,
-                    },
-                }),
-                WorldCommand::parse_input("Spot is a good boy", &app_meta).await,
-            );
-        }
     }
 
     #[tokio::test]
-    async fn autocomplete_test() {
-        let app_meta = test::app_meta::with_test_data().await;
-
-        for (word, summary) in [
-            ("npc", "create person"),
-            // Species
-            ("dragonborn", "create dragonborn"),
-            ("dwarf", "create dwarf"),
-            ("elf", "create elf"),
-            ("gnome", "create gnome"),
-            ("half-elf", "create half-elf"),
-            ("half-orc", "create half-orc"),
-            ("halfling", "create halfling"),
-            ("human", "create human"),
-            ("tiefling", "create tiefling"),
-            // PlaceType
-            ("inn", "create inn"),
-        ] {
-            test::assert_autocomplete_eq!(
-                [(word, summary)],
-                WorldCommand::autocomplete(word, &app_meta).await,
-            );
-
-            test::assert_autocomplete_eq!(
-                [(word, summary)],
-                WorldCommand::autocomplete(&word.to_uppercase(), &app_meta).await,
-            );
-        }
-
-        test::assert_autocomplete_eq!(
-            [
-                ("baby", "create infant"),
-                ("bakery", "create bakery"),
-                ("bank", "create bank"),
-                ("bar", "create bar"),
-                ("barony", "create barony"),
-                ("barracks", "create barracks"),
-                ("barrens", "create barrens"),
-                ("base", "create base"),
-                ("bathhouse", "create bathhouse"),
-                ("beach", "create beach"),
-                ("blacksmith", "create blacksmith"),
-                ("boy", "create child, he/him"),
-                ("brewery", "create brewery"),
-                ("bridge", "create bridge"),
-                ("building", "create building"),
-                ("business", "create business"),
-            ],
-            WorldCommand::autocomplete("b", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [("penelope is [character description]", "edit character")],
-            WorldCommand::autocomplete("penelope", &app_meta).await,
-        );
-
-        test::assert_autocomplete_eq!(
-            [("PENELOPE is a [character description]", "edit character")],
-            WorldCommand::autocomplete("PENELOPE is a ", &app_meta).await,
-        );
+    async fn run_test_lock_unlock() {
+        let mut app_meta = test::app_meta::with_test_data().await;
 
-        test::assert_autocomplete_eq!(
-            [
-                ("penelope is an elderly", "edit character"),
-                ("penelope is an elf", "edit character"),
-                ("penelope is an elvish", "edit character"),
-                ("penelope is an enby", "edit character"),
-            ],
-            WorldCommand::autocomplete("penelope is an e", &app_meta).await,
+        assert_eq!(
+            Err("Odysseus's age is already locked.".to_string()),
+            WorldCommand::Lock {
+                name: "Odysseus".to_string(),
+                field: Some(NpcField::Age.into()),
+            }
+            .run("lock odysseus's age", &mut app_meta)
+            .await,
         );
-    }
-
-    #[tokio::test]
-    async fn display_test() {
-        let app_meta = test::app_meta();
-
-        for command in [
-            create(PlaceData {
-                subtype: "inn".parse::<PlaceType>().ok().into(),
-                ..Default::default()
-            }),
-            create(NpcData::default()),
-            create(test::npc().species(Species::Elf).build()),
-        ] {
-            let command_string = command.to_string();
-            assert_ne!("", command_string);
-
-            assert_eq!(
-                CommandMatches::new_canonical(command.clone()),
-                WorldCommand::parse_input(&command_string, &app_meta).await,
-                "{}",
-                command_string,
-            );
 
-            assert_eq!(
-                CommandMatches::new_canonical(command),
-                WorldCommand::parse_input(&command_string.to_uppercase(), &app_meta).await,
-                "{}",
-                command_string.to_uppercase(),
-            );
+        let output = WorldCommand::Unlock {
+            name: "Odysseus".to_string(),
+            field: Some(NpcField::Age.into()),
         }
-    }
-
-    fn parsed_thing(thing_data: impl Into<ThingData>) -> ParsedThing<ThingData> {
-        ParsedThing {
-            thing_data: thing_data.into(),
-            unknown_words: Vec::new(),
-            word_count: 1,
-        }
-    }
+        .run("unlock odysseus's age", &mut app_meta)
+        .await
+        .unwrap();
+
+        assert!(output.contains("**Age:** 50 years\\\n"), "{}", output);
+        assert!(output.contains("# Odysseus 🔒"), "{}", output);
+        assert!(
+            output.ends_with("_Odysseus's age is now unlocked, and Odysseus has been automatically saved to your `journal`. Use `undo` to reverse this._"),
+            "{}",
+            output,
+        );
 
-    fn create(thing_data: impl Into<ThingData>) -> WorldCommand {
-        WorldCommand::Create {
-            parsed_thing_data: parsed_thing(thing_data),
+        let npc = app_meta
+            .repository
+            .get_by_name("Odysseus")
+            .await
+            .unwrap()
+            .thing
+            .data
+            .npc_data()
+            .cloned()
+            .unwrap();
+        assert!(npc.age.is_unlocked());
+        assert!(npc.age_years.is_unlocked());
+        assert!(npc.name.is_locked());
+
+        let output = WorldCommand::Lock {
+            name: "Odysseus".to_string(),
+            field: None,
         }
+        .run("lock odysseus", &mut app_meta)
+        .await
+        .unwrap();
+
+        assert!(output.contains("**Age:** 50 years 🔒"), "{}", output);
+        assert!(
+            output.ends_with(
+                "_All of Odysseus's fields are now locked. Use `undo` to reverse this._"
+            ),
+            "{}",
+            output,
+        );
     }
 }
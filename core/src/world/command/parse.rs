@@ -1,8 +1,8 @@
 use crate::utils::{capitalize, quoted_words, CaseInsensitiveStr};
 use crate::world::command::ParsedThing;
-use crate::world::npc::NpcData;
-use crate::world::place::PlaceData;
-use crate::world::Field;
+use crate::world::npc::{Creature, NpcData, NpcField, StatBlock};
+use crate::world::place::{PlaceData, PlaceField};
+use crate::world::{Field, Vocabulary};
 use std::str::FromStr;
 
 fn split_name(input: &str) -> Option<(&str, &str)> {
@@ -48,6 +48,41 @@ fn split_name(input: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Split off any free-text fields from the end of a description, eg. "an inn hook: the ale is
+/// poisoned". Returns the remaining description and the labelled values in order.
+fn split_labels<F: Copy>(
+    input: &str,
+    parse_label: impl Fn(&str) -> Option<F>,
+) -> (&str, Vec<(F, &str)>) {
+    let labels: Vec<_> = quoted_words(input)
+        .filter_map(|word| {
+            word.as_str()
+                .strip_suffix(':')
+                .and_then(&parse_label)
+                .map(|field| (field, word.range()))
+        })
+        .collect();
+
+    if let Some((_, first_range)) = labels.first() {
+        (
+            &input[..first_range.start],
+            labels
+                .iter()
+                .enumerate()
+                .map(|(i, (field, range))| {
+                    let end = labels
+                        .get(i + 1)
+                        .map_or(input.len(), |(_, next_range)| next_range.start);
+                    (*field, input[range.end..end].trim())
+                })
+                .filter(|(_, value)| !value.is_empty())
+                .collect(),
+        )
+    } else {
+        (input, Vec::new())
+    }
+}
+
 impl FromStr for ParsedThing<PlaceData> {
     type Err = ();
 
@@ -56,6 +91,33 @@ impl FromStr for ParsedThing<PlaceData> {
         let mut unknown_words = Vec::new();
         let mut word_count = 0;
 
+        let (input, labelled) = split_labels(input, |label| {
+            label.parse::<PlaceField>().ok().filter(|field| {
+                matches!(
+                    field,
+                    PlaceField::Wares | PlaceField::Atmosphere | PlaceField::Hook,
+                )
+            })
+        });
+
+        for (field, value) in labelled {
+            match field {
+                PlaceField::Wares => {
+                    place.wares = Field::new(
+                        value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|ware| !ware.is_empty())
+                            .map(str::to_string)
+                            .collect(),
+                    );
+                }
+                PlaceField::Atmosphere => place.atmosphere = Field::new(capitalize(value)),
+                PlaceField::Hook => place.hook = Field::new(capitalize(value)),
+                _ => unreachable!(),
+            }
+        }
+
         let description = if let Some((name, description)) = split_name(input) {
             place.name = Field::new(capitalize(name));
             description
@@ -71,6 +133,10 @@ impl FromStr for ParsedThing<PlaceData> {
                 word_count -= 1;
             } else if let Ok(place_type) = word_str.parse() {
                 place.subtype = Field::new(place_type);
+            } else if let Ok(quality) = word_str.parse() {
+                place.quality = Field::new(quality);
+            } else if let Ok(price) = word_str.parse() {
+                place.price = Field::new(price);
             } else {
                 unknown_words.push(word.range().to_owned());
             }
@@ -92,10 +158,43 @@ impl FromStr for ParsedThing<NpcData> {
     type Err = ();
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, &Vocabulary::default())
+    }
+}
+
+impl ParsedThing<NpcData> {
+    /// Parse an NPC description, also recognizing any custom ethnicities defined by the user.
+    pub fn parse_with(input: &str, vocabulary: &Vocabulary) -> Result<Self, ()> {
         let mut npc = NpcData::default();
         let mut unknown_words = Vec::new();
         let mut word_count = 0;
 
+        let (input, labelled) = split_labels(input, |label| {
+            label.parse::<NpcField>().ok().filter(|field| {
+                matches!(
+                    field,
+                    NpcField::Ideal
+                        | NpcField::Bond
+                        | NpcField::Flaw
+                        | NpcField::Feature
+                        | NpcField::Mannerism,
+                )
+            })
+        });
+
+        for (field, value) in labelled {
+            let value = Field::new(capitalize(value));
+
+            match field {
+                NpcField::Ideal => npc.ideal = value,
+                NpcField::Bond => npc.bond = value,
+                NpcField::Flaw => npc.flaw = value,
+                NpcField::Feature => npc.feature = value,
+                NpcField::Mannerism => npc.mannerism = value,
+                _ => unreachable!(),
+            }
+        }
+
         let description = if let Some((name, description)) = split_name(input) {
             npc.name = Field::new(capitalize(name));
             description
@@ -109,7 +208,7 @@ impl FromStr for ParsedThing<NpcData> {
 
             if word_str.in_ci(&["a", "an"]) {
                 word_count -= 1;
-            } else if word_str.in_ci(&["character", "npc", "person"]) {
+            } else if word_str.in_ci(&["character", "npc", "person", "with", "stats"]) {
                 // ignore
             } else if let Ok(gender) = word_str.parse() {
                 npc.gender = Field::new(gender);
@@ -125,6 +224,7 @@ impl FromStr for ParsedThing<NpcData> {
                 npc.age = Field::new(age);
             } else if let Ok(species) = word_str.parse() {
                 npc.species = Field::new(species);
+                npc.creature = Field::Locked(None);
 
                 if let Ok(ethnicity) = word_str.parse() {
                     npc.ethnicity.replace(ethnicity);
@@ -132,6 +232,20 @@ impl FromStr for ParsedThing<NpcData> {
                 }
             } else if let Ok(ethnicity) = word_str.parse() {
                 npc.ethnicity = Field::new(ethnicity);
+            } else if let Ok(creature) = word_str.parse::<Creature>() {
+                // A character can be a person or a creature, but not both, eg. `Ana is a wolf`.
+                npc.creature = Field::new(creature);
+                npc.species = Field::Locked(None);
+                npc.ethnicity = Field::Locked(None);
+            } else if let Ok(personality) = word_str.parse() {
+                npc.personality = Field::new(personality);
+            } else if let Ok(stat_block) = word_str.parse::<StatBlock>() {
+                // Hit points start at the stat block's average, replacing any left over from a
+                // previous stat block. They can be rolled with `reroll [name]'s hit points`.
+                npc.stats = Field::new(stat_block);
+                npc.hit_points = Field::new(stat_block.average_hit_points());
+            } else if let Some(ethnicity) = vocabulary.ethnicity(word_str) {
+                npc.ethnicity = Field::new(ethnicity);
             } else if let Some(Ok(age_years)) =
                 word_str.strip_suffix_ci("-year-old").map(|s| s.parse())
             {
@@ -156,8 +270,8 @@ impl FromStr for ParsedThing<NpcData> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::npc::{Age, Gender, Species};
-    use crate::world::place::PlaceType;
+    use crate::world::npc::{Age, Gender, Personality, Species};
+    use crate::world::place::{PlaceType, Price, Quality};
 
     #[test]
     fn place_from_str_test() {
@@ -221,6 +335,70 @@ mod test {
             assert_eq!(0, place.unknown_words.len());
             assert_eq!(1, place.word_count);
         }
+
+        {
+            let place: ParsedThing<PlaceData> = "a cheap squalid inn".parse().unwrap();
+            assert_eq!(Field::Locked(Some(Price::Cheap)), place.thing_data.price);
+            assert_eq!(
+                Field::Locked(Some(Quality::Squalid)),
+                place.thing_data.quality
+            );
+            assert_eq!(0, place.unknown_words.len());
+            assert_eq!(3, place.word_count);
+        }
+    }
+
+    #[test]
+    fn place_from_str_test_labels() {
+        {
+            let place: ParsedThing<PlaceData> =
+                "an inn menu: stew, ale,, bread hook: the ale is poisoned"
+                    .parse()
+                    .unwrap();
+            assert_eq!(
+                Field::Locked("inn".parse::<PlaceType>().ok()),
+                place.thing_data.subtype,
+            );
+            assert_eq!(
+                Some(&vec![
+                    "stew".to_string(),
+                    "ale".to_string(),
+                    "bread".to_string(),
+                ]),
+                place.thing_data.wares.value(),
+            );
+            assert_eq!(
+                Field::Locked(Some("The ale is poisoned".to_string())),
+                place.thing_data.hook,
+            );
+            assert!(place.thing_data.atmosphere.is_none());
+            assert_eq!(0, place.unknown_words.len());
+            assert_eq!(1, place.word_count);
+        }
+
+        {
+            let place: ParsedThing<PlaceData> = "The Pony, an inn atmosphere: smoky and loud"
+                .parse()
+                .unwrap();
+            assert_eq!(
+                Field::Locked(Some("The Pony".to_string())),
+                place.thing_data.name,
+            );
+            assert_eq!(
+                Field::Locked(Some("Smoky and loud".to_string())),
+                place.thing_data.atmosphere,
+            );
+        }
+
+        {
+            let place: ParsedThing<PlaceData> = "hook: someone is watching".parse().unwrap();
+            assert!(place.thing_data.subtype.is_none());
+            assert_eq!(
+                Some("Someone is watching"),
+                place.thing_data.hook.value().map(|s| s.as_str()),
+            );
+            assert_eq!(0, place.word_count);
+        }
     }
 
     #[test]
@@ -295,4 +473,104 @@ mod test {
             assert!("potato".parse::<ParsedThing<NpcData>>().is_err());
         }
     }
+
+    #[test]
+    fn npc_from_str_test_personality() {
+        {
+            let npc: ParsedThing<NpcData> = "grumpy dwarf".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Personality::Grumpy)),
+                npc.thing_data.personality,
+            );
+            assert_eq!(Field::Locked(Some(Species::Dwarf)), npc.thing_data.species);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(2, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<NpcData> =
+                "Ana, a surly elf flaw: hates dwarves mannerism: never blinks"
+                    .parse()
+                    .unwrap();
+            assert_eq!(Field::Locked(Some("Ana".to_string())), npc.thing_data.name);
+            assert_eq!(
+                Field::Locked(Some(Personality::Grumpy)),
+                npc.thing_data.personality,
+            );
+            assert_eq!(
+                Field::Locked(Some("Hates dwarves".to_string())),
+                npc.thing_data.flaw,
+            );
+            assert_eq!(
+                Field::Locked(Some("Never blinks".to_string())),
+                npc.thing_data.mannerism,
+            );
+            assert!(npc.thing_data.ideal.is_none());
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(2, npc.word_count);
+        }
+    }
+
+    #[test]
+    fn npc_from_str_test_creature() {
+        {
+            let npc: ParsedThing<NpcData> = "Smaug, a dragon".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Creature::Dragon)),
+                npc.thing_data.creature,
+            );
+            assert_eq!(Field::Locked(None), npc.thing_data.species);
+            assert!(npc.thing_data.species.is_locked());
+            assert!(npc.thing_data.ethnicity.is_locked());
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(1, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<NpcData> = "grumpy owlbear".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Creature::Owlbear)),
+                npc.thing_data.creature,
+            );
+            assert_eq!(
+                Field::Locked(Some(Personality::Grumpy)),
+                npc.thing_data.personality,
+            );
+        }
+
+        {
+            let npc: ParsedThing<NpcData> = "elf".parse().unwrap();
+            assert!(npc.thing_data.creature.is_none());
+            assert!(npc.thing_data.creature.is_locked());
+        }
+    }
+
+    #[test]
+    fn npc_from_str_test_stats() {
+        {
+            let npc: ParsedThing<NpcData> = "a veteran".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(StatBlock::Veteran)),
+                npc.thing_data.stats,
+            );
+            assert_eq!(Field::Locked(Some(58)), npc.thing_data.hit_points);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(1, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<NpcData> = "dwarf guard with stats".parse().unwrap();
+            assert_eq!(Field::Locked(Some(StatBlock::Guard)), npc.thing_data.stats);
+            assert_eq!(Field::Locked(Some(Species::Dwarf)), npc.thing_data.species);
+            assert_eq!(0, npc.unknown_words.len());
+        }
+
+        {
+            let npc: ParsedThing<NpcData> = "bandit-captain".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(StatBlock::BanditCaptain)),
+                npc.thing_data.stats,
+            );
+        }
+    }
 }
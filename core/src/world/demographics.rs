@@ -1,4 +1,5 @@
 use super::npc::{Ethnicity, Species};
+use super::Vocabulary;
 use initiative_macros::From;
 
 use rand::distributions::WeightedIndex;
@@ -13,6 +14,11 @@ type GroupMapSerialized = Vec<(Species, Ethnicity, u64)>;
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Demographics {
     groups: GroupMapWrapper,
+
+    /// The user's custom names and words. These are carried here since the demographics are
+    /// available to every generator.
+    #[serde(default, skip_serializing_if = "Vocabulary::is_empty")]
+    vocabulary: Vocabulary,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, From, PartialEq, Serialize)]
@@ -23,9 +29,18 @@ impl Demographics {
     pub fn new(groups: GroupMap) -> Self {
         Self {
             groups: GroupMapWrapper(groups),
+            vocabulary: Vocabulary::default(),
         }
     }
 
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+
+    pub fn set_vocabulary(&mut self, vocabulary: Vocabulary) {
+        self.vocabulary = vocabulary;
+    }
+
     pub fn shift_species(&self, species: &Species, amount: f64) -> Self {
         self.shift_by(
             |s, _| s == species,
@@ -42,7 +57,7 @@ impl Demographics {
         self.shift_by(
             |_, e| e == ethnicity,
             amount,
-            (ethnicity.default_species(), *ethnicity),
+            (ethnicity.default_species(), ethnicity.clone()),
         )
     }
 
@@ -59,7 +74,7 @@ impl Demographics {
         self.shift_by(
             |s, e| s == species && e == ethnicity,
             amount,
-            (*species, *ethnicity),
+            (*species, ethnicity.clone()),
         )
     }
 
@@ -71,10 +86,15 @@ impl Demographics {
         if self.groups().is_empty() {
             (Species::Human, Species::Human.default_ethnicity())
         } else {
-            let (groups, weights): (Vec<(Species, Ethnicity)>, Vec<u64>) =
-                self.groups().iter().unzip();
+            // HashMap iteration order varies between instances, so sort the groups to keep the
+            // output reproducible for a given seed.
+            let mut groups: Vec<_> = self.groups().iter().collect();
+            groups.sort_unstable_by_key(|(group, _)| *group);
+
+            let (groups, weights): (Vec<&(Species, Ethnicity)>, Vec<u64>) =
+                groups.into_iter().unzip();
             let dist = WeightedIndex::new(weights).unwrap();
-            groups[dist.sample(rng)]
+            groups[dist.sample(rng)].clone()
         }
     }
 
@@ -100,7 +120,7 @@ impl Demographics {
                 .iter()
                 .map(|((s, e), &v)| {
                     (
-                        (*s, *e),
+                        (*s, e.clone()),
                         if f(s, e) {
                             (v as f64 * (1. - amount)
                                 + (v as f64 * amount * population as f64
@@ -116,7 +136,7 @@ impl Demographics {
         } else {
             self.groups()
                 .iter()
-                .map(|(&k, &v)| (k, (v as f64 * (1. - amount)).round() as u64))
+                .map(|(k, &v)| (k.clone(), (v as f64 * (1. - amount)).round() as u64))
                 .chain(iter::once((
                     default,
                     (population as f64 * amount).round() as u64,
@@ -125,7 +145,10 @@ impl Demographics {
                 .collect()
         };
 
-        Self::new(groups)
+        Self {
+            groups: GroupMapWrapper(groups),
+            vocabulary: self.vocabulary.clone(),
+        }
     }
 
     fn groups(&self) -> &GroupMap {
@@ -383,7 +406,7 @@ mod test {
 
         for i in 0..10 {
             let species_ethnicity = &demographics.gen_species_ethnicity(&mut rng);
-            *counts.entry(*species_ethnicity).or_default() += 1;
+            *counts.entry(species_ethnicity.clone()).or_default() += 1;
             println!("{}: {:?}", i, counts);
         }
 
@@ -47,6 +47,14 @@ impl<T> Field<T> {
         }
     }
 
+    /// A marker to be displayed alongside locked values in detailed views.
+    pub const fn lock_indicator(&self) -> &'static str {
+        match self {
+            Self::Locked(Some(_)) => " 🔒",
+            _ => "",
+        }
+    }
+
     pub fn set_locked(&mut self, locked: bool) {
         if locked {
             self.lock();
@@ -108,6 +116,36 @@ impl<T> Field<T> {
             other.lock();
         }
     }
+
+    /// Apply a diff produced by rerolling or by locking and unlocking fields. Unlike
+    /// [`Field::apply_diff`], the lock state is carried over exactly: a diff with a value is
+    /// applied whether or not it is locked, and a locked empty diff clears the field. The previous
+    /// value is left in `other` (locked if it was empty) so that applying it again in the same way
+    /// reverses the change.
+    pub fn apply_reroll_diff(&mut self, other: &mut Self) {
+        if other.is_locked() || other.is_some() {
+            mem::swap(self, other);
+
+            if other.is_none() {
+                other.lock();
+            }
+        }
+    }
+
+    /// Produce a diff that, when applied to this field, will update it to match the value of
+    /// `other`. Returns an empty diff if the values already match.
+    pub fn diff(&self, other: &Self) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        if self.value() == other.value() {
+            Self::default()
+        } else if other.is_none() {
+            Self::Locked(None)
+        } else {
+            other.clone()
+        }
+    }
 }
 
 impl<T: Eq> Eq for Field<T> {}
@@ -321,13 +359,95 @@ mod test {
             assert_eq!(Field::Locked(None), field);
             assert_eq!(Field::Locked(Some(false)), diff);
         }
+    }
+
+    #[test]
+    fn apply_reroll_diff_test() {
+        {
+            let mut field = Field::Locked(Some(false));
+            let mut diff = Field::Unlocked(None);
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(Field::Locked(Some(false)), field);
+            assert!(field.is_locked());
+            assert_eq!(Field::Unlocked(None), diff);
+        }
 
         {
             let mut field = Field::Unlocked(Some(false));
             let mut diff = Field::Locked(Some(true));
-            field.apply_diff(&mut diff);
+            field.apply_reroll_diff(&mut diff);
             assert_eq!(Field::Locked(Some(true)), field);
+            assert!(field.is_locked());
+            assert_eq!(Field::Unlocked(Some(false)), diff);
+            assert!(diff.is_unlocked());
+        }
+
+        {
+            let mut field = Field::Locked(Some(false));
+            let mut diff = Field::Unlocked(Some(true));
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(Field::Unlocked(Some(true)), field);
+            assert!(field.is_unlocked());
             assert_eq!(Field::Locked(Some(false)), diff);
+            assert!(diff.is_locked());
+        }
+
+        {
+            let mut field: Field<bool> = Field::Unlocked(None);
+            let mut diff = Field::Unlocked(Some(true));
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(Field::Unlocked(Some(true)), field);
+            assert_eq!(Field::Locked(None), diff);
+            assert!(diff.is_locked());
         }
     }
+
+    #[test]
+    fn apply_reroll_diff_test_undo_redo() {
+        for (original, rerolled) in [
+            (Field::Unlocked(Some(1)), Field::Unlocked(Some(2))),
+            (Field::Locked(Some(1)), Field::Unlocked(Some(2))),
+            (Field::Unlocked(Some(1)), Field::Locked(Some(1))),
+            (Field::Locked(Some(1)), Field::Unlocked(Some(1))),
+            (Field::Unlocked(Some(1)), Field::Locked(None)),
+        ] {
+            let mut field = original.clone();
+            let mut diff = rerolled.clone();
+
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(rerolled, field);
+            assert_eq!(rerolled.is_locked(), field.is_locked(), "{:?}", rerolled);
+
+            // undo
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(original, field);
+            assert_eq!(original.is_locked(), field.is_locked(), "{:?}", original);
+
+            // redo
+            field.apply_reroll_diff(&mut diff);
+            assert_eq!(rerolled, field);
+            assert_eq!(rerolled.is_locked(), field.is_locked(), "{:?}", rerolled);
+        }
+    }
+
+    #[test]
+    fn diff_test() {
+        let field = Field::Locked(Some(1));
+
+        let diff = field.diff(&Field::Unlocked(Some(1)));
+        assert!(diff.is_none());
+        assert!(diff.is_unlocked());
+
+        let diff = field.diff(&Field::Unlocked(Some(2)));
+        assert_eq!(Field::Unlocked(Some(2)), diff);
+        assert!(diff.is_unlocked());
+
+        let diff = field.diff(&Field::Unlocked(None));
+        assert_eq!(Field::Locked(None), diff);
+        assert!(diff.is_locked());
+
+        let mut applied = field.clone();
+        applied.apply_reroll_diff(&mut field.diff(&Field::Unlocked(Some(3))));
+        assert_eq!(Field::Unlocked(Some(3)), applied);
+    }
 }
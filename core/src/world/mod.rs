@@ -3,12 +3,15 @@ pub mod npc;
 pub mod place;
 pub mod thing;
 
+pub(crate) use command::{autocomplete_name_field, parse_name_field};
 pub use command::{ParsedThing, WorldCommand};
 pub use demographics::Demographics;
 pub use field::Field;
+pub use vocabulary::{Vocabulary, VocabularyList};
 
 mod command;
 mod field;
+mod vocabulary;
 mod word;
 
 use rand::Rng;
@@ -0,0 +1,236 @@
+use super::{Gender, NpcData, Size};
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A monster or beast tracked in the journal in place of a species, eg. "Smaug, a dragon" or a
+/// pet owlbear. Creatures have their own sizes, but no ethnicity or age.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Creature {
+    Bear,
+    Cat,
+
+    #[alias = "hound"]
+    #[alias = "mastiff"]
+    Dog,
+
+    Dragon,
+    Giant,
+
+    #[alias = "gryphon"]
+    Griffon,
+
+    Horse,
+    Kraken,
+    Ogre,
+    Owlbear,
+    Roc,
+    Troll,
+    Wolf,
+}
+
+impl Creature {
+    /// Height (or length, for creatures that don't stand upright) in inches, and weight in
+    /// pounds.
+    fn dimensions(&self) -> (RangeInclusive<u16>, RangeInclusive<u32>) {
+        match self {
+            Self::Bear => (48..=66, 500..=1200),
+            Self::Cat => (9..=12, 7..=12),
+            Self::Dog => (24..=32, 60..=160),
+            Self::Dragon => (180..=300, 20_000..=80_000),
+            Self::Giant => (192..=312, 2_000..=12_000),
+            Self::Griffon => (60..=72, 500..=800),
+            Self::Horse => (58..=68, 900..=1500),
+            Self::Kraken => (600..=960, 200_000..=600_000),
+            Self::Ogre => (108..=120, 600..=1000),
+            Self::Owlbear => (84..=96, 1000..=1600),
+            Self::Roc => (300..=420, 40_000..=100_000),
+            Self::Troll => (108..=120, 500..=800),
+            Self::Wolf => (26..=34, 70..=150),
+        }
+    }
+
+    pub fn gen_size(&self, rng: &mut impl Rng) -> Size {
+        let (height_range, weight_range) = self.dimensions();
+
+        // Bigger creatures are heavier, give or take.
+        let scale = rng.gen_range(0f32..=1.);
+        let weight_scale = (scale + rng.gen_range(-0.2..=0.2)).clamp(0., 1.);
+        let height = height_range.start()
+            + ((height_range.end() - height_range.start()) as f32 * scale) as u16;
+        let weight = weight_range.start()
+            + ((weight_range.end() - weight_range.start()) as f64 * weight_scale as f64) as u32;
+
+        match self {
+            Self::Cat => Size::Tiny { height, weight },
+            Self::Dog | Self::Wolf => Size::Medium { height, weight },
+            Self::Bear | Self::Griffon | Self::Horse | Self::Ogre | Self::Owlbear | Self::Troll => {
+                Size::Large { height, weight }
+            }
+            Self::Dragon | Self::Giant => Size::Huge { height, weight },
+            Self::Kraken | Self::Roc => Size::Gargantuan { height, weight },
+        }
+    }
+
+    pub fn emoji(&self) -> char {
+        match self {
+            Self::Bear => '\u{1f43b}',
+            Self::Cat => '\u{1f408}',
+            Self::Dog => '\u{1f415}',
+            Self::Dragon => '\u{1f409}',
+            Self::Giant | Self::Ogre | Self::Troll => '\u{1f479}',
+            Self::Griffon | Self::Roc => '\u{1f985}',
+            Self::Horse => '\u{1f40e}',
+            Self::Kraken => '\u{1f991}',
+            Self::Owlbear => '\u{1f989}',
+            Self::Wolf => '\u{1f43a}',
+        }
+    }
+}
+
+impl fmt::Display for Creature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Fill in the gender, size and name of a creature, leaving any locked fields alone.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData) {
+    if let Some(creature) = npc.creature.value().copied() {
+        npc.gender.replace_with(|_| {
+            *[Gender::Feminine, Gender::Masculine, Gender::Neuter]
+                .choose(rng)
+                .unwrap()
+        });
+        npc.size.replace_with(|_| creature.gen_size(rng));
+        npc.name
+            .replace_with(|_| NAMES.choose(rng).unwrap().to_string());
+    }
+}
+
+const NAMES: &[&str] = &[
+    "Ashclaw",
+    "Biscuit",
+    "Bramble",
+    "Cinder",
+    "Dusk",
+    "Ember",
+    "Fang",
+    "Gristle",
+    "Grimtooth",
+    "Hazel",
+    "Ironhide",
+    "Midnight",
+    "Mossback",
+    "Nettle",
+    "Pebble",
+    "Rumble",
+    "Scratch",
+    "Shadow",
+    "Snowpaw",
+    "Thistle",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::world::Field;
+
+    #[test]
+    fn gen_size_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for creature in Creature::get_words().filter_map(|word| word.parse::<Creature>().ok()) {
+            let (height_range, weight_range) = creature.dimensions();
+
+            for _ in 0..10 {
+                let size = creature.gen_size(&mut rng);
+                assert!(height_range.contains(&size.height()), "{:?}", size);
+                assert!(weight_range.contains(&size.weight()), "{:?}", size);
+            }
+        }
+
+        // Gargantuan creatures can weigh more than a u16 can hold.
+        assert!((0..10).all(|_| Creature::Kraken.gen_size(&mut rng).weight() > 100_000));
+
+        assert_eq!("tiny", Creature::Cat.gen_size(&mut rng).name());
+        assert_eq!("large", Creature::Owlbear.gen_size(&mut rng).name());
+        assert_eq!("huge", Creature::Dragon.gen_size(&mut rng).name());
+        assert_eq!("gargantuan", Creature::Roc.gen_size(&mut rng).name());
+    }
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = NpcData {
+            creature: Creature::Owlbear.into(),
+            ..Default::default()
+        };
+
+        regenerate(&mut rng, &mut npc);
+
+        assert!(npc.name.is_some());
+        assert!(npc.gender.is_some());
+        assert_eq!(Some("large"), npc.size.value().map(Size::name));
+        assert!(npc.species.is_none());
+        assert!(npc.age.is_none());
+    }
+
+    #[test]
+    fn regenerate_test_gender() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut genders = Vec::new();
+
+        for _ in 0..30 {
+            let mut npc = NpcData {
+                creature: Creature::Wolf.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc);
+
+            let gender = *npc.gender.value().unwrap();
+            if !genders.contains(&gender) {
+                genders.push(gender);
+            }
+        }
+
+        genders.sort_by_key(|gender| gender.name());
+        assert_eq!(
+            vec![Gender::Feminine, Gender::Masculine, Gender::Neuter],
+            genders,
+        );
+    }
+
+    #[test]
+    fn regenerate_test_locked() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = NpcData {
+            name: "Smaug".into(),
+            gender: Gender::Masculine.into(),
+            creature: Creature::Dragon.into(),
+            ..Default::default()
+        };
+
+        regenerate(&mut rng, &mut npc);
+
+        assert_eq!(Field::new("Smaug".to_string()), npc.name);
+        assert_eq!(Field::new(Gender::Masculine), npc.gender);
+        assert_eq!(Some("huge"), npc.size.value().map(Size::name));
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            r#""owlbear""#,
+            serde_json::to_string(&Creature::Owlbear).unwrap(),
+        );
+        assert_eq!(
+            Creature::Dog,
+            serde_json::from_str::<Creature>(r#""dog""#).unwrap(),
+        );
+        assert_eq!(Ok(Creature::Griffon), "gryphon".parse());
+    }
+}
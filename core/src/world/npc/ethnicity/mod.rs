@@ -8,14 +8,15 @@ mod orcish;
 mod tiefling;
 
 use super::{Age, Gender, NpcData, Species};
-use crate::world::weighted_index_from_tuple;
-use initiative_macros::WordList;
+use crate::utils::CaseInsensitiveStr;
+use crate::world::{weighted_index_from_tuple, Demographics};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, WordList, Serialize, Deserialize)]
-#[serde(into = "&'static str", try_from = "&str")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
 pub enum Ethnicity {
     Dragonborn,
     Dwarvish,
@@ -25,12 +26,26 @@ pub enum Ethnicity {
     Halfling,
     Human,
     Tiefling,
+
+    /// An ethnicity defined by the user, along with its own name lists. See [`Vocabulary`].
+    Custom(String),
 }
 
 impl Ethnicity {
+    const BUILT_IN: [Self; 8] = [
+        Self::Dragonborn,
+        Self::Dwarvish,
+        Self::Elvish,
+        Self::Gnomish,
+        Self::Orcish,
+        Self::Halfling,
+        Self::Human,
+        Self::Tiefling,
+    ];
+
     pub fn default_species(&self) -> Species {
         match self {
-            Self::Human => Species::Human,
+            Self::Human | Self::Custom(_) => Species::Human,
             Self::Dragonborn => Species::Dragonborn,
             Self::Dwarvish => Species::Dwarf,
             Self::Elvish => Species::Elf,
@@ -40,6 +55,62 @@ impl Ethnicity {
             Self::Tiefling => Species::Tiefling,
         }
     }
+
+    /// The names of the built-in ethnicities. Custom ethnicities are not included.
+    pub fn get_words() -> impl Iterator<Item = &'static str> {
+        Self::BUILT_IN.into_iter().map(|ethnicity| match ethnicity {
+            Self::Dragonborn => "dragonborn",
+            Self::Dwarvish => "dwarvish",
+            Self::Elvish => "elvish",
+            Self::Gnomish => "gnomish",
+            Self::Orcish => "orcish",
+            Self::Halfling => "halfling",
+            Self::Human => "human",
+            Self::Tiefling => "tiefling",
+            Self::Custom(_) => unreachable!(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Dragonborn => "dragonborn",
+            Self::Dwarvish => "dwarvish",
+            Self::Elvish => "elvish",
+            Self::Gnomish => "gnomish",
+            Self::Orcish => "orcish",
+            Self::Halfling => "halfling",
+            Self::Human => "human",
+            Self::Tiefling => "tiefling",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+/// Only built-in ethnicities can be parsed, since custom ethnicities depend on the user's
+/// [`Vocabulary`].
+impl FromStr for Ethnicity {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, ()> {
+        Self::BUILT_IN
+            .into_iter()
+            .find(|ethnicity| ethnicity.as_str().eq_ci(input))
+            .ok_or(())
+    }
+}
+
+impl From<String> for Ethnicity {
+    fn from(input: String) -> Self {
+        input
+            .parse()
+            .unwrap_or_else(|_| Self::Custom(input.to_lowercase()))
+    }
+}
+
+impl From<Ethnicity> for String {
+    fn from(input: Ethnicity) -> Self {
+        input.as_str().to_string()
+    }
 }
 
 trait Generate {
@@ -112,7 +183,7 @@ trait GenerateSimple {
     fn word_lname_last() -> &'static [(&'static str, usize)];
 }
 
-pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData) {
+pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData, demographics: &Demographics) {
     if let Some(ethnicity) = npc.ethnicity.value() {
         match ethnicity {
             Ethnicity::Dragonborn => dragonborn::Ethnicity::regenerate(rng, npc),
@@ -123,22 +194,22 @@ pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData) {
             Ethnicity::Halfling => halfling::Ethnicity::regenerate(rng, npc),
             Ethnicity::Human => human::Ethnicity::regenerate(rng, npc),
             Ethnicity::Tiefling => tiefling::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Custom(ethnicity) => {
+                if let Some(name) =
+                    demographics
+                        .vocabulary()
+                        .gen_name(rng, ethnicity, &npc.gender())
+                {
+                    npc.name.replace(name);
+                }
+            }
         }
     }
 }
 
 impl fmt::Display for Ethnicity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Dragonborn => write!(f, "dragonborn"),
-            Self::Dwarvish => write!(f, "dwarvish"),
-            Self::Elvish => write!(f, "elvish"),
-            Self::Gnomish => write!(f, "gnomish"),
-            Self::Orcish => write!(f, "orcish"),
-            Self::Halfling => write!(f, "halfling"),
-            Self::Human => write!(f, "human"),
-            Self::Tiefling => write!(f, "tiefling"),
-        }
+        write!(f, "{}", self.as_str())
     }
 }
 
@@ -176,7 +247,7 @@ pub mod test_utils {
             .ethnicity(ethnicity)
             .gender(gender)
             .build();
-        regenerate(rng, &mut npc);
+        regenerate(rng, &mut npc, &Demographics::default());
         format!("{}", npc.name)
     }
 }
@@ -1,19 +1,26 @@
 pub use age::Age;
+pub use creature::Creature;
 pub use ethnicity::Ethnicity;
 pub use gender::Gender;
+pub use personality::Personality;
 pub use size::Size;
 pub use species::Species;
+pub use stats::{StatBlock, StatBlockView};
 pub use view::{DescriptionView, DetailsView, SummaryView};
 
 mod age;
+mod creature;
 mod ethnicity;
 mod gender;
+mod personality;
 mod size;
 mod species;
+mod stats;
 mod view;
 
 use crate::world::place::Place;
 use crate::world::{Demographics, Field, Generate};
+use initiative_macros::WordList;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -36,6 +43,43 @@ pub struct NpcData {
     pub species: Field<Species>,
     pub ethnicity: Field<Ethnicity>,
     pub location_uuid: Field<Uuid>,
+
+    /// The kind of monster or beast, eg. "dragon", for non-humanoid characters. Creatures have no
+    /// species or ethnicity.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub creature: Field<Creature>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub personality: Field<Personality>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub ideal: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub bond: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub flaw: Field<String>,
+
+    /// A distinguishing physical feature, eg. a scar or tattoo.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub feature: Field<String>,
+
+    /// A habit of speech, useful for giving the character a voice.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub mannerism: Field<String>,
+
+    /// The SRD stat block used for the character in combat, eg. "veteran".
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub stats: Field<StatBlock>,
+
+    /// The character's hit point maximum, if different from the stat block's average.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub hit_points: Field<u16>,
+
+    /// The seed of the random number generator used to generate this NPC, if any.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub seed: Field<u64>,
     // pub home: Field<Uuid>,
     // pub occupation: Field<Role>,
     // pub languages: Field<Vec<String>>,
@@ -45,6 +89,44 @@ pub struct NpcData {
     // pub children: Field<Vec<Uuid>>,
 }
 
+/// The fields of an NPC that can be referred to by name, eg. `reroll Ana's age`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, WordList)]
+pub enum NpcField {
+    Name,
+    Gender,
+    Age,
+    Size,
+    Species,
+    Ethnicity,
+    Creature,
+
+    #[alias = "trait"]
+    #[alias = "traits"]
+    Personality,
+
+    #[alias = "ideals"]
+    Ideal,
+
+    #[alias = "bonds"]
+    Bond,
+
+    #[alias = "flaws"]
+    Flaw,
+
+    #[alias = "appearance"]
+    Feature,
+
+    #[alias = "voice"]
+    Mannerism,
+
+    #[alias = "stat block"]
+    Stats,
+
+    #[term = "hit points"]
+    #[alias = "hp"]
+    HitPoints,
+}
+
 #[derive(Debug, Default)]
 pub struct NpcRelations {
     pub location: Option<(Place, Option<Place>)>,
@@ -114,6 +196,16 @@ impl NpcData {
             species,
             ethnicity,
             location_uuid,
+            creature,
+            personality,
+            ideal,
+            bond,
+            flaw,
+            feature,
+            mannerism,
+            stats,
+            hit_points,
+            seed,
         } = self;
 
         name.lock();
@@ -124,6 +216,16 @@ impl NpcData {
         species.lock();
         ethnicity.lock();
         location_uuid.lock();
+        creature.lock();
+        personality.lock();
+        ideal.lock();
+        bond.lock();
+        flaw.lock();
+        feature.lock();
+        mannerism.lock();
+        stats.lock();
+        hit_points.lock();
+        seed.lock();
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
@@ -136,6 +238,16 @@ impl NpcData {
             species,
             ethnicity,
             location_uuid,
+            creature,
+            personality,
+            ideal,
+            bond,
+            flaw,
+            feature,
+            mannerism,
+            stats,
+            hit_points,
+            seed,
         } = self;
 
         name.apply_diff(&mut diff.name);
@@ -146,38 +258,256 @@ impl NpcData {
         species.apply_diff(&mut diff.species);
         ethnicity.apply_diff(&mut diff.ethnicity);
         location_uuid.apply_diff(&mut diff.location_uuid);
+        creature.apply_diff(&mut diff.creature);
+        personality.apply_diff(&mut diff.personality);
+        ideal.apply_diff(&mut diff.ideal);
+        bond.apply_diff(&mut diff.bond);
+        flaw.apply_diff(&mut diff.flaw);
+        feature.apply_diff(&mut diff.feature);
+        mannerism.apply_diff(&mut diff.mannerism);
+        stats.apply_diff(&mut diff.stats);
+        hit_points.apply_diff(&mut diff.hit_points);
+        seed.apply_diff(&mut diff.seed);
+    }
+
+    /// Like [`Self::apply_diff`], but applies unlocked values and carries over the lock state
+    /// of each field. See [`Field::apply_reroll_diff`].
+    pub fn apply_reroll_diff(&mut self, diff: &mut Self) {
+        let NpcData {
+            name,
+            gender,
+            age,
+            age_years,
+            size,
+            species,
+            ethnicity,
+            location_uuid,
+            creature,
+            personality,
+            ideal,
+            bond,
+            flaw,
+            feature,
+            mannerism,
+            stats,
+            hit_points,
+            seed,
+        } = self;
+
+        name.apply_reroll_diff(&mut diff.name);
+        gender.apply_reroll_diff(&mut diff.gender);
+        age.apply_reroll_diff(&mut diff.age);
+        age_years.apply_reroll_diff(&mut diff.age_years);
+        size.apply_reroll_diff(&mut diff.size);
+        species.apply_reroll_diff(&mut diff.species);
+        ethnicity.apply_reroll_diff(&mut diff.ethnicity);
+        location_uuid.apply_reroll_diff(&mut diff.location_uuid);
+        creature.apply_reroll_diff(&mut diff.creature);
+        personality.apply_reroll_diff(&mut diff.personality);
+        ideal.apply_reroll_diff(&mut diff.ideal);
+        bond.apply_reroll_diff(&mut diff.bond);
+        flaw.apply_reroll_diff(&mut diff.flaw);
+        feature.apply_reroll_diff(&mut diff.feature);
+        mannerism.apply_reroll_diff(&mut diff.mannerism);
+        stats.apply_reroll_diff(&mut diff.stats);
+        hit_points.apply_reroll_diff(&mut diff.hit_points);
+        seed.apply_reroll_diff(&mut diff.seed);
+    }
+
+    pub fn is_field_locked(&self, field: NpcField) -> bool {
+        match field {
+            NpcField::Name => self.name.is_locked(),
+            NpcField::Gender => self.gender.is_locked(),
+            NpcField::Age => self.age.is_locked() && self.age_years.is_locked(),
+            NpcField::Size => self.size.is_locked(),
+            NpcField::Species => self.species.is_locked(),
+            NpcField::Ethnicity => self.ethnicity.is_locked(),
+            NpcField::Creature => self.creature.is_locked(),
+            NpcField::Personality => self.personality.is_locked(),
+            NpcField::Ideal => self.ideal.is_locked(),
+            NpcField::Bond => self.bond.is_locked(),
+            NpcField::Flaw => self.flaw.is_locked(),
+            NpcField::Feature => self.feature.is_locked(),
+            NpcField::Mannerism => self.mannerism.is_locked(),
+            NpcField::Stats => self.stats.is_locked(),
+            NpcField::HitPoints => self.hit_points.is_locked(),
+        }
+    }
+
+    pub fn set_field_locked(&mut self, field: NpcField, locked: bool) {
+        match field {
+            NpcField::Name => self.name.set_locked(locked),
+            NpcField::Gender => self.gender.set_locked(locked),
+            NpcField::Age => {
+                self.age.set_locked(locked);
+                self.age_years.set_locked(locked);
+            }
+            NpcField::Size => self.size.set_locked(locked),
+            NpcField::Species => self.species.set_locked(locked),
+            NpcField::Ethnicity => self.ethnicity.set_locked(locked),
+            NpcField::Creature => self.creature.set_locked(locked),
+            NpcField::Personality => self.personality.set_locked(locked),
+            NpcField::Ideal => self.ideal.set_locked(locked),
+            NpcField::Bond => self.bond.set_locked(locked),
+            NpcField::Flaw => self.flaw.set_locked(locked),
+            NpcField::Feature => self.feature.set_locked(locked),
+            NpcField::Mannerism => self.mannerism.set_locked(locked),
+            NpcField::Stats => self.stats.set_locked(locked),
+            NpcField::HitPoints => self.hit_points.set_locked(locked),
+        }
+    }
+
+    /// Produce a diff that will lock or unlock the given field without changing its value.
+    pub fn lock_diff(&self, field: NpcField, locked: bool) -> Self {
+        let mut diff = Self::default();
+
+        match field {
+            NpcField::Name => diff.name = self.name.clone(),
+            NpcField::Gender => diff.gender = self.gender.clone(),
+            NpcField::Age => {
+                diff.age = self.age.clone();
+                diff.age_years = self.age_years.clone();
+            }
+            NpcField::Size => diff.size = self.size.clone(),
+            NpcField::Species => diff.species = self.species.clone(),
+            NpcField::Ethnicity => diff.ethnicity = self.ethnicity.clone(),
+            NpcField::Creature => diff.creature = self.creature.clone(),
+            NpcField::Personality => diff.personality = self.personality.clone(),
+            NpcField::Ideal => diff.ideal = self.ideal.clone(),
+            NpcField::Bond => diff.bond = self.bond.clone(),
+            NpcField::Flaw => diff.flaw = self.flaw.clone(),
+            NpcField::Feature => diff.feature = self.feature.clone(),
+            NpcField::Mannerism => diff.mannerism = self.mannerism.clone(),
+            NpcField::Stats => diff.stats = self.stats.clone(),
+            NpcField::HitPoints => diff.hit_points = self.hit_points.clone(),
+        }
+
+        diff.set_field_locked(field, locked);
+        diff
+    }
+
+    pub fn describe_field(&self, field: NpcField) -> Option<String> {
+        match field {
+            NpcField::Name => self.name.value().cloned(),
+            NpcField::Gender => self.gender.value().map(|gender| gender.name().to_string()),
+            NpcField::Age => self
+                .age_years
+                .value()
+                .map(|age_years| format!("{} years", age_years))
+                .or_else(|| self.age.value().map(|age| age.to_string())),
+            NpcField::Size => self.size.value().map(|size| size.to_string()),
+            NpcField::Species => self.species.value().map(|species| species.to_string()),
+            NpcField::Ethnicity => self
+                .ethnicity
+                .value()
+                .map(|ethnicity| ethnicity.to_string()),
+            NpcField::Creature => self.creature.value().map(|creature| creature.to_string()),
+            NpcField::Personality => self
+                .personality
+                .value()
+                .map(|personality| personality.to_string()),
+            NpcField::Ideal => self.ideal.value().cloned(),
+            NpcField::Bond => self.bond.value().cloned(),
+            NpcField::Flaw => self.flaw.value().cloned(),
+            NpcField::Feature => self.feature.value().cloned(),
+            NpcField::Mannerism => self.mannerism.value().cloned(),
+            NpcField::Stats => self.stats.value().map(|stats| stats.name().to_string()),
+            NpcField::HitPoints => self.stats.value().map(|_| self.hit_points().to_string()),
+        }
+    }
+
+    /// Regenerate the given fields, or all unlocked fields if none are given, returning a diff
+    /// containing the new values of any fields that changed. The regenerated values are left
+    /// unlocked.
+    pub fn reroll(
+        &self,
+        fields: &[NpcField],
+        rng: &mut impl Rng,
+        demographics: &Demographics,
+    ) -> Self {
+        let mut rerolled = self.clone();
+
+        if !fields.is_empty() {
+            rerolled.lock_all();
+
+            for field in fields {
+                rerolled.set_field_locked(*field, false);
+
+                // Species and ethnicity are generated from one another, so we can't reroll one
+                // without the other if it is missing.
+                match field {
+                    NpcField::Species if rerolled.ethnicity.is_none() => {
+                        rerolled.ethnicity.unlock()
+                    }
+                    NpcField::Ethnicity if rerolled.species.is_none() => rerolled.species.unlock(),
+                    // Hit points are rolled from the stat block, so a new stat block needs new ones.
+                    NpcField::Stats => rerolled.hit_points.unlock(),
+                    _ => {}
+                }
+            }
+        }
+
+        rerolled.regenerate(rng, demographics);
+
+        NpcData {
+            name: self.name.diff(&rerolled.name),
+            gender: self.gender.diff(&rerolled.gender),
+            age: self.age.diff(&rerolled.age),
+            age_years: self.age_years.diff(&rerolled.age_years),
+            size: self.size.diff(&rerolled.size),
+            species: self.species.diff(&rerolled.species),
+            ethnicity: self.ethnicity.diff(&rerolled.ethnicity),
+            location_uuid: Field::default(),
+            creature: self.creature.diff(&rerolled.creature),
+            personality: self.personality.diff(&rerolled.personality),
+            ideal: self.ideal.diff(&rerolled.ideal),
+            bond: self.bond.diff(&rerolled.bond),
+            flaw: self.flaw.diff(&rerolled.flaw),
+            feature: self.feature.diff(&rerolled.feature),
+            mannerism: self.mannerism.diff(&rerolled.mannerism),
+            stats: self.stats.diff(&rerolled.stats),
+            hit_points: self.hit_points.diff(&rerolled.hit_points),
+            seed: Field::default(),
+        }
     }
 }
 
 impl Generate for NpcData {
     fn regenerate(&mut self, rng: &mut impl Rng, demographics: &Demographics) {
-        match (self.species.is_locked(), self.ethnicity.is_locked()) {
-            (false, false) => {
-                let (species, ethnicity) = demographics.gen_species_ethnicity(rng);
-                self.ethnicity.replace(ethnicity);
-                self.species.replace(species);
+        if self.creature.is_some() {
+            creature::regenerate(rng, self);
+        } else {
+            match (self.species.is_locked(), self.ethnicity.is_locked()) {
+                (false, false) => {
+                    let (species, ethnicity) = demographics.gen_species_ethnicity(rng);
+                    self.ethnicity.replace(ethnicity);
+                    self.species.replace(species);
+                }
+                (false, true) => {
+                    self.species.replace(
+                        demographics
+                            .only_ethnicity(self.ethnicity.value().unwrap())
+                            .gen_species_ethnicity(rng)
+                            .0,
+                    );
+                }
+                (true, false) => {
+                    self.ethnicity.replace(
+                        demographics
+                            .only_species(self.species.value().unwrap())
+                            .gen_species_ethnicity(rng)
+                            .1,
+                    );
+                }
+                (true, true) => {}
             }
-            (false, true) => {
-                self.species.replace(
-                    demographics
-                        .only_ethnicity(self.ethnicity.value().unwrap())
-                        .gen_species_ethnicity(rng)
-                        .0,
-                );
-            }
-            (true, false) => {
-                self.ethnicity.replace(
-                    demographics
-                        .only_species(self.species.value().unwrap())
-                        .gen_species_ethnicity(rng)
-                        .1,
-                );
-            }
-            (true, true) => {}
+
+            species::regenerate(rng, self);
+            ethnicity::regenerate(rng, self, demographics);
         }
 
-        species::regenerate(rng, self);
-        ethnicity::regenerate(rng, self);
+        personality::regenerate(rng, self);
+        stats::regenerate(rng, self);
     }
 }
 
@@ -210,15 +540,24 @@ mod test {
     #[test]
     fn serialize_deserialize_test() {
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000011","name":"Odysseus","gender":"masculine","age":"middle-aged","age_years":50,"size":{"type":"Medium","height":72,"weight":180},"species":"human","ethnicity":"human","location_uuid":"00000000-0000-0000-0000-000000000003"}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000011","name":"Odysseus","gender":"masculine","age":"middle-aged","age_years":50,"size":{"type":"Medium","height":72,"weight":180},"species":"human","ethnicity":"human","location_uuid":"00000000-0000-0000-0000-000000000003","personality":"curious","ideal":"Family. Blood is thicker than water.","bond":"Will do anything to return home to Ithaca.","flaw":"Can't resist taunting a defeated foe.","feature":"A scar on the thigh from a boar hunt.","mannerism":"Spins a tall tale at every opportunity."}"#,
             serde_json::to_string(&test::npc::odysseus()).unwrap()
         );
 
-        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000011","name":"Odysseus","gender":"masculine","age":"middle-aged","age_years":50,"size":{"type":"Medium","height":72,"weight":180},"species":"human","ethnicity":"human","location_uuid":"00000000-0000-0000-0000-000000000003"}"#).unwrap();
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000011","name":"Odysseus","gender":"masculine","age":"middle-aged","age_years":50,"size":{"type":"Medium","height":72,"weight":180},"species":"human","ethnicity":"human","location_uuid":"00000000-0000-0000-0000-000000000003","personality":"curious","ideal":"Family. Blood is thicker than water.","bond":"Will do anything to return home to Ithaca.","flaw":"Can't resist taunting a defeated foe.","feature":"A scar on the thigh from a boar hunt.","mannerism":"Spins a tall tale at every opportunity."}"#).unwrap();
 
         assert_eq!(test::npc::odysseus(), value);
     }
 
+    #[test]
+    fn deserialize_test_without_personality() {
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000011","name":"Odysseus","gender":"masculine","age":"middle-aged","age_years":50,"size":{"type":"Medium","height":72,"weight":180},"species":"human","ethnicity":"human","location_uuid":"00000000-0000-0000-0000-000000000003"}"#).unwrap();
+
+        assert_eq!(Field::Unlocked(None), value.data.personality);
+        assert_eq!(Field::Unlocked(None), value.data.mannerism);
+        assert_eq!(test::npc::odysseus().data.name, value.data.name);
+    }
+
     #[test]
     fn apply_diff_test_no_change() {
         let mut npc = test::npc::odysseus();
@@ -244,6 +583,77 @@ mod test {
         assert_eq!(empty_locked, diff);
     }
 
+    #[test]
+    fn reroll_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+        let odysseus = test::npc::odysseus().data;
+
+        assert_eq!(
+            NpcData::default(),
+            odysseus.reroll(&[], &mut rng, &demographics),
+            "All fields are locked, so nothing should be rerolled.",
+        );
+
+        let diff = odysseus.reroll(&[NpcField::Age], &mut rng, &demographics);
+        assert!(diff.age_years.is_some());
+        assert!(diff.age_years.is_unlocked());
+        assert!(diff.name.is_none());
+        assert!(diff.species.is_none());
+        assert!(diff.size.is_none());
+
+        let mut npc = odysseus.clone();
+        npc.name.unlock();
+        let diff = npc.reroll(&[], &mut rng, &demographics);
+        assert!(diff.name.is_some());
+        assert_ne!(odysseus.name, diff.name);
+        assert!(diff.gender.is_none());
+    }
+
+    #[test]
+    fn reroll_test_missing_ethnicity() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let npc = NpcData {
+            species: Field::Locked(None),
+            ethnicity: Field::Locked(None),
+            ..Default::default()
+        };
+
+        let diff = npc.reroll(&[NpcField::Species], &mut rng, &Demographics::default());
+        assert!(diff.species.is_some());
+        assert!(diff.ethnicity.is_some());
+    }
+
+    #[test]
+    fn field_locked_test() {
+        let mut npc = NpcData::default();
+        assert!(!npc.is_field_locked(NpcField::Age));
+
+        npc.set_field_locked(NpcField::Age, true);
+        assert!(npc.age.is_locked());
+        assert!(npc.age_years.is_locked());
+        assert!(npc.is_field_locked(NpcField::Age));
+        assert!(!npc.is_field_locked(NpcField::Name));
+
+        npc.set_field_locked(NpcField::Age, false);
+        assert!(!npc.is_field_locked(NpcField::Age));
+    }
+
+    #[test]
+    fn describe_field_test() {
+        let odysseus = test::npc::odysseus().data;
+
+        assert_eq!(
+            Some("Odysseus".to_string()),
+            odysseus.describe_field(NpcField::Name),
+        );
+        assert_eq!(
+            Some("50 years".to_string()),
+            odysseus.describe_field(NpcField::Age),
+        );
+        assert_eq!(None, NpcData::default().describe_field(NpcField::Gender));
+    }
+
     #[test]
     fn lock_all_test() {
         let mut npc = NpcData::default();
@@ -259,6 +669,16 @@ mod test {
                 species: Field::Locked(None),
                 ethnicity: Field::Locked(None),
                 location_uuid: Field::Locked(None),
+                creature: Field::Locked(None),
+                personality: Field::Locked(None),
+                ideal: Field::Locked(None),
+                bond: Field::Locked(None),
+                flaw: Field::Locked(None),
+                feature: Field::Locked(None),
+                mannerism: Field::Locked(None),
+                stats: Field::Locked(None),
+                hit_points: Field::Locked(None),
+                seed: Field::Locked(None),
             },
             npc,
         );
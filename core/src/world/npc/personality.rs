@@ -0,0 +1,238 @@
+use super::NpcData;
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The most obvious aspect of a character's temperament, eg. "a grumpy dwarf".
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Personality {
+    Arrogant,
+    Blunt,
+
+    #[alias = "jolly"]
+    Cheerful,
+
+    Curious,
+    Friendly,
+
+    #[alias = "melancholy"]
+    Gloomy,
+
+    #[alias = "surly"]
+    Grumpy,
+
+    Honest,
+    Lazy,
+
+    #[alias = "anxious"]
+    Nervous,
+
+    Pious,
+
+    #[alias = "shy"]
+    Quiet,
+
+    Reckless,
+    Stoic,
+    Suspicious,
+
+    #[alias = "chatty"]
+    Talkative,
+}
+
+impl Personality {
+    /// Pick a personality at random, skipping aliases so that every personality is equally
+    /// likely, and avoiding the current personality if there is one.
+    pub fn generate(current: Option<Self>, rng: &mut impl Rng) -> Self {
+        Self::get_words()
+            .filter_map(|word| {
+                word.parse::<Self>()
+                    .ok()
+                    .filter(|personality| personality.as_str() == word)
+            })
+            .filter(|personality| Some(personality) != current.as_ref())
+            .choose(rng)
+            .unwrap()
+    }
+}
+
+impl fmt::Display for Personality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Fill in the roleplaying details of a character, leaving any locked fields alone. Unlocked
+/// fields always get a new value, so that rerolling a single field is never a no-op. Creatures
+/// only get a personality.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData) {
+    npc.personality
+        .replace_with(|current| Personality::generate(current, rng));
+
+    // Ideals, bonds and the like don't make much sense for a pet owlbear.
+    if npc.creature.is_some() {
+        return;
+    }
+
+    npc.ideal
+        .replace_with(|current| choose(IDEALS, current, rng));
+    npc.bond.replace_with(|current| choose(BONDS, current, rng));
+    npc.flaw.replace_with(|current| choose(FLAWS, current, rng));
+    npc.feature
+        .replace_with(|current| choose(FEATURES, current, rng));
+    npc.mannerism
+        .replace_with(|current| choose(MANNERISMS, current, rng));
+}
+
+fn choose(list: &[&str], current: Option<String>, rng: &mut impl Rng) -> String {
+    list.iter()
+        .filter(|s| current.as_deref() != Some(**s))
+        .choose(rng)
+        .unwrap()
+        .to_string()
+}
+
+const IDEALS: &[&str] = &[
+    "Charity. Those with plenty should share with those in need.",
+    "Family. Blood is thicker than water.",
+    "Freedom. Nobody should be bound by chains, tyrants or tradition.",
+    "Greed. Everything has a price, and the price is always going up.",
+    "Honor. A promise given is a promise kept.",
+    "Knowledge. The path to power and self-improvement is through learning.",
+    "Might. The strongest are meant to rule.",
+    "Redemption. There's a spark of good in everyone.",
+    "Respect. People deserve to be treated with dignity.",
+    "Tradition. The old ways must be preserved.",
+];
+
+const BONDS: &[&str] = &[
+    "Would die to protect a younger sibling.",
+    "Owes a life debt to a stranger who vanished without a word.",
+    "Is searching for a parent who disappeared years ago.",
+    "Keeps a keepsake from a lost love and never lets it out of sight.",
+    "Is fiercely loyal to an old mentor.",
+    "Will do anything to keep the family business afloat.",
+    "Swore an oath of vengeance against a local noble.",
+    "Considers the town's temple a second home.",
+    "Is secretly in love with a regular customer.",
+    "Still sends letters to a childhood friend who never writes back.",
+];
+
+const FLAWS: &[&str] = &[
+    "Can't resist a wager, however bad the odds.",
+    "Holds a grudge for years over the slightest insult.",
+    "Lies reflexively, even when the truth would serve better.",
+    "Drinks far more than is wise.",
+    "Trusts nobody, not even friends.",
+    "Will betray anyone for enough gold.",
+    "Is a coward when it really counts.",
+    "Can't keep a secret to save their life.",
+    "Is convinced they're smarter than everyone else.",
+    "Has a violent temper once provoked.",
+];
+
+const FEATURES: &[&str] = &[
+    "A jagged scar runs across one cheek.",
+    "Missing two fingers on the left hand.",
+    "Has striking mismatched eyes.",
+    "Wears an elaborate braided beard or hairstyle.",
+    "Covered in faded tattoos.",
+    "Walks with a pronounced limp.",
+    "Always wears a battered wide-brimmed hat.",
+    "Has a nose that has clearly been broken more than once.",
+    "Smells strongly of pipe smoke.",
+    "Has ink-stained fingers.",
+    "Wears far too much jewellery.",
+    "Is unusually tall and stoops through doorways.",
+];
+
+const MANNERISMS: &[&str] = &[
+    "Speaks in a hoarse whisper.",
+    "Talks far too loudly.",
+    "Punctuates every sentence with a nervous laugh.",
+    "Uses long words, often incorrectly.",
+    "Refers to themself in the third person.",
+    "Hums tunelessly while thinking.",
+    "Constantly fidgets with a coin.",
+    "Never makes eye contact.",
+    "Stutters when flustered.",
+    "Speaks in short, clipped sentences.",
+    "Peppers speech with sailing slang.",
+    "Pauses dramatically before answering any question.",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = NpcData::default();
+
+        regenerate(&mut rng, &mut npc);
+
+        assert!(npc.personality.is_some());
+        assert!(npc.ideal.is_some());
+        assert!(npc.bond.is_some());
+        assert!(npc.flaw.is_some());
+        assert!(npc.feature.is_some());
+        assert!(npc.mannerism.is_some());
+        assert!(npc.personality.is_unlocked());
+    }
+
+    #[test]
+    fn regenerate_test_locked() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = NpcData {
+            personality: Personality::Grumpy.into(),
+            flaw: "Hates elves.".into(),
+            ..Default::default()
+        };
+
+        for _ in 0..10 {
+            regenerate(&mut rng, &mut npc);
+
+            assert_eq!(Some(&Personality::Grumpy), npc.personality.value());
+            assert_eq!(Some("Hates elves."), npc.flaw.value().map(|s| s.as_str()));
+        }
+    }
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let personalities: Vec<_> = (0..100)
+            .map(|_| Personality::generate(None, &mut rng))
+            .collect();
+
+        assert!(personalities.contains(&Personality::Grumpy));
+        assert!(personalities.contains(&Personality::Talkative));
+    }
+
+    #[test]
+    fn regenerate_test_always_changes() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = NpcData::default();
+        regenerate(&mut rng, &mut npc);
+
+        for _ in 0..20 {
+            let before = npc.clone();
+            regenerate(&mut rng, &mut npc);
+
+            assert_ne!(before.personality, npc.personality);
+            assert_ne!(before.bond, npc.bond);
+            assert_ne!(before.mannerism, npc.mannerism);
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("grumpy", Personality::Grumpy.to_string());
+        assert_eq!(
+            "grumpy",
+            "surly".parse::<Personality>().unwrap().to_string()
+        );
+    }
+}
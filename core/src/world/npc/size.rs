@@ -4,20 +4,23 @@ use std::fmt;
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub enum Size {
-    Tiny { height: u16, weight: u16 },
-    Small { height: u16, weight: u16 },
-    Medium { height: u16, weight: u16 },
-    // Large { height: u16, weight: u16 },
-    // Huge { height: u16, weight: u16 },
-    // Gargantuan { height: u16, weight: u16 },
+    Tiny { height: u16, weight: u32 },
+    Small { height: u16, weight: u32 },
+    Medium { height: u16, weight: u32 },
+    Large { height: u16, weight: u32 },
+    Huge { height: u16, weight: u32 },
+    Gargantuan { height: u16, weight: u32 },
 }
 
 impl Size {
-    pub fn height_weight(&self) -> (u16, u16) {
+    pub fn height_weight(&self) -> (u16, u32) {
         match self {
             Self::Tiny { height, weight } => (*height, *weight),
             Self::Small { height, weight } => (*height, *weight),
             Self::Medium { height, weight } => (*height, *weight),
+            Self::Large { height, weight } => (*height, *weight),
+            Self::Huge { height, weight } => (*height, *weight),
+            Self::Gargantuan { height, weight } => (*height, *weight),
         }
     }
 
@@ -30,7 +33,7 @@ impl Size {
         ((height / 12) as u8, (height % 12) as u8)
     }
 
-    pub fn weight(&self) -> u16 {
+    pub fn weight(&self) -> u32 {
         self.height_weight().1
     }
 
@@ -39,6 +42,9 @@ impl Size {
             Self::Tiny { .. } => "tiny",
             Self::Small { .. } => "small",
             Self::Medium { .. } => "medium",
+            Self::Large { .. } => "large",
+            Self::Huge { .. } => "huge",
+            Self::Gargantuan { .. } => "gargantuan",
         }
     }
 }
@@ -115,11 +121,29 @@ mod test {
             }
             .name()
         );
+        assert_eq!(
+            "gargantuan",
+            Size::Gargantuan {
+                height: 0,
+                weight: 0
+            }
+            .name()
+        );
     }
 
     #[test]
     fn fmt_test() {
         assert_eq!("5'11\", 140 lbs (medium)", format!("{}", size()));
+        assert_eq!(
+            "20'0\", 24000 lbs (huge)",
+            format!(
+                "{}",
+                Size::Huge {
+                    height: 240,
+                    weight: 24000
+                }
+            ),
+        );
     }
 
     #[test]
@@ -132,6 +156,26 @@ mod test {
         let value: Size =
             serde_json::from_str(r#"{"type":"Medium","height":71,"weight":140}"#).unwrap();
         assert_eq!(size(), value);
+
+        let value: Size =
+            serde_json::from_str(r#"{"type":"Large","height":90,"weight":1300}"#).unwrap();
+        assert_eq!(
+            Size::Large {
+                height: 90,
+                weight: 1300
+            },
+            value,
+        );
+
+        let value: Size =
+            serde_json::from_str(r#"{"type":"Gargantuan","height":720,"weight":400000}"#).unwrap();
+        assert_eq!(
+            Size::Gargantuan {
+                height: 720,
+                weight: 400_000
+            },
+            value,
+        );
     }
 
     fn size() -> Size {
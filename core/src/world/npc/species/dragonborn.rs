@@ -46,7 +46,7 @@ impl Generate for Species {
         let size = rng.gen_range(1..=6) + rng.gen_range(1..=6);
         Size::Medium {
             height: 72 + size,
-            weight: 220 + size * 6,
+            weight: (220 + size * 6).into(),
         }
     }
 }
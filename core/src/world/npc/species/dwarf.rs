@@ -47,7 +47,7 @@ impl Generate for Species {
         let size = rng.gen_range(1..=6) + rng.gen_range(1..=6);
         Size::Medium {
             height: 48 + size,
-            weight: 125 + size * 4,
+            weight: (125 + size * 4).into(),
         }
     }
 }
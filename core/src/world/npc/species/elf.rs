@@ -47,7 +47,7 @@ impl Generate for Species {
         let size = rng.gen_range(1..=12) + rng.gen_range(1..=12);
         Size::Medium {
             height: 54 + size,
-            weight: 60 + size * 6,
+            weight: (60 + size * 6).into(),
         }
     }
 }
@@ -47,7 +47,7 @@ impl Generate for Species {
         let size = rng.gen_range(1..=8) + rng.gen_range(1..=8);
         Size::Medium {
             height: 60 + size,
-            weight: 130 + size * 6,
+            weight: (130 + size * 6).into(),
         }
     }
 }
@@ -47,7 +47,7 @@ impl Generate for Species {
         let size = rng.gen_range(1..=4) + rng.gen_range(1..=4);
         Size::Small {
             height: 32 + size,
-            weight: 32 + size * 2,
+            weight: (32 + size * 2).into(),
         }
     }
 }
@@ -55,14 +55,14 @@ impl Generate for Species {
                 let size = rng.gen_range(0..=30);
                 Size::Tiny {
                     height: 20 + size / 3,
-                    weight: 7 + size / 2,
+                    weight: (7 + size / 2).into(),
                 }
             }
             (1, _) => {
                 let size = rng.gen_range(0..=5);
                 Size::Tiny {
                     height: 30 + size,
-                    weight: 22 + size,
+                    weight: (22 + size).into(),
                 }
             }
             (2..=9, _) => {
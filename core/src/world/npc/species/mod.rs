@@ -16,7 +16,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::RangeInclusive;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, WordList, Serialize, Deserialize)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, WordList, Serialize, Deserialize,
+)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum Species {
     Dragonborn,
@@ -98,7 +100,7 @@ fn gen_height_weight(
     rng: &mut impl Rng,
     height_range: RangeInclusive<f32>,
     bmi_range: RangeInclusive<f32>,
-) -> (u16, u16) {
+) -> (u16, u32) {
     let height = {
         let mean = (height_range.end() + height_range.start()) / 2.;
         let std_dev = mean - height_range.start();
@@ -113,7 +115,7 @@ fn gen_height_weight(
 
     let weight = bmi * height * height / 703.;
 
-    (height as u16, weight as u16)
+    (height as u16, weight as u32)
 }
 
 impl Species {
@@ -178,7 +180,7 @@ mod test {
             gender: Gender::Neuter.into(),
             size: Size::Tiny {
                 height: u16::MAX,
-                weight: u16::MAX,
+                weight: u32::MAX,
             }
             .into(),
             ..Default::default()
@@ -194,7 +196,7 @@ mod test {
         assert_eq!(
             Some(&Size::Tiny {
                 height: u16::MAX,
-                weight: u16::MAX
+                weight: u32::MAX
             }),
             npc.size.value(),
         );
@@ -239,7 +241,7 @@ mod test {
             ],
             (0..10)
                 .map(|_| gen_height_weight(&mut rng, 64.0..=68.0, 18.5..=25.0))
-                .collect::<Vec<(u16, u16)>>(),
+                .collect::<Vec<(u16, u32)>>(),
         );
     }
 
@@ -0,0 +1,829 @@
+use super::{NpcData, Species};
+use crate::app::{Block, Inline, Output};
+use crate::reference::Trait;
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One of the generic NPC stat blocks from the SRD, eg. `Ana is a veteran`. The numbers
+/// themselves aren't stored with the character, only the template they come from and the
+/// character's current hit point maximum.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum StatBlock {
+    Acolyte,
+    Bandit,
+    BanditCaptain,
+    Berserker,
+    Commoner,
+    CultFanatic,
+    Cultist,
+    Gladiator,
+    Guard,
+    Knight,
+    Mage,
+    Noble,
+    Priest,
+    Scout,
+    Spy,
+    Thug,
+    TribalWarrior,
+    Veteran,
+}
+
+/// A dice formula of the form `XdY + Z`, as used for hit points and damage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Dice {
+    pub count: u8,
+    pub sides: u8,
+    pub bonus: i8,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attack {
+    pub name: &'static str,
+    pub to_hit: i8,
+    pub damage: Dice,
+    pub damage_type: &'static str,
+}
+
+struct Template {
+    name: &'static str,
+    armor_class: u8,
+    armor: Option<&'static str>,
+    hit_dice: Dice,
+    speed: u8,
+    challenge: &'static str,
+    multiattack: Option<&'static str>,
+    attacks: &'static [Attack],
+}
+
+pub struct StatBlockView<'a> {
+    npc: &'a NpcData,
+    stat_block: StatBlock,
+}
+
+impl StatBlock {
+    /// Pick a stat block at random, avoiding the current one if there is one.
+    pub fn generate(current: Option<Self>, rng: &mut impl Rng) -> Self {
+        Self::get_words()
+            .filter_map(|word| word.parse::<Self>().ok())
+            .filter(|stat_block| Some(stat_block) != current.as_ref())
+            .choose(rng)
+            .unwrap()
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.template().name
+    }
+
+    pub fn armor_class(&self) -> u8 {
+        self.template().armor_class
+    }
+
+    /// The armor responsible for the armor class, if any, eg. "chain shirt, shield".
+    pub fn armor(&self) -> Option<&'static str> {
+        self.template().armor
+    }
+
+    pub fn hit_dice(&self) -> Dice {
+        self.template().hit_dice
+    }
+
+    /// Walking speed in feet.
+    pub fn speed(&self) -> u8 {
+        self.template().speed
+    }
+
+    /// The challenge rating, eg. "1/8".
+    pub fn challenge(&self) -> &'static str {
+        self.template().challenge
+    }
+
+    pub fn multiattack(&self) -> Option<&'static str> {
+        self.template().multiattack
+    }
+
+    pub fn attacks(&self) -> &'static [Attack] {
+        self.template().attacks
+    }
+
+    /// The hit point maximum listed in the SRD.
+    pub fn average_hit_points(&self) -> u16 {
+        self.hit_dice().average()
+    }
+
+    /// Roll a new hit point maximum, avoiding the current value if possible so that rerolling
+    /// hit points is never a no-op.
+    pub fn roll_hit_points(&self, current: Option<u16>, rng: &mut impl Rng) -> u16 {
+        let hit_dice = self.hit_dice();
+
+        loop {
+            let hit_points = hit_dice.roll(rng);
+            if Some(hit_points) != current || hit_dice.count == 0 {
+                break hit_points;
+            }
+        }
+    }
+
+    fn template(&self) -> &'static Template {
+        match self {
+            Self::Acolyte => &ACOLYTE,
+            Self::Bandit => &BANDIT,
+            Self::BanditCaptain => &BANDIT_CAPTAIN,
+            Self::Berserker => &BERSERKER,
+            Self::Commoner => &COMMONER,
+            Self::CultFanatic => &CULT_FANATIC,
+            Self::Cultist => &CULTIST,
+            Self::Gladiator => &GLADIATOR,
+            Self::Guard => &GUARD,
+            Self::Knight => &KNIGHT,
+            Self::Mage => &MAGE,
+            Self::Noble => &NOBLE,
+            Self::Priest => &PRIEST,
+            Self::Scout => &SCOUT,
+            Self::Spy => &SPY,
+            Self::Thug => &THUG,
+            Self::TribalWarrior => &TRIBAL_WARRIOR,
+            Self::Veteran => &VETERAN,
+        }
+    }
+}
+
+impl fmt::Display for StatBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Dice {
+    const fn new(count: u8, sides: u8, bonus: i8) -> Self {
+        Self {
+            count,
+            sides,
+            bonus,
+        }
+    }
+
+    /// The average result, rounded down, as printed in the SRD.
+    pub fn average(&self) -> u16 {
+        (self.count as i32 * (self.sides as i32 + 1) / 2 + self.bonus as i32).max(1) as u16
+    }
+
+    pub fn roll(&self, rng: &mut impl Rng) -> u16 {
+        let total: i32 = (0..self.count)
+            .map(|_| rng.gen_range(1..=self.sides as i32))
+            .sum();
+        (total + self.bonus as i32).max(1) as u16
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+
+        match self.bonus {
+            0 => Ok(()),
+            bonus if bonus < 0 => write!(f, " - {}", -bonus),
+            bonus => write!(f, " + {}", bonus),
+        }
+    }
+}
+
+impl fmt::Display for Attack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Inline::to_markdown(&self.to_inlines()))
+    }
+}
+
+impl<'a> StatBlockView<'a> {
+    pub fn new(npc: &'a NpcData, stat_block: StatBlock) -> Self {
+        Self { npc, stat_block }
+    }
+}
+
+impl fmt::Display for StatBlockView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Output::new(self.to_blocks()).to_markdown())
+    }
+}
+
+impl Attack {
+    fn to_inlines(self) -> Vec<Inline> {
+        vec![
+            Inline::emphasis(vec![Inline::text(format!("{}.", self.name))]),
+            Inline::text(format!(
+                " {:+} to hit, {} (",
+                self.to_hit,
+                self.damage.average(),
+            )),
+            Inline::command(self.damage.to_string()),
+            Inline::text(format!(") {} damage.", self.damage_type)),
+        ]
+    }
+}
+
+impl StatBlockView<'_> {
+    /// The stat block's fields, followed by a paragraph for each attack.
+    pub fn to_blocks(&self) -> Vec<Block> {
+        let Self { npc, stat_block } = self;
+
+        let mut lines = vec![
+            Inline::field(
+                "Stat block",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    stat_block.name(),
+                    npc.stats.lock_indicator(),
+                ))],
+            ),
+            Inline::field(
+                "Armor Class",
+                vec![Inline::text(match stat_block.armor() {
+                    Some(armor) => format!("{} ({})", stat_block.armor_class(), armor),
+                    None => stat_block.armor_class().to_string(),
+                })],
+            ),
+            Inline::field(
+                "Hit Points",
+                vec![
+                    Inline::text(format!("{} (", npc.hit_points())),
+                    Inline::command(stat_block.hit_dice().to_string()),
+                    Inline::text(format!("){}", npc.hit_points.lock_indicator())),
+                ],
+            ),
+            Inline::field(
+                "Speed",
+                vec![Inline::text(format!("{} ft.", stat_block.speed()))],
+            ),
+            Inline::field(
+                "Challenge",
+                vec![Inline::text(stat_block.challenge().to_string())],
+            ),
+        ];
+
+        let species_traits = npc.species_traits();
+        if !species_traits.is_empty() {
+            let mut traits = Vec::new();
+            for (i, species_trait) in species_traits.iter().enumerate() {
+                if i > 0 {
+                    traits.push(Inline::text(", "));
+                }
+                traits.push(Inline::command(species_trait.get_name()));
+            }
+            lines.push(Inline::field("Species traits", traits));
+        }
+
+        let mut blocks = vec![Block::lines(lines)];
+
+        if let Some(multiattack) = stat_block.multiattack() {
+            blocks.push(Block::paragraph(vec![
+                Inline::emphasis(vec![Inline::text("Multiattack.")]),
+                Inline::text(format!(" {}", multiattack)),
+            ]));
+        }
+        for attack in stat_block.attacks() {
+            blocks.push(Block::paragraph(attack.to_inlines()));
+        }
+
+        blocks
+    }
+}
+
+impl NpcData {
+    /// The character's hit point maximum, falling back on the stat block's average if none has
+    /// been recorded. Returns 0 if the character has no stat block.
+    pub fn hit_points(&self) -> u16 {
+        self.hit_points
+            .value()
+            .copied()
+            .or_else(|| self.stats.value().map(StatBlock::average_hit_points))
+            .unwrap_or_default()
+    }
+
+    /// The SRD traits shared by every member of the character's species, eg. dwarven darkvision.
+    pub fn species_traits(&self) -> Vec<Trait> {
+        self.species
+            .value()
+            .map(|species| species_traits(*species))
+            .unwrap_or_default()
+    }
+}
+
+fn species_traits(species: Species) -> Vec<Trait> {
+    Trait::get_words()
+        .filter_map(|word| word.parse::<Trait>().ok())
+        .filter(|species_trait| species_trait.get_species().contains(&species.as_str()))
+        .collect()
+}
+
+/// Fill in the hit points of a character with a stat block, leaving them alone if locked. Stat
+/// blocks are never added to a character that doesn't already have one, but an unlocked stat
+/// block is swapped for a different one, eg. by `reroll Ana's stats`.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut NpcData) {
+    if npc.stats.is_some() {
+        npc.stats
+            .replace_with(|current| StatBlock::generate(current, rng));
+    }
+
+    if let Some(stat_block) = npc.stats.value().copied() {
+        npc.hit_points
+            .replace_with(|current| stat_block.roll_hit_points(current, rng));
+    }
+}
+
+const ACOLYTE: Template = Template {
+    name: "Acolyte",
+    armor_class: 10,
+    armor: None,
+    hit_dice: Dice::new(2, 8, 0),
+    speed: 30,
+    challenge: "1/4",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Club",
+        to_hit: 2,
+        damage: Dice::new(1, 4, 0),
+        damage_type: "bludgeoning",
+    }],
+};
+
+const BANDIT: Template = Template {
+    name: "Bandit",
+    armor_class: 12,
+    armor: Some("leather armor"),
+    hit_dice: Dice::new(2, 8, 2),
+    speed: 30,
+    challenge: "1/8",
+    multiattack: None,
+    attacks: &[
+        Attack {
+            name: "Scimitar",
+            to_hit: 3,
+            damage: Dice::new(1, 6, 1),
+            damage_type: "slashing",
+        },
+        Attack {
+            name: "Light Crossbow",
+            to_hit: 3,
+            damage: Dice::new(1, 8, 1),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const BANDIT_CAPTAIN: Template = Template {
+    name: "Bandit Captain",
+    armor_class: 15,
+    armor: Some("studded leather"),
+    hit_dice: Dice::new(10, 8, 20),
+    speed: 30,
+    challenge: "2",
+    multiattack: Some("Three melee attacks: two with its scimitar and one with its dagger."),
+    attacks: &[
+        Attack {
+            name: "Scimitar",
+            to_hit: 5,
+            damage: Dice::new(1, 6, 3),
+            damage_type: "slashing",
+        },
+        Attack {
+            name: "Dagger",
+            to_hit: 5,
+            damage: Dice::new(1, 4, 3),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const BERSERKER: Template = Template {
+    name: "Berserker",
+    armor_class: 13,
+    armor: Some("hide armor"),
+    hit_dice: Dice::new(9, 8, 27),
+    speed: 30,
+    challenge: "2",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Greataxe",
+        to_hit: 5,
+        damage: Dice::new(1, 12, 3),
+        damage_type: "slashing",
+    }],
+};
+
+const COMMONER: Template = Template {
+    name: "Commoner",
+    armor_class: 10,
+    armor: None,
+    hit_dice: Dice::new(1, 8, 0),
+    speed: 30,
+    challenge: "0",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Club",
+        to_hit: 2,
+        damage: Dice::new(1, 4, 0),
+        damage_type: "bludgeoning",
+    }],
+};
+
+const CULT_FANATIC: Template = Template {
+    name: "Cult Fanatic",
+    armor_class: 13,
+    armor: Some("leather armor"),
+    hit_dice: Dice::new(6, 8, 6),
+    speed: 30,
+    challenge: "2",
+    multiattack: Some("Two melee attacks."),
+    attacks: &[Attack {
+        name: "Dagger",
+        to_hit: 4,
+        damage: Dice::new(1, 4, 2),
+        damage_type: "piercing",
+    }],
+};
+
+const CULTIST: Template = Template {
+    name: "Cultist",
+    armor_class: 12,
+    armor: Some("leather armor"),
+    hit_dice: Dice::new(2, 8, 0),
+    speed: 30,
+    challenge: "1/8",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Scimitar",
+        to_hit: 3,
+        damage: Dice::new(1, 6, 1),
+        damage_type: "slashing",
+    }],
+};
+
+const GLADIATOR: Template = Template {
+    name: "Gladiator",
+    armor_class: 16,
+    armor: Some("studded leather, shield"),
+    hit_dice: Dice::new(15, 8, 45),
+    speed: 30,
+    challenge: "5",
+    multiattack: Some("Three melee attacks or two ranged attacks."),
+    attacks: &[
+        Attack {
+            name: "Spear",
+            to_hit: 7,
+            damage: Dice::new(2, 6, 4),
+            damage_type: "piercing",
+        },
+        Attack {
+            name: "Shield Bash",
+            to_hit: 7,
+            damage: Dice::new(2, 4, 4),
+            damage_type: "bludgeoning",
+        },
+    ],
+};
+
+const GUARD: Template = Template {
+    name: "Guard",
+    armor_class: 16,
+    armor: Some("chain shirt, shield"),
+    hit_dice: Dice::new(2, 8, 2),
+    speed: 30,
+    challenge: "1/8",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Spear",
+        to_hit: 3,
+        damage: Dice::new(1, 6, 1),
+        damage_type: "piercing",
+    }],
+};
+
+const KNIGHT: Template = Template {
+    name: "Knight",
+    armor_class: 18,
+    armor: Some("plate"),
+    hit_dice: Dice::new(8, 8, 16),
+    speed: 30,
+    challenge: "3",
+    multiattack: Some("Two melee attacks."),
+    attacks: &[
+        Attack {
+            name: "Greatsword",
+            to_hit: 5,
+            damage: Dice::new(2, 6, 3),
+            damage_type: "slashing",
+        },
+        Attack {
+            name: "Heavy Crossbow",
+            to_hit: 2,
+            damage: Dice::new(1, 10, 0),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const MAGE: Template = Template {
+    name: "Mage",
+    armor_class: 12,
+    armor: Some("15 with mage armor"),
+    hit_dice: Dice::new(9, 8, 0),
+    speed: 30,
+    challenge: "6",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Dagger",
+        to_hit: 5,
+        damage: Dice::new(1, 4, 2),
+        damage_type: "piercing",
+    }],
+};
+
+const NOBLE: Template = Template {
+    name: "Noble",
+    armor_class: 15,
+    armor: Some("breastplate"),
+    hit_dice: Dice::new(2, 8, 0),
+    speed: 30,
+    challenge: "1/8",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Rapier",
+        to_hit: 3,
+        damage: Dice::new(1, 8, 1),
+        damage_type: "piercing",
+    }],
+};
+
+const PRIEST: Template = Template {
+    name: "Priest",
+    armor_class: 13,
+    armor: Some("chain shirt"),
+    hit_dice: Dice::new(5, 8, 5),
+    speed: 25,
+    challenge: "2",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Mace",
+        to_hit: 2,
+        damage: Dice::new(1, 6, 0),
+        damage_type: "bludgeoning",
+    }],
+};
+
+const SCOUT: Template = Template {
+    name: "Scout",
+    armor_class: 13,
+    armor: Some("leather armor"),
+    hit_dice: Dice::new(3, 8, 3),
+    speed: 30,
+    challenge: "1/2",
+    multiattack: Some("Two melee attacks or two ranged attacks."),
+    attacks: &[
+        Attack {
+            name: "Shortsword",
+            to_hit: 4,
+            damage: Dice::new(1, 6, 2),
+            damage_type: "piercing",
+        },
+        Attack {
+            name: "Longbow",
+            to_hit: 4,
+            damage: Dice::new(1, 8, 2),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const SPY: Template = Template {
+    name: "Spy",
+    armor_class: 12,
+    armor: None,
+    hit_dice: Dice::new(6, 8, 0),
+    speed: 30,
+    challenge: "1",
+    multiattack: Some("Two melee attacks."),
+    attacks: &[
+        Attack {
+            name: "Shortsword",
+            to_hit: 4,
+            damage: Dice::new(1, 6, 2),
+            damage_type: "piercing",
+        },
+        Attack {
+            name: "Hand Crossbow",
+            to_hit: 4,
+            damage: Dice::new(1, 6, 2),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const THUG: Template = Template {
+    name: "Thug",
+    armor_class: 11,
+    armor: Some("leather armor"),
+    hit_dice: Dice::new(5, 8, 10),
+    speed: 30,
+    challenge: "1/2",
+    multiattack: Some("Two melee attacks."),
+    attacks: &[
+        Attack {
+            name: "Mace",
+            to_hit: 4,
+            damage: Dice::new(1, 6, 2),
+            damage_type: "bludgeoning",
+        },
+        Attack {
+            name: "Heavy Crossbow",
+            to_hit: 2,
+            damage: Dice::new(1, 10, 0),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+const TRIBAL_WARRIOR: Template = Template {
+    name: "Tribal Warrior",
+    armor_class: 12,
+    armor: Some("hide armor"),
+    hit_dice: Dice::new(2, 8, 2),
+    speed: 30,
+    challenge: "1/8",
+    multiattack: None,
+    attacks: &[Attack {
+        name: "Spear",
+        to_hit: 3,
+        damage: Dice::new(1, 6, 1),
+        damage_type: "piercing",
+    }],
+};
+
+const VETERAN: Template = Template {
+    name: "Veteran",
+    armor_class: 17,
+    armor: Some("splint"),
+    hit_dice: Dice::new(9, 8, 18),
+    speed: 30,
+    challenge: "3",
+    multiattack: Some(
+        "Two longsword attacks. If it has a shortsword drawn, it can also make a shortsword \
+        attack.",
+    ),
+    attacks: &[
+        Attack {
+            name: "Longsword",
+            to_hit: 5,
+            damage: Dice::new(1, 8, 3),
+            damage_type: "slashing",
+        },
+        Attack {
+            name: "Shortsword",
+            to_hit: 5,
+            damage: Dice::new(1, 6, 3),
+            damage_type: "piercing",
+        },
+        Attack {
+            name: "Heavy Crossbow",
+            to_hit: 3,
+            damage: Dice::new(1, 10, 1),
+            damage_type: "piercing",
+        },
+    ],
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::world::Field;
+
+    #[test]
+    fn average_hit_points_test() {
+        assert_eq!(4, StatBlock::Commoner.average_hit_points());
+        assert_eq!(11, StatBlock::Guard.average_hit_points());
+        assert_eq!(58, StatBlock::Veteran.average_hit_points());
+        assert_eq!(65, StatBlock::BanditCaptain.average_hit_points());
+        assert_eq!(112, StatBlock::Gladiator.average_hit_points());
+    }
+
+    #[test]
+    fn roll_hit_points_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut hit_points = None;
+
+        for _ in 0..20 {
+            let rolled = StatBlock::Guard.roll_hit_points(hit_points, &mut rng);
+            assert!((3..=18).contains(&rolled), "{}", rolled);
+            assert_ne!(hit_points, Some(rolled));
+            hit_points = Some(rolled);
+        }
+    }
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let mut npc = NpcData::default();
+        regenerate(&mut rng, &mut npc);
+        assert!(npc.hit_points.is_none());
+
+        let mut npc = NpcData {
+            stats: StatBlock::Veteran.into(),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc);
+        assert_eq!(Some(&StatBlock::Veteran), npc.stats.value());
+        assert!(npc.hit_points.is_some());
+
+        let mut npc = NpcData {
+            stats: Field::Unlocked(Some(StatBlock::Veteran)),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc);
+        assert_ne!(Some(&StatBlock::Veteran), npc.stats.value());
+        assert!(npc.stats.is_some());
+
+        let mut npc = NpcData {
+            stats: StatBlock::Veteran.into(),
+            hit_points: Field::new(40),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc);
+        assert_eq!(40, npc.hit_points());
+    }
+
+    #[test]
+    fn hit_points_test() {
+        let mut npc = NpcData::default();
+        assert_eq!(0, npc.hit_points());
+
+        npc.stats = StatBlock::Guard.into();
+        assert_eq!(11, npc.hit_points());
+
+        npc.hit_points = Field::new(15);
+        assert_eq!(15, npc.hit_points());
+    }
+
+    #[test]
+    fn species_traits_test() {
+        for species in Species::get_words().filter_map(|word| word.parse::<Species>().ok()) {
+            for species_trait in species_traits(species) {
+                assert!(
+                    species_trait.get_species().contains(&species.as_str()),
+                    "{:?}",
+                    species_trait,
+                );
+            }
+        }
+
+        assert!(NpcData::default().species_traits().is_empty());
+    }
+
+    #[test]
+    fn dice_display_test() {
+        assert_eq!("1d8", Dice::new(1, 8, 0).to_string());
+        assert_eq!("2d8 + 2", Dice::new(2, 8, 2).to_string());
+        assert_eq!("1d4 - 1", Dice::new(1, 4, -1).to_string());
+    }
+
+    #[test]
+    fn attack_display_test() {
+        assert_eq!(
+            "_Longsword._ +5 to hit, 7 (`1d8 + 3`) slashing damage.",
+            StatBlock::Veteran.attacks()[0].to_string(),
+        );
+    }
+
+    #[test]
+    fn stat_block_view_test() {
+        let npc = NpcData {
+            stats: StatBlock::Guard.into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "**Stat block:** Guard 🔒\\
+**Armor Class:** 16 (chain shirt, shield)\\
+**Hit Points:** 11 (`2d8 + 2`)\\
+**Speed:** 30 ft.\\
+**Challenge:** 1/8
+
+_Spear._ +3 to hit, 4 (`1d6 + 1`) piercing damage.",
+            StatBlockView::new(&npc, StatBlock::Guard).to_string(),
+        );
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            r#""bandit-captain""#,
+            serde_json::to_string(&StatBlock::BanditCaptain).unwrap(),
+        );
+        assert_eq!(
+            StatBlock::TribalWarrior,
+            serde_json::from_str::<StatBlock>(r#""tribal-warrior""#).unwrap(),
+        );
+    }
+}
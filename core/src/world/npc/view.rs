@@ -1,4 +1,5 @@
-use super::{Age, Gender, NpcData, NpcRelations, Uuid};
+use super::{Age, Gender, NpcData, NpcRelations, StatBlockView, Uuid};
+use crate::app::{Block, Inline, Output};
 use std::fmt;
 
 pub struct SummaryView<'a>(&'a NpcData);
@@ -12,7 +13,16 @@ pub struct DetailsView<'a> {
 }
 
 fn write_summary_details(npc: &NpcData, f: &mut fmt::Formatter) -> fmt::Result {
-    if let Some(age) = npc.age.value() {
+    if let Some(creature) = npc.creature.value() {
+        // Size matters more than age when it comes to dragons.
+        if let Some(size) = npc.size.value() {
+            write!(f, "{} ", size.name())?;
+        }
+        if let Some(age) = npc.age.value() {
+            write!(f, "{} ", age)?;
+        }
+        write!(f, "{}", creature)?;
+    } else if let Some(age) = npc.age.value() {
         age.fmt_with_species_ethnicity(npc.species.value(), npc.ethnicity.value(), f)?;
     } else if let Some(species) = npc.species.value() {
         write!(f, "{}", species)?;
@@ -51,17 +61,13 @@ impl<'a> DetailsView<'a> {
     }
 }
 
-impl fmt::Display for SummaryView<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl SummaryView<'_> {
+    fn emoji(&self) -> char {
         let npc = self.0;
-        let has_details = npc.age.is_some()
-            || npc.ethnicity.is_some()
-            || npc.gender.is_some()
-            || npc.species.is_some();
 
-        write!(
-            f,
-            "{} ",
+        if let Some(creature) = npc.creature.value() {
+            creature.emoji()
+        } else {
             match (npc.age.value(), npc.gender.value()) {
                 (Some(Age::Infant), _) => '\u{1f476}',
                 (Some(Age::Child | Age::Adolescent), Some(Gender::Feminine)) => '\u{1f467}',
@@ -73,22 +79,44 @@ impl fmt::Display for SummaryView<'_> {
                 (_, Some(Gender::Feminine)) => '\u{1f469}',
                 (_, Some(Gender::Masculine)) => '\u{1f468}',
                 _ => '\u{1f9d1}',
-            },
-        )?;
-
-        if let Some(name) = npc.name.value() {
-            if has_details {
-                write!(f, "`{}` (", name)?;
-                write_summary_details(npc, f)?;
-                write!(f, ")")?;
-            } else {
-                write!(f, "`{}`", name)?;
             }
-        } else {
-            write_summary_details(npc, f)?;
         }
+    }
+
+    fn has_details(&self) -> bool {
+        let npc = self.0;
 
-        Ok(())
+        npc.age.is_some()
+            || npc.ethnicity.is_some()
+            || npc.gender.is_some()
+            || npc.species.is_some()
+            || npc.creature.is_some()
+    }
+
+    /// The emoji and description, with the name (if any) as a command link.
+    pub fn to_inlines(&self) -> Vec<Inline> {
+        let npc = self.0;
+        let emoji = Inline::text(format!("{} ", self.emoji()));
+
+        match npc.name.value() {
+            Some(name) if self.has_details() => vec![
+                emoji,
+                Inline::command(name),
+                Inline::text(format!(" ({})", DescriptionView::new(npc))),
+            ],
+            Some(name) => vec![emoji, Inline::command(name)],
+            None => vec![Inline::text(format!(
+                "{} {}",
+                self.emoji(),
+                DescriptionView::new(npc),
+            ))],
+        }
+    }
+}
+
+impl fmt::Display for SummaryView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Inline::to_markdown(&self.to_inlines()))
     }
 }
 
@@ -100,73 +128,191 @@ impl fmt::Display for DescriptionView<'_> {
 
 impl fmt::Display for DetailsView<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Output::new(vec![self.to_block()]).to_markdown())
+    }
+}
+
+impl DetailsView<'_> {
+    /// The full details of the character as a thing box.
+    pub fn to_block(&self) -> Block {
         let Self {
             npc,
             uuid,
             relations,
         } = self;
 
-        writeln!(f, "<div class=\"thing-box npc\" data-uuid=\"{}\">\n", uuid)?;
+        let mut blocks = vec![
+            Block::Heading {
+                level: 1,
+                content: vec![Inline::text(npc.name.value().map_or_else(
+                    || "Unnamed NPC".to_string(),
+                    |name| format!("{}{}", name, npc.name.lock_indicator()),
+                ))],
+            },
+            Block::paragraph(vec![Inline::emphasis(vec![Inline::text(
+                DescriptionView::new(npc).to_string(),
+            )])]),
+        ];
+
+        let mut fields = Vec::new();
+
+        if let Some(creature) = npc.creature.value() {
+            fields.push(Inline::field(
+                "Creature",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    creature,
+                    npc.creature.lock_indicator(),
+                ))],
+            ));
+        } else {
+            fields.push(match (npc.species.value(), npc.ethnicity.value()) {
+                (Some(species), Some(ethnicity)) if ethnicity != &species.default_ethnicity() => {
+                    Inline::field(
+                        "Species",
+                        vec![Inline::text(format!(
+                            "{} ({}){}",
+                            species,
+                            ethnicity,
+                            if npc.ethnicity.is_locked() {
+                                npc.species.lock_indicator()
+                            } else {
+                                ""
+                            },
+                        ))],
+                    )
+                }
+                (Some(species), _) => Inline::field(
+                    "Species",
+                    vec![Inline::text(format!(
+                        "{}{}",
+                        species,
+                        npc.species.lock_indicator(),
+                    ))],
+                ),
+                (None, Some(ethnicity)) => Inline::field(
+                    "Ethnicity",
+                    vec![Inline::text(format!(
+                        "{}{}",
+                        ethnicity,
+                        npc.ethnicity.lock_indicator(),
+                    ))],
+                ),
+                (None, None) => Inline::field("Species", vec![Inline::text("N/A")]),
+            });
+        }
+
+        if let Some(gender) = npc.gender.value() {
+            fields.push(Inline::field(
+                "Gender",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    gender.name(),
+                    npc.gender.lock_indicator(),
+                ))],
+            ));
+        }
+
+        if let Some(age_years) = npc.age_years.value() {
+            fields.push(Inline::field(
+                "Age",
+                vec![Inline::text(format!(
+                    "{} years{}",
+                    age_years,
+                    if npc.age.is_locked() {
+                        npc.age.lock_indicator()
+                    } else {
+                        npc.age_years.lock_indicator()
+                    },
+                ))],
+            ));
+        }
 
-        npc.name
-            .value()
-            .map(|name| write!(f, "# {}", name))
-            .unwrap_or_else(|| write!(f, "# Unnamed NPC"))?;
+        if let Some(size) = npc.size.value() {
+            fields.push(Inline::field(
+                "Size",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    size,
+                    npc.size.lock_indicator(),
+                ))],
+            ));
+        }
 
-        write!(f, "\n*")?;
-        write_summary_details(npc, f)?;
-        write!(f, "*")?;
+        if let Some((parent, grandparent)) = &relations.location {
+            let mut location = Vec::new();
 
-        match (npc.species.value(), npc.ethnicity.value()) {
-            (Some(species), Some(ethnicity)) if ethnicity != &species.default_ethnicity() => {
-                write!(f, "\n\n**Species:** {} ({})", species, ethnicity)?
+            if let Some(grandparent) = grandparent {
+                location.extend(parent.display_name().to_inlines());
+                location.push(Inline::text(", "));
+                location.extend(grandparent.display_name().to_inlines());
+            } else {
+                location.extend(parent.display_summary().to_inlines());
             }
-            (Some(species), _) => write!(f, "\n\n**Species:** {}", species)?,
-            (None, Some(ethnicity)) => write!(f, "\n\n**Ethnicity:** {}", ethnicity)?,
-            (None, None) => write!(f, "\n\n**Species:** N/A")?,
+
+            fields.push(Inline::field("Location", location));
         }
 
-        npc.gender
-            .value()
-            .map(|gender| write!(f, "\\\n**Gender:** {}", gender.name()))
-            .transpose()?;
-        npc.age_years
-            .value()
-            .map(|age_years| write!(f, "\\\n**Age:** {} years", age_years))
-            .transpose()?;
-        npc.size
-            .value()
-            .map(|size| write!(f, "\\\n**Size:** {}", size))
-            .transpose()?;
-
-        relations
-            .location
-            .as_ref()
-            .map(|(parent, grandparent)| {
-                if let Some(grandparent) = grandparent {
-                    write!(
-                        f,
-                        "\\\n**Location:** {}, {}",
-                        parent.display_name(),
-                        grandparent.display_name(),
-                    )
-                } else {
-                    write!(f, "\\\n**Location:** {}", parent.display_summary())
-                }
+        blocks.push(Block::lines(fields));
+
+        let roleplaying: Vec<_> = [
+            (
+                "Personality",
+                npc.personality.value().map(|p| p.to_string()),
+                npc.personality.lock_indicator(),
+            ),
+            (
+                "Ideal",
+                npc.ideal.value().cloned(),
+                npc.ideal.lock_indicator(),
+            ),
+            ("Bond", npc.bond.value().cloned(), npc.bond.lock_indicator()),
+            ("Flaw", npc.flaw.value().cloned(), npc.flaw.lock_indicator()),
+            (
+                "Feature",
+                npc.feature.value().cloned(),
+                npc.feature.lock_indicator(),
+            ),
+            (
+                "Mannerism",
+                npc.mannerism.value().cloned(),
+                npc.mannerism.lock_indicator(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(label, value, lock_indicator)| {
+            value.map(|value| {
+                Inline::field(
+                    label,
+                    vec![Inline::text(format!("{}{}", value, lock_indicator))],
+                )
             })
-            .transpose()?;
+        })
+        .collect();
 
-        write!(f, "\n\n</div>")?;
+        if !roleplaying.is_empty() {
+            blocks.push(Block::lines(roleplaying));
+        }
+
+        if let Some(stat_block) = npc.stats.value() {
+            blocks.extend(StatBlockView::new(npc, *stat_block).to_blocks());
+        }
 
-        Ok(())
+        Block::Thing {
+            kind: "npc".to_string(),
+            uuid: uuid.to_string(),
+            blocks,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::app::Output;
     use crate::test_utils as test;
-    use crate::world::npc::{Age, Ethnicity, Gender, Npc, Species};
+    use crate::world::npc::{Age, Creature, Ethnicity, Gender, Npc, Size, Species, StatBlock};
+    use crate::world::Field;
 
     const NAME: u8 = 0b1;
     const AGE: u8 = 0b10;
@@ -224,18 +370,58 @@ mod test {
     }
 
     #[test]
-    fn details_view_test_filled() {
+    fn details_view_test_unlocked() {
+        let mut npc = test::npc::odysseus();
+        npc.data.name.unlock();
+        npc.data.age.unlock();
+        npc.data.age_years.unlock();
+        npc.data.size.unlock();
+
         assert_eq!(
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000011">
 
 # Odysseus
-*middle-aged human, he/him*
 
-**Species:** human\
-**Gender:** masculine\
+_middle-aged human, he/him_
+
+**Species:** human 🔒\
+**Gender:** masculine 🔒\
 **Age:** 50 years\
 **Size:** 6'0", 180 lbs (medium)
 
+**Personality:** curious 🔒\
+**Ideal:** Family. Blood is thicker than water. 🔒\
+**Bond:** Will do anything to return home to Ithaca. 🔒\
+**Flaw:** Can't resist taunting a defeated foe. 🔒\
+**Feature:** A scar on the thigh from a boar hunt. 🔒\
+**Mannerism:** Spins a tall tale at every opportunity. 🔒
+
+</div>"#,
+            npc.display_details(NpcRelations::default()).to_string(),
+        );
+    }
+
+    #[test]
+    fn details_view_test_filled() {
+        assert_eq!(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000011">
+
+# Odysseus 🔒
+
+_middle-aged human, he/him_
+
+**Species:** human 🔒\
+**Gender:** masculine 🔒\
+**Age:** 50 years 🔒\
+**Size:** 6'0", 180 lbs (medium) 🔒
+
+**Personality:** curious 🔒\
+**Ideal:** Family. Blood is thicker than water. 🔒\
+**Bond:** Will do anything to return home to Ithaca. 🔒\
+**Flaw:** Can't resist taunting a defeated foe. 🔒\
+**Feature:** A scar on the thigh from a boar hunt. 🔒\
+**Mannerism:** Spins a tall tale at every opportunity. 🔒
+
 </div>"#,
             test::npc::odysseus::data()
                 .display_details(test::npc::odysseus::UUID, NpcRelations::default())
@@ -243,15 +429,107 @@ mod test {
         );
     }
 
+    #[test]
+    fn summary_view_test_creature() {
+        let mut npc = test::npc()
+            .name("Smaug")
+            .creature(Creature::Dragon)
+            .gender(Gender::Masculine)
+            .size(Size::Huge {
+                height: 240,
+                weight: 24000,
+            })
+            .build();
+
+        assert_eq!(
+            "\u{1f409} `Smaug` (huge dragon, he/him)",
+            npc.display_summary().to_string(),
+        );
+        assert_eq!("huge dragon, he/him", npc.display_description().to_string());
+
+        npc.size = Field::default();
+        npc.gender = Field::default();
+        npc.age = Age::Elderly.into();
+        assert_eq!(
+            "\u{1f409} `Smaug` (elderly dragon)",
+            npc.display_summary().to_string(),
+        );
+    }
+
+    #[test]
+    fn details_view_test_creature() {
+        assert_eq!(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Bramble 🔒
+
+_large owlbear, she/her_
+
+**Creature:** owlbear 🔒\
+**Gender:** feminine 🔒\
+**Size:** 7'6", 1300 lbs (large) 🔒
+
+</div>"#,
+            test::npc()
+                .name("Bramble")
+                .creature(Creature::Owlbear)
+                .gender(Gender::Feminine)
+                .size(Size::Large {
+                    height: 90,
+                    weight: 1300,
+                })
+                .build()
+                .display_details(Uuid::nil(), NpcRelations::default())
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn details_view_test_stats() {
+        assert_eq!(
+            r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Ana 🔒
+
+_person_
+
+**Species:** N/A
+
+**Stat block:** Veteran 🔒\
+**Armor Class:** 17 (splint)\
+**Hit Points:** 61 (`9d8 + 18`) 🔒\
+**Speed:** 30 ft.\
+**Challenge:** 3
+
+_Multiattack._ Two longsword attacks. If it has a shortsword drawn, it can also make a shortsword attack.
+
+_Longsword._ +5 to hit, 7 (`1d8 + 3`) slashing damage.
+
+_Shortsword._ +5 to hit, 6 (`1d6 + 3`) piercing damage.
+
+_Heavy Crossbow._ +3 to hit, 6 (`1d10 + 1`) piercing damage.
+
+</div>"#,
+            test::npc()
+                .name("Ana")
+                .stats(StatBlock::Veteran)
+                .hit_points(61)
+                .build()
+                .display_details(Uuid::nil(), NpcRelations::default())
+                .to_string(),
+        );
+    }
+
     #[test]
     fn details_view_test_species_ethnicity() {
         assert_eq!(
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000004">
 
 # Unnamed NPC
-*human*
 
-**Species:** human
+_human_
+
+**Species:** human 🔒
 
 </div>"#,
             gen_npc(SPECIES)
@@ -262,9 +540,10 @@ mod test {
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000010">
 
 # Unnamed NPC
-*elvish person*
 
-**Ethnicity:** elvish
+_elvish person_
+
+**Ethnicity:** elvish 🔒
 
 </div>"#,
             gen_npc(ETHNICITY)
@@ -275,9 +554,10 @@ mod test {
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000014">
 
 # Unnamed NPC
-*human*
 
-**Species:** human (elvish)
+_human_
+
+**Species:** human (elvish) 🔒
 
 </div>"#,
             gen_npc(ETHNICITY | SPECIES)
@@ -292,7 +572,8 @@ mod test {
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000000">
 
 # Unnamed NPC
-*person*
+
+_person_
 
 **Species:** N/A
 
@@ -308,8 +589,9 @@ mod test {
         assert_eq!(
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000011">
 
-# Odysseus
-*person*
+# Odysseus 🔒
+
+_person_
 
 **Species:** N/A\
 **Location:** 🏞 `Styx` (river)
@@ -329,8 +611,9 @@ mod test {
         assert_eq!(
             r#"<div class="thing-box npc" data-uuid="00000000-0000-0000-0000-000000000012">
 
-# Penelope
-*person*
+# Penelope 🔒
+
+_person_
 
 **Species:** N/A\
 **Location:** 🏝 `Ithaca`, 👑 `Greece`
@@ -345,6 +628,84 @@ mod test {
         );
     }
 
+    #[test]
+    fn details_view_test_to_block() {
+        let mut npcs: Vec<(NpcData, Uuid, NpcRelations)> = (0..32)
+            .map(|i| {
+                let npc = gen_npc(i);
+                (npc.data, npc.uuid, NpcRelations::default())
+            })
+            .collect();
+
+        let mut unlocked = test::npc::odysseus::data();
+        unlocked.name.unlock();
+        unlocked.age.unlock();
+        unlocked.size.unlock();
+
+        npcs.extend([
+            (
+                test::npc::odysseus::data(),
+                test::npc::odysseus::UUID,
+                NpcRelations::default(),
+            ),
+            (unlocked, Uuid::nil(), NpcRelations::default()),
+            (
+                test::npc()
+                    .name("Bramble")
+                    .creature(Creature::Owlbear)
+                    .gender(Gender::Feminine)
+                    .build(),
+                Uuid::nil(),
+                NpcRelations::default(),
+            ),
+            (
+                test::npc()
+                    .name("Gimli")
+                    .species(Species::Dwarf)
+                    .stats(StatBlock::Veteran)
+                    .build(),
+                Uuid::nil(),
+                NpcRelations::default(),
+            ),
+            (
+                test::npc().name("Odysseus").build(),
+                test::npc::odysseus::UUID,
+                test::npc::odysseus::relations(),
+            ),
+            (
+                test::npc().name("Penelope").build(),
+                test::npc::penelope::UUID,
+                test::npc::penelope::relations(),
+            ),
+        ]);
+
+        for (npc, uuid, relations) in npcs {
+            let view = DetailsView::new(&npc, uuid, relations);
+
+            assert_eq!(
+                Output::from_markdown(&view.to_string()),
+                Output::new(vec![view.to_block()]),
+                "{}",
+                view,
+            );
+        }
+    }
+
+    #[test]
+    fn summary_view_test_to_inlines() {
+        for i in 0..32 {
+            let npc = gen_npc(i);
+            let summary = npc.display_summary();
+
+            assert_eq!(
+                Inline::from_markdown(&summary.to_string()),
+                summary.to_inlines(),
+                "{}",
+                summary,
+            );
+        }
+    }
+
     fn gen_npc(bitmask: u8) -> Npc {
         let mut builder = test::npc();
 
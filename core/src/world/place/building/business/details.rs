@@ -0,0 +1,259 @@
+use super::BusinessType;
+use crate::world::place::{InventoryItem, PlaceData, Price, Quality};
+use crate::world::word::ListGenerator;
+use rand::prelude::*;
+
+/// Fill in the quality, prices, wares, atmosphere and hook of a business, leaving any locked
+/// fields alone. The atmosphere and hook always change if unlocked, so that rerolling them on
+/// their own is never a no-op.
+pub fn generate(place: &mut PlaceData, subtype: BusinessType, rng: &mut impl Rng) {
+    place.quality.replace_with(|_| Quality::generate(rng));
+
+    let quality = place.quality.value().copied().unwrap_or(Quality::Modest);
+
+    place.price.replace_with(|_| Price::generate(quality, rng));
+    place.wares.replace_with(|_| match place.inventory.value() {
+        Some(inventory) if !inventory.is_empty() => wares_from_inventory(inventory, rng),
+        _ => wares(subtype, rng),
+    });
+    place.atmosphere.replace_with(|current| loop {
+        let atmosphere = atmosphere(subtype, quality, rng);
+        if current.as_ref() != Some(&atmosphere) {
+            break atmosphere;
+        }
+    });
+    place.hook.replace_with(|current| loop {
+        let hook = hook(subtype, rng);
+        if current.as_ref() != Some(&hook) {
+            break hook;
+        }
+    });
+}
+
+/// Pick three things that the business is known for. Where possible, these use the names of SRD
+/// items so that they can be linked from the details view.
+fn wares(subtype: BusinessType, rng: &mut impl Rng) -> Vec<String> {
+    wares_list(subtype)
+        .choose_multiple(rng, 3)
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Pick three items from a shop's stock to show as its wares, so that the details view never
+/// advertises something that the inventory doesn't have.
+pub fn wares_from_inventory(inventory: &[InventoryItem], rng: &mut impl Rng) -> Vec<String> {
+    let mut wares: Vec<String> = inventory
+        .choose_multiple(rng, 3)
+        .map(|item| item.name.clone())
+        .collect();
+    wares.sort();
+    wares
+}
+
+#[rustfmt::skip]
+fn wares_list(subtype: BusinessType) -> &'static [&'static str] {
+    match subtype {
+        BusinessType::Any | BusinessType::GeneralStore | BusinessType::TradingPost => &["Backpack", "Bedroll", "Candle", "Crowbar", "Rations (1 day)", "Tinderbox", "Torch", "Waterskin"],
+        BusinessType::Arena | BusinessType::FightingPit => &["bets on the next bout", "front-row seats", "roasted nuts in paper cones", "a chance to fight the champion"],
+        BusinessType::Armorer => &["Breastplate", "Chain Mail", "Chain Shirt", "Leather Armor", "Scale Mail", "Shield", "Studded Leather Armor"],
+        BusinessType::Bakery => &["honey cakes", "rye loaves", "seed buns", "meat pies", "fruit tarts", "Rations (1 day)"],
+        BusinessType::Bank | BusinessType::Vault => &["letters of credit", "currency exchange", "loans at interest", "safe deposit boxes"],
+        BusinessType::Bar | BusinessType::Inn => &["house ale", "mulled wine", "rabbit stew", "a hot bath", "a bed for the night", "roast mutton", "dark bread and cheese"],
+        BusinessType::Bathhouse => &["hot baths", "cold plunges", "massages", "scented oils", "a shave and a haircut"],
+        BusinessType::Blacksmith | BusinessType::Forge => &["Chain (10 feet)", "Crowbar", "Hammer", "Iron Pot", "Piton", "Smith's Tools", "horseshoes", "nails"],
+        BusinessType::Brewery => &["pale ale", "stout", "cider", "seasonal brews", "barrels to go"],
+        BusinessType::Casino => &["dice", "cards", "a roulette wheel", "a high-stakes back room", "free drinks for high rollers"],
+        BusinessType::Club => &["membership", "private rooms", "fine brandy", "political gossip", "cigars"],
+        BusinessType::Distillery | BusinessType::SpiritsShop => &["whiskey", "brandy", "fortified wine", "elven cordial", "dwarven firewater"],
+        BusinessType::FoodCounter => &["fried fish", "skewered meat", "spiced noodles", "hand pies", "hot cider"],
+        BusinessType::FurnitureShop | BusinessType::Woodshop => &["Carpenter's Tools", "Chest", "Woodcarver's Tools", "chairs", "tables", "bedframes", "cabinets"],
+        BusinessType::Furrier => &["fur cloaks", "fur-lined boots", "pelts", "Hide Armor", "Leatherworker's Tools"],
+        BusinessType::GuildHall => &["membership", "work contracts", "trade licenses", "guild-certified tools"],
+        BusinessType::ImportsShop | BusinessType::SpecialtyShop => &["Perfume (vial)", "Spyglass", "exotic spices", "foreign silks", "rare teas", "ornate music boxes"],
+        BusinessType::Jeweller => &["Jeweler's Tools", "Signet Ring", "gold rings", "silver necklaces", "cut gemstones", "jewelled brooches"],
+        BusinessType::Lumberyard => &["oak beams", "pine planks", "firewood", "Pole (10-foot)", "Ladder (10-foot)"],
+        BusinessType::MagicShop => &["Potion of Healing", "Spell Scroll", "Component Pouch", "Arcane Focus", "Holy Water (flask)", "Alchemist's Fire (flask)"],
+        BusinessType::Mill => &["flour", "cornmeal", "animal feed", "milling by the sack"],
+        BusinessType::PetStore => &["Mastiff", "Hunting Trap", "songbirds", "kittens", "ferrets", "tortoises"],
+        BusinessType::Restaurant => &["roast pheasant", "venison pie", "fish soup", "honeyed ham", "spiced wine", "sweet custard"],
+        BusinessType::Stable => &["Riding Horse", "Draft Horse", "Pony", "Mule", "Saddle, Riding", "Feed (per day)", "Stabling (per day)"],
+        BusinessType::TextilesShop => &["Clothes, Fine", "Clothes, Common", "Clothes, Traveler's", "Weaver's Tools", "bolts of wool", "dyed silk"],
+        BusinessType::Theater => &["tragedies", "comedies", "puppet shows", "a famous visiting troupe", "box seats"],
+        BusinessType::Wainwright => &["Cart", "Wagon", "Carriage", "Sled", "Cartographer's Tools", "wheels"],
+        BusinessType::Warehouse => &["storage by the month", "crates", "barrels", "Block and Tackle"],
+        BusinessType::Weaponsmith => &["Battleaxe", "Dagger", "Longsword", "Shortsword", "Spear", "Warhammer", "Crossbow, Light", "Longbow"],
+    }
+}
+
+/// A sight to match the quality of the place, followed by a sound or smell to match its trade.
+fn atmosphere(subtype: BusinessType, quality: Quality, rng: &mut impl Rng) -> String {
+    format!(
+        "{} {}",
+        ListGenerator(sights(quality)).gen(rng),
+        ListGenerator(sounds_smells(subtype)).gen(rng),
+    )
+}
+
+#[rustfmt::skip]
+fn sights(quality: Quality) -> &'static [&'static str] {
+    match quality {
+        Quality::Squalid => &["Rotting floorboards creak underfoot.", "Rats scurry along the walls.", "Every surface is filthy."],
+        Quality::Poor => &["The furniture is battered and mismatched.", "Patched curtains hang over grimy windows.", "The roof leaks in one corner."],
+        Quality::Modest => &["The place is plain but tidy.", "Simple wooden furnishings fill the room.", "A few faded paintings hang on the walls."],
+        Quality::Comfortable => &["A warm fire burns in the hearth.", "Thick rugs cover the floor.", "Everything is clean and well cared for."],
+        Quality::Wealthy => &["Polished brass fittings gleam in the lamplight.", "Fine tapestries line the walls.", "Liveried staff greet every visitor."],
+        Quality::Aristocratic => &["Crystal chandeliers glitter overhead.", "Marble floors are polished to a mirror shine.", "Gilded mirrors line every wall."],
+    }
+}
+
+#[rustfmt::skip]
+fn sounds_smells(subtype: BusinessType) -> &'static [&'static str] {
+    match subtype {
+        subtype if subtype.serves_food() => &["The smell of roasting meat hangs in the air.", "Laughter and the clatter of tankards fill the room.", "It smells of woodsmoke and spilled ale.", "Something delicious is bubbling in the kitchen."],
+        BusinessType::Armorer
+        | BusinessType::Blacksmith
+        | BusinessType::Forge
+        | BusinessType::FurnitureShop
+        | BusinessType::Lumberyard
+        | BusinessType::Mill
+        | BusinessType::Wainwright
+        | BusinessType::Weaponsmith
+        | BusinessType::Woodshop => &["The ring of hammer on metal carries from the back.", "Sawdust drifts through the air.", "It smells of hot iron and coal smoke.", "Workers shout to each other over the din."],
+        BusinessType::Furrier | BusinessType::PetStore | BusinessType::Stable => &["It smells strongly of hay and animals.", "Something in the back squawks and chitters.", "The air is heavy with the smell of tanned hides."],
+        BusinessType::Arena | BusinessType::Casino | BusinessType::FightingPit | BusinessType::Theater => &["The roar of the crowd rises and falls.", "Dice rattle and coins clink on every side.", "Music and cheering spill out from within."],
+        BusinessType::Bank | BusinessType::Club | BusinessType::GuildHall | BusinessType::Vault => &["Voices are kept to a polite murmur.", "The only sound is the scratching of quills.", "Coins clink softly as they are counted."],
+        BusinessType::Bathhouse => &["Steam curls through the air, scented with herbs.", "Water drips and echoes off the tiles."],
+        _ => &["It smells of dust and old wood.", "A bell jingles as the door opens.", "Shelves are crammed floor to ceiling with goods."],
+    }
+}
+
+#[rustfmt::skip]
+fn hook(subtype: BusinessType, rng: &mut impl Rng) -> String {
+    let owner = ListGenerator(&["owner", "proprietor", "manager"]).gen(rng);
+
+    match rng.gen_range(0..8) {
+        0 => format!("The {} is desperate to recover a shipment stolen on the road into town.", owner),
+        1 => format!("The {} owes money to some very dangerous people, and the debt is due tomorrow.", owner),
+        2 => format!("The {} is quietly looking for someone to deliver a sealed package, no questions asked.", owner),
+        3 => "A regular hasn't been seen in a week, and nobody wants to talk about why.".to_string(),
+        4 => "A rival business has been spreading rumours, and someone wants them stopped.".to_string(),
+        5 => "Strange noises have been coming from the cellar at night.".to_string(),
+        6 => "A stranger has been asking a lot of questions about the place.".to_string(),
+        7 => match subtype {
+            BusinessType::Inn | BusinessType::Bar => "One of the guests is not who they claim to be.".to_string(),
+            BusinessType::Bank | BusinessType::Vault => "Someone has been tunnelling toward the strongroom.".to_string(),
+            BusinessType::MagicShop => "One of the items for sale is cursed, and the owner knows it.".to_string(),
+            _ => "Something valuable was hidden here years ago and never found.".to_string(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reference::Item;
+    use crate::world::place::PlaceType;
+    use crate::world::Field;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut place = PlaceData {
+            subtype: "inn".parse::<PlaceType>().unwrap().into(),
+            ..Default::default()
+        };
+
+        generate(&mut place, BusinessType::Inn, &mut rng);
+
+        assert!(place.quality.is_some());
+        assert!(place.price.is_some());
+        assert_eq!(Some(3), place.wares.value().map(|wares| wares.len()));
+        assert!(place.atmosphere.is_some());
+        assert!(place.hook.is_some());
+        assert!(place.quality.is_unlocked());
+    }
+
+    #[test]
+    fn generate_test_locked() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut place = PlaceData {
+            quality: Quality::Squalid.into(),
+            hook: "The ale is poisoned.".into(),
+            ..Default::default()
+        };
+
+        for _ in 0..10 {
+            generate(&mut place, BusinessType::Inn, &mut rng);
+
+            assert_eq!(Some(&Quality::Squalid), place.quality.value());
+            assert!(place.price.value().unwrap() <= &Price::Reasonable);
+            assert_eq!(
+                Some("The ale is poisoned."),
+                place.hook.value().map(|s| s.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn generate_test_always_changes() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut place = PlaceData {
+            quality: Quality::Modest.into(),
+            ..Default::default()
+        };
+        generate(&mut place, BusinessType::Bathhouse, &mut rng);
+
+        for _ in 0..20 {
+            let before = place.clone();
+            generate(&mut place, BusinessType::Bathhouse, &mut rng);
+
+            assert_ne!(before.atmosphere, place.atmosphere);
+            assert_ne!(before.hook, place.hook);
+        }
+    }
+
+    #[test]
+    fn generate_test_inventory() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let inventory: Vec<_> = ["Dagger", "Longsword", "Net", "Spear"]
+            .into_iter()
+            .map(|name| InventoryItem {
+                name: name.to_string(),
+                quantity: 1,
+                price_cp: 100,
+            })
+            .collect();
+        let mut place = PlaceData {
+            inventory: Field::new_generated(inventory.clone()),
+            ..Default::default()
+        };
+
+        for _ in 0..10 {
+            generate(&mut place, BusinessType::Weaponsmith, &mut rng);
+
+            let wares = place.wares.value().unwrap();
+            assert_eq!(3, wares.len());
+            assert!(
+                wares
+                    .iter()
+                    .all(|ware| inventory.iter().any(|item| &item.name == ware)),
+                "{:?}",
+                wares,
+            );
+        }
+    }
+
+    #[test]
+    fn wares_list_test() {
+        for word in BusinessType::get_words() {
+            let subtype: BusinessType = word.parse().unwrap();
+            assert!(wares_list(subtype).len() >= 3, "{}", word);
+        }
+
+        // The generator is only useful if some of the wares actually link to the SRD.
+        assert!(wares_list(BusinessType::Weaponsmith)
+            .iter()
+            .any(|ware| ware.parse::<Item>().is_ok()));
+    }
+}
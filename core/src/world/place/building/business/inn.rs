@@ -3,54 +3,60 @@ use crate::world::place::PlaceData;
 use crate::world::{word, word::ListGenerator, Demographics};
 use rand::prelude::*;
 
-pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
-    place.name.replace_with(|_| name(rng));
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
+    place
+        .name
+        .replace_with(|_| name(rng, demographics.vocabulary().inn_words()));
 }
 
-fn name(rng: &mut impl Rng) -> String {
+/// Generate an inn name. Any custom words provided by the user are treated as an additional
+/// category alongside the built-in word lists.
+fn name(rng: &mut impl Rng, custom: &[String]) -> String {
     match rng.gen_range(0..6) {
-        0 => format!("The {}", thing(rng)),
+        0 => format!("The {}", thing(rng, custom)),
         1 => {
             let (profession, s) = pluralize(word::profession(rng));
             format!("{}{} Arms", profession, s)
         }
         2..=3 => {
-            let (thing1, thing2) = thing_thing(rng);
+            let (thing1, thing2) = thing_thing(rng, custom);
             format!("{} and {}", thing1, thing2)
         }
-        4 => format!("The {} {}", word::adjective(rng), thing(rng)),
+        4 => format!("The {} {}", word::adjective(rng), thing(rng, custom)),
         5 => {
-            let (thing, s) = pluralize(thing(rng));
+            let (thing, s) = pluralize(thing(rng, custom));
             format!("{} {}{}", number(rng), thing, s)
         }
         _ => unreachable!(),
     }
 }
 
-fn thing(rng: &mut impl Rng) -> &'static str {
-    match rng.gen_range(0..5) {
+fn thing<'a>(rng: &mut impl Rng, custom: &'a [String]) -> &'a str {
+    match rng.gen_range(0..categories(custom)) {
         0 => word::animal(rng),
         1 => word::enemy(rng),
         2 => word::food(rng),
         3 => word::profession(rng),
         4 => word::symbol(rng),
+        5 => custom_word(rng, custom),
         _ => unreachable!(),
     }
 }
 
-fn thing_thing(rng: &mut impl Rng) -> (&'static str, &'static str) {
+fn thing_thing<'a>(rng: &mut impl Rng, custom: &'a [String]) -> (&'a str, &'a str) {
     // We're more likely to have two things in the same category.
     let (thing1, thing2) = if rng.gen_bool(0.5) {
-        match rng.gen_range(0..5) {
+        match rng.gen_range(0..categories(custom)) {
             0 => (word::animal(rng), word::animal(rng)),
             1 => (word::enemy(rng), word::enemy(rng)),
             2 => (word::food(rng), word::food(rng)),
             3 => (word::profession(rng), word::profession(rng)),
             4 => (word::symbol(rng), word::symbol(rng)),
+            5 => (custom_word(rng, custom), custom_word(rng, custom)),
             _ => unreachable!(),
         }
     } else {
-        (thing(rng), thing(rng))
+        (thing(rng, custom), thing(rng, custom))
     };
 
     // 50% chance of rolling again if we don't get two words starting with the same letter.
@@ -64,12 +70,24 @@ fn thing_thing(rng: &mut impl Rng) -> (&'static str, &'static str) {
                 .map(|c| !thing2.starts_with(c))
                 .unwrap_or(false)
     {
-        thing_thing(rng)
+        thing_thing(rng, custom)
     } else {
         (thing1, thing2)
     }
 }
 
+fn categories(custom: &[String]) -> usize {
+    if custom.is_empty() {
+        5
+    } else {
+        6
+    }
+}
+
+fn custom_word<'a>(rng: &mut impl Rng, custom: &'a [String]) -> &'a str {
+    custom.choose(rng).map_or("", |s| s.as_str())
+}
+
 #[rustfmt::skip]
 fn number(rng: &mut impl Rng) -> &'static str {
     ListGenerator(&["Three", "Five", "Seven", "Ten"]).gen(rng)
@@ -109,7 +127,19 @@ mod test {
             .iter()
             .map(|s| s.to_string())
             .collect::<Vec<_>>(),
-            (0..20).map(|_| name(&mut rng)).collect::<Vec<String>>(),
+            (0..20)
+                .map(|_| name(&mut rng, &[]))
+                .collect::<Vec<String>>(),
         );
     }
+
+    #[test]
+    fn name_test_custom() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let custom = ["Griffin".to_string()];
+
+        assert!((0..50)
+            .map(|_| name(&mut rng, &custom))
+            .any(|name| name.contains("Griffin")));
+    }
 }
@@ -0,0 +1,207 @@
+use super::BusinessType;
+use crate::reference::{Item, ItemCategory, MagicItem};
+use crate::world::place::{round_cp, InventoryItem, Price, SettlementSize};
+use rand::prelude::*;
+
+/// Generate stock for a business drawn from the SRD equipment lists, or `None` if the business
+/// isn't the sort that keeps an inventory.
+pub fn generate(
+    subtype: BusinessType,
+    price: Option<Price>,
+    settlement_size: SettlementSize,
+    rng: &mut impl Rng,
+) -> Option<Vec<InventoryItem>> {
+    let markup = match price {
+        Some(Price::Cheap) => 80,
+        Some(Price::Reasonable) | None => 100,
+        Some(Price::Expensive) => 150,
+        Some(Price::Exorbitant) => 250,
+    };
+
+    let mut inventory: Vec<InventoryItem> = match subtype {
+        BusinessType::MagicShop => {
+            let count = match settlement_size {
+                SettlementSize::Small => rng.gen_range(1..=3),
+                SettlementSize::Medium => rng.gen_range(3..=5),
+                SettlementSize::Large => rng.gen_range(5..=8),
+            };
+
+            magic_items(settlement_size)
+                .choose_multiple(rng, count)
+                .map(|(item, base_price_cp)| {
+                    let name = item.get_name();
+
+                    InventoryItem {
+                        name: name.to_string(),
+                        quantity: if name.starts_with("Potion") {
+                            rng.gen_range(1..=3)
+                        } else {
+                            1
+                        },
+                        price_cp: round_cp(base_price_cp * markup / 100),
+                    }
+                })
+                .collect()
+        }
+        subtype => {
+            let count = match settlement_size {
+                SettlementSize::Small => rng.gen_range(4..=6),
+                SettlementSize::Medium => rng.gen_range(6..=10),
+                SettlementSize::Large => rng.gen_range(10..=16),
+            };
+
+            items(categories(subtype)?)
+                .choose_multiple(rng, count)
+                .map(|item| InventoryItem {
+                    name: item.get_name().to_string(),
+                    quantity: match settlement_size {
+                        SettlementSize::Small => rng.gen_range(1..=3),
+                        SettlementSize::Medium => rng.gen_range(1..=6),
+                        SettlementSize::Large => rng.gen_range(2..=12),
+                    },
+                    price_cp: round_cp(item.get_cost_cp() * markup / 100),
+                })
+                .collect()
+        }
+    };
+
+    inventory.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(inventory)
+}
+
+/// The SRD categories that a business of this type stocks, if any.
+fn categories(subtype: BusinessType) -> Option<&'static [ItemCategory]> {
+    match subtype {
+        BusinessType::Armorer => Some(&[ItemCategory::Armor, ItemCategory::Shields]),
+        BusinessType::Blacksmith => Some(&[
+            ItemCategory::Weapon,
+            ItemCategory::Armor,
+            ItemCategory::Tools,
+        ]),
+        BusinessType::GeneralStore => Some(&[ItemCategory::AdventuringGear, ItemCategory::Tools]),
+        BusinessType::Weaponsmith => Some(&[ItemCategory::Weapon]),
+        _ => None,
+    }
+}
+
+/// All of the items in the given categories, without duplicates.
+fn items(categories: &[ItemCategory]) -> Vec<&'static Item> {
+    let mut items: Vec<&Item> = Vec::new();
+
+    for item in categories.iter().flat_map(|category| category.get_items()) {
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+
+    items
+}
+
+/// Magic items rare enough to be worth stocking but common enough to be found in a settlement of
+/// the given size, along with a base price in copper pieces based on their rarity.
+fn magic_items(settlement_size: SettlementSize) -> Vec<(&'static MagicItem, u32)> {
+    let mut magic_items: Vec<(&MagicItem, u32)> = Vec::new();
+
+    for magic_item in ItemCategory::get_words()
+        .filter_map(|word| word.parse::<ItemCategory>().ok())
+        .flat_map(|category| category.get_magic_items())
+    {
+        let base_price_gp = match (magic_item.get_rarity(), settlement_size) {
+            (Some("common"), _) => 75,
+            (Some("uncommon" | "varies"), SettlementSize::Medium | SettlementSize::Large) => 300,
+            (Some("rare"), SettlementSize::Large) => 2500,
+            _ => continue,
+        };
+
+        if !magic_items.iter().any(|(item, _)| item == &magic_item) {
+            magic_items.push((magic_item, base_price_gp * 100));
+        }
+    }
+
+    magic_items
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for subtype in [
+            BusinessType::Armorer,
+            BusinessType::Blacksmith,
+            BusinessType::GeneralStore,
+            BusinessType::Weaponsmith,
+        ] {
+            let inventory = generate(subtype, None, SettlementSize::Large, &mut rng).unwrap();
+
+            assert!(!inventory.is_empty(), "{:?}", subtype);
+
+            for item in &inventory {
+                let srd_item: Item = item.name.parse().unwrap();
+                assert_eq!(round_cp(srd_item.get_cost_cp()), item.price_cp);
+                assert!(item.quantity >= 2, "{:?}", item);
+            }
+
+            let mut names: Vec<_> = inventory.iter().map(|item| &item.name).collect();
+            names.dedup();
+            assert_eq!(inventory.len(), names.len());
+        }
+
+        assert_eq!(
+            None,
+            generate(BusinessType::Inn, None, SettlementSize::Large, &mut rng),
+        );
+    }
+
+    #[test]
+    fn generate_test_price() {
+        let mut rng1 = SmallRng::seed_from_u64(0);
+        let mut rng2 = SmallRng::seed_from_u64(0);
+
+        let cheap = generate(
+            BusinessType::Weaponsmith,
+            Some(Price::Cheap),
+            SettlementSize::Medium,
+            &mut rng1,
+        )
+        .unwrap();
+        let exorbitant = generate(
+            BusinessType::Weaponsmith,
+            Some(Price::Exorbitant),
+            SettlementSize::Medium,
+            &mut rng2,
+        )
+        .unwrap();
+
+        for (cheap, exorbitant) in cheap.iter().zip(exorbitant.iter()) {
+            assert_eq!(cheap.name, exorbitant.name);
+            assert!(cheap.price_cp < exorbitant.price_cp, "{:?}", cheap);
+        }
+    }
+
+    #[test]
+    fn generate_test_magic_shop() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for settlement_size in [
+            SettlementSize::Small,
+            SettlementSize::Medium,
+            SettlementSize::Large,
+        ] {
+            let inventory =
+                generate(BusinessType::MagicShop, None, settlement_size, &mut rng).unwrap();
+
+            for item in &inventory {
+                let rarity = item.name.parse::<MagicItem>().unwrap().get_rarity();
+                assert_ne!(Some("legendary"), rarity, "{:?}", item);
+
+                if settlement_size == SettlementSize::Small {
+                    assert_eq!(Some("common"), rarity, "{:?}", item);
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,14 @@
+pub use details::wares_from_inventory;
+pub use inventory::generate as generate_inventory;
+
+mod details;
 mod inn;
+mod inventory;
 mod theater;
 
 use super::BuildingType;
 use crate::world::place::{PlaceData, PlaceType};
-use crate::world::Demographics;
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -105,14 +110,184 @@ impl BusinessType {
             | Self::Wainwright => Some("🪙"),
         }
     }
+
+    /// Whether the business stocks items from the SRD equipment lists.
+    pub const fn keeps_inventory(&self) -> bool {
+        matches!(
+            self,
+            Self::Armorer
+                | Self::Blacksmith
+                | Self::GeneralStore
+                | Self::MagicShop
+                | Self::Weaponsmith
+        )
+    }
+
+    /// Whether the business's wares are better described as a menu.
+    pub const fn serves_food(&self) -> bool {
+        matches!(
+            self,
+            Self::Bakery
+                | Self::Bar
+                | Self::Brewery
+                | Self::Distillery
+                | Self::FoodCounter
+                | Self::Inn
+                | Self::Restaurant
+                | Self::SpiritsShop
+        )
+    }
 }
 
 pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
-    if let Some(PlaceType::Building(BuildingType::Business(subtype))) = place.subtype.value() {
+    if let Some(&PlaceType::Building(BuildingType::Business(subtype))) = place.subtype.value() {
         match subtype {
             BusinessType::Inn => inn::generate(place, rng, demographics),
             BusinessType::Theater => theater::generate(place, rng, demographics),
-            _ => {}
+            subtype => place.name.replace_with(|_| name(subtype, rng)),
+        }
+
+        details::generate(place, subtype, rng);
+    }
+}
+
+/// Generate a name for any business that doesn't have a more specific generator, eg. "The Silver
+/// Anvil" or "Oakford Bakery".
+fn name(subtype: BusinessType, rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..6) {
+        0..=1 => format!("The {} {}", word::adjective(rng), thing(subtype, rng)),
+        2..=3 => format!("{} {}", thing(subtype, rng), synonym(subtype, rng)),
+        4 => format!(
+            "{} {} {}",
+            word::adjective(rng),
+            thing(subtype, rng),
+            synonym(subtype, rng),
+        ),
+        5 => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        _ => unreachable!(),
+    }
+}
+
+/// The sort of thing that might appear on the business's sign.
+fn thing(subtype: BusinessType, rng: &mut impl Rng) -> &'static str {
+    match subtype {
+        BusinessType::Armorer
+        | BusinessType::Blacksmith
+        | BusinessType::Forge
+        | BusinessType::FurnitureShop
+        | BusinessType::Lumberyard
+        | BusinessType::Mill
+        | BusinessType::Wainwright
+        | BusinessType::Weaponsmith
+        | BusinessType::Woodshop => word::symbol(rng),
+        BusinessType::Bakery
+        | BusinessType::Bar
+        | BusinessType::Brewery
+        | BusinessType::Distillery
+        | BusinessType::FoodCounter
+        | BusinessType::Restaurant
+        | BusinessType::SpiritsShop => word::food(rng),
+        BusinessType::Furrier | BusinessType::PetStore | BusinessType::Stable => {
+            word::land_animal(rng)
         }
+        BusinessType::Bank | BusinessType::Jeweller | BusinessType::Vault => word::gem(rng),
+        BusinessType::Arena
+        | BusinessType::Casino
+        | BusinessType::FightingPit
+        | BusinessType::MagicShop => word::enemy(rng),
+        _ => match rng.gen_range(0..3) {
+            0 => word::animal(rng),
+            1 => word::symbol(rng),
+            2 => word::gem(rng),
+            _ => unreachable!(),
+        },
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: BusinessType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        BusinessType::Any => &["Emporium", "Goods", "Shop", "Store", "Trading Company"],
+        BusinessType::Arena => &["Arena", "Circus", "Coliseum", "Games"],
+        BusinessType::Armorer => &["Armorer", "Armory", "Armorworks", "Plate and Mail"],
+        BusinessType::Bakery => &["Bakehouse", "Bakery", "Loaves", "Ovens"],
+        BusinessType::Bank => &["Bank", "Counting House", "Exchange", "Moneylenders"],
+        BusinessType::Bar => &["Alehouse", "Pub", "Tap", "Taproom", "Tavern"],
+        BusinessType::Bathhouse => &["Baths", "Bathhouse", "Springs", "Steam Rooms"],
+        BusinessType::Blacksmith => &["Forge", "Ironworks", "Smithy"],
+        BusinessType::Brewery => &["Ales", "Brewery", "Brewhouse", "Brewing Company"],
+        BusinessType::Casino => &["Card Room", "Casino", "Dice Den", "Gaming House"],
+        BusinessType::Club => &["Club", "Lodge", "Salon", "Society"],
+        BusinessType::Distillery => &["Distillery", "Spirits", "Still", "Stillhouse"],
+        BusinessType::FightingPit => &["Blood Pit", "Pit", "Pits", "Ring"],
+        BusinessType::FoodCounter => &["Kitchen", "Pies", "Skewers", "Stall", "Stew Pot"],
+        BusinessType::Forge => &["Forge", "Foundry", "Ironworks", "Smelter"],
+        BusinessType::FurnitureShop => &["Cabinetmakers", "Chairs and Tables", "Furnishings", "Joinery"],
+        BusinessType::Furrier => &["Furrier", "Furs", "Hides", "Pelts"],
+        BusinessType::GeneralStore => &["General Store", "Goods", "Mercantile", "Provisions", "Sundries"],
+        BusinessType::GuildHall => &["Fellowship", "Guild", "Guildhall", "Hall"],
+        BusinessType::ImportsShop => &["Curiosities", "Exotic Goods", "Far Wares", "Imports"],
+        BusinessType::Inn => &["Inn"],
+        BusinessType::Jeweller => &["Fine Jewels", "Gemcutters", "Gems", "Jewellers"],
+        BusinessType::Lumberyard => &["Lumberyard", "Sawmill", "Timber", "Woodyard"],
+        BusinessType::MagicShop => &["Arcana", "Curios", "Magic Emporium", "Oddities", "Wonders"],
+        BusinessType::Mill => &["Gristmill", "Mill", "Watermill", "Windmill"],
+        BusinessType::PetStore => &["Companions", "Critters", "Menagerie", "Pets"],
+        BusinessType::Restaurant => &["Dining Room", "Eatery", "Feast Hall", "Kitchen", "Table"],
+        BusinessType::SpecialtyShop => &["Fine Goods", "Goods", "Specialties", "Wares"],
+        BusinessType::SpiritsShop => &["Bottle Shop", "Cellar", "Spirits", "Wines and Spirits"],
+        BusinessType::Stable => &["Horse Yard", "Livery", "Paddock", "Stables"],
+        BusinessType::TextilesShop => &["Cloth", "Looms", "Silks", "Textiles", "Weavers"],
+        BusinessType::Theater => &["Theater"],
+        BusinessType::TradingPost => &["Exchange", "Post", "Trading Company", "Trading Post"],
+        BusinessType::Vault => &["Deposits", "Lockhouse", "Strongrooms", "Vaults"],
+        BusinessType::Wainwright => &["Carts and Wagons", "Wagons", "Wainwrights", "Wheelwrights"],
+        BusinessType::Warehouse => &["Depot", "Storehouse", "Stores", "Warehouse"],
+        BusinessType::Weaponsmith => &["Arms", "Blades", "Weaponworks"],
+        BusinessType::Woodshop => &["Carpentry", "Joinery", "Woodshop", "Woodworks"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Lance Trading Company",
+                "Maplehaven Ovens",
+                "The Wild Anvil",
+                "Eastfield Jewellers",
+                "Lost Unicorn Wonders",
+                "The Red Stork",
+                "The Hallowed Drum",
+                "Happy Barley Ovens",
+                "Book Ironworks",
+                "Hallowed Citrine Jewellers",
+                "The Brown Witch",
+                "The Driven Scorpion",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                BusinessType::Any,
+                BusinessType::Bakery,
+                BusinessType::Blacksmith,
+                BusinessType::Jeweller,
+                BusinessType::MagicShop,
+                BusinessType::Stable,
+            ]
+            .iter()
+            .cycle()
+            .take(12)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
     }
 }
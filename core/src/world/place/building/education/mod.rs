@@ -1,4 +1,8 @@
+use super::BuildingType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -19,3 +23,84 @@ impl EducationType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Building(BuildingType::Education(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: EducationType, rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..6) {
+        0..=1 => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        2..=3 => format!("{} of {}", synonym(subtype, rng), subject(rng)),
+        4 => format!("The {}'s {}", word::person(rng), synonym(subtype, rng)),
+        5 => format!(
+            "The {} {} {}",
+            word::adjective(rng),
+            word::symbol(rng),
+            synonym(subtype, rng),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: EducationType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        EducationType::Academy => &["Academy", "Conservatory", "Institute"],
+        EducationType::College => &["College", "Collegium", "Seminary"],
+        EducationType::Library => &["Archive", "Athenaeum", "Library", "Scriptorium"],
+        EducationType::School => &["Academy", "School", "Schoolhouse"],
+        EducationType::University => &["Collegium", "Studium", "University"],
+    })
+    .gen(rng)
+}
+
+#[rustfmt::skip]
+fn subject(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "Alchemy", "Arms", "Healing", "Histories", "Law", "Letters", "Lore", "Music",
+        "Natural Philosophy", "the Arcane", "the Stars", "Tongues",
+    ]).gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Conservatory of Letters",
+                "The Wasted Lance Collegium",
+                "Mosswick Athenaeum",
+                "The Warrior's School",
+                "Elderton Studium",
+                "The Wild Spur Conservatory",
+                "The Blue Hammer Collegium",
+                "Oakhaven Athenaeum",
+                "Schoolhouse of Music",
+                "Blackcliff Collegium",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                EducationType::Academy,
+                EducationType::College,
+                EducationType::Library,
+                EducationType::School,
+                EducationType::University,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
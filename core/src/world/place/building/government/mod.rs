@@ -1,4 +1,8 @@
+use super::BuildingType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -23,3 +27,72 @@ impl GovernmentType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Building(BuildingType::Government(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: GovernmentType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        (GovernmentType::Embassy, 0..=2) => format!("Embassy of {}", word::place_name(rng)),
+        (_, 0..=2) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        (_, 3) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        (_, 4) => format!("The {} {}", word::symbol(rng), synonym(subtype, rng)),
+        (_, 5) => format!("{} of the {}", synonym(subtype, rng), word::symbol(rng)),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: GovernmentType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        GovernmentType::Court => &["Assizes", "Court", "Courthouse", "Hall of Justice"],
+        GovernmentType::Embassy => &["Consulate", "Embassy", "Legation"],
+        GovernmentType::Guardhouse => &["Gatehouse", "Guardhouse", "Watch House", "Watch Post"],
+        GovernmentType::Palace => &["Court", "Palace", "Residence"],
+        GovernmentType::Prison => &["Cells", "Gaol", "Oubliette", "Prison", "Stockade"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hawkworth Courthouse",
+                "Embassy of Greydale",
+                "Westfield Watch Post",
+                "The Moon Court",
+                "Thornworth Cells",
+                "Stonebank Court",
+                "Embassy of the Drum",
+                "The Anchor Watch Post",
+                "Briarhaven Residence",
+                "Frostham Stockade",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                GovernmentType::Court,
+                GovernmentType::Embassy,
+                GovernmentType::Guardhouse,
+                GovernmentType::Palace,
+                GovernmentType::Prison,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -1,4 +1,8 @@
+use super::BuildingType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -29,3 +33,86 @@ impl MilitaryType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Building(BuildingType::Military(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: MilitaryType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        // "Castle Ashford" works, but "Tower Ashford" doesn't.
+        (
+            MilitaryType::Barracks
+            | MilitaryType::Base
+            | MilitaryType::Stronghold
+            | MilitaryType::Tower,
+            0..=1,
+        )
+        | (_, 2) => {
+            format!("{} {}", word::place_name(rng), synonym(subtype, rng))
+        }
+        (_, 0..=1) => format!("{} {}", synonym(subtype, rng), word::place_name(rng)),
+        (_, 3) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        (_, 4) => format!("{} of the {}", synonym(subtype, rng), word::enemy(rng)),
+        (_, 5) => format!("{} {}", word::symbol(rng), synonym(subtype, rng)),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: MilitaryType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        MilitaryType::Barracks => &["Barracks", "Garrison", "Quarters"],
+        MilitaryType::Base => &["Base", "Camp", "Encampment", "Garrison"],
+        MilitaryType::Castle => &["Castle"],
+        MilitaryType::Citadel => &["Citadel"],
+        MilitaryType::Fort => &["Fort"],
+        MilitaryType::Fortress => &["Fortress"],
+        MilitaryType::Keep => &["Keep"],
+        MilitaryType::Stronghold => &["Hold", "Stronghold"],
+        MilitaryType::Tower => &["Spire", "Tower", "Watchtower"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hawkworth Garrison",
+                "Trumpet Castle",
+                "Silverdale Fort",
+                "Column Keep",
+                "Watchtower of the Orc",
+                "Elderton Garrison",
+                "Wand Castle",
+                "Stonebank Fort",
+                "Feather Keep",
+                "Oakhaven Watchtower",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                MilitaryType::Barracks,
+                MilitaryType::Castle,
+                MilitaryType::Fort,
+                MilitaryType::Keep,
+                MilitaryType::Tower,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-mod business;
+pub(super) mod business;
 mod education;
 mod government;
 mod military;
@@ -6,7 +6,7 @@ mod religious;
 mod travel;
 
 use crate::world::place::{PlaceData, PlaceType};
-use crate::world::Demographics;
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -47,9 +47,95 @@ impl BuildingType {
 pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
     if let Some(PlaceType::Building(subtype)) = place.subtype.value() {
         match subtype {
+            BuildingType::Any => place.name.replace_with(|_| name(rng)),
             BuildingType::Business(_) => business::generate(place, rng, demographics),
+            BuildingType::Education(_) => education::generate(place, rng, demographics),
+            BuildingType::Government(_) => government::generate(place, rng, demographics),
+            BuildingType::Military(_) => military::generate(place, rng, demographics),
             BuildingType::Religious(_) => religious::generate(place, rng, demographics),
-            _ => {}
+            BuildingType::Residence => place.name.replace_with(|_| residence_name(rng)),
+            BuildingType::Travel(_) => travel::generate(place, rng, demographics),
         }
     }
 }
+
+/// A name for a building of no particular type, eg. "Ashford Hall".
+fn name(rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..3) {
+        0..=1 => format!("{} Hall", word::place_name(rng)),
+        2 => format!("The {} {}", word::adjective(rng), word::symbol(rng)),
+        _ => unreachable!(),
+    }
+}
+
+fn residence_name(rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..5) {
+        0..=1 => format!("{} {}", word::place_name(rng), residence_synonym(rng)),
+        2 => format!("The {} {}", word::adjective(rng), residence_synonym(rng)),
+        3 => format!("{} {}", word::symbol(rng), residence_synonym(rng)),
+        4 => format!("The {}'s {}", word::profession(rng), residence_synonym(rng)),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn residence_synonym(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "Cottage", "Estate", "Hall", "House", "Lodge", "Manor", "Residence",
+    ]).gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hawkworth Hall",
+                "Thornwood Hall",
+                "Greydale Hall",
+                "Westfield Hall",
+                "The Orange Moon",
+                "Elderton Hall",
+                "Thornworth Hall",
+                "Silverbank Hall",
+                "Fairgate Hall",
+                "The Happy Anchor",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10).map(|_| name(&mut rng)).collect::<Vec<String>>(),
+        );
+    }
+
+    #[test]
+    fn residence_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "The Hidden Residence",
+                "The Thirsty Residence",
+                "The Happy Manor",
+                "Mosswick Estate",
+                "Faircliff Estate",
+                "The Soldier's Residence",
+                "Silverbank Hall",
+                "Goldmouth Hall",
+                "Salthaven Lodge",
+                "The Red Lodge",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10)
+                .map(|_| residence_name(&mut rng))
+                .collect::<Vec<String>>(),
+        );
+    }
+}
@@ -1,10 +1,10 @@
 mod shrine;
 use initiative_macros::WordList;
-use rand::Rng;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::world::place::{PlaceData, PlaceType};
-use crate::world::Demographics;
+use crate::world::{word, word::ListGenerator, Demographics};
 
 use super::BuildingType;
 
@@ -38,12 +38,77 @@ impl ReligiousType {
 }
 
 pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
-    #[expect(clippy::collapsible_match)]
     if let Some(PlaceType::Building(BuildingType::Religious(subtype))) = place.subtype.value() {
-        #[expect(clippy::single_match)]
-        match subtype {
+        match *subtype {
             ReligiousType::Shrine => shrine::generate(place, rng, demographics),
-            _ => {}
+            subtype => place.name.replace_with(|_| name(subtype, rng)),
         }
     }
 }
+
+fn name(subtype: ReligiousType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        (ReligiousType::Crypt | ReligiousType::Mausoleum | ReligiousType::Tomb, 0..=2) => format!(
+            "{} of the {} {}",
+            synonym(subtype, rng),
+            word::adjective(rng),
+            word::person(rng),
+        ),
+        (_, 0..=2) => format!("{} of {}", synonym(subtype, rng), shrine::deity(rng)),
+        (_, 3..=4) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        (_, 5) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: ReligiousType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        ReligiousType::Abbey => &["Abbey", "Priory"],
+        ReligiousType::Cemetery => &["Boneyard", "Burial Ground", "Cemetery", "Graveyard", "Necropolis"],
+        ReligiousType::Crypt => &["Catacombs", "Crypt", "Ossuary", "Undercroft"],
+        ReligiousType::Mausoleum => &["Mausoleum", "Sepulchre"],
+        ReligiousType::Monastery => &["Cloister", "Friary", "Hermitage", "Monastery"],
+        ReligiousType::Shrine => &["Shrine"],
+        ReligiousType::Temple => &["Cathedral", "Chapel", "Church", "Sanctuary", "Temple"],
+        ReligiousType::Tomb => &["Barrow", "Cairn", "Grave", "Tomb"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Priory of the Stag",
+                "Graveyard of the Ancestor",
+                "Chapel of Healing",
+                "Tomb of the Happy Ancestor",
+                "Abbey of the Octopus",
+                "Burial Ground of the Frog",
+                "Sanctuary of the Hedgehog",
+                "Barrow of the Enchanted Mother",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                ReligiousType::Abbey,
+                ReligiousType::Cemetery,
+                ReligiousType::Temple,
+                ReligiousType::Tomb,
+            ]
+            .iter()
+            .cycle()
+            .take(8)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -92,7 +92,7 @@ fn number(rng: &mut impl Rng) -> &'static str {
 }
 
 //DEITY can be PERSON, ANIMAL, or DIVINE CONCEPT
-fn deity(rng: &mut impl Rng) -> String {
+pub(super) fn deity(rng: &mut impl Rng) -> String {
     match rng.gen_range(0..10) {
         0..=1 => format!("the {}", word::person(rng)),
         2 => format!("the {} {}", descriptor(rng), word::person(rng)),
@@ -1,4 +1,8 @@
+use super::BuildingType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -27,3 +31,80 @@ impl TravelType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Building(BuildingType::Travel(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: TravelType, rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..6) {
+        0..=1 => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        2 => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        3 => format!("{} {}", word::symbol(rng), synonym(subtype, rng)),
+        4 => format!("{}'s {}", word::profession(rng), synonym(subtype, rng)),
+        5 => format!(
+            "{} {}",
+            word::cardinal_direction(rng),
+            synonym(subtype, rng)
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: TravelType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        TravelType::Bridge => &["Bridge", "Crossing"],
+        TravelType::DutyHouse => &["Customs House", "Duty House", "Toll House"],
+        TravelType::Ferry => &["Crossing", "Ferry"],
+        TravelType::Gate => &["Arch", "Gate", "Gates"],
+        TravelType::Lighthouse => &["Beacon", "Light", "Lighthouse"],
+        TravelType::Market => &["Bazaar", "Fair", "Market", "Market Square"],
+        TravelType::Pier => &["Jetty", "Pier", "Quay", "Wharf"],
+        TravelType::Portal => &["Door", "Gateway", "Portal", "Waygate"],
+        TravelType::Shipyard => &["Docks", "Drydock", "Shipyard"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "The Hidden Crossing",
+                "The Happy Crossing",
+                "Westfield Lighthouse",
+                "Mage's Fair",
+                "North Waygate",
+                "The Silent Bridge",
+                "The Wasted Ferry",
+                "The Blue Lighthouse",
+                "Enchanter's Fair",
+                "The Red Door",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                TravelType::Bridge,
+                TravelType::Ferry,
+                TravelType::Lighthouse,
+                TravelType::Market,
+                TravelType::Portal,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
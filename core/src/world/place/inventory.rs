@@ -0,0 +1,140 @@
+use crate::reference::{Item, MagicItem};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A line of a business's stock, as generated by `inventory of [name]` and replaced by
+/// `restock [name]`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InventoryItem {
+    pub name: String,
+    pub quantity: u16,
+
+    /// The asking price in copper pieces.
+    pub price_cp: u32,
+}
+
+/// How much trade passes through a settlement, which determines how well-stocked its shops are.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SettlementSize {
+    Small,
+    Medium,
+    Large,
+}
+
+pub struct InventoryView<'a> {
+    name: &'a str,
+    inventory: &'a [InventoryItem],
+}
+
+impl<'a> InventoryView<'a> {
+    pub fn new(name: &'a str, inventory: &'a [InventoryItem]) -> Self {
+        Self { name, inventory }
+    }
+}
+
+impl fmt::Display for InventoryView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "# Inventory of {}", self.name)?;
+
+        if self.inventory.is_empty() {
+            return write!(f, "\n\n*The shelves are bare.*");
+        }
+
+        write!(f, "\n\n| Item | Qty | Price |\n|---|--:|--:|")?;
+
+        for item in self.inventory {
+            if item.name.parse::<Item>().is_ok() || item.name.parse::<MagicItem>().is_ok() {
+                write!(f, "\n| `{}` |", item.name)?;
+            } else {
+                write!(f, "\n| {} |", item.name)?;
+            }
+
+            write!(f, " {} | {} |", item.quantity, format_cp(item.price_cp))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Round a price to the nearest whole coin of the largest denomination that makes sense for its
+/// size, eg. 2,250 cp becomes 23 gp and 45 cp becomes 5 sp. Never rounds down to nothing.
+pub fn round_cp(cp: u32) -> u32 {
+    let unit = if cp >= 100 {
+        100
+    } else if cp >= 10 {
+        10
+    } else {
+        1
+    };
+
+    ((cp + unit / 2) / unit * unit).max(1)
+}
+
+/// Format a price in copper pieces using the largest denomination that divides it evenly.
+pub fn format_cp(cp: u32) -> String {
+    if cp >= 100 && cp.is_multiple_of(100) {
+        format!("{} gp", cp / 100)
+    } else if cp >= 10 && cp.is_multiple_of(10) {
+        format!("{} sp", cp / 10)
+    } else {
+        format!("{} cp", cp)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_cp_test() {
+        assert_eq!(1, round_cp(0));
+        assert_eq!(1, round_cp(1));
+        assert_eq!(9, round_cp(9));
+        assert_eq!(50, round_cp(45));
+        assert_eq!(40, round_cp(44));
+        assert_eq!(2300, round_cp(2250));
+        assert_eq!(100, round_cp(120));
+    }
+
+    #[test]
+    fn format_cp_test() {
+        assert_eq!("1 cp", format_cp(1));
+        assert_eq!("5 sp", format_cp(50));
+        assert_eq!("15 gp", format_cp(1500));
+        assert_eq!("1000 gp", format_cp(100_000));
+        assert_eq!("15 sp", format_cp(150));
+        assert_eq!("123 cp", format_cp(123));
+    }
+
+    #[test]
+    fn inventory_view_test() {
+        let inventory = [
+            InventoryItem {
+                name: "Dagger".to_string(),
+                quantity: 3,
+                price_cp: 200,
+            },
+            InventoryItem {
+                name: "Horseshoes".to_string(),
+                quantity: 12,
+                price_cp: 5,
+            },
+        ];
+
+        assert_eq!(
+            "\
+# Inventory of The Rusty Anvil
+
+| Item | Qty | Price |
+|---|--:|--:|
+| `Dagger` | 3 | 2 gp |
+| Horseshoes | 12 | 5 cp |",
+            InventoryView::new("The Rusty Anvil", &inventory).to_string(),
+        );
+
+        assert_eq!(
+            "# Inventory of Nowhere\n\n*The shelves are bare.*",
+            InventoryView::new("Nowhere", &[]).to_string(),
+        );
+    }
+}
@@ -2,11 +2,12 @@ mod beach;
 mod canyon;
 
 use initiative_macros::WordList;
-use rand::Rng;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::pluralize;
 use crate::world::place::{PlaceData, PlaceType};
-use crate::world::Demographics;
+use crate::world::{word, word::ListGenerator, Demographics};
 
 use super::LocationType;
 
@@ -53,10 +54,102 @@ impl GeographicalType {
 
 pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
     if let Some(PlaceType::Location(LocationType::Geographical(subtype))) = place.subtype.value() {
-        match subtype {
+        match *subtype {
             GeographicalType::Beach => beach::generate(place, rng, demographics),
             GeographicalType::Canyon => canyon::generate(place, rng, demographics),
-            _ => {}
+            subtype => place.name.replace_with(|_| name(subtype, rng)),
         }
     }
 }
+
+fn name(subtype: GeographicalType, rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..7) {
+        0..=1 => format!("{} {}", thing(rng), synonym(subtype, rng)),
+        2 => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        3 => format!(
+            "{} {}",
+            word::cardinal_direction(rng),
+            synonym(subtype, rng)
+        ),
+        4 => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        5 => {
+            let (enemy, s) = pluralize(word::enemy(rng));
+            format!("{} of the {}{}", synonym(subtype, rng), enemy, s)
+        }
+        6 => format!("{}'s {}", word::profession(rng), synonym(subtype, rng)),
+        _ => unreachable!(),
+    }
+}
+
+fn thing(rng: &mut impl Rng) -> &'static str {
+    match rng.gen_range(0..4) {
+        0..=1 => word::animal(rng),
+        2 => word::enemy(rng),
+        3 => word::gem(rng),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: GeographicalType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        GeographicalType::Beach => &["Beach"],
+        GeographicalType::Canyon => &["Canyon"],
+        GeographicalType::Cave => &["Cave", "Cavern", "Caves", "Grotto", "Hollow"],
+        GeographicalType::Chasm => &["Abyss", "Chasm", "Gulf", "Rift"],
+        GeographicalType::Glacier => &["Glacier", "Ice", "Icefield"],
+        GeographicalType::Grove => &["Copse", "Glade", "Grove", "Thicket"],
+        GeographicalType::Hill => &["Downs", "Hill", "Knoll", "Mound", "Tor"],
+        GeographicalType::Island => &["Holm", "Island", "Isle", "Key"],
+        GeographicalType::Monolith => &["Menhir", "Monolith", "Needle", "Pillar", "Stone"],
+        GeographicalType::Oasis => &["Oasis", "Pool", "Springs", "Wells"],
+        GeographicalType::Pass => &["Col", "Gap", "Notch", "Pass"],
+        GeographicalType::Peninsula => &["Cape", "Head", "Peninsula", "Point"],
+        GeographicalType::Ridge => &["Crest", "Escarpment", "Ridge", "Spine"],
+        GeographicalType::Rift => &["Fissure", "Rent", "Rift", "Scar"],
+        GeographicalType::River => &["River", "Run", "Stream", "Water"],
+        GeographicalType::Tree => &["Ash", "Oak", "Tree", "Yew"],
+        GeographicalType::Valley => &["Dale", "Glen", "Hollow", "Vale", "Valley"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "East Caves",
+                "The Red Hill",
+                "Waterman's Island",
+                "Stream of the Sirens",
+                "Otter Ash",
+                "Hollow of the Kappas",
+                "Octopus Hill",
+                "Salthaven Island",
+                "The Hidden Water",
+                "Blackbrook Tree",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                GeographicalType::Cave,
+                GeographicalType::Hill,
+                GeographicalType::Island,
+                GeographicalType::River,
+                GeographicalType::Tree,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
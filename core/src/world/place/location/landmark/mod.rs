@@ -1,4 +1,9 @@
+use super::LocationType;
+use crate::utils::pluralize;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -30,3 +35,94 @@ impl LandmarkType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Location(LocationType::Landmark(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: LandmarkType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        (LandmarkType::Monument, 0..=1) => format!(
+            "{} to the {} {}",
+            synonym(subtype, rng),
+            word::adjective(rng),
+            word::person(rng),
+        ),
+        (LandmarkType::Ruin, 0..=1) => format!("Ruins of {}", word::place_name(rng)),
+        (_, 0..=1) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        (_, 2) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        (_, 3..=4) => format!("{} {}", thing(rng), synonym(subtype, rng)),
+        (_, 5) => {
+            let (profession, s) = pluralize(word::profession(rng));
+            format!("{}{} {}", profession, s, synonym(subtype, rng))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn thing(rng: &mut impl Rng) -> &'static str {
+    match rng.gen_range(0..3) {
+        0 => word::animal(rng),
+        1 => word::symbol(rng),
+        2 => word::food(rng),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: LandmarkType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        LandmarkType::Farm => &["Farm", "Farmstead", "Fields", "Orchard", "Ranch"],
+        LandmarkType::Fountain => &["Fountain", "Spring", "Well"],
+        LandmarkType::Garden => &["Garden", "Gardens", "Green", "Park"],
+        LandmarkType::Harbor => &["Anchorage", "Docks", "Harbor", "Port"],
+        LandmarkType::Mine => &["Dig", "Mine", "Mines", "Pit", "Shaft"],
+        LandmarkType::Monument => &["Column", "Memorial", "Monument", "Obelisk", "Statue"],
+        LandmarkType::Ruin => &["Remains", "Rubble", "Ruins"],
+        LandmarkType::Street => &["Alley", "Lane", "Road", "Row", "Street", "Way"],
+        LandmarkType::Wall => &["Bulwark", "Rampart", "Wall"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "The Hidden Fields",
+                "Warriors Monument",
+                "The Red Remains",
+                "Westfield Street",
+                "Cap Wall",
+                "The Thirsty Farm",
+                "The Silent Column",
+                "The Wasted Rubble",
+                "Oakhaven Street",
+                "The Grouchy Rampart",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                LandmarkType::Farm,
+                LandmarkType::Monument,
+                LandmarkType::Ruin,
+                LandmarkType::Street,
+                LandmarkType::Wall,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -6,7 +6,7 @@ use initiative_macros::WordList;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::world::Demographics;
+use crate::world::{word, Demographics};
 
 use super::{PlaceData, PlaceType};
 
@@ -33,12 +33,12 @@ impl LocationType {
 }
 
 pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
-    #[expect(clippy::collapsible_match)]
     if let Some(PlaceType::Location(subtype)) = place.subtype.value() {
-        #[expect(clippy::single_match)]
         match subtype {
+            LocationType::Any => place.name.replace_with(|_| word::place_name(rng)),
             LocationType::Geographical(_) => geographical::generate(place, rng, demographics),
-            _ => {}
+            LocationType::Landmark(_) => landmark::generate(place, rng, demographics),
+            LocationType::Settlement(_) => settlement::generate(place, rng, demographics),
         }
     }
 }
@@ -1,4 +1,8 @@
+use super::LocationType;
+use crate::world::place::{PlaceData, PlaceType, SettlementSize};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -29,4 +33,107 @@ impl SettlementType {
             Self::District | Self::Town => Some("🏘"),
         }
     }
+
+    pub const fn size(&self) -> SettlementSize {
+        match self {
+            Self::Camp | Self::Outpost => SettlementSize::Small,
+            Self::Town => SettlementSize::Medium,
+            Self::Capital | Self::City | Self::District => SettlementSize::Large,
+        }
+    }
+}
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Location(LocationType::Settlement(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: SettlementType, rng: &mut impl Rng) -> String {
+    match subtype {
+        SettlementType::Capital | SettlementType::City | SettlementType::Town => {
+            match rng.gen_range(0..8) {
+                0..=5 => word::place_name(rng),
+                6 => format!(
+                    "{} {}",
+                    word::cardinal_direction(rng),
+                    word::place_name(rng)
+                ),
+                7 => format!("{}-on-{}", word::place_name(rng), word::place_name(rng)),
+                _ => unreachable!(),
+            }
+        }
+        SettlementType::District => match rng.gen_range(0..4) {
+            0 => format!("{}'s {}", word::profession(rng), synonym(subtype, rng)),
+            1 => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+            2 => format!("{} {}", word::symbol(rng), synonym(subtype, rng)),
+            3 => format!(
+                "{} {}",
+                word::cardinal_direction(rng),
+                synonym(subtype, rng)
+            ),
+            _ => unreachable!(),
+        },
+        SettlementType::Camp | SettlementType::Outpost => match (subtype, rng.gen_range(0..3)) {
+            (SettlementType::Camp, 0) => format!("Camp {}", word::place_name(rng)),
+            (_, 0..=1) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+            (_, 2) => format!(
+                "{} {}",
+                word::cardinal_direction(rng),
+                synonym(subtype, rng)
+            ),
+            _ => unreachable!(),
+        },
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: SettlementType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        SettlementType::Camp => &["Camp", "Encampment"],
+        SettlementType::Capital | SettlementType::City | SettlementType::Town => &["Town"],
+        SettlementType::District => &["District", "End", "Quarter", "Row", "Ward"],
+        SettlementType::Outpost => &["Outpost", "Post", "Station", "Watch"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hawkworth Encampment",
+                "Silverdale",
+                "Waterman's End",
+                "Faircliff Post",
+                "Thornworth",
+                "Camp Silverbank",
+                "Fairgate-on-Fairmouth",
+                "Miller's Quarter",
+                "South Post",
+                "Silverminster",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                SettlementType::Camp,
+                SettlementType::City,
+                SettlementType::District,
+                SettlementType::Outpost,
+                SettlementType::Town,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
 }
@@ -1,11 +1,17 @@
+pub use inventory::{round_cp, InventoryItem, InventoryView, SettlementSize};
+pub use price::Price;
+pub use quality::Quality;
 pub use view::{DescriptionView, DetailsView, NameView, SummaryView};
 
 mod building;
+mod inventory;
 mod location;
+mod price;
+mod quality;
 mod region;
 mod view;
 
-use super::{Demographics, Field, Generate};
+use super::{word, Demographics, Field, Generate};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -27,15 +33,60 @@ pub struct PlaceData {
 
     pub name: Field<String>,
     pub description: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub quality: Field<Quality>,
+
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub price: Field<Price>,
+
+    /// Notable things on the menu or for sale. Any that match the name of an SRD item are linked
+    /// in the details view.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub wares: Field<Vec<String>>,
+
+    /// What you see, hear and smell when you walk in the door.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub atmosphere: Field<String>,
+
+    /// Something going on here that might draw the party in.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub hook: Field<String>,
+
+    /// The stock of a shop, generated on demand so that it stays the same between visits.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub inventory: Field<Vec<InventoryItem>>,
+
+    /// The seed of the random number generator used to generate this place, if any.
+    #[serde(default, skip_serializing_if = "Field::is_none")]
+    pub seed: Field<u64>,
     // pub architecture: Option<String>,
     // pub floors: Field<u8>,
     // pub owner: Field<Vec<Uuid>>,
     // pub staff: Field<Vec<Uuid>>,
     // pub occupants: Field<Vec<Uuid>>,
-    // pub services: Option<String>,
     // pub worship: Field<String>,
-    // pub quality: something
-    // pub price: something
+}
+
+/// The fields of a place that can be referred to by name, eg. `reroll The Prancing Pony's name`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, WordList)]
+pub enum PlaceField {
+    Name,
+
+    #[term = "type"]
+    Subtype,
+
+    Quality,
+
+    #[term = "prices"]
+    #[alias = "price"]
+    Price,
+
+    #[alias = "menu"]
+    Wares,
+
+    Atmosphere,
+    Hook,
 }
 
 #[derive(Debug, Default)]
@@ -107,12 +158,26 @@ impl PlaceData {
             subtype,
             name,
             description,
+            quality,
+            price,
+            wares,
+            atmosphere,
+            hook,
+            inventory,
+            seed,
         } = self;
 
         location_uuid.lock();
         subtype.lock();
         name.lock();
         description.lock();
+        quality.lock();
+        price.lock();
+        wares.lock();
+        atmosphere.lock();
+        hook.lock();
+        inventory.lock();
+        seed.lock();
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
@@ -121,12 +186,199 @@ impl PlaceData {
             subtype,
             name,
             description,
+            quality,
+            price,
+            wares,
+            atmosphere,
+            hook,
+            inventory,
+            seed,
         } = self;
 
         location_uuid.apply_diff(&mut diff.location_uuid);
         subtype.apply_diff(&mut diff.subtype);
         name.apply_diff(&mut diff.name);
         description.apply_diff(&mut diff.description);
+        quality.apply_diff(&mut diff.quality);
+        price.apply_diff(&mut diff.price);
+        wares.apply_diff(&mut diff.wares);
+        atmosphere.apply_diff(&mut diff.atmosphere);
+        hook.apply_diff(&mut diff.hook);
+        inventory.apply_diff(&mut diff.inventory);
+        seed.apply_diff(&mut diff.seed);
+    }
+
+    /// Like [`Self::apply_diff`], but applies unlocked values and carries over the lock state
+    /// of each field. See [`Field::apply_reroll_diff`].
+    pub fn apply_reroll_diff(&mut self, diff: &mut Self) {
+        let Self {
+            location_uuid,
+            subtype,
+            name,
+            description,
+            quality,
+            price,
+            wares,
+            atmosphere,
+            hook,
+            inventory,
+            seed,
+        } = self;
+
+        location_uuid.apply_reroll_diff(&mut diff.location_uuid);
+        subtype.apply_reroll_diff(&mut diff.subtype);
+        name.apply_reroll_diff(&mut diff.name);
+        description.apply_reroll_diff(&mut diff.description);
+        quality.apply_reroll_diff(&mut diff.quality);
+        price.apply_reroll_diff(&mut diff.price);
+        wares.apply_reroll_diff(&mut diff.wares);
+        atmosphere.apply_reroll_diff(&mut diff.atmosphere);
+        hook.apply_reroll_diff(&mut diff.hook);
+        inventory.apply_reroll_diff(&mut diff.inventory);
+        seed.apply_reroll_diff(&mut diff.seed);
+    }
+
+    pub fn is_field_locked(&self, field: PlaceField) -> bool {
+        match field {
+            PlaceField::Name => self.name.is_locked(),
+            PlaceField::Subtype => self.subtype.is_locked(),
+            PlaceField::Quality => self.quality.is_locked(),
+            PlaceField::Price => self.price.is_locked(),
+            PlaceField::Wares => self.wares.is_locked(),
+            PlaceField::Atmosphere => self.atmosphere.is_locked(),
+            PlaceField::Hook => self.hook.is_locked(),
+        }
+    }
+
+    pub fn set_field_locked(&mut self, field: PlaceField, locked: bool) {
+        match field {
+            PlaceField::Name => self.name.set_locked(locked),
+            PlaceField::Subtype => self.subtype.set_locked(locked),
+            PlaceField::Quality => self.quality.set_locked(locked),
+            PlaceField::Price => self.price.set_locked(locked),
+            PlaceField::Wares => self.wares.set_locked(locked),
+            PlaceField::Atmosphere => self.atmosphere.set_locked(locked),
+            PlaceField::Hook => self.hook.set_locked(locked),
+        }
+    }
+
+    /// Produce a diff that will lock or unlock the given field without changing its value.
+    pub fn lock_diff(&self, field: PlaceField, locked: bool) -> Self {
+        let mut diff = Self::default();
+
+        match field {
+            PlaceField::Name => diff.name = self.name.clone(),
+            PlaceField::Subtype => diff.subtype = self.subtype.clone(),
+            PlaceField::Quality => diff.quality = self.quality.clone(),
+            PlaceField::Price => diff.price = self.price.clone(),
+            PlaceField::Wares => diff.wares = self.wares.clone(),
+            PlaceField::Atmosphere => diff.atmosphere = self.atmosphere.clone(),
+            PlaceField::Hook => diff.hook = self.hook.clone(),
+        }
+
+        diff.set_field_locked(field, locked);
+        diff
+    }
+
+    pub fn describe_field(&self, field: PlaceField) -> Option<String> {
+        match field {
+            PlaceField::Name => self.name.value().cloned(),
+            PlaceField::Subtype => self.subtype.value().map(|subtype| subtype.to_string()),
+            PlaceField::Quality => self.quality.value().map(|quality| quality.to_string()),
+            PlaceField::Price => self.price.value().map(|price| price.to_string()),
+            PlaceField::Wares => self.wares.value().map(|wares| wares.join(", ")),
+            PlaceField::Atmosphere => self.atmosphere.value().cloned(),
+            PlaceField::Hook => self.hook.value().cloned(),
+        }
+    }
+
+    /// Generate stock for this place if it's a business that keeps an inventory, pricing it
+    /// according to the business's price level. Returns a diff containing the new inventory and,
+    /// unless they're locked, wares picked from it.
+    pub fn generate_inventory(
+        &self,
+        settlement_size: SettlementSize,
+        rng: &mut impl Rng,
+    ) -> Option<PlaceData> {
+        let Some(&PlaceType::Building(building::BuildingType::Business(subtype))) =
+            self.subtype.value()
+        else {
+            return None;
+        };
+
+        let inventory = building::business::generate_inventory(
+            subtype,
+            self.price.value().copied(),
+            settlement_size,
+            rng,
+        )?;
+
+        Some(PlaceData {
+            wares: if self.wares.is_unlocked() {
+                Field::Unlocked(Some(building::business::wares_from_inventory(
+                    &inventory, rng,
+                )))
+            } else {
+                Field::default()
+            },
+            inventory: Field::Unlocked(Some(inventory)),
+            ..Default::default()
+        })
+    }
+
+    /// Regenerate the given fields, or all unlocked fields if none are given, returning a diff
+    /// containing the new values of any fields that changed. The regenerated values are left
+    /// unlocked.
+    pub fn reroll(
+        &self,
+        fields: &[PlaceField],
+        rng: &mut impl Rng,
+        demographics: &Demographics,
+    ) -> Self {
+        let mut rerolled = self.clone();
+
+        if !fields.is_empty() {
+            rerolled.lock_all();
+
+            for field in fields {
+                rerolled.set_field_locked(*field, false);
+            }
+
+            // Normal generation leaves the subtype alone when the name is locked, so we need to
+            // handle explicit requests to reroll it ourselves.
+            if rerolled.name.is_locked() && fields.contains(&PlaceField::Subtype) {
+                rerolled
+                    .subtype
+                    .replace_with(|_| PlaceType::generate(rng, demographics));
+            }
+        }
+
+        rerolled.regenerate(rng, demographics);
+
+        PlaceData {
+            location_uuid: Field::default(),
+            subtype: self.subtype.diff(&rerolled.subtype),
+            name: self.name.diff(&rerolled.name),
+            description: Field::default(),
+            quality: self.quality.diff(&rerolled.quality),
+            price: self.price.diff(&rerolled.price),
+            wares: self.wares.diff(&rerolled.wares),
+            atmosphere: self.atmosphere.diff(&rerolled.atmosphere),
+            hook: self.hook.diff(&rerolled.hook),
+            inventory: Field::default(),
+            seed: Field::default(),
+        }
+    }
+}
+
+impl PlaceRelations {
+    /// The size of the nearest settlement containing this place, if any.
+    pub fn settlement_size(&self) -> Option<SettlementSize> {
+        let (parent, grandparent) = self.location.as_ref()?;
+
+        std::iter::once(parent)
+            .chain(grandparent)
+            .find_map(|place| place.data.subtype.value()?.settlement_size())
     }
 }
 
@@ -139,15 +391,42 @@ impl Generate for PlaceData {
 
         if let Some(value) = self.subtype.value() {
             match value {
+                PlaceType::Any => self.name.replace_with(|_| word::place_name(rng)),
                 PlaceType::Building(_) => building::generate(self, rng, demographics),
                 PlaceType::Location(_) => location::generate(self, rng, demographics),
-                _ => {}
+                PlaceType::Region(_) => region::generate(self, rng, demographics),
             }
         }
     }
 }
 
 impl PlaceType {
+    /// Whether the place's wares are better described as a menu.
+    pub const fn serves_food(&self) -> bool {
+        if let Self::Building(building::BuildingType::Business(subtype)) = self {
+            subtype.serves_food()
+        } else {
+            false
+        }
+    }
+
+    /// Whether the place is a shop with stock that can be browsed with `inventory of [name]`.
+    pub const fn keeps_inventory(&self) -> bool {
+        if let Self::Building(building::BuildingType::Business(subtype)) = self {
+            subtype.keeps_inventory()
+        } else {
+            false
+        }
+    }
+
+    pub const fn settlement_size(&self) -> Option<SettlementSize> {
+        if let Self::Location(location::LocationType::Settlement(subtype)) = self {
+            Some(subtype.size())
+        } else {
+            None
+        }
+    }
+
     pub const fn get_emoji(&self) -> &'static str {
         if let Some(emoji) = match self {
             Self::Any => None,
@@ -206,6 +485,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn generate_test_name() {
+        let demographics = Demographics::default();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for word in PlaceType::get_words() {
+            let subtype: PlaceType = word.parse().unwrap();
+
+            for _ in 0..10 {
+                let mut place = PlaceData {
+                    subtype: subtype.into(),
+                    ..Default::default()
+                };
+                place.regenerate(&mut rng, &demographics);
+
+                assert_eq!(Some(&subtype), place.subtype.value(), "{}", word);
+                assert!(
+                    place.name.value().is_some_and(|name| !name.is_empty()),
+                    "{}: {:?}",
+                    word,
+                    place.name,
+                );
+            }
+        }
+    }
+
     #[test]
     fn place_type_default_test() {
         assert_eq!(PlaceType::Any, PlaceType::default());
@@ -252,15 +557,28 @@ mod test {
         let place = oaken_mermaid_inn();
 
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","quality":"wealthy","price":"expensive","wares":["mulled wine"],"atmosphere":"Fine tapestries line the walls.","hook":"Mordenkainen is in town."}"#,
             serde_json::to_string(&place).unwrap(),
         );
 
-        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","quality":"wealthy","price":"expensive","wares":["mulled wine"],"atmosphere":"Fine tapestries line the walls.","hook":"Mordenkainen is in town."}"#).unwrap();
 
         assert_eq!(place, value);
     }
 
+    #[test]
+    fn place_deserialize_test_without_details() {
+        let place: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+
+        assert_eq!(
+            Some(&"Oaken Mermaid Inn".to_string()),
+            place.data.name.value()
+        );
+        assert!(place.data.quality.is_none());
+        assert!(place.data.wares.is_none());
+        assert!(place.data.hook.is_none());
+    }
+
     #[test]
     fn apply_diff_test_no_change() {
         let mut place = oaken_mermaid_inn();
@@ -288,6 +606,48 @@ mod test {
         assert_eq!(empty_locked, diff);
     }
 
+    #[test]
+    fn reroll_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+        let inn = oaken_mermaid_inn().data;
+
+        assert_eq!(
+            PlaceData::default(),
+            inn.reroll(&[], &mut rng, &demographics),
+            "All fields are locked, so nothing should be rerolled.",
+        );
+
+        let diff = inn.reroll(&[PlaceField::Name], &mut rng, &demographics);
+        assert!(diff.name.is_some());
+        assert!(diff.name.is_unlocked());
+        assert_ne!(inn.name, diff.name);
+        assert!(diff.subtype.is_none());
+        assert!(diff.description.is_none());
+
+        let diff = inn.reroll(&[PlaceField::Subtype], &mut rng, &demographics);
+        assert!(diff.name.is_none());
+        assert!(diff.subtype.is_some());
+
+        let diff = inn.reroll(&[PlaceField::Hook], &mut rng, &demographics);
+        assert!(diff.hook.is_some());
+        assert!(diff.hook.is_unlocked());
+        assert_ne!(inn.hook, diff.hook);
+        assert!(diff.name.is_none());
+        assert!(diff.quality.is_none());
+        assert!(diff.wares.is_none());
+    }
+
+    #[test]
+    fn field_locked_test() {
+        let mut place = PlaceData::default();
+        assert!(!place.is_field_locked(PlaceField::Name));
+
+        place.set_field_locked(PlaceField::Name, true);
+        assert!(place.is_field_locked(PlaceField::Name));
+        assert!(!place.is_field_locked(PlaceField::Subtype));
+    }
+
     #[test]
     fn lock_all_test() {
         let mut place = PlaceData::default();
@@ -299,6 +659,13 @@ mod test {
                 subtype: Field::Locked(None),
                 name: Field::Locked(None),
                 description: Field::Locked(None),
+                quality: Field::Locked(None),
+                price: Field::Locked(None),
+                wares: Field::Locked(None),
+                atmosphere: Field::Locked(None),
+                hook: Field::Locked(None),
+                inventory: Field::Locked(None),
+                seed: Field::Locked(None),
             },
             place,
         );
@@ -522,6 +889,13 @@ mod test {
 
                 name: "Oaken Mermaid Inn".into(),
                 description: "I am Mordenkainen".into(),
+                quality: Quality::Wealthy.into(),
+                price: Price::Expensive.into(),
+                wares: vec!["mulled wine".to_string()].into(),
+                atmosphere: "Fine tapestries line the walls.".into(),
+                hook: "Mordenkainen is in town.".into(),
+                inventory: Field::default(),
+                seed: Field::default(),
             },
         }
     }
@@ -0,0 +1,84 @@
+use super::Quality;
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a business charges relative to what you'd expect to pay elsewhere.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Price {
+    #[alias = "inexpensive"]
+    Cheap,
+
+    #[alias = "fair"]
+    Reasonable,
+
+    #[alias = "pricey"]
+    Expensive,
+
+    #[alias = "extortionate"]
+    Exorbitant,
+}
+
+impl Price {
+    /// Prices mostly follow quality, but not always: there are bargains to be found, and some
+    /// proprietors charge whatever they think they can get away with.
+    pub fn generate(quality: Quality, rng: &mut impl Rng) -> Self {
+        let base: u8 = match quality {
+            Quality::Squalid | Quality::Poor => 0,
+            Quality::Modest | Quality::Comfortable => 1,
+            Quality::Wealthy => 2,
+            Quality::Aristocratic => 3,
+        };
+
+        let index = match rng.gen_range(0..6) {
+            0 => base.saturating_sub(1),
+            1 => (base + 1).min(3),
+            _ => base,
+        };
+
+        match index {
+            0 => Self::Cheap,
+            1 => Self::Reasonable,
+            2 => Self::Expensive,
+            3 => Self::Exorbitant,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        assert_eq!("cheap", Price::Cheap.to_string());
+        assert_eq!("exorbitant", Price::Exorbitant.to_string());
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Price::Cheap), "inexpensive".parse::<Price>());
+        assert_eq!(Ok(Price::Reasonable), "fair".parse::<Price>());
+        assert_eq!(Ok(Price::Expensive), "Expensive".parse::<Price>());
+        assert_eq!(Err(()), "potato".parse::<Price>());
+    }
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..100 {
+            assert!(Price::generate(Quality::Squalid, &mut rng) <= Price::Reasonable);
+            assert!(Price::generate(Quality::Aristocratic, &mut rng) >= Price::Expensive);
+        }
+    }
+}
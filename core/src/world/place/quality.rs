@@ -0,0 +1,77 @@
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How well-kept and well-appointed a place is, roughly following the lifestyle tiers of the
+/// SRD.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Quality {
+    #[alias = "filthy"]
+    Squalid,
+
+    #[alias = "shabby"]
+    Poor,
+
+    #[alias = "humble"]
+    Modest,
+
+    #[alias = "cozy"]
+    Comfortable,
+
+    #[alias = "upscale"]
+    Wealthy,
+
+    #[alias = "luxurious"]
+    Aristocratic,
+}
+
+impl Quality {
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        match rng.gen_range(0..13) {
+            0 => Self::Squalid,
+            1..=2 => Self::Poor,
+            3..=6 => Self::Modest,
+            7..=9 => Self::Comfortable,
+            10..=11 => Self::Wealthy,
+            12 => Self::Aristocratic,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        assert_eq!("squalid", Quality::Squalid.to_string());
+        assert_eq!("aristocratic", Quality::Aristocratic.to_string());
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Quality::Squalid), "filthy".parse::<Quality>());
+        assert_eq!(Ok(Quality::Comfortable), "Comfortable".parse::<Quality>());
+        assert_eq!(Ok(Quality::Aristocratic), "luxurious".parse::<Quality>());
+        assert_eq!(Err(()), "potato".parse::<Quality>());
+    }
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut qualities: Vec<Quality> = (0..100).map(|_| Quality::generate(&mut rng)).collect();
+        qualities.sort();
+        qualities.dedup();
+
+        assert_eq!(6, qualities.len());
+    }
+}
@@ -1,4 +1,8 @@
+use super::RegionType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -48,3 +52,102 @@ impl GeographyType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Region(RegionType::Geography(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: GeographyType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        (GeographyType::Continent | GeographyType::World, _) => word::place_name(rng),
+        (GeographyType::Mountain, 0) => format!("Mount {}", word::place_name(rng)),
+        (_, 0..=1) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        (_, 2) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        (_, 3) => format!(
+            "The {}ern {}",
+            word::cardinal_direction(rng),
+            synonym(subtype, rng),
+        ),
+        (_, 4) => format!("{} of {}", synonym(subtype, rng), epithet(rng)),
+        (_, 5) => format!("{} {}", word::animal(rng), synonym(subtype, rng)),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: GeographyType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        GeographyType::Archipelago => &["Archipelago", "Isles", "Islands"],
+        GeographyType::Barrens => &["Badlands", "Barrens", "Wastes"],
+        GeographyType::Coastline => &["Coast", "Shore", "Strand"],
+        GeographyType::Continent => &["Continent"],
+        GeographyType::Desert => &["Desert", "Dunes", "Sands", "Waste"],
+        GeographyType::Forest => &["Forest", "Weald", "Wood", "Woods"],
+        GeographyType::Jungle => &["Jungle", "Tangle", "Wilds"],
+        GeographyType::Lake => &["Lake", "Loch", "Mere", "Water"],
+        GeographyType::Marsh => &["Fen", "Marsh", "Marshes", "Mire"],
+        GeographyType::Mesa => &["Butte", "Mesa", "Tableland"],
+        GeographyType::Moor => &["Heath", "Moor", "Moors"],
+        GeographyType::Mountain => &["Crags", "Mountain", "Mountains", "Peak"],
+        GeographyType::Ocean => &["Deep", "Ocean"],
+        GeographyType::Plain => &["Fields", "Grasslands", "Plains", "Steppe"],
+        GeographyType::Plateau => &["Highlands", "Plateau", "Tableland"],
+        GeographyType::Reef => &["Banks", "Reef", "Shoals"],
+        GeographyType::Sea => &["Gulf", "Sea", "Waters"],
+        GeographyType::Swamp => &["Bog", "Mire", "Morass", "Swamp"],
+        GeographyType::Tundra => &["Frozen Wastes", "Icelands", "Snowfields", "Tundra"],
+        GeographyType::Wasteland => &["Blight", "Scar", "Wasteland", "Wastes"],
+        GeographyType::World => &["World"],
+    })
+    .gen(rng)
+}
+
+#[rustfmt::skip]
+fn epithet(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "Ash", "Bones", "Mists", "Shadows", "Silence", "Sorrows", "Stars", "Storms", "Tears",
+        "Whispers",
+    ]).gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "The Hidden Sands",
+                "The Happy Woods",
+                "Mosswick Mountain",
+                "Waters of Mists",
+                "Thornworth",
+                "Silverbank Dunes",
+                "Elephant Forest",
+                "Peak of Shadows",
+                "The Morose Sea",
+                "Silverminster",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                GeographyType::Desert,
+                GeographyType::Forest,
+                GeographyType::Mountain,
+                GeographyType::Sea,
+                GeographyType::World,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
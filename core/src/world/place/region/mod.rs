@@ -2,8 +2,13 @@ mod geography;
 mod political;
 
 use initiative_macros::WordList;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use crate::world::{word, Demographics};
+
+use super::{PlaceData, PlaceType};
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum RegionType {
@@ -23,3 +28,13 @@ impl RegionType {
         }
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, demographics: &Demographics) {
+    if let Some(PlaceType::Region(subtype)) = place.subtype.value() {
+        match subtype {
+            RegionType::Any => place.name.replace_with(|_| word::place_name(rng)),
+            RegionType::Geography(_) => geography::generate(place, rng, demographics),
+            RegionType::Political(_) => political::generate(place, rng, demographics),
+        }
+    }
+}
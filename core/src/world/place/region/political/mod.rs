@@ -1,4 +1,8 @@
+use super::RegionType;
+use crate::world::place::{PlaceData, PlaceType};
+use crate::world::{word, word::ListGenerator, Demographics};
 use initiative_macros::WordList;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -26,3 +30,89 @@ impl PoliticalType {
         Some("👑")
     }
 }
+
+pub fn generate(place: &mut PlaceData, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(&PlaceType::Region(RegionType::Political(subtype))) = place.subtype.value() {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: PoliticalType, rng: &mut impl Rng) -> String {
+    match (subtype, rng.gen_range(0..6)) {
+        (PoliticalType::CityState | PoliticalType::Country | PoliticalType::Nation, 0) => {
+            word::place_name(rng)
+        }
+        (_, 0..=1) => format!("{} of {}", synonym(subtype, rng), word::place_name(rng)),
+        (_, 2..=3) => format!("{} {}", word::place_name(rng), synonym(subtype, rng)),
+        (_, 4) => format!("The {} {}", word::adjective(rng), synonym(subtype, rng)),
+        (_, 5) => format!(
+            "The {} of the {} {}",
+            synonym(subtype, rng),
+            word::adjective(rng),
+            word::symbol(rng),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[rustfmt::skip]
+fn synonym(subtype: PoliticalType, rng: &mut impl Rng) -> &'static str {
+    ListGenerator(match subtype {
+        PoliticalType::Barony => &["Barony"],
+        PoliticalType::CityState => &["City-State", "Free City"],
+        PoliticalType::Confederation => &["Alliance", "Confederation", "League", "Union"],
+        PoliticalType::Country => &["Commonwealth", "Country", "Land"],
+        PoliticalType::County => &["County", "Shire"],
+        PoliticalType::Domain => &["Demesne", "Domain", "Lands"],
+        PoliticalType::Duchy => &["Duchy", "Grand Duchy"],
+        PoliticalType::Empire => &["Empire", "Imperium"],
+        PoliticalType::Kingdom => &["Kingdom"],
+        PoliticalType::Nation => &["Commonwealth", "Nation", "Republic"],
+        PoliticalType::Principality => &["Principality"],
+        PoliticalType::Province => &["March", "Province"],
+        PoliticalType::Realm => &["Realm"],
+        PoliticalType::Region => &["Marches", "Reach", "Region"],
+        PoliticalType::Territory => &["Protectorate", "Territories", "Territory"],
+    })
+    .gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hawkworth Barony",
+                "The Free City of the Hungry Helmet",
+                "Imperium of Applewick",
+                "Kingdom of Faircliff",
+                "Republic of Thornworth",
+                "Barony of Stonebank",
+                "Fairgate Free City",
+                "Salthaven Imperium",
+                "Frostham Kingdom",
+                "Crowreach",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            [
+                PoliticalType::Barony,
+                PoliticalType::CityState,
+                PoliticalType::Empire,
+                PoliticalType::Kingdom,
+                PoliticalType::Nation,
+            ]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|subtype| name(*subtype, &mut rng))
+            .collect::<Vec<_>>(),
+        );
+    }
+}
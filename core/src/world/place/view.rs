@@ -1,3 +1,5 @@
+use crate::app::{Block, Inline, Output};
+use crate::reference::{Item, MagicItem};
 use crate::world::place::{PlaceData, PlaceRelations, PlaceType};
 use std::fmt;
 use uuid::Uuid;
@@ -42,36 +44,64 @@ impl<'a> DetailsView<'a> {
     }
 }
 
-impl fmt::Display for NameView<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl NameView<'_> {
+    /// The emoji and name (if any), with the name as a command link.
+    pub fn to_inlines(&self) -> Vec<Inline> {
         let place = self.0;
 
         if let Some(name) = place.name.value() {
-            write!(
-                f,
-                "{} `{}`",
-                place.subtype.value().unwrap_or(&PlaceType::Any).get_emoji(),
-                name,
-            )
+            vec![
+                Inline::text(format!(
+                    "{} ",
+                    place.subtype.value().unwrap_or(&PlaceType::Any).get_emoji(),
+                )),
+                Inline::command(name),
+            ]
         } else {
-            Ok(())
+            Vec::new()
         }
     }
 }
 
-impl fmt::Display for SummaryView<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl SummaryView<'_> {
+    /// The name and type of the place, with the name (if any) as a command link.
+    pub fn to_inlines(&self) -> Vec<Inline> {
         let place = self.0;
 
         match (place.subtype.value(), place.name.is_some()) {
-            (Some(subtype), true) => write!(f, "{} ({})", place.display_name(), subtype),
-            (Some(subtype), false) => write!(f, "{} {}", subtype.get_emoji(), subtype),
-            (None, true) => write!(f, "{} (place)", place.display_name()),
-            (None, false) => write!(f, "{} place", PlaceType::Any.get_emoji()),
+            (Some(subtype), true) => {
+                let mut inlines = place.display_name().to_inlines();
+                inlines.push(Inline::text(format!(" ({})", subtype)));
+                inlines
+            }
+            (Some(subtype), false) => {
+                vec![Inline::text(format!("{} {}", subtype.get_emoji(), subtype))]
+            }
+            (None, true) => {
+                let mut inlines = place.display_name().to_inlines();
+                inlines.push(Inline::text(" (place)"));
+                inlines
+            }
+            (None, false) => vec![Inline::text(format!(
+                "{} place",
+                PlaceType::Any.get_emoji()
+            ))],
         }
     }
 }
 
+impl fmt::Display for NameView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Inline::to_markdown(&self.to_inlines()))
+    }
+}
+
+impl fmt::Display for SummaryView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Inline::to_markdown(&self.to_inlines()))
+    }
+}
+
 impl fmt::Display for DescriptionView<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(subtype) = self.0.subtype.value() {
@@ -84,59 +114,142 @@ impl fmt::Display for DescriptionView<'_> {
 
 impl fmt::Display for DetailsView<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Output::new(vec![self.to_block()]).to_markdown())
+    }
+}
+
+impl DetailsView<'_> {
+    /// The full details of the place as a thing box.
+    pub fn to_block(&self) -> Block {
         let Self {
             place,
             uuid,
             relations,
         } = self;
 
-        writeln!(
-            f,
-            "<div class=\"thing-box place\" data-uuid=\"{}\">\n",
-            uuid
-        )?;
-
-        place
-            .name
-            .value()
-            .map(|name| write!(f, "# {}", name))
-            .unwrap_or_else(|| write!(f, "# Unnamed {}", place.display_description()))?;
-
-        write!(f, "\n*{}*", place.display_description())?;
-
-        relations
-            .location
-            .as_ref()
-            .map(|(parent, grandparent)| {
-                if let Some(grandparent) = grandparent {
-                    write!(
-                        f,
-                        "\n\n**Location:** {}, {}",
-                        parent.display_name(),
-                        grandparent.display_name(),
-                    )
+        let mut blocks = vec![
+            Block::Heading {
+                level: 1,
+                content: vec![Inline::text(place.name.value().map_or_else(
+                    || format!("Unnamed {}", place.display_description()),
+                    |name| format!("{}{}", name, place.name.lock_indicator()),
+                ))],
+            },
+            Block::paragraph(vec![
+                Inline::emphasis(vec![Inline::text(place.display_description().to_string())]),
+                Inline::text(place.subtype.lock_indicator()),
+            ]),
+        ];
+
+        let mut fields = Vec::new();
+
+        if let Some((parent, grandparent)) = &relations.location {
+            let mut location = Vec::new();
+
+            if let Some(grandparent) = grandparent {
+                location.extend(parent.display_name().to_inlines());
+                location.push(Inline::text(", "));
+                location.extend(grandparent.display_name().to_inlines());
+            } else {
+                location.extend(parent.display_summary().to_inlines());
+            }
+
+            fields.push(Inline::field("Location", location));
+        }
+
+        if let Some(quality) = place.quality.value() {
+            fields.push(Inline::field(
+                "Quality",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    quality,
+                    place.quality.lock_indicator(),
+                ))],
+            ));
+        }
+
+        if let Some(price) = place.price.value() {
+            fields.push(Inline::field(
+                "Prices",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    price,
+                    place.price.lock_indicator(),
+                ))],
+            ));
+        }
+
+        if let Some(wares) = place.wares.value().filter(|wares| !wares.is_empty()) {
+            let mut inlines = Vec::new();
+
+            for (i, ware) in wares.iter().enumerate() {
+                if i > 0 {
+                    inlines.push(Inline::text(", "));
+                }
+
+                if let Ok(item) = ware.parse::<Item>() {
+                    inlines.push(Inline::command(item.get_name()));
+                } else if let Ok(item) = ware.parse::<MagicItem>() {
+                    inlines.push(Inline::command(item.get_name()));
                 } else {
-                    write!(f, "\n\n**Location:** {}", parent.display_summary())
+                    inlines.push(Inline::text(ware));
                 }
-            })
-            .transpose()?;
+            }
+
+            inlines.push(Inline::text(place.wares.lock_indicator()));
+
+            fields.push(Inline::field(
+                if place.subtype.value().is_some_and(PlaceType::serves_food) {
+                    "Menu"
+                } else {
+                    "Wares"
+                },
+                inlines,
+            ));
+        }
+
+        if !fields.is_empty() {
+            blocks.push(Block::lines(fields));
+        }
+
+        if let Some(atmosphere) = place.atmosphere.value() {
+            blocks.push(Block::paragraph(vec![
+                Inline::emphasis(vec![Inline::text(atmosphere)]),
+                Inline::text(place.atmosphere.lock_indicator()),
+            ]));
+        }
 
-        place
-            .description
-            .value()
-            .map(|description| write!(f, "\n\n{}", description))
-            .transpose()?;
+        // Descriptions are written by the user, and may contain markdown of their own.
+        if let Some(description) = place.description.value() {
+            blocks.extend(Output::from_markdown(description).blocks);
+        }
 
-        write!(f, "\n\n</div>")?;
+        if let Some(hook) = place.hook.value() {
+            blocks.push(Block::paragraph(Inline::field(
+                "Hook",
+                vec![Inline::text(format!(
+                    "{}{}",
+                    hook,
+                    place.hook.lock_indicator(),
+                ))],
+            )));
+        }
 
-        Ok(())
+        Block::Thing {
+            kind: "place".to_string(),
+            uuid: uuid.to_string(),
+            blocks,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::app::Output;
     use crate::test_utils as test;
+    use crate::world::place::{Price, Quality};
+    use crate::world::Field;
 
     #[test]
     fn view_test_empty() {
@@ -148,7 +261,8 @@ mod test {
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
 # Unnamed place
-*place*
+
+_place_
 
 </div>"#,
             place
@@ -169,8 +283,9 @@ mod test {
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
-# Olympus
-*place*
+# Olympus 🔒
+
+_place_
 
 </div>"#,
             place
@@ -191,7 +306,8 @@ mod test {
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
 # Unnamed inn
-*inn*
+
+_inn_ 🔒
 
 </div>"#,
             place
@@ -210,7 +326,8 @@ mod test {
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
 # Unnamed place
-*place*
+
+_place_
 
 A street with no name.
 
@@ -233,8 +350,9 @@ A street with no name.
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000001">
 
-# Ithaca
-*island*
+# Ithaca 🔒
+
+_island_ 🔒
 
 </div>"#,
             place.display_details(PlaceRelations::default()).to_string(),
@@ -259,8 +377,9 @@ A street with no name.
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
-# The Invulnerable Vagrant
-*place*
+# The Invulnerable Vagrant 🔒
+
+_place_
 
 Come in and see me, and me, and me!
 
@@ -284,7 +403,8 @@ Come in and see me, and me, and me!
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
 # Unnamed inn
-*inn*
+
+_inn_ 🔒
 
 You can check out any time you like.
 
@@ -307,8 +427,9 @@ You can check out any time you like.
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000002">
 
-# Greece
-*territory*
+# Greece 🔒
+
+_territory_ 🔒
 
 You're cruisin' for a bruisin'.
 
@@ -322,8 +443,9 @@ You're cruisin' for a bruisin'.
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000001">
 
-# Ithaca
-*island*
+# Ithaca 🔒
+
+_island_ 🔒
 
 **Location:** 👑 `Greece` (territory)
 
@@ -339,8 +461,9 @@ You're cruisin' for a bruisin'.
         assert_eq!(
             r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
 
-# Chez Penelope
-*castle*
+# Chez Penelope 🔒
+
+_castle_ 🔒
 
 **Location:** 🏝 `Ithaca`, 👑 `Greece`
 
@@ -359,4 +482,152 @@ You're cruisin' for a bruisin'.
                 .to_string(),
         );
     }
+
+    #[test]
+    fn details_view_test_business() {
+        assert_eq!(
+            r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# The Rusty Blade 🔒
+
+_weaponsmith_ 🔒
+
+**Location:** 🏝 `Ithaca` (island)\
+**Quality:** poor 🔒\
+**Prices:** cheap 🔒\
+**Wares:** `Dagger`, `Longsword`, bent nails 🔒
+
+_The furniture is battered and mismatched._ 🔒
+
+Half of the stock is secondhand.
+
+**Hook:** The owner is fencing stolen blades. 🔒
+
+</div>"#,
+            test::place()
+                .name("The Rusty Blade")
+                .subtype("weaponsmith".parse::<PlaceType>().unwrap())
+                .description("Half of the stock is secondhand.")
+                .quality(Quality::Poor)
+                .price(Price::Cheap)
+                .wares(vec![
+                    "dagger".to_string(),
+                    "Longsword".to_string(),
+                    "bent nails".to_string(),
+                ])
+                .atmosphere("The furniture is battered and mismatched.".to_string())
+                .hook("The owner is fencing stolen blades.".to_string())
+                .build()
+                .display_details(
+                    Uuid::nil(),
+                    test::place::relations()
+                        .location(test::place::ithaca())
+                        .build()
+                )
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn details_view_test_menu() {
+        assert_eq!(
+            r#"<div class="thing-box place" data-uuid="00000000-0000-0000-0000-000000000000">
+
+# Unnamed inn
+
+_inn_ 🔒
+
+**Quality:** comfortable\
+**Menu:** rabbit stew, house ale
+
+</div>"#,
+            test::place()
+                .subtype("inn".parse::<PlaceType>().unwrap())
+                .quality(Field::new_generated(Quality::Comfortable))
+                .wares(Field::new_generated(vec![
+                    "rabbit stew".to_string(),
+                    "house ale".to_string(),
+                ]))
+                .build()
+                .display_details(Uuid::nil(), PlaceRelations::default())
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn details_view_test_to_block() {
+        let places = [
+            (PlaceData::default(), PlaceRelations::default()),
+            (
+                test::place().name("Olympus").build(),
+                PlaceRelations::default(),
+            ),
+            (
+                test::place()
+                    .subtype("inn".parse::<PlaceType>().unwrap())
+                    .description("A *cozy* spot for `ale`.")
+                    .build(),
+                PlaceRelations::default(),
+            ),
+            (
+                test::place::ithaca::data(),
+                test::place::ithaca::relations(),
+            ),
+            (
+                test::place()
+                    .name("Chez Penelope")
+                    .subtype("castle".parse::<PlaceType>().unwrap())
+                    .build(),
+                test::place::relations()
+                    .location(test::place::ithaca())
+                    .location(test::place::greece())
+                    .build(),
+            ),
+            (
+                test::place()
+                    .name("The Rusty Blade")
+                    .subtype("weaponsmith".parse::<PlaceType>().unwrap())
+                    .description("Half of the stock is secondhand.")
+                    .quality(Quality::Poor)
+                    .price(Price::Cheap)
+                    .wares(vec![
+                        "dagger".to_string(),
+                        "Longsword".to_string(),
+                        "bent nails".to_string(),
+                    ])
+                    .atmosphere("The furniture is battered and mismatched.".to_string())
+                    .hook("The owner is fencing stolen blades.".to_string())
+                    .build(),
+                test::place::relations()
+                    .location(test::place::ithaca())
+                    .build(),
+            ),
+            (
+                test::place()
+                    .subtype("inn".parse::<PlaceType>().unwrap())
+                    .quality(Field::new_generated(Quality::Comfortable))
+                    .wares(Field::new_generated(vec![
+                        "rabbit stew".to_string(),
+                        "house ale".to_string(),
+                    ]))
+                    .build(),
+                PlaceRelations::default(),
+            ),
+        ];
+
+        for (place, relations) in places {
+            let view = place.display_details(Uuid::nil(), relations);
+
+            assert_eq!(
+                Output::from_markdown(&view.to_string()),
+                Output::new(vec![view.to_block()]),
+                "{}",
+                view,
+            );
+            assert_eq!(
+                Inline::from_markdown(&place.display_summary().to_string()),
+                place.display_summary().to_inlines(),
+            );
+        }
+    }
 }
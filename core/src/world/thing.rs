@@ -1,10 +1,15 @@
-use super::{Demographics, Field, Generate};
+use super::{Demographics, Field, Generate, Vocabulary};
+use crate::app::{Block, Inline};
 use crate::storage::ThingType;
 use crate::world::command::ParsedThing;
-use crate::world::npc::{DetailsView as NpcDetailsView, Gender, Npc, NpcData, NpcRelations};
-use crate::world::place::{DetailsView as PlaceDetailsView, Place, PlaceData, PlaceRelations};
+use crate::world::npc::{
+    DetailsView as NpcDetailsView, Gender, Npc, NpcData, NpcField, NpcRelations,
+};
+use crate::world::place::{
+    DetailsView as PlaceDetailsView, Place, PlaceData, PlaceField, PlaceRelations,
+};
 use initiative_macros::From;
-use rand::Rng;
+use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
@@ -26,6 +31,12 @@ pub enum ThingData {
     Place(PlaceData),
 }
 
+#[derive(Clone, Copy, Debug, Eq, From, PartialEq)]
+pub enum ThingField {
+    Npc(NpcField),
+    Place(PlaceField),
+}
+
 #[derive(Debug, Default, From)]
 pub enum ThingRelations {
     #[default]
@@ -116,6 +127,25 @@ impl ThingData {
             ThingData::Npc(npc) => npc.regenerate(rng, demographics),
         }
     }
+
+    /// Regenerate the thing using a dedicated random number generator seeded with `seed`, and
+    /// record the seed so that the same result can be reproduced later.
+    pub fn regenerate_with_seed(&mut self, seed: u64, demographics: &Demographics) {
+        match self {
+            ThingData::Place(place) => place.seed.replace(seed),
+            ThingData::Npc(npc) => npc.seed.replace(seed),
+        }
+
+        self.regenerate(&mut SmallRng::seed_from_u64(seed), demographics);
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            ThingData::Place(place) => place.seed.value().copied(),
+            ThingData::Npc(npc) => npc.seed.value().copied(),
+        }
+    }
+
     pub fn gender(&self) -> Gender {
         if let Self::Npc(npc) = self {
             npc.gender()
@@ -162,6 +192,119 @@ impl ThingData {
         }
     }
 
+    /// Parse the name of a field that is applicable to this type of thing, eg. "age" for an NPC.
+    pub fn parse_field(&self, input: &str) -> Option<ThingField> {
+        match self {
+            Self::Npc(_) => input.parse::<NpcField>().ok().map(|f| f.into()),
+            Self::Place(_) => input.parse::<PlaceField>().ok().map(|f| f.into()),
+        }
+    }
+
+    pub fn is_field_locked(&self, field: ThingField) -> bool {
+        match (self, field) {
+            (Self::Npc(npc), ThingField::Npc(field)) => npc.is_field_locked(field),
+            (Self::Place(place), ThingField::Place(field)) => place.is_field_locked(field),
+            _ => false,
+        }
+    }
+
+    /// Produce a diff suitable for use with `Change::Reroll` that will lock or unlock the given
+    /// fields (or all fields if none are given) without changing their values.
+    pub fn lock_diff(&self, fields: &[ThingField], locked: bool) -> Self {
+        let fields: Vec<ThingField> = if fields.is_empty() {
+            self.fields()
+        } else {
+            fields.to_vec()
+        };
+
+        match self {
+            Self::Npc(npc) => {
+                let mut diff = NpcData::default();
+                for field in fields {
+                    if let ThingField::Npc(field) = field {
+                        diff.apply_reroll_diff(&mut npc.lock_diff(field, locked));
+                    }
+                }
+                diff.into()
+            }
+            Self::Place(place) => {
+                let mut diff = PlaceData::default();
+                for field in fields {
+                    if let ThingField::Place(field) = field {
+                        diff.apply_reroll_diff(&mut place.lock_diff(field, locked));
+                    }
+                }
+                diff.into()
+            }
+        }
+    }
+
+    /// All fields applicable to this type of thing. Fields with aliases are only included once.
+    pub fn fields(&self) -> Vec<ThingField> {
+        let mut fields = Vec::new();
+
+        for field in self
+            .field_words()
+            .into_iter()
+            .filter_map(|word| self.parse_field(word))
+        {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+
+        fields
+    }
+
+    /// The names of all fields applicable to this type of thing.
+    pub fn field_words(&self) -> Vec<&'static str> {
+        match self {
+            Self::Npc(_) => NpcField::get_words().collect(),
+            Self::Place(_) => PlaceField::get_words().collect(),
+        }
+    }
+
+    /// Regenerate the given fields (or all unlocked fields if none are given), returning a diff
+    /// suitable for use with `Change::Reroll`. Fields belonging to other thing types are ignored.
+    pub fn reroll(
+        &self,
+        fields: &[ThingField],
+        rng: &mut impl Rng,
+        demographics: &Demographics,
+    ) -> Self {
+        match self {
+            Self::Npc(npc) => {
+                let fields: Vec<NpcField> = fields
+                    .iter()
+                    .filter_map(|field| match field {
+                        ThingField::Npc(field) => Some(*field),
+                        ThingField::Place(_) => None,
+                    })
+                    .collect();
+                npc.reroll(&fields, rng, demographics).into()
+            }
+            Self::Place(place) => {
+                let fields: Vec<PlaceField> = fields
+                    .iter()
+                    .filter_map(|field| match field {
+                        ThingField::Place(field) => Some(*field),
+                        ThingField::Npc(_) => None,
+                    })
+                    .collect();
+                place.reroll(&fields, rng, demographics).into()
+            }
+        }
+    }
+
+    /// A human-readable representation of the value of a field, if it has one.
+    pub fn describe_field(&self, field: ThingField) -> Option<String> {
+        match (self, field) {
+            (Self::Npc(npc), ThingField::Npc(field)) => npc.describe_field(field),
+            (Self::Place(place), ThingField::Place(field)) => place.describe_field(field),
+            _ => None,
+        }
+    }
+
     pub fn try_apply_diff(&mut self, diff: &mut Self) -> Result<(), ()> {
         match (self, diff) {
             (Self::Npc(npc), Self::Npc(diff_npc)) => npc.apply_diff(diff_npc),
@@ -171,6 +314,18 @@ impl ThingData {
 
         Ok(())
     }
+
+    /// Like [`Self::try_apply_diff`], but for diffs produced by [`Self::reroll`] or
+    /// [`Self::lock_diff`], which carry the lock state of each field with them.
+    pub fn try_apply_reroll_diff(&mut self, diff: &mut Self) -> Result<(), ()> {
+        match (self, diff) {
+            (Self::Npc(npc), Self::Npc(diff_npc)) => npc.apply_reroll_diff(diff_npc),
+            (Self::Place(place), Self::Place(diff_place)) => place.apply_reroll_diff(diff_place),
+            _ => return Err(()),
+        }
+
+        Ok(())
+    }
 }
 
 impl From<Npc> for Thing {
@@ -269,8 +424,16 @@ impl FromStr for ParsedThing<ThingData> {
     type Err = ();
 
     fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(raw, &Vocabulary::default())
+    }
+}
+
+impl ParsedThing<ThingData> {
+    /// Parse a description of any type of thing, also recognizing any custom ethnicities defined
+    /// by the user.
+    pub fn parse_with(raw: &str, vocabulary: &Vocabulary) -> Result<Self, ()> {
         match (
-            raw.parse::<ParsedThing<NpcData>>(),
+            ParsedThing::<NpcData>::parse_with(raw, vocabulary),
             raw.parse::<ParsedThing<PlaceData>>(),
         ) {
             (Ok(parsed_npc), Ok(parsed_place)) => match parsed_npc
@@ -289,6 +452,41 @@ impl FromStr for ParsedThing<ThingData> {
     }
 }
 
+impl ThingField {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Npc(field) => field.as_str(),
+            Self::Place(field) => field.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for ThingField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl SummaryView<'_> {
+    /// A one-line summary of the thing, with the name (if any) as a command link.
+    pub fn to_inlines(&self) -> Vec<Inline> {
+        match self.0 {
+            ThingData::Place(l) => l.display_summary().to_inlines(),
+            ThingData::Npc(n) => n.display_summary().to_inlines(),
+        }
+    }
+}
+
+impl DetailsView<'_> {
+    /// The full details of the thing as a thing box.
+    pub fn to_block(&self) -> Block {
+        match self {
+            DetailsView::Npc(view) => view.to_block(),
+            DetailsView::Place(view) => view.to_block(),
+        }
+    }
+}
+
 impl fmt::Display for SummaryView<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.0 {
@@ -401,6 +599,24 @@ mod test {
         assert_eq!(Gender::Feminine, npc.gender());
     }
 
+    #[test]
+    fn fields_test() {
+        for thing_data in [
+            ThingData::from(NpcData::default()),
+            ThingData::from(PlaceData::default()),
+        ] {
+            let fields = thing_data.fields();
+
+            for (i, field) in fields.iter().enumerate() {
+                assert!(!fields[i + 1..].contains(field), "{:?}", field);
+            }
+        }
+
+        assert!(ThingData::from(NpcData::default())
+            .fields()
+            .contains(&NpcField::Flaw.into()));
+    }
+
     #[test]
     fn lock_all_test_npc() {
         let mut npc = NpcData::default();
@@ -432,4 +648,20 @@ mod test {
             data: ThingData::Npc(NpcData::default()),
         }
     }
+
+    #[test]
+    fn regenerate_with_seed_test() {
+        let demographics = Demographics::default();
+        let generate = |seed| {
+            let mut thing_data = ThingData::Npc(NpcData::default());
+            thing_data.regenerate_with_seed(seed, &demographics);
+            thing_data
+        };
+
+        let thing_data = generate(1234);
+        assert_eq!(Some(1234), thing_data.seed());
+        assert_eq!(thing_data, generate(1234));
+        assert_ne!(thing_data, generate(4321));
+        assert_eq!(None, ThingData::Npc(NpcData::default()).seed());
+    }
 }
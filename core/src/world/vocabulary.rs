@@ -0,0 +1,415 @@
+use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::{Ethnicity, Gender};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Names and words provided by the user to supplement the built-in generators, eg. for a homebrew
+/// setting. The whole vocabulary is persisted as a single value in the key-value store.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Vocabulary {
+    /// Name lists for custom ethnicities, keyed by the lowercase name of the ethnicity.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    ethnicities: BTreeMap<String, NameLists>,
+
+    /// Extra words that can appear in the names of inns, eg. "Griffin" in "The Griffin".
+    #[serde(default, rename = "innWords", skip_serializing_if = "Vec::is_empty")]
+    inn_words: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+struct NameLists {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    feminine: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    masculine: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    neutral: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    surnames: Vec<String>,
+}
+
+/// Identifies one of the lists that make up the [`Vocabulary`], eg. "valyrian feminine names".
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VocabularyList {
+    Names {
+        ethnicity: String,
+        name_type: NameType,
+    },
+    InnWords,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NameType {
+    Feminine,
+    Masculine,
+    Neutral,
+    Surname,
+}
+
+impl Vocabulary {
+    pub fn is_empty(&self) -> bool {
+        self.ethnicities.is_empty() && self.inn_words.is_empty()
+    }
+
+    /// Get the custom ethnicity with a given name, if one has been defined.
+    pub fn ethnicity(&self, name: &str) -> Option<Ethnicity> {
+        self.ethnicities
+            .keys()
+            .find(|key| key.eq_ci(name))
+            .map(|key| Ethnicity::Custom(key.to_string()))
+    }
+
+    pub fn inn_words(&self) -> &[String] {
+        &self.inn_words
+    }
+
+    pub fn get(&self, list: &VocabularyList) -> &[String] {
+        match list {
+            VocabularyList::Names {
+                ethnicity,
+                name_type,
+            } => self
+                .ethnicities
+                .get(&ethnicity.to_lowercase())
+                .map_or(&[][..], |lists| lists.get(*name_type)),
+            VocabularyList::InnWords => &self.inn_words,
+        }
+    }
+
+    /// Add words to a list, skipping any that are already present. Returns the words that were
+    /// actually added.
+    pub fn add(&mut self, list: &VocabularyList, words: &[String]) -> Vec<String> {
+        let target = match list {
+            VocabularyList::Names {
+                ethnicity,
+                name_type,
+            } => self
+                .ethnicities
+                .entry(ethnicity.to_lowercase())
+                .or_default()
+                .get_mut(*name_type),
+            VocabularyList::InnWords => &mut self.inn_words,
+        };
+
+        let mut added = Vec::new();
+
+        for word in words {
+            if !target.iter().any(|existing| existing.eq_ci(word)) {
+                target.push(word.to_string());
+                added.push(word.to_string());
+            }
+        }
+
+        added
+    }
+
+    /// Consume the vocabulary, returning each of its non-empty lists along with its words.
+    pub fn into_lists(self) -> Vec<(VocabularyList, Vec<String>)> {
+        let mut lists = Vec::new();
+
+        for (ethnicity, mut name_lists) in self.ethnicities {
+            for name_type in [
+                NameType::Feminine,
+                NameType::Masculine,
+                NameType::Neutral,
+                NameType::Surname,
+            ] {
+                let words = std::mem::take(name_lists.get_mut(name_type));
+
+                if !words.is_empty() {
+                    let ethnicity = ethnicity.clone();
+                    lists.push((
+                        VocabularyList::Names {
+                            ethnicity,
+                            name_type,
+                        },
+                        words,
+                    ));
+                }
+            }
+        }
+
+        if !self.inn_words.is_empty() {
+            lists.push((VocabularyList::InnWords, self.inn_words));
+        }
+
+        lists
+    }
+
+    /// Generate a name for a member of a custom ethnicity, preferring first names matching the
+    /// character's gender. Returns `None` if the ethnicity doesn't have any first names.
+    pub fn gen_name(&self, rng: &mut impl Rng, ethnicity: &str, gender: &Gender) -> Option<String> {
+        let lists = self.ethnicities.get(&ethnicity.to_lowercase())?;
+
+        let gendered = match gender {
+            Gender::Feminine => &lists.feminine[..],
+            Gender::Masculine => &lists.masculine[..],
+            _ => &[][..],
+        };
+
+        let first_name = if gendered.is_empty() && lists.neutral.is_empty() {
+            lists
+                .feminine
+                .iter()
+                .chain(lists.masculine.iter())
+                .choose(rng)?
+        } else {
+            gendered.iter().chain(lists.neutral.iter()).choose(rng)?
+        };
+
+        Some(if let Some(surname) = lists.surnames.choose(rng) {
+            format!("{} {}", first_name, surname)
+        } else {
+            first_name.to_string()
+        })
+    }
+}
+
+impl NameLists {
+    fn get(&self, name_type: NameType) -> &[String] {
+        match name_type {
+            NameType::Feminine => &self.feminine,
+            NameType::Masculine => &self.masculine,
+            NameType::Neutral => &self.neutral,
+            NameType::Surname => &self.surnames,
+        }
+    }
+
+    fn get_mut(&mut self, name_type: NameType) -> &mut Vec<String> {
+        match name_type {
+            NameType::Feminine => &mut self.feminine,
+            NameType::Masculine => &mut self.masculine,
+            NameType::Neutral => &mut self.neutral,
+            NameType::Surname => &mut self.surnames,
+        }
+    }
+}
+
+impl FromStr for VocabularyList {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.eq_ci("inn words") {
+            return Ok(Self::InnWords);
+        }
+
+        let mut words = input.split_whitespace();
+        let ethnicity = words.next().ok_or(())?;
+
+        if !ethnicity
+            .chars()
+            .all(|c| c.is_alphabetic() || c == '-' || c == '\'')
+        {
+            return Err(());
+        }
+
+        let name_type = match (words.next(), words.next(), words.next()) {
+            (Some(s), None, None) if s.eq_ci("surnames") => NameType::Surname,
+            (Some(s), None, None) if s.eq_ci("names") => NameType::Neutral,
+            (Some(g), Some(s), None) if s.eq_ci("names") => {
+                if g.eq_ci("feminine") {
+                    NameType::Feminine
+                } else if g.eq_ci("masculine") {
+                    NameType::Masculine
+                } else if g.eq_ci("neutral") {
+                    NameType::Neutral
+                } else {
+                    return Err(());
+                }
+            }
+            _ => return Err(()),
+        };
+
+        Ok(Self::Names {
+            ethnicity: ethnicity.to_lowercase(),
+            name_type,
+        })
+    }
+}
+
+impl fmt::Display for VocabularyList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Names {
+                ethnicity,
+                name_type: NameType::Feminine,
+            } => write!(f, "{} feminine names", ethnicity),
+            Self::Names {
+                ethnicity,
+                name_type: NameType::Masculine,
+            } => write!(f, "{} masculine names", ethnicity),
+            Self::Names {
+                ethnicity,
+                name_type: NameType::Neutral,
+            } => write!(f, "{} names", ethnicity),
+            Self::Names {
+                ethnicity,
+                name_type: NameType::Surname,
+            } => write!(f, "{} surnames", ethnicity),
+            Self::InnWords => write!(f, "inn words"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn valyrian() -> VocabularyList {
+        VocabularyList::Names {
+            ethnicity: "valyrian".to_string(),
+            name_type: NameType::Feminine,
+        }
+    }
+
+    #[test]
+    fn add_test() {
+        let mut vocabulary = Vocabulary::default();
+        assert!(vocabulary.is_empty());
+        assert_eq!(None, vocabulary.ethnicity("Valyrian"));
+
+        assert_eq!(
+            vec!["Daenerys".to_string(), "Rhaenyra".to_string()],
+            vocabulary.add(
+                &valyrian(),
+                &["Daenerys".to_string(), "Rhaenyra".to_string()],
+            ),
+        );
+
+        assert_eq!(
+            vec!["Visenya".to_string()],
+            vocabulary.add(
+                &valyrian(),
+                &["daenerys".to_string(), "Visenya".to_string()],
+            ),
+        );
+
+        assert_eq!(
+            vocabulary.get(&valyrian()),
+            ["Daenerys", "Rhaenyra", "Visenya"],
+        );
+        assert_eq!(
+            Some(Ethnicity::Custom("valyrian".to_string())),
+            vocabulary.ethnicity("Valyrian"),
+        );
+        assert!(!vocabulary.is_empty());
+
+        vocabulary.add(&VocabularyList::InnWords, &["Griffin".to_string()]);
+        assert_eq!(vocabulary.inn_words(), ["Griffin"]);
+    }
+
+    #[test]
+    fn into_lists_test() {
+        let mut vocabulary = Vocabulary::default();
+        assert!(vocabulary.clone().into_lists().is_empty());
+
+        vocabulary.add(&VocabularyList::InnWords, &["Griffin".to_string()]);
+        vocabulary.add(&valyrian(), &["Daenerys".to_string()]);
+
+        assert_eq!(
+            vec![
+                (valyrian(), vec!["Daenerys".to_string()]),
+                (VocabularyList::InnWords, vec!["Griffin".to_string()]),
+            ],
+            vocabulary.into_lists(),
+        );
+    }
+
+    #[test]
+    fn gen_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut vocabulary = Vocabulary::default();
+
+        assert_eq!(
+            None,
+            vocabulary.gen_name(&mut rng, "valyrian", &Gender::Feminine),
+        );
+
+        vocabulary.add(&valyrian(), &["Daenerys".to_string()]);
+        assert_eq!(
+            Some("Daenerys".to_string()),
+            vocabulary.gen_name(&mut rng, "valyrian", &Gender::Masculine),
+        );
+
+        vocabulary.add(
+            &VocabularyList::Names {
+                ethnicity: "valyrian".to_string(),
+                name_type: NameType::Masculine,
+            },
+            &["Aegon".to_string()],
+        );
+        vocabulary.add(
+            &VocabularyList::Names {
+                ethnicity: "valyrian".to_string(),
+                name_type: NameType::Surname,
+            },
+            &["Targaryen".to_string()],
+        );
+        assert_eq!(
+            Some("Aegon Targaryen".to_string()),
+            vocabulary.gen_name(&mut rng, "Valyrian", &Gender::Masculine),
+        );
+        assert_eq!(
+            Some("Daenerys Targaryen".to_string()),
+            vocabulary.gen_name(&mut rng, "valyrian", &Gender::Feminine),
+        );
+    }
+
+    #[test]
+    fn vocabulary_list_from_str_test() {
+        assert_eq!(Ok(VocabularyList::InnWords), "Inn Words".parse());
+        assert_eq!(Ok(valyrian()), "Valyrian feminine names".parse());
+        assert_eq!(
+            Ok(VocabularyList::Names {
+                ethnicity: "valyrian".to_string(),
+                name_type: NameType::Neutral,
+            }),
+            "valyrian names".parse(),
+        );
+        assert_eq!(
+            Ok(VocabularyList::Names {
+                ethnicity: "valyrian".to_string(),
+                name_type: NameType::Surname,
+            }),
+            "valyrian surnames".parse(),
+        );
+        assert_eq!(Err(()), "valyrian potato names".parse::<VocabularyList>());
+        assert_eq!(Err(()), "high valyrian names".parse::<VocabularyList>());
+        assert_eq!(Err(()), "names".parse::<VocabularyList>());
+    }
+
+    #[test]
+    fn vocabulary_list_display_test() {
+        for list in [
+            "valyrian feminine names",
+            "valyrian masculine names",
+            "valyrian names",
+            "valyrian surnames",
+            "inn words",
+        ] {
+            assert_eq!(list, list.parse::<VocabularyList>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn serialize_test() {
+        let mut vocabulary = Vocabulary::default();
+        assert_eq!("{}", serde_json::to_string(&vocabulary).unwrap());
+
+        vocabulary.add(&valyrian(), &["Daenerys".to_string()]);
+        vocabulary.add(&VocabularyList::InnWords, &["Griffin".to_string()]);
+
+        let serialized = serde_json::to_string(&vocabulary).unwrap();
+        assert_eq!(
+            r#"{"ethnicities":{"valyrian":{"feminine":["Daenerys"]}},"innWords":["Griffin"]}"#,
+            serialized,
+        );
+        assert_eq!(vocabulary, serde_json::from_str(&serialized).unwrap());
+    }
+}
@@ -76,6 +76,25 @@ const SYMBOLS: &[&str] = &[
     "Tower", "Trumpet", "Wand", "Wheel",
 ];
 
+/// The first half of a compound place name, eg. "Oak" in "Oakford".
+#[rustfmt::skip]
+const PLACE_PREFIXES: &[&str] = &[
+    "Alder", "Amber", "Apple", "Ash", "Black", "Bright", "Briar", "Cold", "Crow", "Deep", "East",
+    "Elder", "Elm", "Fair", "Fox", "Frost", "Gold", "Green", "Grey", "Hart", "Hawk", "High",
+    "Holly", "Iron", "Kings", "Lark", "Long", "Maple", "Mill", "Moss", "North", "Oak",
+    "Queens", "Raven", "Red", "Rose", "Salt", "Silver", "South", "Stone", "Storm", "Thorn",
+    "West", "White", "Willow", "Wolf",
+];
+
+/// The second half of a compound place name, eg. "ford" in "Oakford".
+#[rustfmt::skip]
+const PLACE_SUFFIXES: &[&str] = &[
+    "bank", "borough", "bridge", "brook", "bury", "by", "cliff", "combe", "dale", "fall",
+    "field", "ford", "gate", "glen", "ham", "haven", "hill", "hold", "holm", "hurst", "mere",
+    "minster", "moor", "mouth", "port", "reach", "stead", "stone", "thorpe", "ton", "vale",
+    "wick", "wood", "worth",
+];
+
 pub fn adjective(rng: &mut impl Rng) -> &'static str {
     ListGenerator(ADJECTIVES).gen(rng)
 }
@@ -96,6 +115,16 @@ pub fn gem(rng: &mut impl Rng) -> &'static str {
     ListGenerator(GEMS).gen(rng)
 }
 
+/// A compound place name such as "Oakford" or "Ravenmere", suitable for a town or anything named
+/// after one.
+pub fn place_name(rng: &mut impl Rng) -> String {
+    format!(
+        "{}{}",
+        ListGenerator(PLACE_PREFIXES).gen(rng),
+        ListGenerator(PLACE_SUFFIXES).gen(rng),
+    )
+}
+
 pub fn person(rng: &mut impl Rng) -> &'static str {
     ListGenerator(PEOPLE).gen(rng)
 }
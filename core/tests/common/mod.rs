@@ -1,4 +1,5 @@
-use initiative_core::app::AutocompleteSuggestion;
+use futures::channel::mpsc::UnboundedReceiver;
+use initiative_core::app::{AutocompleteSuggestion, Output};
 use initiative_core::{app, App, BackupData, DataStore, Event, MemoryDataStore, NullDataStore};
 use tokio_test::block_on;
 
@@ -47,14 +48,26 @@ impl SyncApp {
         block_on(self.0.init())
     }
 
+    pub fn set_seed(&mut self, seed: u64) {
+        self.0.set_seed(seed);
+    }
+
     pub fn command(&mut self, input: &str) -> Result<String, String> {
         block_on(self.0.command(input))
     }
 
+    pub fn command_output(&mut self, input: &str) -> Result<Output, Output> {
+        block_on(self.0.command_output(input))
+    }
+
     pub fn autocomplete(&self, input: &str) -> Vec<AutocompleteSuggestion> {
         block_on(self.0.autocomplete(input))
     }
 
+    pub fn subscribe(&mut self) -> UnboundedReceiver<Event> {
+        self.0.subscribe()
+    }
+
     pub fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
         block_on(self.0.bulk_import(data))
     }
@@ -0,0 +1,195 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{Event, MemoryDataStore};
+
+#[test]
+fn alias_can_be_created_and_run() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Created the alias `when`, which runs `now`.",
+        app.command("alias when = now").unwrap(),
+    );
+
+    assert_eq!(
+        "It is currently day 1 at 8:00:00 am.",
+        app.command("when").unwrap(),
+    );
+
+    assert_eq!(
+        "Updated the alias `When`, which runs `date`.",
+        app.command("alias When = date").unwrap(),
+    );
+
+    assert_eq!(
+        "It is currently day 1 at 8:00:00 am.",
+        app.command("WHEN").unwrap(),
+    );
+
+    assert_eq!(
+        vec![("When".to_string(), "date".to_string())],
+        app.autocomplete("whe")
+            .into_iter()
+            .map(|suggestion| (suggestion.term.to_string(), suggestion.summary.to_string()))
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn macro_can_be_created_and_run() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Created the macro `rest`, which runs `+8h`, then `now`.",
+        app.command("macro rest = +8h; now").unwrap(),
+    );
+
+    assert_eq!(
+        "\\> +8h\n\nIt is now day 1 at 4:00:00 pm. Use `undo` to reverse.\n\n\\> now\n\nIt is currently day 1 at 4:00:00 pm.",
+        app.command("rest").unwrap(),
+    );
+}
+
+#[test]
+fn aliases_can_be_listed_and_removed() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "You haven't created any aliases yet. Try `alias fight = roll d20+5`, then type `fight` to run it.",
+        app.command("aliases").unwrap(),
+    );
+
+    app.command("alias when = now").unwrap();
+    app.command("macro rest = +8h; now").unwrap();
+
+    assert_eq!(
+        "# Aliases\n\n* `rest`: `+8h`; `now`\n* `when`: `now`",
+        app.command("aliases").unwrap(),
+    );
+
+    assert_eq!(
+        "Removed the macro \"rest\".",
+        app.command("unalias rest").unwrap(),
+    );
+
+    assert_eq!(
+        Err("There is no alias named \"rest\".".to_string()),
+        app.command("unalias rest"),
+    );
+
+    assert_eq!(
+        "# Aliases\n\n* `when`: `now`",
+        app.command("aliases").unwrap(),
+    );
+}
+
+#[test]
+fn invalid_aliases_are_rejected() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        Err("\"now\" already means something to initiative.sh, so it can't be used as the name of an alias.".to_string()),
+        app.command("alias now = roll d20"),
+    );
+
+    assert_eq!(
+        Err("\"florp\" isn't a command that initiative.sh understands.".to_string()),
+        app.command("macro rest = +8h; florp"),
+    );
+
+    assert_eq!(
+        "You haven't created any aliases yet. Try `alias fight = roll d20+5`, then type `fight` to run it.",
+        app.command("aliases").unwrap(),
+    );
+}
+
+#[test]
+fn aliases_are_persisted() {
+    let data_store = MemoryDataStore::default();
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        app.init();
+        app.command("alias when = now").unwrap();
+    }
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        app.init();
+
+        assert_eq!(
+            "It is currently day 1 at 8:00:00 am.",
+            app.command("when").unwrap(),
+        );
+
+        app.command("unalias when").unwrap();
+    }
+
+    {
+        let mut app = sync_app_with_data_store(data_store);
+        app.init();
+        assert!(app.command("when").is_err());
+    }
+}
+
+#[test]
+fn aliases_are_exported_and_imported() {
+    let backup_data = {
+        let mut app = sync_app();
+        let mut receiver = app.subscribe();
+
+        app.command("macro rest = +8h; now").unwrap();
+        app.command("export").unwrap();
+
+        std::iter::from_fn(|| receiver.try_next().ok().flatten())
+            .find_map(|event| match event {
+                Event::Export(data) => Some(data),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Key/values: 1 created",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    assert_eq!(
+        "# Aliases\n\n* `rest`: `+8h`; `now`",
+        app.command("aliases").unwrap(),
+    );
+}
+
+#[test]
+fn imported_aliases_are_merged_with_existing_aliases() {
+    let backup_data = {
+        let mut app = sync_app();
+        let mut receiver = app.subscribe();
+
+        app.command("alias fight = roll d20+5").unwrap();
+        app.command("alias rest = +8h").unwrap();
+        app.command("export").unwrap();
+
+        std::iter::from_fn(|| receiver.try_next().ok().flatten())
+            .find_map(|event| match event {
+                Event::Export(data) => Some(data),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    let mut app = sync_app();
+    app.command("alias flee = roll d20").unwrap();
+    app.command("alias rest = +1h").unwrap();
+
+    assert_eq!(
+        "Key/values: 1 created, 1 updated",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    assert_eq!(
+        "# Aliases\n\n* `fight`: `roll d20+5`\n* `flee`: `roll d20`\n* `rest`: `+8h`",
+        app.command("aliases").unwrap(),
+    );
+}
@@ -0,0 +1,93 @@
+use crate::common::{get_name, sync_app};
+use futures::channel::mpsc::UnboundedReceiver;
+use initiative_core::{app, Event, MemoryDataStore};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tokio_test::block_on;
+
+fn take_events(receiver: &mut UnboundedReceiver<Event>) -> Vec<Event> {
+    std::iter::from_fn(|| receiver.try_next().ok().flatten()).collect()
+}
+
+#[test]
+fn subscribe() {
+    let mut app = sync_app();
+    let mut receiver = app.subscribe();
+
+    app.command("npc named Penelope").unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::ThingCreated(thing)] if thing.name().value().map(String::as_str) == Some("Penelope"),
+    ));
+
+    app.command("undo").unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::Undo, Event::ThingDeleted { name, .. }] if name == "Penelope",
+    ));
+
+    app.command("redo").unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::Redo, Event::ThingCreated(_)],
+    ));
+
+    app.command("delete Penelope").unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::ThingDeleted { name, .. }] if name == "Penelope",
+    ));
+
+    let npc_name = get_name(&app.command("npc").unwrap());
+    take_events(&mut receiver);
+
+    app.command(&format!("save {}", npc_name)).unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::ThingEdited(thing)] if thing.name().value() == Some(&npc_name),
+    ));
+
+    app.command("+1d").unwrap();
+    assert!(matches!(
+        &take_events(&mut receiver)[..],
+        [Event::TimeChanged(_)],
+    ));
+
+    app.command("roll d20").unwrap();
+    assert!(take_events(&mut receiver).is_empty());
+}
+
+#[test]
+fn subscribe_dropped_receiver() {
+    let mut app = sync_app();
+    drop(app.subscribe());
+    let mut receiver = app.subscribe();
+
+    app.command("npc named Penelope").unwrap();
+    assert_eq!(1, take_events(&mut receiver).len());
+}
+
+#[test]
+fn event_dispatcher_closure() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    let mut app = {
+        let events = events.clone();
+        app(MemoryDataStore::default(), move |event| {
+            events.borrow_mut().push(event)
+        })
+    };
+    block_on(app.init());
+
+    block_on(app.command("npc named Penelope")).unwrap();
+    block_on(app.command("export")).unwrap();
+
+    assert!(
+        matches!(
+            &events.borrow()[..],
+            [Event::ThingCreated(_), Event::Export(_)],
+        ),
+        "{:?}",
+        events.borrow(),
+    );
+}
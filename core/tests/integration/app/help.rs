@@ -23,3 +23,37 @@ fn all_commands_are_valid() {
         }
     }
 }
+
+#[test]
+fn topic_pages_work() {
+    let mut app = sync_app();
+    let output = app.command("help").unwrap();
+
+    for topic in output
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .filter_map(|s| s.strip_prefix("help "))
+        .filter(|s| !s.contains('['))
+    {
+        let page = app.command(&format!("help {}", topic)).unwrap();
+        assert!(page.contains("## Syntax"), "{}", page);
+        assert!(page.contains("## Examples"), "{}", page);
+    }
+}
+
+#[test]
+fn topic_page_shows_syntax() {
+    assert_eq!(
+        "# Load\n\n\
+        Load an entry from your journal.\n\n\
+        ## Syntax\n\n\
+        * `load [name]`\n\
+        * `[name]`\n\n\
+        **Keywords:** `load`\n\n\
+        ## Examples\n\n\
+        * `load Odysseus`: show the details of Odysseus\n\
+        * `Odysseus`: the same, without the `load` keyword",
+        sync_app().command("help load").unwrap(),
+    );
+}
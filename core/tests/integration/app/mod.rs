@@ -1,8 +1,13 @@
 mod about;
+mod alias;
 mod changelog;
 mod debug;
+mod events;
 mod help;
+mod output;
 mod roll;
+mod seed;
+mod table;
 mod tutorial;
 
 use crate::common::{get_name, sync_app};
@@ -40,7 +45,7 @@ fn autocomplete_proper_noun() {
     let mut app = sync_app();
     let output = app.command("npc").unwrap();
     let npc_name = get_name(&output);
-    let npc_description = output.lines().nth(3).unwrap().trim_matches('*');
+    let npc_description = output.lines().nth(4).unwrap().trim_matches('_');
     let query = npc_name.split_whitespace().next().unwrap();
     let autocomplete_results = app.autocomplete(query);
 
@@ -0,0 +1,118 @@
+use crate::common::{get_name, sync_app, sync_app_with_invalid_data_store, SyncApp};
+use initiative_core::app::{Block, Output};
+
+/// Commands that build structured output directly should produce the same content as their
+/// markdown, so that every frontend displays the same thing.
+fn assert_matches_markdown(mut markdown_app: SyncApp, mut output_app: SyncApp) {
+    let mut commands: Vec<String> = [
+        "seed 1",
+        "journal",
+        "npc",
+        "Ana, a grumpy dwarf veteran",
+        "a male sea turtle young adult named Smaug",
+        "Bramble, an owlbear",
+        "inn",
+        "The Rusty Blade, a poor weaponsmith",
+        "Ana is elderly",
+        "Nobody is elderly",
+        "journal",
+        "undo",
+        "redo",
+        "load Ana",
+        "load Nobody",
+        "delete Bramble",
+        "undo",
+        "delete Nobody",
+        "save Nobody",
+        "srd spell Shield",
+        "Open Game License",
+        "import",
+        "export",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect();
+
+    let mut i = 0;
+    while let Some(command) = commands.get(i).cloned() {
+        let markdown = markdown_app.command(&command);
+
+        // Generated names are the same in both apps thanks to the seed.
+        if command == "npc" {
+            let name = get_name(markdown.as_ref().unwrap());
+            commands.extend([
+                format!("load {}", name),
+                format!("save {}", name),
+                format!("load {}", name),
+            ]);
+        }
+
+        assert_eq!(
+            without_uuids(Output::from_result(markdown)),
+            without_uuids(output_app.command_output(&command)),
+            "{}",
+            command,
+        );
+
+        i += 1;
+    }
+}
+
+/// UUIDs are random even with a seed, so they can't be compared between apps.
+fn without_uuids(result: Result<Output, Output>) -> Result<Output, Output> {
+    let strip = |mut output: Output| {
+        output.blocks.iter_mut().for_each(|block| {
+            if let Block::Thing { uuid, .. } = block {
+                uuid.clear();
+            }
+        });
+        output
+    };
+
+    result.map(strip).map_err(strip)
+}
+
+#[test]
+fn structured_output_matches_markdown() {
+    assert_matches_markdown(sync_app(), sync_app());
+}
+
+#[test]
+fn structured_output_matches_markdown_without_storage() {
+    assert_matches_markdown(
+        sync_app_with_invalid_data_store(),
+        sync_app_with_invalid_data_store(),
+    );
+}
+
+#[test]
+fn structured_output_thing() {
+    let mut app = sync_app();
+
+    let output = app.command_output("Ana, a dwarf").unwrap();
+    assert!(
+        matches!(&output.blocks[..], [Block::Thing { kind, .. }, Block::Paragraph { .. }] if kind == "npc"),
+        "{:?}",
+        output,
+    );
+
+    let html = output.to_html();
+    assert!(
+        html.starts_with(r#"<div class="thing-box npc" data-uuid=""#),
+        "{}",
+        html,
+    );
+    assert!(html.contains("<h1>Ana 🔒</h1>"), "{}", html);
+
+    let json = app.command_output("journal").unwrap().to_json();
+    assert!(
+        json.starts_with(r#"{"blocks":[{"type":"heading","level":1,"content":[{"type":"text","text":"Journal"}]}"#),
+        "{}",
+        json,
+    );
+    assert!(
+        json.contains(r#"{"type":"command","command":"Ana"}"#),
+        "{}",
+        json,
+    );
+}
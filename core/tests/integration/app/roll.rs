@@ -1,4 +1,5 @@
 use crate::common::sync_app;
+use initiative_core::app::{Block, Inline, Output};
 
 #[test]
 fn it_works() {
@@ -18,3 +19,98 @@ fn it_works() {
 
     assert_ne!(app.command("roll 100d1000"), app.command("roll 100d1000"));
 }
+
+#[test]
+fn structured_output() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        Ok(Output::new(vec![Block::Paragraph {
+            content: vec![Inline::Roll {
+                expression: "[1] + 2".to_string(),
+                total: 3,
+            }],
+        }])),
+        app.command_output("roll d1+2"),
+    );
+
+    let output = app.command_output("roll banana").unwrap_err();
+    assert!(
+        matches!(output.blocks[..], [Block::Error { .. }]),
+        "{:?}",
+        output,
+    );
+    assert_eq!(
+        r#"{"blocks":[{"type":"paragraph","content":[{"type":"roll","expression":"[1]","total":1}]}]}"#,
+        app.command_output("d1").unwrap().to_json(),
+    );
+}
+
+#[test]
+fn extended_syntax() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "[1] + 5 = **6**, Reason: stealth",
+        app.command("roll d1+5 stealth").unwrap(),
+    );
+
+    assert_eq!(
+        "[1, 1] = **1**\\\n[1, 1] = **1**",
+        app.command("roll 2d1 drop lowest x2").unwrap(),
+    );
+
+    for _ in 0..10 {
+        let (dice, total) = two_dice(&app.command("roll adv +3").unwrap());
+        assert_eq!(dice.0.max(dice.1) + 3, total);
+
+        let (dice, total) = two_dice(&app.command("roll dis").unwrap());
+        assert_eq!(dice.0.min(dice.1), total);
+    }
+}
+
+/// Parse output like "[12, 5] + 3 = **15**".
+fn two_dice(output: &str) -> ((u8, u8), u8) {
+    let (dice, rest) = output[1..].split_once(']').unwrap();
+    let (first, second) = dice.split_once(", ").unwrap();
+    let total = rest.split("**").nth(1).unwrap();
+
+    (
+        (first.parse().unwrap(), second.parse().unwrap()),
+        total.parse().unwrap(),
+    )
+}
+
+#[test]
+fn history() {
+    let mut app = sync_app();
+
+    app.command("roll d1+5 stealth").unwrap();
+    app.command("d1").unwrap();
+    app.command_output("roll (d1)^2").unwrap();
+
+    assert_eq!(
+        "# Roll history\n\n* `roll (d1)^2`: **1**, **1**\n* `roll d1`: **1**\n* `roll d1+5 stealth`: **6**",
+        app.command("roll history").unwrap(),
+    );
+}
+
+#[test]
+fn stats() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "# Statistics for `d4`\n\nRange: **1** to **4**\\\nAverage: **2.5**\n\n| Total | Chance | At least |\n|---:|---:|---:|\n| 1 | 25% | 100% |\n| 2 | 25% | 75% |\n| 3 | 25% | 50% |\n| 4 | 25% | 25% |",
+        app.command("roll stats d4").unwrap(),
+    );
+
+    assert_eq!(
+        Err("Statistics aren't available for \"3d6 e6\". Try a simpler formula, like `roll stats 3d6` or `roll stats adv d20+5`.".to_string()),
+        app.command("roll stats 3d6 e6"),
+    );
+
+    assert_eq!(
+        Err("\"1000d1000\" has too many outcomes to compute statistics for. Try fewer or smaller dice.".to_string()),
+        app.command("roll stats 1000d1000"),
+    );
+}
@@ -0,0 +1,112 @@
+use crate::common::{sync_app, sync_app_with_data_store, SyncApp};
+use initiative_core::MemoryDataStore;
+
+/// Strip the line containing the UUID, which is never seeded.
+fn without_uuid(output: &str) -> String {
+    output
+        .lines()
+        .filter(|line| !line.contains("data-uuid"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn seed_is_reproducible() {
+    let generate = || {
+        let mut app = sync_app();
+
+        assert_eq!(
+            "The random seed is now 1234. Anything generated from here on will be the same each time this seed is used.",
+            app.command("seed 1234").unwrap(),
+        );
+
+        ["npc", "inn", "elf", "more", "d20"]
+            .into_iter()
+            .map(|command| without_uuid(&app.command(command).unwrap()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(generate(), generate());
+}
+
+#[test]
+fn seed_is_recorded_on_things() {
+    let data_store = MemoryDataStore::default();
+    let mut app = sync_app_with_data_store(data_store.clone());
+
+    app.command("npc named Ana").unwrap();
+
+    let thing = data_store.things.borrow().values().next().cloned().unwrap();
+    let seed = thing.data.seed().unwrap();
+
+    assert!(
+        serde_json::to_string(&thing)
+            .unwrap()
+            .contains(&format!("\"seed\":{}", seed)),
+        "{:?}",
+        thing,
+    );
+}
+
+#[test]
+fn seed_is_persisted() {
+    let data_store = MemoryDataStore::default();
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+
+        assert_eq!(
+            "No random seed has been set. Use `seed [number]` to make generated results reproducible.",
+            app.command("seed").unwrap(),
+        );
+
+        app.command("seed 42").unwrap();
+    }
+
+    let first_output = {
+        let mut app = sync_app_with_data_store(data_store.clone());
+
+        assert_eq!(
+            "The random seed is currently 42. Use `seed random` to return to unpredictable results.",
+            app.command("seed").unwrap(),
+        );
+
+        without_uuid(&app.command("npc").unwrap())
+    };
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        assert_eq!(first_output, without_uuid(&app.command("npc").unwrap()));
+
+        assert_eq!(
+            "The random seed has been cleared. Generated results are unpredictable once again.",
+            app.command("seed random").unwrap(),
+        );
+    }
+
+    assert!(data_store.key_values.borrow().get("seed").is_none());
+}
+
+#[test]
+fn set_seed_overrides_stored_seed() {
+    let data_store = MemoryDataStore::default();
+    sync_app_with_data_store(data_store.clone())
+        .command("seed 42")
+        .unwrap();
+
+    let generate = |data_store: MemoryDataStore| {
+        let mut app = SyncApp::new(data_store, &|_| {});
+        app.set_seed(1234);
+        app.init();
+        without_uuid(&app.command("npc").unwrap())
+    };
+
+    assert_eq!(
+        generate(MemoryDataStore::default()),
+        generate(data_store.clone()),
+    );
+    assert_eq!(
+        Some(&"42".to_string()),
+        data_store.key_values.borrow().get("seed")
+    );
+}
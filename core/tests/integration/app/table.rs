@@ -0,0 +1,169 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{Event, MemoryDataStore};
+
+#[test]
+fn table_can_be_created_and_rolled() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Created the table Weather. Add some entries with `table Weather add [entry]; [entry]`, where each entry looks like `1-3 rain`, `4 snow`, `2x fog` or just `sun`.",
+        app.command("table create Weather").unwrap(),
+    );
+
+    assert_eq!(
+        "# Weather\n\n| d6 | Weather |\n|---:|---|\n| 1-3 | rain |\n| 4 | snow |\n| 5-6 | fog |\n\n_Use `roll on Weather` to roll on this table._",
+        app.command("table weather add 1-3 rain; 4 snow; 2x fog").unwrap(),
+    );
+
+    for _ in 0..10 {
+        let output = app.command("roll on weather").unwrap();
+
+        assert!(
+            [
+                "**Weather** (1 on a d6): rain",
+                "**Weather** (2 on a d6): rain",
+                "**Weather** (3 on a d6): rain",
+                "**Weather** (4 on a d6): snow",
+                "**Weather** (5 on a d6): fog",
+                "**Weather** (6 on a d6): fog",
+            ]
+            .contains(&output.as_str()),
+            "{}",
+            output,
+        );
+    }
+
+    assert_eq!(
+        Err(
+            "There is no table named \"Loot\". Use `tables` to see the tables you've created."
+                .to_string()
+        ),
+        app.command("roll on Loot"),
+    );
+}
+
+#[test]
+fn tables_can_refer_to_other_tables() {
+    let mut app = sync_app();
+
+    app.command("table create Wind").unwrap();
+    app.command("table Wind add howling").unwrap();
+    app.command("table create Weather").unwrap();
+    app.command("table Weather add {Wind} wind and {1d1+1} inches of {Snow}")
+        .unwrap();
+
+    assert_eq!(
+        "**Weather** (1 on a d1): howling wind and 2 inches of {Snow}",
+        app.command("roll on Weather").unwrap(),
+    );
+}
+
+#[test]
+fn tables_can_be_listed_edited_and_deleted() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "You haven't created any tables yet. Try `table create Weather`.",
+        app.command("tables").unwrap(),
+    );
+
+    app.command("table create Weather").unwrap();
+    app.command("table Weather add rain; snow").unwrap();
+
+    assert_eq!(
+        "# Tables\n\n* `table Weather` (d2, 2 entries)",
+        app.command("tables").unwrap(),
+    );
+
+    assert_eq!(
+        "Removed \"snow\" from the table Weather.\n\n# Weather\n\n| d1 | Weather |\n|---:|---|\n| 1 | rain |",
+        app.command("table Weather remove snow").unwrap(),
+    );
+
+    assert_eq!(
+        "Deleted the table Weather.",
+        app.command("table delete Weather").unwrap(),
+    );
+
+    assert_eq!(
+        "You haven't created any tables yet. Try `table create Weather`.",
+        app.command("tables").unwrap(),
+    );
+}
+
+#[test]
+fn table_names_are_autocompleted() {
+    let mut app = sync_app();
+
+    app.command("table create Weather").unwrap();
+    app.command("table Weather add rain; snow").unwrap();
+
+    assert_eq!(
+        vec![("roll on Weather".to_string(), "d2 table".to_string())],
+        app.autocomplete("roll on w")
+            .into_iter()
+            .map(|suggestion| (suggestion.term.to_string(), suggestion.summary.to_string()))
+            .collect::<Vec<_>>(),
+    );
+
+    assert_eq!(
+        vec![("table Weather".to_string(), "d2 table".to_string())],
+        app.autocomplete("table WEA")
+            .into_iter()
+            .map(|suggestion| (suggestion.term.to_string(), suggestion.summary.to_string()))
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn tables_are_persisted() {
+    let data_store = MemoryDataStore::default();
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        app.init();
+        app.command("table create Weather").unwrap();
+        app.command("table Weather add rain").unwrap();
+    }
+
+    {
+        let mut app = sync_app_with_data_store(data_store);
+        app.init();
+
+        assert_eq!(
+            "**Weather** (1 on a d1): rain",
+            app.command("roll on Weather").unwrap(),
+        );
+    }
+}
+
+#[test]
+fn tables_are_exported_and_imported() {
+    let backup_data = {
+        let mut app = sync_app();
+        let mut receiver = app.subscribe();
+
+        app.command("table create Weather").unwrap();
+        app.command("table Weather add rain").unwrap();
+        app.command("export").unwrap();
+
+        std::iter::from_fn(|| receiver.try_next().ok().flatten())
+            .find_map(|event| match event {
+                Event::Export(data) => Some(data),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Key/values: 1 created",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    assert_eq!(
+        "# Tables\n\n* `table Weather` (d1, 1 entry)",
+        app.command("tables").unwrap(),
+    );
+}
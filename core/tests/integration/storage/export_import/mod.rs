@@ -138,6 +138,7 @@ fn bulk_import_v1() {
         "# Journal
 
 ## NPCs
+
 👨 `Faman Halin` (middle-aged human, he/him)\\
 👧 `Halynn Mardeka` (adolescent human, she/her)\\
 👴 `Losno Khayrysi` (elderly halfling, he/him)\\
@@ -145,13 +146,14 @@ fn bulk_import_v1() {
 👶 `Pino Nesgarth` (halfling infant, he/him)
 
 ## Places
+
 🏨 `Book and Soldier` (inn)\\
 🏨 `Five Millers` (inn)\\
 🏨 `Raven and Fisherman` (inn)\\
 🏨 `Ten Ghosts` (inn)\\
 🏨 `The Moody Conjurer` (inn)
 
-*To export the contents of your journal, use `export`.*",
+_To export the contents of your journal, use `export`._",
         app.command("journal").unwrap(),
     );
 
@@ -176,6 +178,7 @@ fn bulk_import_v2() {
         "# Journal
 
 ## NPCs
+
 👨 `Faman Halin` (middle-aged human, he/him)\\
 👧 `Halynn Mardeka` (adolescent human, she/her)\\
 👴 `Losno Khayrysi` (elderly halfling, he/him)\\
@@ -183,13 +186,14 @@ fn bulk_import_v2() {
 👶 `Pino Nesgarth` (halfling infant, he/him)
 
 ## Places
+
 🏨 `Book and Soldier` (inn)\\
 🏨 `Five Millers` (inn)\\
 🏨 `Raven and Fisherman` (inn)\\
 🏨 `Ten Ghosts` (inn)\\
 🏨 `The Moody Conjurer` (inn)
 
-*To export the contents of your journal, use `export`.*",
+_To export the contents of your journal, use `export`._",
         app.command("journal").unwrap(),
     );
 
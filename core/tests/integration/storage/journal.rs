@@ -6,7 +6,7 @@ fn it_shows_a_message_when_empty() {
         "\
 # Journal
 
-*Your journal is currently empty.*",
+_Your journal is currently empty._",
         sync_app().command("journal").unwrap(),
     );
 }
@@ -15,7 +15,7 @@ fn it_shows_a_message_when_empty() {
 fn it_shows_an_error_without_a_data_store() {
     let mut app = sync_app_with_invalid_data_store();
     assert_eq!(
-        "# Journal\n\n*Your journal is currently empty.*\n\n! Your browser does not support local storage. Any changes will not persist beyond this session.",
+        "# Journal\n\n_Your journal is currently empty._\n\n! Your browser does not support local storage. Any changes will not persist beyond this session.",
         app.command("journal").unwrap(),
     );
 }
@@ -77,6 +77,7 @@ fn it_shows_alphabetized_results() {
     assert_eq!(Some("# Journal"), output_iter.next());
     assert_eq!(Some(""), output_iter.next());
     assert_eq!(Some("## NPCs"), output_iter.next());
+    assert_eq!(Some(""), output_iter.next());
 
     npcs.into_iter()
         .zip(output_iter.by_ref())
@@ -91,6 +92,7 @@ fn it_shows_alphabetized_results() {
 
     assert_eq!(Some(""), output_iter.next());
     assert_eq!(Some("## Places"), output_iter.next());
+    assert_eq!(Some(""), output_iter.next());
 
     inns.into_iter()
         .zip(output_iter.by_ref())
@@ -104,7 +106,7 @@ fn it_shows_alphabetized_results() {
         });
 
     assert_eq!(
-        Some("*To export the contents of your journal, use `export`.*"),
+        Some("_To export the contents of your journal, use `export`._"),
         output_iter.by_ref().nth(1),
     );
 
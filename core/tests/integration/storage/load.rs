@@ -59,16 +59,50 @@ fn npc_can_be_loaded_from_storage_with_location() {
     assert_eq!(
         r#"<div class="thing-box npc" data-uuid="55a46566-1dc2-40a5-8138-ac7aa2a1ae9d">
 
-# Faman Halin
-*middle-aged human, he/him*
+# Faman Halin 🔒
 
-**Species:** human\
-**Gender:** masculine\
-**Age:** 49 years\
-**Size:** 5'9", 189 lbs (medium)\
+_middle-aged human, he/him_
+
+**Species:** human 🔒\
+**Gender:** masculine 🔒\
+**Age:** 49 years 🔒\
+**Size:** 5'9", 189 lbs (medium) 🔒\
 **Location:** 🏨 `The Moody Conjurer` (inn)
 
 </div>"#,
         app.command("Faman Halin").unwrap(),
     );
 }
+
+#[test]
+fn misspelled_name_is_suggested() {
+    let mut app = sync_app();
+
+    app.command("npc named Elendor").unwrap();
+
+    let output = app.command("load Elandor").unwrap_err();
+    assert!(
+        output.starts_with("No matches for \"Elandor\"")
+            && output.ends_with("Did you mean:\n\n* `load Elendor`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Elandor").unwrap();
+    assert!(
+        output.starts_with("_Showing results for `load Elendor`._\n\n"),
+        "{}",
+        output,
+    );
+    assert!(output.contains("# Elendor"), "{}", output);
+
+    let output = app.command("Elendor").unwrap();
+    assert!(!output.contains("Showing results for"), "{}", output);
+
+    assert_eq!(
+        Some("load Elendor"),
+        app.autocomplete("load Elan")
+            .first()
+            .map(|suggestion| suggestion.term.as_ref()),
+    );
+}
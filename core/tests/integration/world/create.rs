@@ -51,7 +51,7 @@ fn create_npc_with_custom_attributes() {
     {
         let output = app.command("Sue, a young enby dwarvish elf").unwrap();
         assert!(
-            output.contains("# Sue\n*young adult elf, they/them*"),
+            output.contains("# Sue 🔒\n\n_young adult elf, they/them_"),
             "{}",
             output,
         );
@@ -109,7 +109,11 @@ fn create_place_with_custom_attributes() {
 
     {
         let output = app.command("an inn called The Prancing Pony").unwrap();
-        assert!(output.contains("# The Prancing Pony\n*inn*"), "{}", output);
+        assert!(
+            output.contains("# The Prancing Pony 🔒\n\n_inn_ 🔒"),
+            "{}",
+            output
+        );
         assert!(
             output.contains("has been automatically added to your `journal`."),
             "{}",
@@ -139,7 +143,7 @@ fn create_with_unknown_words() {
 
     {
         let output = app
-            .command("a male dragon turtle young adult named Smaug")
+            .command("a male sea turtle young adult named Smaug")
             .unwrap();
 
         assert!(output.contains("# Smaug"), "{}", output);
@@ -148,8 +152,8 @@ fn create_with_unknown_words() {
             output.ends_with(
                 "! initiative.sh doesn't know some of those words, but it did its best.\n\
                 \n\
-                \\> a male **dragon** **turtle** young adult named Smaug\\\n\
-                \u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}^^^^^^\u{a0}^^^^^^\\\n\
+                \\> a male **sea** **turtle** young adult named Smaug\\\n\
+                \u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}\u{a0}^^^\u{a0}^^^^^^\\\n\
                 Want to help improve its vocabulary? Join us [on Discord](https://discord.gg/ZrqJPpxXVZ) and suggest your new words!"
             ),
             "{}",
@@ -159,13 +163,14 @@ fn create_with_unknown_words() {
 }
 
 #[test]
-fn generate_location_with_no_name_generator() {
+fn generate_region() {
     let mut app = sync_app();
 
-    assert_eq!(
-        "There is no name generator implemented for that type. You must specify your own name using `kingdom named [name]`.",
-        app.command("kingdom").unwrap_err(),
-    );
+    {
+        let output = app.command("kingdom").unwrap();
+        assert!(output.contains("\n# "), "{}", output);
+        assert!(output.contains("_kingdom_"), "{}", output);
+    }
 
     {
         let output = app.command("kingdom named Narnia").unwrap();
@@ -177,3 +182,35 @@ fn generate_location_with_no_name_generator() {
         assert!(output.contains("# Narnia"), "{}", output);
     }
 }
+
+#[test]
+fn create_creature() {
+    let mut app = sync_app();
+
+    {
+        let output = app.command("Smaug, a dragon").unwrap();
+        assert!(
+            output.contains("# Smaug 🔒\n\n_huge dragon, "),
+            "{}",
+            output
+        );
+        assert!(output.contains("**Creature:** dragon 🔒"), "{}", output);
+        assert!(output.contains(" (huge)"), "{}", output);
+        assert!(!output.contains("**Species:**"), "{}", output);
+    }
+
+    {
+        let output = app.command("owlbear").unwrap();
+        assert!(output.contains("_large owlbear, "), "{}", output);
+        assert!(output.contains(" (large)"), "{}", output);
+    }
+
+    {
+        let output = app.command("journal").unwrap();
+        assert!(
+            output.contains("\u{1f409} `Smaug` (huge dragon, "),
+            "{}",
+            output
+        );
+    }
+}
@@ -168,3 +168,121 @@ fn edit_with_unknown_words() {
         output,
     );
 }
+
+#[test]
+fn edit_business_details() {
+    let mut app = sync_app();
+    app.command("weaponsmith named The Rusty Blade").unwrap();
+
+    {
+        let output = app.command("The Rusty Blade").unwrap();
+        assert!(output.contains("**Quality:** "), "{}", output);
+        assert!(output.contains("**Prices:** "), "{}", output);
+        assert!(output.contains("**Wares:** "), "{}", output);
+        assert!(output.contains("**Hook:** "), "{}", output);
+    }
+
+    {
+        let output = app
+            .command("The Rusty Blade is a shabby cheap weaponsmith wares: dagger, bent nails hook: the owner is fencing stolen blades")
+            .unwrap();
+        assert!(output.contains("**Quality:** poor 🔒"), "{}", output);
+        assert!(output.contains("**Prices:** cheap 🔒"), "{}", output);
+        assert!(
+            output.contains("**Wares:** `Dagger`, bent nails 🔒"),
+            "{}",
+            output,
+        );
+        assert!(
+            output.contains("**Hook:** The owner is fencing stolen blades 🔒"),
+            "{}",
+            output,
+        );
+    }
+
+    {
+        let output = app.command("reroll The Rusty Blade's atmosphere").unwrap();
+        assert!(output.contains("**Quality:** poor 🔒"), "{}", output);
+        assert!(output.contains("**Hook:** The owner"), "{}", output);
+    }
+}
+
+#[test]
+fn edit_npc_personality() {
+    let mut app = sync_app();
+    app.command("dwarf named Borin").unwrap();
+
+    {
+        let output = app.command("Borin").unwrap();
+        assert!(output.contains("**Personality:** "), "{}", output);
+        assert!(output.contains("**Ideal:** "), "{}", output);
+        assert!(output.contains("**Bond:** "), "{}", output);
+        assert!(output.contains("**Flaw:** "), "{}", output);
+        assert!(output.contains("**Feature:** "), "{}", output);
+        assert!(output.contains("**Mannerism:** "), "{}", output);
+    }
+
+    {
+        let output = app
+            .command("Borin is grumpy flaw: hates elves mannerism: grunts instead of answering")
+            .unwrap();
+        assert!(output.contains("**Personality:** grumpy 🔒"), "{}", output);
+        assert!(output.contains("**Flaw:** Hates elves 🔒"), "{}", output);
+        assert!(
+            output.contains("**Mannerism:** Grunts instead of answering 🔒"),
+            "{}",
+            output,
+        );
+    }
+
+    {
+        let output = app.command("reroll Borin's bond").unwrap();
+        assert_eq!(1, output.matches("**Bond:** ").count() - 1, "{}", output);
+        assert!(output.contains("**Personality:** grumpy 🔒"), "{}", output);
+        assert!(output.contains("**Flaw:** Hates elves 🔒"), "{}", output);
+    }
+}
+
+#[test]
+fn edit_npc_stats() {
+    let mut app = sync_app();
+    app.command("dwarf named Borin").unwrap();
+
+    {
+        let output = app.command("Borin").unwrap();
+        assert!(!output.contains("**Stat block:** "), "{}", output);
+    }
+
+    {
+        let output = app.command("Borin is a veteran").unwrap();
+        assert!(output.contains("**Stat block:** Veteran 🔒"), "{}", output);
+        assert!(
+            output.contains("**Armor Class:** 17 (splint)"),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("**Hit Points:** 58 (`9d8 + 18`) 🔒"),
+            "{}",
+            output,
+        );
+        assert!(output.contains("_Longsword._ +5 to hit"), "{}", output);
+        assert!(output.contains("`Darkvision`"), "{}", output);
+    }
+
+    {
+        let output = app.command("reroll Borin's hit points").unwrap();
+        assert!(output.contains("**Hit points:** 58 → "), "{}", output);
+        assert!(output.contains("**Stat block:** Veteran 🔒"), "{}", output);
+    }
+
+    {
+        let output = app.command("Borin is a guard").unwrap();
+        assert!(output.contains("**Stat block:** Guard 🔒"), "{}", output);
+        assert!(
+            output.contains("**Hit Points:** 11 (`2d8 + 2`) 🔒"),
+            "{}",
+            output,
+        );
+    }
+}
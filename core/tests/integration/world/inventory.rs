@@ -0,0 +1,117 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn inventory_is_persisted() {
+    let mut app = sync_app();
+
+    app.command("weaponsmith named The Rusty Anvil").unwrap();
+
+    let output = app.command("inventory of The Rusty Anvil").unwrap();
+    assert!(
+        output.starts_with("# Inventory of The Rusty Anvil\n\n| Item | Qty | Price |"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(
+            "_Use `restock The Rusty Anvil` to replace this stock. Use `undo` to reverse this._",
+        ),
+        "{}",
+        output,
+    );
+
+    let inventory = output.split("\n\n_").next().unwrap().to_string();
+    assert_eq!(
+        inventory,
+        app.command("inventory of the rusty anvil").unwrap(),
+    );
+
+    let output = app.command("restock The Rusty Anvil").unwrap();
+    assert!(
+        output.starts_with("# Inventory of The Rusty Anvil"),
+        "{}",
+        output
+    );
+    assert!(
+        output.ends_with(
+            "_The Rusty Anvil was successfully restocked. Use `undo` to reverse this._",
+        ),
+        "{}",
+        output,
+    );
+
+    app.command("undo").unwrap();
+    assert_eq!(
+        inventory,
+        app.command("inventory of The Rusty Anvil").unwrap(),
+    );
+}
+
+#[test]
+fn inventory_saves_generated_shop() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("general-store").unwrap());
+
+    let output = app.command(&format!("inventory of {}", name)).unwrap();
+    assert!(
+        output.ends_with(&format!(
+            "{} was automatically saved to your `journal` so that it keeps its stock. Use `undo` to reverse this._",
+            name,
+        )),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn inventory_errors() {
+    let mut app = sync_app();
+
+    app.command("inn named The Prancing Pony").unwrap();
+    app.command("elf named Ana").unwrap();
+
+    assert_eq!(
+        Err("The Prancing Pony doesn't keep an inventory. Only armorers, blacksmiths, general stores, magic shops, and weaponsmiths have stock to browse.".to_string()),
+        app.command("inventory of The Prancing Pony"),
+    );
+    assert_eq!(
+        Err("Ana is not a shop.".to_string()),
+        app.command("restock Ana"),
+    );
+    assert_eq!(
+        Err(r#"There is no place named "Nowhere"."#.to_string()),
+        app.command("inventory of Nowhere"),
+    );
+}
+
+#[test]
+fn inventory_replaces_wares() {
+    let mut app = sync_app();
+
+    app.command("weaponsmith named The Rusty Anvil").unwrap();
+    let inventory = app.command("inventory of The Rusty Anvil").unwrap();
+
+    let output = app.command("The Rusty Anvil").unwrap();
+    let wares = output
+        .lines()
+        .find_map(|line| line.strip_prefix("**Wares:** "))
+        .unwrap();
+
+    for ware in wares.split(", ") {
+        assert!(
+            inventory.contains(&format!("| {} |", ware)),
+            "{}\n\n{}",
+            ware,
+            inventory,
+        );
+    }
+
+    app.command("lock The Rusty Anvil's wares").unwrap();
+    app.command("restock The Rusty Anvil").unwrap();
+    assert!(
+        app.command("The Rusty Anvil").unwrap().contains(wares),
+        "{}",
+        wares,
+    );
+}
@@ -0,0 +1,125 @@
+use crate::common::sync_app;
+
+#[test]
+fn lock_npc_field() {
+    let mut app = sync_app();
+
+    app.command("elf named Ana").unwrap();
+
+    {
+        let output = app.command("lock Ana's age").unwrap();
+        assert!(output.contains("# Ana 🔒"), "{}", output);
+        assert!(output.contains("**Species:** elf 🔒"), "{}", output);
+        assert!(output.contains(" years 🔒\\\n"), "{}", output);
+        assert!(
+            output.ends_with("_Ana's age is now locked. Use `undo` to reverse this._"),
+            "{}",
+            output,
+        );
+    }
+
+    assert_eq!(
+        Err("Ana's age is already locked.".to_string()),
+        app.command("lock Ana's age"),
+    );
+
+    {
+        let output = app.command("reroll Ana").unwrap();
+        assert!(
+            !output
+                .lines()
+                .any(|line| line.starts_with("**Age:**") && line.contains('→')),
+            "{}",
+            output,
+        );
+    }
+
+    {
+        let output = app.command("unlock Ana's name").unwrap();
+        assert!(output.contains("# Ana\n"), "{}", output);
+        assert!(
+            output.ends_with("_Ana's name is now unlocked. Use `undo` to reverse this._"),
+            "{}",
+            output,
+        );
+    }
+
+    {
+        let output = app.command("undo").unwrap();
+        assert!(output.contains("# Ana 🔒"), "{}", output);
+    }
+}
+
+#[test]
+fn lock_undo_redo() {
+    let mut app = sync_app();
+
+    app.command("elf named Ana").unwrap();
+
+    {
+        let output = app.command("lock Ana's age").unwrap();
+        assert!(output.contains(" years 🔒\\\n"), "{}", output);
+    }
+
+    {
+        let output = app.command("undo").unwrap();
+        assert!(!output.contains(" years 🔒"), "{}", output);
+        assert!(output.contains("# Ana 🔒\n"), "{}", output);
+    }
+
+    {
+        let output = app.command("redo").unwrap();
+        assert!(output.contains(" years 🔒\\\n"), "{}", output);
+    }
+
+    {
+        let output = app.command("unlock Ana").unwrap();
+        assert!(!output.contains('🔒'), "{}", output);
+    }
+
+    {
+        let output = app.command("undo").unwrap();
+        assert!(output.contains("# Ana 🔒\n"), "{}", output);
+        assert!(output.contains(" years 🔒\\\n"), "{}", output);
+    }
+}
+
+#[test]
+fn lock_all_fields() {
+    let mut app = sync_app();
+
+    app.command("inn named The Prancing Pony").unwrap();
+
+    {
+        let output = app.command("unlock The Prancing Pony").unwrap();
+        assert!(
+            output.contains("# The Prancing Pony\n\n_inn_\n"),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("_All of The Prancing Pony's fields are now unlocked."),
+            "{}",
+            output,
+        );
+    }
+
+    assert_eq!(
+        Err("All of The Prancing Pony's fields are already unlocked.".to_string()),
+        app.command("unlock the prancing pony"),
+    );
+
+    {
+        let output = app.command("lock The Prancing Pony").unwrap();
+        assert!(
+            output.contains("# The Prancing Pony 🔒\n\n_inn_ 🔒\n"),
+            "{}",
+            output,
+        );
+    }
+
+    assert_eq!(
+        Err("There is no entity named \"Nobody's name\".".to_string()),
+        app.command("lock Nobody's name"),
+    );
+}
@@ -1,6 +1,10 @@
 mod create;
 mod create_multiple;
 mod edit;
+mod inventory;
+mod lock;
+mod reroll;
+mod vocabulary;
 
 use crate::common::{get_name, sync_app};
 
@@ -90,6 +94,13 @@ fn generated_content_is_persisted() {
     // **Age:** 64 years\
     // **Size:** 5'7", 112 lbs (medium)
     //
+    // **Personality:** curious\
+    // **Ideal:** Knowledge. The path to power and self-improvement is through learning.\
+    // **Bond:** Is fiercely loyal to an old mentor.\
+    // **Flaw:** Drinks far more than is wise.\
+    // **Feature:** Has ink-stained fingers.\
+    // **Mannerism:** Hums tunelessly while thinking.
+    //
     // _Sybil has not yet been saved. Use ~save~ to save her to your `journal`. For more
     // suggestions, type ~more~._
     let generated_output = app.command("npc").unwrap();
@@ -102,7 +113,7 @@ fn generated_content_is_persisted() {
         persisted_output.lines().nth(2).unwrap(),
     );
     assert_eq!(
-        12,
+        19,
         generated_output
             .lines()
             .zip(persisted_output.lines())
@@ -0,0 +1,129 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn reroll_npc_field() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("npc").unwrap());
+
+    {
+        let output = app.command(&format!("reroll {}'s name", name)).unwrap();
+        let new_name = get_name(&output);
+        assert_ne!(name, new_name);
+        assert!(
+            output.contains(&format!("**Name:** {} → {}", name, new_name)),
+            "{}",
+            output,
+        );
+        assert!(
+            output.ends_with(&format!(
+                "_{} was successfully rerolled and automatically saved to your `journal`. Use `undo` to reverse this._",
+                name,
+            )),
+            "{}",
+            output,
+        );
+
+        assert!(app.command(&new_name).is_ok());
+    }
+
+    {
+        let output = app.command("undo").unwrap();
+        assert_eq!(format!("# {}", name), output.lines().nth(2).unwrap());
+    }
+}
+
+#[test]
+fn reroll_npc_unlocked_fields() {
+    let mut app = sync_app();
+
+    app.command("elf named Ana").unwrap();
+
+    let output = app.command("reroll Ana").unwrap();
+    assert!(output.contains("# Ana"), "{}", output);
+    assert!(output.contains("**Age:**"), "{}", output);
+    assert!(!output.contains("**Name:**"), "{}", output);
+    assert!(!output.contains("**Species:** elf →"), "{}", output);
+    assert!(
+        output.ends_with("_Ana was successfully rerolled. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    // Rerolled fields remain unlocked, so they can be rerolled again.
+    assert!(app.command("reroll Ana").is_ok());
+}
+
+#[test]
+fn reroll_place() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("inn").unwrap());
+
+    let output = app.command(&format!("reroll {}", name)).unwrap();
+    assert_ne!(name, get_name(&output));
+    assert!(output.contains("**Name:**"), "{}", output);
+    assert!(!output.contains("**Type:**"), "{}", output);
+}
+
+#[test]
+fn reroll_misspelled_name() {
+    let mut app = sync_app();
+
+    app.command("elf named Elendor").unwrap();
+
+    let output = app.command("reroll Elandor").unwrap();
+    assert!(
+        output.starts_with("_Showing results for `reroll Elendor`._"),
+        "{}",
+        output,
+    );
+    assert!(output.contains("rerolled"), "{}", output);
+}
+
+#[test]
+fn reroll_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        Err(r#"There is no entity named "Nobody"."#.to_string()),
+        app.command("reroll Nobody"),
+    );
+
+    app.command("human named Ana").unwrap();
+    app.command("Ana is a woman").unwrap();
+    app.command("Ana is a 30-year-old").unwrap();
+
+    let output = app.command("reroll Ana's name").unwrap();
+    assert!(output.contains("**Name:** Ana →"), "{}", output);
+}
+
+#[test]
+fn reroll_undo_redo_lock_state() {
+    let mut app = sync_app();
+
+    app.command("elf named Ana").unwrap();
+
+    let new_name = {
+        let output = app.command("reroll Ana's name").unwrap();
+        let new_name = get_name(&output);
+        assert!(output.contains(&format!("# {}\n", new_name)), "{}", output);
+        new_name
+    };
+
+    {
+        let output = app.command("undo").unwrap();
+        assert!(output.contains("# Ana 🔒\n"), "{}", output);
+        assert!(output.contains("**Species:** elf 🔒"), "{}", output);
+    }
+
+    {
+        let output = app.command("redo").unwrap();
+        assert!(output.contains(&format!("# {}\n", new_name)), "{}", output);
+        assert!(output.contains("**Species:** elf 🔒"), "{}", output);
+    }
+
+    // The rerolled name is still unlocked, so it is rerolled along with everything else.
+    let output = app.command(&format!("reroll {}", new_name)).unwrap();
+    assert!(output.contains("**Name:**"), "{}", output);
+}
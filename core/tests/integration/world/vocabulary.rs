@@ -0,0 +1,116 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{Event, MemoryDataStore};
+
+#[test]
+fn custom_ethnicity() {
+    let data_store = MemoryDataStore::default();
+    let mut app = sync_app_with_data_store(data_store.clone());
+
+    assert_eq!(
+        "Added Daenerys, Rhaenyra to the valyrian feminine names list. Try it out: `valyrian person`",
+        app.command("add valyrian feminine names: daenerys, Rhaenyra")
+            .unwrap(),
+    );
+    assert_eq!(
+        "Added Aegon to the valyrian masculine names list. Try it out: `valyrian person`",
+        app.command("add valyrian masculine names: Aegon, aegon")
+            .unwrap(),
+    );
+    assert_eq!(
+        "Added Targaryen to the valyrian surnames list. Try it out: `valyrian person`",
+        app.command("add Valyrian surnames: Targaryen").unwrap(),
+    );
+    assert_eq!(
+        Err("Those are all already in the valyrian surnames list.".to_string()),
+        app.command("add valyrian surnames: targaryen"),
+    );
+
+    for _ in 0..2 {
+        let output = app.command("valyrian woman").unwrap();
+        assert!(output.contains(" Targaryen"), "{}", output);
+        assert!(
+            output.contains("# Daenerys") || output.contains("# Rhaenyra"),
+            "{}",
+            output,
+        );
+        assert!(
+            output.contains("**Species:** human (valyrian)"),
+            "{}",
+            output
+        );
+    }
+
+    let output = app.command("valyrian man").unwrap();
+    assert!(output.contains("# Aegon Targaryen"), "{}", output);
+
+    // The vocabulary persists across sessions.
+    let mut app = sync_app_with_data_store(data_store);
+    let output = app.command("valyrian man").unwrap();
+    assert!(output.contains("# Aegon Targaryen"), "{}", output);
+}
+
+#[test]
+fn custom_ethnicity_cannot_shadow_existing_words() {
+    let mut app = sync_app();
+
+    for word in ["elvish", "elf", "inn", "child"] {
+        assert_eq!(
+            Err(format!(
+                r#""{}" already means something to initiative.sh, so it can't be used as the name of a custom ethnicity."#,
+                word,
+            )),
+            app.command(&format!("add {} names: Bob", word)),
+        );
+    }
+}
+
+#[test]
+fn custom_inn_words() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Added Griffin to the inn words list. Try it out: `inn`",
+        app.command("add inn words: griffin").unwrap(),
+    );
+
+    assert!(
+        (0..50).any(|_| app.command("inn").unwrap().contains("Griffin")),
+        "Custom inn word was never used.",
+    );
+}
+
+#[test]
+fn imported_vocabulary_is_merged_with_existing_vocabulary() {
+    let backup_data = {
+        let mut app = sync_app();
+        let mut receiver = app.subscribe();
+
+        app.command("add valyrian surnames: Targaryen").unwrap();
+        app.command("add inn words: Griffin").unwrap();
+        app.command("export").unwrap();
+
+        std::iter::from_fn(|| receiver.try_next().ok().flatten())
+            .find_map(|event| match event {
+                Event::Export(data) => Some(data),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    let mut app = sync_app();
+    app.command("add valyrian surnames: Velaryon").unwrap();
+
+    assert_eq!(
+        "Key/values: 1 created, 1 updated",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    assert_eq!(
+        Err("Those are all already in the valyrian surnames list.".to_string()),
+        app.command("add valyrian surnames: Targaryen, Velaryon"),
+    );
+    assert_eq!(
+        Err("Those are all already in the inn words list.".to_string()),
+        app.command("add inn words: griffin"),
+    );
+}
@@ -64,25 +64,35 @@ fn impl_command_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
                 }
             }
 
-            fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
+            fn help(&self) -> CommandHelp {
                 match self {
-                    #( #match_items => c.get_priority(token_match), )*
+                    #( #match_items => c.help(), )*
                 }
             }
 
-            fn get_canonical_form_of(&self, token_match: &TokenMatch) -> Option<String> {
+            fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
                 match self {
-                    #( #match_items => c.get_canonical_form_of(token_match), )*
+                    #( #match_items => c.get_priority(token_match), )*
                 }
             }
 
-            async fn run<'a>(
+            async fn parse<'a>(
                 &self,
                 token_match: TokenMatch<'a>,
-                app_meta: &mut AppMeta,
-            ) -> Result<String, String> {
+                app_meta: &AppMeta,
+            ) -> Option<CommandType> {
+                match self {
+                    #( #match_items => c.parse(token_match, app_meta).await, )*
+                }
+            }
+
+            fn parse_autocomplete<'a>(
+                &'a self,
+                input: &'a str,
+                app_meta: &'a AppMeta,
+            ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
                 match self {
-                    #( #match_items => c.run(token_match, app_meta).await, )*
+                    #( #match_items => c.parse_autocomplete(input, app_meta), )*
                 }
             }
         }
@@ -124,6 +134,14 @@ mod test {
                         }
                     }
 
+                    fn help(&self) -> CommandHelp {
+                        match self {
+                            CommandList::About(c) => c.help(),
+                            CommandList::Create(c) => c.help(),
+                            CommandList::Save(c) => c.help(),
+                        }
+                    }
+
                     fn get_priority(&self, token_match: &TokenMatch) -> Option<CommandPriority> {
                         match self {
                             CommandList::About(c) => c.get_priority(token_match),
@@ -132,23 +150,27 @@ mod test {
                         }
                     }
 
-                    fn get_canonical_form_of(&self, token_match: &TokenMatch) -> Option<String> {
+                    async fn parse<'a>(
+                        &self,
+                        token_match: TokenMatch<'a>,
+                        app_meta: &AppMeta,
+                    ) -> Option<CommandType> {
                         match self {
-                            CommandList::About(c) => c.get_canonical_form_of(token_match),
-                            CommandList::Create(c) => c.get_canonical_form_of(token_match),
-                            CommandList::Save(c) => c.get_canonical_form_of(token_match),
+                            CommandList::About(c) => c.parse(token_match, app_meta).await,
+                            CommandList::Create(c) => c.parse(token_match, app_meta).await,
+                            CommandList::Save(c) => c.parse(token_match, app_meta).await,
                         }
                     }
 
-                    async fn run<'a>(
-                        &self,
-                        token_match: TokenMatch<'a>,
-                        app_meta: &mut AppMeta,
-                    ) -> Result<String, String> {
+                    fn parse_autocomplete<'a>(
+                        &'a self,
+                        input: &'a str,
+                        app_meta: &'a AppMeta,
+                    ) -> Pin<Box<dyn Stream<Item = AutocompleteSuggestion> + 'a>> {
                         match self {
-                            CommandList::About(c) => c.run(token_match, app_meta).await,
-                            CommandList::Create(c) => c.run(token_match, app_meta).await,
-                            CommandList::Save(c) => c.run(token_match, app_meta).await,
+                            CommandList::About(c) => c.parse_autocomplete(input, app_meta),
+                            CommandList::Create(c) => c.parse_autocomplete(input, app_meta),
+                            CommandList::Save(c) => c.parse_autocomplete(input, app_meta),
                         }
                     }
                 }
@@ -7,6 +7,11 @@ struct Entry {
     name: String,
     aliases: Vec<String>,
     details: String,
+    cost_cp: u32,
+    rarity: Option<String>,
+    items: Vec<syn::Ident>,
+    magic_items: Vec<syn::Ident>,
+    species: Vec<String>,
 }
 #[derive(Default)]
 struct EntryBuilder {
@@ -14,6 +19,11 @@ struct EntryBuilder {
     name: Option<String>,
     aliases: Vec<String>,
     details: Option<String>,
+    cost_cp: u32,
+    rarity: Option<String>,
+    items: Vec<syn::Ident>,
+    magic_items: Vec<syn::Ident>,
+    species: Vec<String>,
 }
 
 impl EntryBuilder {
@@ -37,16 +47,56 @@ impl EntryBuilder {
         self
     }
 
+    fn with_cost_cp(mut self, cost_cp: u32) -> Self {
+        self.cost_cp = cost_cp;
+        self
+    }
+
+    fn with_rarity(mut self, rarity: Option<String>) -> Self {
+        self.rarity = rarity;
+        self
+    }
+
+    /// Only tokens present in `known_tokens` are included, since categories sometimes refer to
+    /// things that don't exist in the data set.
+    fn with_items(mut self, tokens: &[String], known_tokens: &[String]) -> Self {
+        self.items = filter_idents(tokens, known_tokens);
+        self
+    }
+
+    fn with_magic_items(mut self, tokens: &[String], known_tokens: &[String]) -> Self {
+        self.magic_items = filter_idents(tokens, known_tokens);
+        self
+    }
+
+    fn with_species(mut self, species: Vec<String>) -> Self {
+        self.species = species;
+        self
+    }
+
     fn into_entry(self) -> Result<Entry, ()> {
         Ok(Entry {
             ident: self.ident.ok_or(())?,
             name: self.name.ok_or(())?,
             aliases: self.aliases,
             details: self.details.ok_or(())?,
+            cost_cp: self.cost_cp,
+            rarity: self.rarity,
+            items: self.items,
+            magic_items: self.magic_items,
+            species: self.species,
         })
     }
 }
 
+fn filter_idents(tokens: &[String], known_tokens: &[String]) -> Vec<syn::Ident> {
+    tokens
+        .iter()
+        .filter(|token| known_tokens.contains(token))
+        .filter_map(|token| syn::parse_str(token).ok())
+        .collect()
+}
+
 pub fn run(input: TokenStream) -> Result<TokenStream, String> {
     let ident = parse_args(input)?;
 
@@ -70,6 +120,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_name(item.name())
                     .with_aliases(item.alt_name().into_iter().collect())
                     .with_details(&item.display_details())
+                    .with_cost_cp(item.cost_cp())
                     .into_entry()
                     .unwrap()
             })
@@ -77,6 +128,9 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
         "ItemCategory" => {
             let items = srd_5e::items()?;
             let magic_items = srd_5e::magic_items()?;
+            let item_tokens: Vec<String> = items.iter().map(|item| item.token()).collect();
+            let magic_item_tokens: Vec<String> =
+                magic_items.iter().map(|item| item.token()).collect();
             let mut result = Vec::new();
 
             for category in srd_5e::item_categories()? {
@@ -90,6 +144,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                             .with_name(category.name().to_lowercase())
                             .with_aliases(category.alt_names())
                             .with_details(&category.display_item_table(&items))
+                            .with_items(&category.equipment_tokens(), &item_tokens)
                             .into_entry()
                             .unwrap(),
                     );
@@ -111,6 +166,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                                     &magic_items,
                                     &format!("Magic {}", category_name),
                                 ))
+                                .with_magic_items(&category.magic_item_tokens(), &magic_item_tokens)
                                 .into_entry()
                                 .unwrap(),
                         );
@@ -123,6 +179,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                                 .with_details(
                                     &category.display_magic_item_list(&magic_items, &category_name),
                                 )
+                                .with_magic_items(&category.magic_item_tokens(), &magic_item_tokens)
                                 .into_entry()
                                 .unwrap(),
                         );
@@ -139,6 +196,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_ident(&item.token())
                     .with_name(item.name.to_owned())
                     .with_details(&item.display_details())
+                    .with_rarity(item.rarity())
                     .into_entry()
                     .unwrap()
             })
@@ -162,6 +220,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_ident(&t.token())
                     .with_name(t.name.to_owned())
                     .with_details(&t.display_details())
+                    .with_species(t.species())
                     .into_entry()
                     .unwrap()
             })
@@ -217,6 +276,88 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
         quote! {}
     };
 
+    let get_extra = match format!("{}", ident).as_str() {
+        "Item" => {
+            let cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let cost_cp = entry.cost_cp;
+                quote! { #ident::#variant => #cost_cp }
+            });
+
+            quote! {
+                /// The cost of the item in copper pieces.
+                pub fn get_cost_cp(&self) -> u32 {
+                    match self {
+                        #(#cases),*
+                    }
+                }
+            }
+        }
+        "ItemCategory" => {
+            let item_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let items = &entry.items;
+                quote! { #ident::#variant => &[#(Item::#items),*] }
+            });
+
+            let magic_item_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let magic_items = &entry.magic_items;
+                quote! { #ident::#variant => &[#(MagicItem::#magic_items),*] }
+            });
+
+            quote! {
+                pub fn get_items(&self) -> &'static [Item] {
+                    match self {
+                        #(#item_cases),*
+                    }
+                }
+
+                pub fn get_magic_items(&self) -> &'static [MagicItem] {
+                    match self {
+                        #(#magic_item_cases),*
+                    }
+                }
+            }
+        }
+        "MagicItem" => {
+            let cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                match &entry.rarity {
+                    Some(rarity) => quote! { #ident::#variant => Some(#rarity) },
+                    None => quote! { #ident::#variant => None },
+                }
+            });
+
+            quote! {
+                /// The rarity of the item in lower case, eg. "very rare", or "varies" for items
+                /// whose rarity depends on the variant.
+                pub fn get_rarity(&self) -> Option<&'static str> {
+                    match self {
+                        #(#cases),*
+                    }
+                }
+            }
+        }
+        "Trait" => {
+            let cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let species = &entry.species;
+                quote! { #ident::#variant => &[#(#species),*] }
+            });
+
+            quote! {
+                /// The species that share this trait, eg. "dwarf".
+                pub fn get_species(&self) -> &'static [&'static str] {
+                    match self {
+                        #(#cases),*
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
     let words = entries.iter().flat_map(|entry| {
         let name = &entry.name;
         std::iter::once(quote! { #name, })
@@ -236,6 +377,8 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
 
             #get_list
 
+            #get_extra
+
             pub fn get_name(&self) -> &'static str {
                 match self {
                     #(#get_name_cases),*
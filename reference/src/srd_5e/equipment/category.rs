@@ -56,6 +56,23 @@ impl ItemCategory {
         self.items.iter().map(|item| item.token()).collect()
     }
 
+    /// Tokens of the mundane items in this category, as opposed to the magic items.
+    pub fn equipment_tokens(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|item| item.url.contains("/equipment/"))
+            .map(|item| item.token())
+            .collect()
+    }
+
+    pub fn magic_item_tokens(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|item| item.url.contains("/magic-items/"))
+            .map(|item| item.token())
+            .collect()
+    }
+
     pub fn has_items(&self) -> bool {
         self.items
             .iter()
@@ -94,6 +94,20 @@ impl Item {
         name
     }
 
+    /// The cost of the item in copper pieces, or 0 if the unit isn't recognized.
+    pub fn cost_cp(&self) -> u32 {
+        let multiplier = match self.cost.unit.as_str() {
+            "cp" => 1.,
+            "sp" => 10.,
+            "ep" => 50.,
+            "gp" => 100.,
+            "pp" => 1000.,
+            _ => 0.,
+        };
+
+        (self.cost.quantity * multiplier).round() as u32
+    }
+
     pub fn alt_name(&self) -> Option<String> {
         if self.name.contains(", ") {
             Some(self.name.to_string())
@@ -30,6 +30,23 @@ impl MagicItem {
     pub fn has_variants(&self) -> bool {
         !self.variants.is_empty()
     }
+
+    /// The rarity of the item, taken from the first line of its description, eg. "Wondrous item,
+    /// uncommon" or "Ring, rare (requires attunement)". Items of varying rarity return "varies".
+    pub fn rarity(&self) -> Option<String> {
+        let line = self.desc.first()?;
+        let line = line
+            .split_once(" (requires")
+            .map_or(line.as_str(), |(line, _)| line);
+        let (_, rarity) = line.rsplit_once(", ")?;
+        let rarity = rarity.to_lowercase();
+
+        if rarity.contains("varies") {
+            Some("varies".to_string())
+        } else {
+            Some(rarity)
+        }
+    }
 }
 
 pub struct SummaryView<'a>(&'a MagicItem);
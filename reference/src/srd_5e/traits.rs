@@ -36,6 +36,15 @@ impl Trait {
     pub fn has_parent(&self) -> bool {
         self.parent.is_some()
     }
+
+    /// The indexes of the species that share this trait, eg. "dwarf". Traits that belong only to
+    /// a subspecies return an empty list.
+    pub fn species(&self) -> Vec<String> {
+        self.races
+            .iter()
+            .map(|race| race.index.to_owned())
+            .collect()
+    }
 }
 
 impl fmt::Display for SummaryView<'_> {
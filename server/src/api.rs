@@ -0,0 +1,496 @@
+//! The JSON API itself. Every endpoint except `POST /sessions` requires an
+//! `Authorization: Bearer [token]` header identifying the session, each of which has its own
+//! independent [`App`].
+
+use crate::http::{Request, Response};
+use initiative_core::{self as core, App, BackupData, Event, MemoryDataStore, Uuid};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    /// Events dispatched by the app during the current request. Requests are handled one at a
+    /// time on a single thread, so anything in here belongs to the request being handled.
+    static EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sessions live in memory for as long as the server runs, so once there are this many, creating
+/// a new one closes the session that has gone the longest without being used.
+const MAX_SESSIONS: usize = 64;
+
+#[derive(Debug, Default)]
+pub struct Api {
+    sessions: HashMap<String, Session>,
+
+    /// Incremented with each use of a session, to tell which was used least recently.
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct Session {
+    app: App,
+    last_used: u64,
+}
+
+#[derive(Deserialize)]
+struct CommandRequest {
+    input: String,
+
+    #[serde(default)]
+    format: Format,
+}
+
+#[derive(Deserialize)]
+struct AutocompleteRequest {
+    input: String,
+}
+
+/// The format of a command's output. Markdown is the same as displayed by the web version, while
+/// `json` is a structured representation of the output.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Format {
+    #[default]
+    Markdown,
+    Ansi,
+    Html,
+    Json,
+}
+
+impl Api {
+    pub async fn handle(&mut self, request: &Request) -> Response {
+        let response = match (request.method.as_str(), request.path.as_str()) {
+            ("POST", "/sessions") => self.create_session().await,
+            ("DELETE", "/sessions") => self.delete_session(request),
+            ("POST", "/command") => self.command(request).await,
+            ("POST", "/autocomplete") => self.autocomplete(request).await,
+            ("POST", "/export") => self.export(request).await,
+            ("POST", "/import") => self.import(request).await,
+            (_, "/sessions" | "/command" | "/autocomplete" | "/export" | "/import") => {
+                Err(Response::error(405, "Method not allowed."))
+            }
+            _ => Err(Response::error(404, "Not found.")),
+        };
+
+        // Don't let events from a failed request leak into the next one.
+        EVENTS.with(|events| events.borrow_mut().clear());
+
+        response.unwrap_or_else(|e| e)
+    }
+
+    async fn create_session(&mut self) -> Result<Response, Response> {
+        let token = Uuid::new_v4().to_string();
+        let mut app = core::app(MemoryDataStore::default(), dispatch_event);
+        let motd = app.init().await;
+
+        if self.sessions.len() >= MAX_SESSIONS {
+            if let Some(oldest) = self
+                .sessions
+                .iter()
+                .min_by_key(|(_, session)| session.last_used)
+                .map(|(token, _)| token.clone())
+            {
+                self.sessions.remove(&oldest);
+            }
+        }
+
+        self.clock += 1;
+        self.sessions.insert(
+            token.clone(),
+            Session {
+                app,
+                last_used: self.clock,
+            },
+        );
+
+        Ok(Response {
+            status: 201,
+            body: Some(json!({ "token": token, "motd": motd })),
+        })
+    }
+
+    fn delete_session(&mut self, request: &Request) -> Result<Response, Response> {
+        request
+            .bearer_token()
+            .and_then(|token| self.sessions.remove(token))
+            .map(|_| Response::no_content())
+            .ok_or_else(unauthorized)
+    }
+
+    async fn command(&mut self, request: &Request) -> Result<Response, Response> {
+        let CommandRequest { input, format } = parse_body(request)?;
+        let app = self.app(request)?;
+
+        let (ok, output) = if format == Format::Markdown {
+            match app.command(&input).await {
+                Ok(output) => (true, json!(output)),
+                Err(output) => (false, json!(output)),
+            }
+        } else {
+            let (ok, output) = match app.command_output(&input).await {
+                Ok(output) => (true, output),
+                Err(output) => (false, output),
+            };
+
+            let output = match format {
+                Format::Ansi => json!(output.to_ansi()),
+                Format::Html => json!(output.to_html()),
+                Format::Json | Format::Markdown => json!(output),
+            };
+
+            (ok, output)
+        };
+
+        Ok(Response::ok(json!({
+            "ok": ok,
+            "output": output,
+            "events": take_events(),
+        })))
+    }
+
+    async fn autocomplete(&mut self, request: &Request) -> Result<Response, Response> {
+        let AutocompleteRequest { input } = parse_body(request)?;
+        let suggestions = self.app(request)?.autocomplete(&input).await;
+
+        Ok(Response::ok(json!({ "suggestions": suggestions })))
+    }
+
+    /// Export the session's journal. This is the same as running the `export` command, except
+    /// that the backup is returned directly rather than as an event.
+    async fn export(&mut self, request: &Request) -> Result<Response, Response> {
+        let app = self.app(request)?;
+
+        if let Err(e) = app.command("export").await {
+            return Err(Response::error(500, &e));
+        }
+
+        let data = EVENTS.with(|events| {
+            events.borrow_mut().drain(..).find_map(|event| match event {
+                Event::Export(data) => Some(data),
+                _ => None,
+            })
+        });
+
+        data.map(|data| Response::ok(json!({ "data": data })))
+            .ok_or_else(|| Response::error(500, "The journal could not be exported."))
+    }
+
+    async fn import(&mut self, request: &Request) -> Result<Response, Response> {
+        let data: BackupData = parse_body(request)?;
+
+        Ok(match self.app(request)?.bulk_import(data).await {
+            Ok(output) => Response::ok(json!({ "ok": true, "output": output })),
+            Err(output) => Response::ok(json!({ "ok": false, "output": output })),
+        })
+    }
+
+    fn app(&mut self, request: &Request) -> Result<&mut App, Response> {
+        let session = request
+            .bearer_token()
+            .and_then(|token| self.sessions.get_mut(token))
+            .ok_or_else(unauthorized)?;
+
+        self.clock += 1;
+        session.last_used = self.clock;
+        Ok(&mut session.app)
+    }
+}
+
+fn parse_body<T: DeserializeOwned>(request: &Request) -> Result<T, Response> {
+    serde_json::from_slice(&request.body)
+        .map_err(|e| Response::error(400, &format!("Invalid request body: {}", e)))
+}
+
+fn unauthorized() -> Response {
+    Response::error(
+        401,
+        "Missing or unknown session token. Create a session with POST /sessions.",
+    )
+}
+
+fn dispatch_event(event: Event) {
+    EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+/// The events dispatched during the current request, as JSON. Import events are included so that
+/// the client can respond to the `import` command by calling `POST /import`, and changes so that
+/// it can keep any copies of the journal up to date.
+fn take_events() -> Vec<Value> {
+    EVENTS.with(|events| {
+        events
+            .borrow_mut()
+            .drain(..)
+            .map(|event| match event {
+                Event::Export(data) => json!({ "type": "export", "data": data }),
+                Event::Import => json!({ "type": "import" }),
+                Event::ThingCreated(thing) => json!({ "type": "thingCreated", "thing": thing }),
+                Event::ThingEdited(thing) => json!({ "type": "thingEdited", "thing": thing }),
+                Event::ThingDeleted { uuid, name } => {
+                    json!({ "type": "thingDeleted", "uuid": uuid, "name": name })
+                }
+                Event::TimeChanged(time) => {
+                    json!({ "type": "timeChanged", "time": time.display_short().to_string() })
+                }
+                Event::Undo => json!({ "type": "undo" }),
+                Event::Redo => json!({ "type": "redo" }),
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(method: &str, path: &str, token: Option<&str>, body: Value) -> Request {
+        let mut request = Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string().into_bytes(),
+            ..Default::default()
+        };
+
+        if let Some(token) = token {
+            request
+                .headers
+                .insert("authorization".to_string(), format!("Bearer {}", token));
+        }
+
+        request
+    }
+
+    async fn create_session(api: &mut Api) -> String {
+        let response = api
+            .handle(&request("POST", "/sessions", None, Value::Null))
+            .await;
+
+        assert_eq!(201, response.status);
+        response.body.unwrap()["token"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn command_test() {
+        let mut api = Api::default();
+        let token = create_session(&mut api).await;
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "roll d1" }),
+            ))
+            .await;
+
+        assert_eq!(
+            Response::ok(json!({ "ok": true, "output": "[1] = **1**", "events": [] })),
+            response,
+        );
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "roll d1", "format": "html" }),
+            ))
+            .await;
+
+        assert_eq!(
+            json!("<p><span class=\"roll\">[1] = <strong>1</strong></span></p>"),
+            response.body.unwrap()["output"],
+        );
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "roll banana" }),
+            ))
+            .await;
+
+        assert_eq!(json!(false), response.body.unwrap()["ok"]);
+    }
+
+    #[tokio::test]
+    async fn command_test_events() {
+        let mut api = Api::default();
+        let token = create_session(&mut api).await;
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "import" }),
+            ))
+            .await;
+
+        assert_eq!(
+            json!([{ "type": "import" }]),
+            response.body.unwrap()["events"],
+        );
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "npc named Odysseus" }),
+            ))
+            .await;
+
+        let events = &response.body.unwrap()["events"];
+        assert_eq!(json!("thingCreated"), events[0]["type"], "{}", events);
+        assert_eq!(json!("Odysseus"), events[0]["thing"]["name"], "{}", events);
+    }
+
+    #[tokio::test]
+    async fn sessions_test() {
+        let mut api = Api::default();
+        let (token_1, token_2) = (
+            create_session(&mut api).await,
+            create_session(&mut api).await,
+        );
+        assert_ne!(token_1, token_2);
+
+        let save = json!({ "input": "npc named Odysseus" });
+        api.handle(&request("POST", "/command", Some(&token_1), save))
+            .await;
+        let save = json!({ "input": "save Odysseus" });
+        api.handle(&request("POST", "/command", Some(&token_1), save))
+            .await;
+
+        let autocomplete = |token| {
+            request(
+                "POST",
+                "/autocomplete",
+                Some(token),
+                json!({ "input": "Odys" }),
+            )
+        };
+
+        assert_eq!(
+            json!("Odysseus"),
+            api.handle(&autocomplete(&token_1)).await.body.unwrap()["suggestions"][0][0],
+        );
+        assert_eq!(
+            json!([]),
+            api.handle(&autocomplete(&token_2)).await.body.unwrap()["suggestions"],
+        );
+
+        assert_eq!(
+            Response::no_content(),
+            api.handle(&request("DELETE", "/sessions", Some(&token_1), Value::Null))
+                .await,
+        );
+        assert_eq!(401, api.handle(&autocomplete(&token_1)).await.status);
+    }
+
+    #[tokio::test]
+    async fn sessions_test_limit() {
+        let mut api = Api::default();
+        let (token_1, token_2) = (
+            create_session(&mut api).await,
+            create_session(&mut api).await,
+        );
+
+        for _ in 2..MAX_SESSIONS {
+            create_session(&mut api).await;
+        }
+
+        let roll = |token| request("POST", "/command", Some(token), json!({ "input": "d1" }));
+        assert_eq!(200, api.handle(&roll(&token_1)).await.status);
+
+        let token_3 = create_session(&mut api).await;
+        assert_eq!(MAX_SESSIONS, api.sessions.len());
+
+        assert_eq!(200, api.handle(&roll(&token_1)).await.status);
+        assert_eq!(401, api.handle(&roll(&token_2)).await.status);
+        assert_eq!(200, api.handle(&roll(&token_3)).await.status);
+    }
+
+    #[tokio::test]
+    async fn export_import_test() {
+        let mut api = Api::default();
+        let (token_1, token_2) = (
+            create_session(&mut api).await,
+            create_session(&mut api).await,
+        );
+
+        let save = json!({ "input": "npc named Penelope" });
+        api.handle(&request("POST", "/command", Some(&token_1), save))
+            .await;
+        let save = json!({ "input": "save Penelope" });
+        api.handle(&request("POST", "/command", Some(&token_1), save))
+            .await;
+
+        let response = api
+            .handle(&request("POST", "/export", Some(&token_1), Value::Null))
+            .await;
+        assert_eq!(200, response.status);
+        let data = response.body.unwrap()["data"].clone();
+
+        let response = api
+            .handle(&request("POST", "/import", Some(&token_2), data))
+            .await;
+        assert_eq!(json!(true), response.body.as_ref().unwrap()["ok"]);
+
+        let response = api
+            .handle(&request(
+                "POST",
+                "/command",
+                Some(&token_2),
+                json!({ "input": "load Penelope" }),
+            ))
+            .await;
+        assert_eq!(json!(true), response.body.unwrap()["ok"]);
+    }
+
+    #[tokio::test]
+    async fn handle_test_errors() {
+        let mut api = Api::default();
+        let token = create_session(&mut api).await;
+
+        assert_eq!(
+            404,
+            api.handle(&request("GET", "/", None, Value::Null))
+                .await
+                .status,
+        );
+        assert_eq!(
+            405,
+            api.handle(&request("GET", "/command", None, Value::Null))
+                .await
+                .status,
+        );
+        assert_eq!(
+            401,
+            api.handle(&request(
+                "POST",
+                "/command",
+                Some("potato"),
+                json!({ "input": "npc" }),
+            ))
+            .await
+            .status,
+        );
+        assert_eq!(
+            400,
+            api.handle(&request(
+                "POST",
+                "/command",
+                Some(&token),
+                json!({ "input": "npc", "format": "pdf" }),
+            ))
+            .await
+            .status,
+        );
+    }
+}
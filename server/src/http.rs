@@ -0,0 +1,270 @@
+//! Just enough HTTP/1.1 to serve a JSON API to clients on the same machine. Each connection
+//! carries a single request, after which it is closed.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Requests larger than this are rejected rather than buffered, since the largest legitimate
+/// request is an imported journal backup.
+const MAX_BODY_LEN: usize = 16 * 1024 * 1024;
+
+/// The request line and each header line are limited to this many bytes, so that a client can't
+/// make the server buffer a line that never ends.
+const MAX_LINE_LEN: u64 = 8 * 1024;
+
+/// Requests with more headers than this are rejected.
+const MAX_HEADERS: usize = 64;
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+
+    /// Header names are lowercased.
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Option<Value>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// The token from an `Authorization: Bearer [token]` header.
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.header("authorization")?
+            .strip_prefix("Bearer ")
+            .map(str::trim)
+    }
+}
+
+impl Response {
+    pub fn ok(body: Value) -> Response {
+        Response {
+            status: 200,
+            body: Some(body),
+        }
+    }
+
+    pub fn no_content() -> Response {
+        Response {
+            status: 204,
+            body: None,
+        }
+    }
+
+    /// An error response with a body of the form `{"error": "[message]"}`.
+    pub fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: Some(serde_json::json!({ "error": message })),
+        }
+    }
+}
+
+/// Read a request from the stream, returning `Ok(None)` if the client disconnected or sent
+/// something that isn't HTTP.
+pub async fn read_request(stream: &mut (impl AsyncBufRead + Unpin)) -> io::Result<Option<Request>> {
+    let mut line = String::new();
+
+    if !read_line(stream, &mut line).await? {
+        return Ok(None);
+    }
+
+    let mut request = {
+        let mut parts = line.split_whitespace();
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(method), Some(path), Some(version)) if version.starts_with("HTTP/1.") => {
+                Request {
+                    method: method.to_string(),
+                    path: path.to_string(),
+                    ..Default::default()
+                }
+            }
+            _ => return Ok(None),
+        }
+    };
+
+    let mut header_count = 0;
+
+    loop {
+        if !read_line(stream, &mut line).await? {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        header_count += 1;
+
+        if header_count > MAX_HEADERS {
+            return Ok(None);
+        } else if let Some((name, value)) = line.split_once(':') {
+            request
+                .headers
+                .insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = match request.header("content-length").map(str::parse::<usize>) {
+        Some(Ok(len)) if len <= MAX_BODY_LEN => len,
+        Some(_) => return Ok(None),
+        None => 0,
+    };
+
+    request.body.resize(content_length, 0);
+    stream.read_exact(&mut request.body).await?;
+
+    Ok(Some(request))
+}
+
+/// Replace the contents of `line` with the next line from the stream, returning `false` if the
+/// stream ended before the end of the line or the line exceeded [`MAX_LINE_LEN`].
+async fn read_line(
+    stream: &mut (impl AsyncBufRead + Unpin),
+    line: &mut String,
+) -> io::Result<bool> {
+    line.clear();
+    (&mut *stream).take(MAX_LINE_LEN).read_line(line).await?;
+    Ok(line.ends_with('\n'))
+}
+
+pub async fn write_response(
+    stream: &mut (impl AsyncWrite + Unpin),
+    response: &Response,
+) -> io::Result<()> {
+    let body = response
+        .body
+        .as_ref()
+        .map(|body| body.to_string())
+        .unwrap_or_default();
+
+    let mut output = format!(
+        "HTTP/1.1 {} {}\r\nConnection: close\r\nContent-Length: {}\r\n",
+        response.status,
+        reason(response.status),
+        body.len(),
+    );
+
+    if response.body.is_some() {
+        output.push_str("Content-Type: application/json\r\n");
+    }
+
+    output.push_str("\r\n");
+    output.push_str(&body);
+
+    stream.write_all(output.as_bytes()).await?;
+    stream.flush().await
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    async fn parse(input: &str) -> Option<Request> {
+        read_request(&mut input.as_bytes()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn read_request_test() {
+        let request = parse(
+            "POST /command HTTP/1.1\r\nAuthorization: Bearer abc123\r\nContent-Length: 16\r\n\r\n{\"input\":\"npc\"}\n",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!("POST", request.method);
+        assert_eq!("/command", request.path);
+        assert_eq!(Some("abc123"), request.bearer_token());
+        assert_eq!(b"{\"input\":\"npc\"}\n", &request.body[..]);
+    }
+
+    #[tokio::test]
+    async fn read_request_test_invalid() {
+        assert_eq!(None, parse("").await);
+        assert_eq!(None, parse("hello\r\n\r\n").await);
+        assert_eq!(None, parse("GET / HTTP/1.1\r\nHost: localhost").await);
+        assert_eq!(
+            None,
+            parse("POST / HTTP/1.1\r\nContent-Length: potato\r\n\r\n").await,
+        );
+    }
+
+    #[tokio::test]
+    async fn read_request_test_limits() {
+        let long_header = format!("X-Padding: {}\r\n", "a".repeat(MAX_LINE_LEN as usize));
+        assert_eq!(
+            None,
+            parse(&format!("GET / HTTP/1.1\r\n{}\r\n", long_header)).await,
+        );
+
+        let headers = |count| {
+            (0..count)
+                .map(|i| format!("X-Header: {}\r\n", i))
+                .collect::<String>()
+        };
+        assert!(
+            parse(&format!("GET / HTTP/1.1\r\n{}\r\n", headers(MAX_HEADERS)))
+                .await
+                .is_some()
+        );
+        assert_eq!(
+            None,
+            parse(&format!(
+                "GET / HTTP/1.1\r\n{}\r\n",
+                headers(MAX_HEADERS + 1)
+            ))
+            .await,
+        );
+    }
+
+    #[tokio::test]
+    async fn write_response_test() {
+        let mut output = Vec::new();
+
+        write_response(&mut output, &Response::error(404, "Not found."))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            "HTTP/1.1 404 Not Found\r\nConnection: close\r\nContent-Length: 22\r\nContent-Type: application/json\r\n\r\n{\"error\":\"Not found.\"}",
+            String::from_utf8(output).unwrap(),
+        );
+
+        let mut output = Vec::new();
+
+        write_response(&mut output, &Response::no_content())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            "HTTP/1.1 204 No Content\r\nConnection: close\r\nContent-Length: 0\r\n\r\n",
+            String::from_utf8(output).unwrap(),
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! A local HTTP server exposing the initiative.sh [`App`] as a JSON API, so that it can be driven
+//! by other programs running on the same machine, such as chat bots and virtual tabletop macros.
+//!
+//! Start by creating a session, which returns a token identifying an independent instance of the
+//! app with its own in-memory journal:
+//!
+//! ```text
+//! POST /sessions                     -> 201 {"token": "...", "motd": "..."}
+//! ```
+//!
+//! At most 64 sessions are kept at a time. Creating another closes the one that has gone the
+//! longest without a request.
+//!
+//! All other requests must include the header `Authorization: Bearer [token]`:
+//!
+//! ```text
+//! POST /command      {"input": "npc", "format": "markdown"}
+//!                                    -> 200 {"ok": true, "output": "...", "events": [...]}
+//! POST /autocomplete {"input": "np"} -> 200 {"suggestions": [["npc", "generate NPC"], ...]}
+//! POST /export                       -> 200 {"data": {...}}
+//! POST /import       {...}           -> 200 {"ok": true, "output": "..."}
+//! DELETE /sessions                   -> 204
+//! ```
+//!
+//! The `format` of command output may be `markdown` (the default), `ansi`, `html`, or `json` for
+//! a structured representation. Events triggered by a command, such as the `export` command, are
+//! returned alongside its output rather than pushed to the client.
+//!
+//! [`App`]: initiative_core::App
+
+mod api;
+mod http;
+
+pub use api::Api;
+
+use std::io;
+use std::rc::Rc;
+use tokio::io::BufReader;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tokio::task::{self, LocalSet};
+
+/// Accept connections on `listener` until an I/O error occurs.
+pub async fn run(listener: TcpListener) -> io::Result<()> {
+    LocalSet::new().run_until(serve(listener)).await
+}
+
+/// As [`run`], but must be called from within a [`LocalSet`], since the app isn't thread-safe.
+pub async fn serve(listener: TcpListener) -> io::Result<()> {
+    let api = Rc::new(Mutex::new(Api::default()));
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let api = api.clone();
+
+        task::spawn_local(async move {
+            // There is nobody to report a broken connection to.
+            let _ = handle_connection(stream, &api).await;
+        });
+    }
+}
+
+async fn handle_connection(stream: TcpStream, api: &Mutex<Api>) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+
+    let response = match http::read_request(&mut BufReader::new(reader)).await? {
+        Some(request) => api.lock().await.handle(&request).await,
+        None => http::Response::error(400, "Malformed request."),
+    };
+
+    http::write_response(&mut writer, &response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::net::SocketAddr;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    async fn send(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn serve_test() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        LocalSet::new()
+            .run_until(async move {
+                task::spawn_local(serve(listener));
+
+                let response = send(addr, "POST /sessions HTTP/1.1\r\n\r\n").await;
+                assert!(response.starts_with("HTTP/1.1 201 Created\r\n"), "{}", response);
+
+                let (_, body) = response.split_once("\r\n\r\n").unwrap();
+                let body: serde_json::Value = serde_json::from_str(body).unwrap();
+                let token = body["token"].as_str().unwrap();
+
+                let request_body = r#"{"input":"roll d1"}"#;
+                let response = send(
+                    addr,
+                    &format!(
+                        "POST /command HTTP/1.1\r\nAuthorization: Bearer {}\r\nContent-Length: {}\r\n\r\n{}",
+                        token,
+                        request_body.len(),
+                        request_body,
+                    ),
+                )
+                .await;
+
+                assert!(
+                    response.ends_with(r#"{"events":[],"ok":true,"output":"[1] = **1**"}"#),
+                    "{}",
+                    response,
+                );
+
+                let response = send(addr, "potato\r\n\r\n").await;
+                assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{}", response);
+            })
+            .await;
+    }
+}
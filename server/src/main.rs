@@ -0,0 +1,82 @@
+use std::env;
+use std::error::Error;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::process;
+use tokio::net::TcpListener;
+
+const USAGE: &str = "Usage: initiative-server [--port NUMBER]";
+
+const DEFAULT_PORT: u16 = 8080;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let port = match parse_args(env::args().skip(1)) {
+        Ok(port) => port,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // Only listen on the loopback interface, since there is no authentication beyond the session
+    // token.
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port))).await?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+
+    initiative_server::run(listener).await?;
+
+    Ok(())
+}
+
+/// Parse arguments of the form `--port 8080` or `--port=8080`.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<u16, String> {
+    let mut port = DEFAULT_PORT;
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+
+        match flag.as_str() {
+            "--port" => {
+                port = inline_value
+                    .or_else(|| args.next())
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| "--port requires a number, eg. --port 8080".to_string())?;
+            }
+            _ => {
+                return Err(format!(
+                    "Unrecognized argument: {}",
+                    inline_value.map_or(flag.clone(), |v| format!("{}={}", flag, v)),
+                ))
+            }
+        }
+    }
+
+    Ok(port)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<u16, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(Ok(DEFAULT_PORT), parse(&[]));
+        assert_eq!(Ok(1234), parse(&["--port", "1234"]));
+        assert_eq!(Ok(1234), parse(&["--port=1234"]));
+        assert_eq!(
+            Err("--port requires a number, eg. --port 8080".to_string()),
+            parse(&["--port", "potato"]),
+        );
+        assert_eq!(
+            Err("Unrecognized argument: potato".to_string()),
+            parse(&["potato"]),
+        );
+    }
+}
@@ -41,5 +41,5 @@ terminalElement.addEventListener(
 
 terminalElement.addEventListener(
   "initiative.command",
-  async (event) => terminal.output(await wasm.command(event.detail.command)),
+  async (event) => terminal.outputHtml(await wasm.command_html(event.detail.command)),
 )
@@ -332,9 +332,13 @@ function reducedMotion() {
 }
 
 function output(text) {
+  outputHtml(marked(text))
+}
+
+function outputHtml(html) {
   let outputBlock = document.createElement("div")
   outputBlock.className = "output-block"
-  outputBlock.insertAdjacentHTML("beforeend", marked(text))
+  outputBlock.insertAdjacentHTML("beforeend", html)
   document.getElementById("output").insertAdjacentElement("beforeend", outputBlock)
 
   window.scroll({
@@ -344,4 +348,4 @@ function output(text) {
   })
 }
 
-export default { initialize, output }
+export default { initialize, output, outputHtml }
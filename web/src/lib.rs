@@ -34,6 +34,33 @@ pub async fn command(input: JsValue) -> JsValue {
     }
 }
 
+/// Like [`command`], but renders the structured output as HTML rather than markdown. Errors are
+/// rendered inline as error blocks.
+#[wasm_bindgen]
+pub async fn command_html(input: JsValue) -> JsValue {
+    if let Some(input) = input.as_string() {
+        app()
+            .command_output(&input)
+            .await
+            .unwrap_or_else(|e| e)
+            .to_html()
+            .into()
+    } else {
+        JsValue::undefined()
+    }
+}
+
+/// Like [`command`], but returns the structured output as a JS object for the frontend to render
+/// however it likes.
+#[wasm_bindgen]
+pub async fn command_json(input: JsValue) -> JsValue {
+    if let Some(input) = input.as_string() {
+        JsValue::from_serde(&app().command_output(&input).await.unwrap_or_else(|e| e)).unwrap()
+    } else {
+        JsValue::undefined()
+    }
+}
+
 #[wasm_bindgen]
 pub async fn autocomplete(input: JsValue) -> JsValue {
     if let Some(input) = input.as_string() {
@@ -63,6 +90,9 @@ fn event_dispatcher(event: core::Event) {
             CustomEvent::new_with_event_init_dict("initiative.export", &init).unwrap()
         }
         core::Event::Import => CustomEvent::new("initiative.startImport").unwrap(),
+
+        // The web frontend doesn't display anything that needs to be kept in sync with changes.
+        _ => return,
     };
 
     get_root_element()
@@ -80,7 +110,7 @@ fn app() -> &'static mut core::app::App {
     unsafe {
         if APP.is_none() {
             let data_store = DataStore;
-            APP = Some(core::app(data_store, &event_dispatcher));
+            APP = Some(core::app(data_store, event_dispatcher));
         }
 
         APP.as_mut().unwrap()
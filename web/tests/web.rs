@@ -1,6 +1,7 @@
 #![cfg(target_arch = "wasm32")]
 
 use initiative_web::app;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -76,3 +77,42 @@ async fn memory_exhaustion() {
         );
     }
 }
+
+#[wasm_bindgen_test]
+async fn command_html() {
+    let output = initiative_web::command_html("about".into())
+        .await
+        .as_string()
+        .unwrap();
+    assert!(output.contains("initiative.sh"), "{}", output);
+    assert!(output.starts_with('<'), "{}", output);
+
+    let output = initiative_web::command_html("Nobody is elderly".into())
+        .await
+        .as_string()
+        .unwrap();
+    assert!(output.contains("Nobody"), "{}", output);
+
+    assert!(initiative_web::command_html(JsValue::undefined())
+        .await
+        .is_undefined());
+}
+
+#[wasm_bindgen_test]
+async fn command_json() {
+    let output = initiative_web::command_json("about".into()).await;
+    assert!(output.is_object());
+
+    let blocks = js_sys::Reflect::get(&output, &"blocks".into()).unwrap();
+    assert!(js_sys::Array::is_array(&blocks));
+
+    let output = js_sys::JSON::stringify(&output)
+        .unwrap()
+        .as_string()
+        .unwrap();
+    assert!(output.contains("initiative.sh"), "{}", output);
+
+    assert!(initiative_web::command_json(JsValue::undefined())
+        .await
+        .is_undefined());
+}